@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS fish_specimens_pond_id_index;
+ALTER TABLE fish_specimens
+    DROP COLUMN IF EXISTS pond_id;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE fish_specimens
+    DROP COLUMN IF EXISTS catch_seed;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE fish_specimens
+    ADD COLUMN IF NOT EXISTS catch_seed BIGINT DEFAULT NULL;
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN IF EXISTS smallest_catch_specimen_id,
+    DROP COLUMN IF EXISTS smallest_catch_size_mm,
+    DROP COLUMN IF EXISTS smallest_catch_weight_g,
+    DROP COLUMN IF EXISTS smallest_catch_time,
+    DROP COLUMN IF EXISTS largest_catch_specimen_id,
+    DROP COLUMN IF EXISTS largest_catch_size_mm,
+    DROP COLUMN IF EXISTS largest_catch_weight_g,
+    DROP COLUMN IF EXISTS largest_catch_time;
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN IF NOT EXISTS smallest_catch_specimen_id BIGINT      DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS smallest_catch_size_mm     REAL        DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS smallest_catch_weight_g    REAL        DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS smallest_catch_time        TIMESTAMPTZ DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS largest_catch_specimen_id  BIGINT      DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS largest_catch_size_mm      REAL        DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS largest_catch_weight_g     REAL        DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS largest_catch_time         TIMESTAMPTZ DEFAULT NULL;
\ No newline at end of file
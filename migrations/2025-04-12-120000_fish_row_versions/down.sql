@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE fish_users
+    DROP COLUMN IF EXISTS version;
+ALTER TABLE fish_specimens
+    DROP COLUMN IF EXISTS version;
+ALTER TABLE fish_ponds
+    DROP COLUMN IF EXISTS version;
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN IF EXISTS version;
+ALTER TABLE fish_items
+    DROP COLUMN IF EXISTS version;
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fish_users
+(
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    external_id BIGINT    NOT NULL UNIQUE,
+    credits     BIGINT    NOT NULL DEFAULT 0,
+    created_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    timezone    TEXT      NOT NULL DEFAULT 'UTC'
+);
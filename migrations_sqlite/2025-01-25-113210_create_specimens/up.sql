@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fish_specimens
+(
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id             BIGINT    NOT NULL REFERENCES fish_users (id),
+    species_id          INTEGER   NOT NULL,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    size_baby_ratio     REAL      NOT NULL,
+    size_adult_ratio    REAL      NOT NULL,
+    lifespan_days_ratio REAL      NOT NULL,
+    catch_age           REAL      NOT NULL
+);
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fish_ponds
+(
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id    BIGINT    NOT NULL REFERENCES fish_users (id),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    capacity   INTEGER   NOT NULL
+);
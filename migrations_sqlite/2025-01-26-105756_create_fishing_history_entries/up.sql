@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fish_fishing_history_entries
+(
+    id                        INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id                   BIGINT    NOT NULL REFERENCES fish_users (id),
+    species_id                INTEGER   NOT NULL,
+    created_at                TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at                TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    caught_count              INTEGER   NOT NULL,
+    sold_count                INTEGER   NOT NULL,
+    smallest_catch_size_ratio REAL      NOT NULL,
+    largest_catch_size_ratio  REAL      NOT NULL,
+    last_catch                TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    first_sell                TIMESTAMP          DEFAULT NULL,
+    last_sell                 TIMESTAMP          DEFAULT NULL
+);
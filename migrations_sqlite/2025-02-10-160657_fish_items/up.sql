@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fish_items
+(
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id    BIGINT    NOT NULL REFERENCES fish_users (id),
+    type_id    INTEGER   NOT NULL,
+    properties TEXT      NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,23 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS fish_specimens_pond_id_index;
+
+-- SQLite can't drop a column with a foreign key, so the table is rebuilt without it
+CREATE TABLE fish_specimens_without_pond
+(
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id             BIGINT    NOT NULL REFERENCES fish_users (id),
+    species_id          INTEGER   NOT NULL,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    size_baby_ratio     REAL      NOT NULL,
+    size_adult_ratio    REAL      NOT NULL,
+    lifespan_days_ratio REAL      NOT NULL,
+    catch_age           REAL      NOT NULL
+);
+
+INSERT INTO fish_specimens_without_pond
+SELECT id, user_id, species_id, created_at, updated_at, size_baby_ratio, size_adult_ratio, lifespan_days_ratio, catch_age
+FROM fish_specimens;
+
+DROP TABLE fish_specimens;
+ALTER TABLE fish_specimens_without_pond RENAME TO fish_specimens;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE fish_specimens
+    ADD COLUMN pond_id BIGINT DEFAULT NULL REFERENCES fish_ponds (id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS fish_specimens_pond_id_index ON fish_specimens (pond_id);
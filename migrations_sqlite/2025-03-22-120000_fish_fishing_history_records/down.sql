@@ -0,0 +1,17 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN smallest_catch_specimen_id;
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN smallest_catch_size_mm;
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN smallest_catch_weight_g;
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN smallest_catch_time;
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN largest_catch_specimen_id;
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN largest_catch_size_mm;
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN largest_catch_weight_g;
+ALTER TABLE fish_fishing_history_entries
+    DROP COLUMN largest_catch_time;
\ No newline at end of file
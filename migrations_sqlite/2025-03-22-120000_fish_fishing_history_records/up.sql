@@ -0,0 +1,17 @@
+-- Your SQL goes here
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN smallest_catch_specimen_id BIGINT DEFAULT NULL;
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN smallest_catch_size_mm REAL DEFAULT NULL;
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN smallest_catch_weight_g REAL DEFAULT NULL;
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN smallest_catch_time TIMESTAMP DEFAULT NULL;
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN largest_catch_specimen_id BIGINT DEFAULT NULL;
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN largest_catch_size_mm REAL DEFAULT NULL;
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN largest_catch_weight_g REAL DEFAULT NULL;
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN largest_catch_time TIMESTAMP DEFAULT NULL;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fish_user_achievements
+(
+    user_id        BIGINT    NOT NULL REFERENCES fish_users (id),
+    achievement_id INTEGER   NOT NULL,
+    unlocked_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, achievement_id)
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS fish_sale_events;
+DROP TABLE IF EXISTS fish_catch_events;
\ No newline at end of file
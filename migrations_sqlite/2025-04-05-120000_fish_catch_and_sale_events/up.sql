@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fish_catch_events
+(
+    id               INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id          BIGINT    NOT NULL REFERENCES fish_users (id),
+    species_id       INTEGER   NOT NULL,
+    specimen_id      BIGINT    NOT NULL,
+    total_size_ratio REAL      NOT NULL,
+    size_mm          REAL      NOT NULL,
+    weight_g         REAL      NOT NULL,
+    caught_at        TIMESTAMP NOT NULL
+);
+CREATE INDEX IF NOT EXISTS fish_catch_events_caught_at_index ON fish_catch_events (caught_at);
+
+CREATE TABLE IF NOT EXISTS fish_sale_events
+(
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id     BIGINT    NOT NULL REFERENCES fish_users (id),
+    species_id  INTEGER   NOT NULL,
+    specimen_id BIGINT    NOT NULL,
+    sold_at     TIMESTAMP NOT NULL
+);
+CREATE INDEX IF NOT EXISTS fish_sale_events_sold_at_index ON fish_sale_events (sold_at);
\ No newline at end of file
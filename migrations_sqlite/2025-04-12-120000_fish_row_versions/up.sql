@@ -0,0 +1,11 @@
+-- Your SQL goes here
+ALTER TABLE fish_users
+    ADD COLUMN version BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE fish_specimens
+    ADD COLUMN version BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE fish_ponds
+    ADD COLUMN version BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE fish_fishing_history_entries
+    ADD COLUMN version BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE fish_items
+    ADD COLUMN version BIGINT NOT NULL DEFAULT 0;
\ No newline at end of file
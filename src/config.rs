@@ -1,9 +1,10 @@
 use crate::config::validation_error::ConfigValidationError;
 use crate::config::validation_report::ConfigValidationReport;
+use crate::data::achievement_data::{AchievementData, AchievementRequirement};
 use crate::data::encounter_data::EncounterData;
 use crate::data::item_data::ItemData;
 use crate::data::location_data::LocationData;
-use crate::data::settings::Settings;
+use crate::data::settings::{Settings, MAX_TIME_SPEED_MULTIPLIER};
 use crate::data::species_data::SpeciesData;
 use crate::enums::item_category::ItemCategory;
 use crate::models::item::attributes::ItemAttributesType;
@@ -23,6 +24,7 @@ pub trait ConfigInterface: Debug + Send + Sync {
     fn species(&self) -> Arc<HashMap<i32, Arc<SpeciesData>>>;
     fn locations(&self) -> Arc<HashMap<i32, Arc<LocationData>>>;
     fn items(&self) -> Arc<HashMap<i32, Arc<ItemData>>>;
+    fn achievements(&self) -> Arc<HashMap<i32, Arc<AchievementData>>>;
     fn settings(&self) -> Arc<Settings>;
     fn species_names(&self) -> Arc<HashMap<i32, String>>;
     fn location_names(&self) -> Arc<HashMap<i32, String>>;
@@ -44,6 +46,10 @@ pub trait ConfigInterface: Debug + Send + Sync {
         self.items().get(&item_id).cloned()
     }
 
+    fn get_achievement_data(&self, achievement_id: i32) -> Option<Arc<AchievementData>> {
+        self.achievements().get(&achievement_id).cloned()
+    }
+
     fn get_items_by_attributes_type(
         &self,
         attributes_type: ItemAttributesType,
@@ -86,6 +92,7 @@ pub struct Config {
     species: Arc<HashMap<i32, Arc<SpeciesData>>>,
     locations: Arc<HashMap<i32, Arc<LocationData>>>,
     items: Arc<HashMap<i32, Arc<ItemData>>>,
+    achievements: Arc<HashMap<i32, Arc<AchievementData>>>,
     settings: Arc<Settings>,
     species_names: Arc<HashMap<i32, String>>,
     location_names: Arc<HashMap<i32, String>>,
@@ -109,6 +116,10 @@ impl ConfigInterface for Config {
         self.items.clone()
     }
 
+    fn achievements(&self) -> Arc<HashMap<i32, Arc<AchievementData>>> {
+        self.achievements.clone()
+    }
+
     fn settings(&self) -> Arc<Settings> {
         self.settings.clone()
     }
@@ -262,6 +273,32 @@ impl ConfigBuilder {
         Ok(self.items(items))
     }
 
+    pub fn achievements(mut self, achievements: HashMap<i32, AchievementData>) -> Self {
+        let achievements = achievements
+            .into_iter()
+            .map(|(id, mut data)| {
+                data.id = id;
+                (id, Arc::new(data))
+            })
+            .collect();
+        self.config.achievements = Arc::new(achievements);
+        self
+    }
+
+    pub fn achievements_json(self, json_string: &str) -> Result<Self, serde_json::Error> {
+        let achievements: HashMap<i32, AchievementData> = serde_json::from_str(json_string)?;
+        Ok(self.achievements(achievements))
+    }
+
+    pub fn achievements_json_file(
+        self,
+        json_file_path: &Path,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let file = File::open(json_file_path)?;
+        let achievements: HashMap<i32, AchievementData> = serde_json::from_reader(file)?;
+        Ok(self.achievements(achievements))
+    }
+
     pub fn settings(mut self, settings: Settings) -> Self {
         self.config.settings = Arc::new(settings);
         self
@@ -408,11 +445,29 @@ impl ConfigBuilder {
         self.validate_species(&mut report);
         self.validate_locations(&mut report);
         self.validate_items(&mut report);
+        self.validate_achievements(&mut report);
+        self.validate_settings(&mut report);
         report
     }
 
+    fn validate_settings(&self, report: &mut ConfigValidationReport) {
+        let time_speed_multiplier = self.config.settings.time_speed_multiplier;
+        if !time_speed_multiplier.is_finite()
+            || time_speed_multiplier <= 0.0
+            || time_speed_multiplier > MAX_TIME_SPEED_MULTIPLIER
+        {
+            report.add_error(
+                ConfigValidationError::settings_invalid_time_speed_multiplier(
+                    time_speed_multiplier,
+                ),
+            );
+        }
+    }
+
     fn validate_locations(&self, report: &mut ConfigValidationReport) {
         for location_data in self.config.locations.values() {
+            self.validate_locations_coordinates(report, location_data);
+
             for required_location_id in &location_data.required_locations_unlocked {
                 self.validate_locations_required_locations(
                     report,
@@ -431,6 +486,26 @@ impl ConfigBuilder {
         }
     }
 
+    fn validate_locations_coordinates(
+        &self,
+        report: &mut ConfigValidationReport,
+        location_data: &Arc<LocationData>,
+    ) {
+        let valid = match (location_data.latitude, location_data.longitude) {
+            (None, None) => true,
+            (Some(latitude), Some(longitude)) => {
+                (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude)
+            }
+            _ => false,
+        };
+
+        if !valid {
+            report.add_error(ConfigValidationError::location_invalid_coordinates(
+                location_data.id,
+            ));
+        }
+    }
+
     fn validate_locations_required_locations(
         &self,
         report: &mut ConfigValidationReport,
@@ -478,12 +553,120 @@ impl ConfigBuilder {
         encounter_data: &EncounterData,
     ) {
         let location_id = encounter_data.location_id;
-        if self.config.get_location_data(location_id).is_none() {
-            report.add_error(ConfigValidationError::species_encounter_location(
+        match self.config.get_location_data(location_id) {
+            None => report.add_error(ConfigValidationError::species_encounter_location(
                 species_data.id,
                 location_id,
+            )),
+            Some(location_data) => {
+                if encounter_data.needs_tides() && !location_data.coastal {
+                    report.add_error(ConfigValidationError::species_encounter_tides_not_coastal(
+                        species_data.id,
+                        location_id,
+                    ));
+                }
+            }
+        }
+
+        if encounter_data.has_preferences() != encounter_data.preferred_rarity_level.is_some() {
+            report.add_error(ConfigValidationError::species_encounter_invalid_preference(
+                species_data.id,
             ));
         }
+
+        for hour in [encounter_data.min_time_hour, encounter_data.max_time_hour] {
+            if hour > 23 {
+                report.add_error(ConfigValidationError::species_encounter_invalid_hour(
+                    species_data.id,
+                    hour,
+                ));
+            }
+        }
+
+        for minute in [
+            encounter_data.min_time_minute,
+            encounter_data.max_time_minute,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if minute > 59 {
+                report.add_error(ConfigValidationError::species_encounter_invalid_minute(
+                    species_data.id,
+                    minute,
+                ));
+            }
+        }
+
+        if encounter_data.needs_rain && encounter_data.needs_no_rain {
+            report.add_error(ConfigValidationError::species_encounter_conflicting_rain(
+                species_data.id,
+            ));
+        }
+
+        let weather_conditions = &encounter_data.weather_conditions;
+        if !weather_conditions.is_empty() {
+            let any_precipitation = weather_conditions
+                .iter()
+                .any(|condition| condition.is_precipitation());
+            let any_dry = weather_conditions
+                .iter()
+                .any(|condition| !condition.is_precipitation());
+            if (encounter_data.needs_rain && !any_precipitation)
+                || (encounter_data.needs_no_rain && !any_dry)
+            {
+                report.add_error(
+                    ConfigValidationError::species_encounter_conflicting_weather_conditions(
+                        species_data.id,
+                    ),
+                );
+            }
+        }
+
+        if let (Some(min_temperature_c), Some(max_temperature_c)) = (
+            encounter_data.min_temperature_c,
+            encounter_data.max_temperature_c,
+        ) {
+            if min_temperature_c > max_temperature_c {
+                report.add_error(
+                    ConfigValidationError::species_encounter_invalid_temperature_range(
+                        species_data.id,
+                        min_temperature_c,
+                        max_temperature_c,
+                    ),
+                );
+            }
+        }
+
+        if let (Some(min_water_temperature_c), Some(max_water_temperature_c)) = (
+            encounter_data.min_water_temperature_c,
+            encounter_data.max_water_temperature_c,
+        ) {
+            if min_water_temperature_c > max_water_temperature_c {
+                report.add_error(
+                    ConfigValidationError::species_encounter_invalid_water_temperature_range(
+                        species_data.id,
+                        min_water_temperature_c,
+                        max_water_temperature_c,
+                    ),
+                );
+            }
+        }
+
+        let min_light_level = encounter_data.min_light_level.unwrap_or(0.0);
+        let max_light_level = encounter_data.max_light_level.unwrap_or(1.0);
+        if !(0.0..=1.0).contains(&min_light_level)
+            || !(0.0..=1.0).contains(&max_light_level)
+            || min_light_level > max_light_level
+        {
+            report.add_error(
+                ConfigValidationError::species_encounter_invalid_light_level_range(
+                    species_data.id,
+                    min_light_level,
+                    max_light_level,
+                ),
+            );
+        }
     }
 
     fn validate_items(&self, report: &mut ConfigValidationReport) {
@@ -499,4 +682,109 @@ impl ConfigBuilder {
             }
         }
     }
+
+    fn validate_achievements(&self, report: &mut ConfigValidationReport) {
+        for achievement_data in self.config.achievements.values() {
+            let achievement_id = achievement_data.id;
+            match &achievement_data.requirement {
+                AchievementRequirement::CatchCount { count, species_id } => {
+                    self.validate_achievements_count(report, achievement_id, *count, None);
+                    if let Some(species_id) = species_id {
+                        self.validate_achievements_species(report, achievement_id, *species_id);
+                    }
+                }
+                AchievementRequirement::SpeciesCount { count } => {
+                    let species_count = self.config.species.len() as u64;
+                    self.validate_achievements_count(
+                        report,
+                        achievement_id,
+                        *count,
+                        Some(species_count),
+                    );
+                }
+                AchievementRequirement::Catch {
+                    species_id,
+                    location_id,
+                    min_size_ratio,
+                    ..
+                } => {
+                    if let Some(species_id) = species_id {
+                        self.validate_achievements_species(report, achievement_id, *species_id);
+                    }
+                    if let Some(location_id) = location_id {
+                        if self.config.get_location_data(*location_id).is_none() {
+                            report.add_error(ConfigValidationError::achievement_location(
+                                achievement_id,
+                                *location_id,
+                            ));
+                        }
+                    }
+                    if let Some(min_size_ratio) = min_size_ratio {
+                        if !(0.0..=1.0).contains(min_size_ratio) {
+                            report.add_error(
+                                ConfigValidationError::achievement_invalid_size_ratio(
+                                    achievement_id,
+                                    *min_size_ratio,
+                                ),
+                            );
+                        }
+                    }
+                }
+                AchievementRequirement::SellCount { count } => {
+                    self.validate_achievements_count(report, achievement_id, *count, None);
+                }
+                AchievementRequirement::Credits { .. } => {}
+                AchievementRequirement::Purchase { item_id } => {
+                    if let Some(item_id) = item_id {
+                        if self.config.get_item_data(*item_id).is_none() {
+                            report.add_error(ConfigValidationError::achievement_item(
+                                achievement_id,
+                                *item_id,
+                            ));
+                        }
+                    }
+                }
+                AchievementRequirement::LocationsUnlocked { count } => {
+                    if let Some(count) = count {
+                        let location_count = self.config.locations.len() as u64;
+                        self.validate_achievements_count(
+                            report,
+                            achievement_id,
+                            *count,
+                            Some(location_count),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_achievements_count(
+        &self,
+        report: &mut ConfigValidationReport,
+        achievement_id: i32,
+        count: u64,
+        max_count: Option<u64>,
+    ) {
+        if count < 1 || max_count.is_some_and(|max_count| count > max_count) {
+            report.add_error(ConfigValidationError::achievement_invalid_count(
+                achievement_id,
+                count,
+            ));
+        }
+    }
+
+    fn validate_achievements_species(
+        &self,
+        report: &mut ConfigValidationReport,
+        achievement_id: i32,
+        species_id: i32,
+    ) {
+        if self.config.get_species_data(species_id).is_none() {
+            report.add_error(ConfigValidationError::achievement_species(
+                achievement_id,
+                species_id,
+            ));
+        }
+    }
 }
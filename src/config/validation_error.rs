@@ -1,11 +1,41 @@
+use crate::data::settings::MAX_TIME_SPEED_MULTIPLIER;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum ConfigValidationError {
+    #[error("Achievement (ID: {source_achievement_id}): Invalid count '{count}', has to be at least 1 and can't exceed the configured species or locations")]
+    AchievementInvalidCount {
+        source_achievement_id: i32,
+        count: u64,
+    },
+    #[error("Achievement (ID: {source_achievement_id}): Invalid min_size_ratio '{min_size_ratio}', has to be within 0.0 and 1.0")]
+    AchievementInvalidSizeRatio {
+        source_achievement_id: i32,
+        min_size_ratio: f32,
+    },
+    #[error("Achievement (ID: {source_achievement_id}): Invalid item_id '{target_item_id}'")]
+    AchievementItem {
+        source_achievement_id: i32,
+        target_item_id: i32,
+    },
+    #[error(
+        "Achievement (ID: {source_achievement_id}): Invalid location_id '{target_location_id}'"
+    )]
+    AchievementLocation {
+        source_achievement_id: i32,
+        target_location_id: i32,
+    },
+    #[error("Achievement (ID: {source_achievement_id}): Invalid species_id '{target_species_id}'")]
+    AchievementSpecies {
+        source_achievement_id: i32,
+        target_species_id: i32,
+    },
     #[error("Item (ID: {source_item_id}): max_count has to be greater or equal 1")]
     ItemInvalidMaxCount { source_item_id: i32 },
     #[error("Item (ID: {source_item_id}): stackable items must have a max_count of 1")]
     ItemNonUniqueNotStackable { source_item_id: i32 },
+    #[error("Location (ID: {source_location_id}): Invalid coordinates, latitude and longitude have to be set together, within -90 to 90 and -180 to 180 degrees")]
+    LocationInvalidCoordinates { source_location_id: i32 },
     #[error("Location (ID: {source_location_id}): Invalid required_locations_unlocked location_id '{target_location_id}'")]
     LocationRequiredLocation {
         source_location_id: i32,
@@ -16,6 +46,11 @@ pub enum ConfigValidationError {
         source_location_id: i32,
         target_species_id: i32,
     },
+    #[error(
+        "Settings: time_speed_multiplier has to be greater than 0 and at most {}, got '{time_speed_multiplier}'",
+        MAX_TIME_SPEED_MULTIPLIER
+    )]
+    SettingsInvalidTimeSpeedMultiplier { time_speed_multiplier: f32 },
     #[error(
         "Species (ID: {source_species_id}): Invalid encounter location_id '{target_location_id}'"
     )]
@@ -23,9 +58,79 @@ pub enum ConfigValidationError {
         source_species_id: i32,
         target_location_id: i32,
     },
+    #[error("Species (ID: {source_species_id}): Invalid encounter hour '{hour}', has to be between 0 and 23")]
+    SpeciesEncounterInvalidHour { source_species_id: i32, hour: u8 },
+    #[error("Species (ID: {source_species_id}): Invalid encounter minute '{minute}', has to be between 0 and 59")]
+    SpeciesEncounterInvalidMinute { source_species_id: i32, minute: u8 },
+    #[error(
+        "Species (ID: {source_species_id}): Encounter can't need rain and no rain at the same time"
+    )]
+    SpeciesEncounterConflictingRain { source_species_id: i32 },
+    #[error("Species (ID: {source_species_id}): Encounter weather conditions can never be met with its rain requirement")]
+    SpeciesEncounterConflictingWeatherConditions { source_species_id: i32 },
+    #[error("Species (ID: {source_species_id}): Encounter depends on tides, but location '{target_location_id}' is not coastal")]
+    SpeciesEncounterTidesNotCoastal {
+        source_species_id: i32,
+        target_location_id: i32,
+    },
+    #[error("Species (ID: {source_species_id}): Encounter needs both preferred moon phases or tide states and a preferred_rarity_level")]
+    SpeciesEncounterInvalidPreference { source_species_id: i32 },
+    #[error("Species (ID: {source_species_id}): Invalid encounter temperature range '{min_temperature_c}' to '{max_temperature_c}'")]
+    SpeciesEncounterInvalidTemperatureRange {
+        source_species_id: i32,
+        min_temperature_c: f32,
+        max_temperature_c: f32,
+    },
+    #[error("Species (ID: {source_species_id}): Invalid encounter water temperature range '{min_water_temperature_c}' to '{max_water_temperature_c}'")]
+    SpeciesEncounterInvalidWaterTemperatureRange {
+        source_species_id: i32,
+        min_water_temperature_c: f32,
+        max_water_temperature_c: f32,
+    },
+    #[error("Species (ID: {source_species_id}): Invalid encounter light level range '{min_light_level}' to '{max_light_level}', has to be within 0.0 and 1.0")]
+    SpeciesEncounterInvalidLightLevelRange {
+        source_species_id: i32,
+        min_light_level: f32,
+        max_light_level: f32,
+    },
 }
 
 impl ConfigValidationError {
+    pub fn achievement_invalid_count(source_achievement_id: i32, count: u64) -> Self {
+        Self::AchievementInvalidCount {
+            source_achievement_id,
+            count,
+        }
+    }
+
+    pub fn achievement_invalid_size_ratio(source_achievement_id: i32, min_size_ratio: f32) -> Self {
+        Self::AchievementInvalidSizeRatio {
+            source_achievement_id,
+            min_size_ratio,
+        }
+    }
+
+    pub fn achievement_item(source_achievement_id: i32, target_item_id: i32) -> Self {
+        Self::AchievementItem {
+            source_achievement_id,
+            target_item_id,
+        }
+    }
+
+    pub fn achievement_location(source_achievement_id: i32, target_location_id: i32) -> Self {
+        Self::AchievementLocation {
+            source_achievement_id,
+            target_location_id,
+        }
+    }
+
+    pub fn achievement_species(source_achievement_id: i32, target_species_id: i32) -> Self {
+        Self::AchievementSpecies {
+            source_achievement_id,
+            target_species_id,
+        }
+    }
+
     pub fn item_invalid_max_count(source_item_id: i32) -> Self {
         Self::ItemInvalidMaxCount { source_item_id }
     }
@@ -34,6 +139,10 @@ impl ConfigValidationError {
         Self::ItemNonUniqueNotStackable { source_item_id }
     }
 
+    pub fn location_invalid_coordinates(source_location_id: i32) -> Self {
+        Self::LocationInvalidCoordinates { source_location_id }
+    }
+
     pub fn location_required_location(source_location_id: i32, target_location_id: i32) -> Self {
         Self::LocationRequiredLocation {
             source_location_id,
@@ -48,6 +157,12 @@ impl ConfigValidationError {
         }
     }
 
+    pub fn settings_invalid_time_speed_multiplier(time_speed_multiplier: f32) -> Self {
+        Self::SettingsInvalidTimeSpeedMultiplier {
+            time_speed_multiplier,
+        }
+    }
+
     pub fn species_encounter_location(source_species_id: i32, target_location_id: i32) -> Self {
         Self::SpeciesEncounterLocation {
             source_species_id,
@@ -55,6 +170,98 @@ impl ConfigValidationError {
         }
     }
 
+    pub fn species_encounter_invalid_hour(source_species_id: i32, hour: u8) -> Self {
+        Self::SpeciesEncounterInvalidHour {
+            source_species_id,
+            hour,
+        }
+    }
+
+    pub fn species_encounter_invalid_minute(source_species_id: i32, minute: u8) -> Self {
+        Self::SpeciesEncounterInvalidMinute {
+            source_species_id,
+            minute,
+        }
+    }
+
+    pub fn species_encounter_conflicting_rain(source_species_id: i32) -> Self {
+        Self::SpeciesEncounterConflictingRain { source_species_id }
+    }
+
+    pub fn species_encounter_conflicting_weather_conditions(source_species_id: i32) -> Self {
+        Self::SpeciesEncounterConflictingWeatherConditions { source_species_id }
+    }
+
+    pub fn species_encounter_tides_not_coastal(
+        source_species_id: i32,
+        target_location_id: i32,
+    ) -> Self {
+        Self::SpeciesEncounterTidesNotCoastal {
+            source_species_id,
+            target_location_id,
+        }
+    }
+
+    pub fn species_encounter_invalid_preference(source_species_id: i32) -> Self {
+        Self::SpeciesEncounterInvalidPreference { source_species_id }
+    }
+
+    pub fn species_encounter_invalid_temperature_range(
+        source_species_id: i32,
+        min_temperature_c: f32,
+        max_temperature_c: f32,
+    ) -> Self {
+        Self::SpeciesEncounterInvalidTemperatureRange {
+            source_species_id,
+            min_temperature_c,
+            max_temperature_c,
+        }
+    }
+
+    pub fn species_encounter_invalid_water_temperature_range(
+        source_species_id: i32,
+        min_water_temperature_c: f32,
+        max_water_temperature_c: f32,
+    ) -> Self {
+        Self::SpeciesEncounterInvalidWaterTemperatureRange {
+            source_species_id,
+            min_water_temperature_c,
+            max_water_temperature_c,
+        }
+    }
+
+    pub fn species_encounter_invalid_light_level_range(
+        source_species_id: i32,
+        min_light_level: f32,
+        max_light_level: f32,
+    ) -> Self {
+        Self::SpeciesEncounterInvalidLightLevelRange {
+            source_species_id,
+            min_light_level,
+            max_light_level,
+        }
+    }
+
+    pub fn is_achievement_invalid_count(&self) -> bool {
+        matches!(self, Self::AchievementInvalidCount { .. })
+    }
+
+    pub fn is_achievement_invalid_size_ratio(&self) -> bool {
+        matches!(self, Self::AchievementInvalidSizeRatio { .. })
+    }
+
+    pub fn is_achievement_item(&self) -> bool {
+        matches!(self, Self::AchievementItem { .. })
+    }
+
+    pub fn is_achievement_location(&self) -> bool {
+        matches!(self, Self::AchievementLocation { .. })
+    }
+
+    pub fn is_achievement_species(&self) -> bool {
+        matches!(self, Self::AchievementSpecies { .. })
+    }
+
     pub fn is_item_invalid_max_count(&self) -> bool {
         matches!(self, Self::ItemInvalidMaxCount { .. })
     }
@@ -63,6 +270,10 @@ impl ConfigValidationError {
         matches!(self, Self::ItemNonUniqueNotStackable { .. })
     }
 
+    pub fn is_location_invalid_coordinates(&self) -> bool {
+        matches!(self, Self::LocationInvalidCoordinates { .. })
+    }
+
     pub fn is_location_required_location(&self) -> bool {
         matches!(self, Self::LocationRequiredLocation { .. })
     }
@@ -71,21 +282,121 @@ impl ConfigValidationError {
         matches!(self, Self::LocationRequiredSpecies { .. })
     }
 
+    pub fn is_settings_invalid_time_speed_multiplier(&self) -> bool {
+        matches!(self, Self::SettingsInvalidTimeSpeedMultiplier { .. })
+    }
+
     pub fn is_species_encounter_location(&self) -> bool {
         matches!(self, Self::SpeciesEncounterLocation { .. })
     }
 
+    pub fn is_species_encounter_invalid_hour(&self) -> bool {
+        matches!(self, Self::SpeciesEncounterInvalidHour { .. })
+    }
+
+    pub fn is_species_encounter_invalid_minute(&self) -> bool {
+        matches!(self, Self::SpeciesEncounterInvalidMinute { .. })
+    }
+
+    pub fn is_species_encounter_conflicting_rain(&self) -> bool {
+        matches!(self, Self::SpeciesEncounterConflictingRain { .. })
+    }
+
+    pub fn is_species_encounter_conflicting_weather_conditions(&self) -> bool {
+        matches!(
+            self,
+            Self::SpeciesEncounterConflictingWeatherConditions { .. }
+        )
+    }
+
+    pub fn is_species_encounter_tides_not_coastal(&self) -> bool {
+        matches!(self, Self::SpeciesEncounterTidesNotCoastal { .. })
+    }
+
+    pub fn is_species_encounter_invalid_preference(&self) -> bool {
+        matches!(self, Self::SpeciesEncounterInvalidPreference { .. })
+    }
+
+    pub fn is_species_encounter_invalid_temperature_range(&self) -> bool {
+        matches!(self, Self::SpeciesEncounterInvalidTemperatureRange { .. })
+    }
+
+    pub fn is_species_encounter_invalid_water_temperature_range(&self) -> bool {
+        matches!(
+            self,
+            Self::SpeciesEncounterInvalidWaterTemperatureRange { .. }
+        )
+    }
+
+    pub fn is_species_encounter_invalid_light_level_range(&self) -> bool {
+        matches!(self, Self::SpeciesEncounterInvalidLightLevelRange { .. })
+    }
+
+    pub fn get_source_achievement_id(&self) -> Option<i32> {
+        match self {
+            Self::AchievementInvalidCount {
+                source_achievement_id,
+                ..
+            } => Some(*source_achievement_id),
+            Self::AchievementInvalidSizeRatio {
+                source_achievement_id,
+                ..
+            } => Some(*source_achievement_id),
+            Self::AchievementItem {
+                source_achievement_id,
+                ..
+            } => Some(*source_achievement_id),
+            Self::AchievementLocation {
+                source_achievement_id,
+                ..
+            } => Some(*source_achievement_id),
+            Self::AchievementSpecies {
+                source_achievement_id,
+                ..
+            } => Some(*source_achievement_id),
+            _ => None,
+        }
+    }
+
     pub fn get_source_species_id(&self) -> Option<i32> {
         match self {
             Self::SpeciesEncounterLocation {
                 source_species_id, ..
             } => Some(*source_species_id),
+            Self::SpeciesEncounterInvalidHour {
+                source_species_id, ..
+            } => Some(*source_species_id),
+            Self::SpeciesEncounterInvalidMinute {
+                source_species_id, ..
+            } => Some(*source_species_id),
+            Self::SpeciesEncounterConflictingRain { source_species_id } => Some(*source_species_id),
+            Self::SpeciesEncounterConflictingWeatherConditions { source_species_id } => {
+                Some(*source_species_id)
+            }
+            Self::SpeciesEncounterTidesNotCoastal {
+                source_species_id, ..
+            } => Some(*source_species_id),
+            Self::SpeciesEncounterInvalidPreference { source_species_id } => {
+                Some(*source_species_id)
+            }
+            Self::SpeciesEncounterInvalidTemperatureRange {
+                source_species_id, ..
+            } => Some(*source_species_id),
+            Self::SpeciesEncounterInvalidWaterTemperatureRange {
+                source_species_id, ..
+            } => Some(*source_species_id),
+            Self::SpeciesEncounterInvalidLightLevelRange {
+                source_species_id, ..
+            } => Some(*source_species_id),
             _ => None,
         }
     }
 
     pub fn get_target_species_id(&self) -> Option<i32> {
         match self {
+            Self::AchievementSpecies {
+                target_species_id, ..
+            } => Some(*target_species_id),
             Self::LocationRequiredSpecies {
                 target_species_id, ..
             } => Some(*target_species_id),
@@ -95,6 +406,7 @@ impl ConfigValidationError {
 
     pub fn get_source_location_id(&self) -> Option<i32> {
         match self {
+            Self::LocationInvalidCoordinates { source_location_id } => Some(*source_location_id),
             Self::LocationRequiredLocation {
                 source_location_id, ..
             } => Some(*source_location_id),
@@ -107,6 +419,12 @@ impl ConfigValidationError {
 
     pub fn get_target_location_id(&self) -> Option<i32> {
         match self {
+            Self::AchievementLocation {
+                target_location_id, ..
+            } => Some(*target_location_id),
+            Self::SpeciesEncounterTidesNotCoastal {
+                target_location_id, ..
+            } => Some(*target_location_id),
             Self::SpeciesEncounterLocation {
                 target_location_id, ..
             } => Some(*target_location_id),
@@ -117,6 +435,20 @@ impl ConfigValidationError {
         }
     }
 
+    pub fn get_hour(&self) -> Option<u8> {
+        match self {
+            Self::SpeciesEncounterInvalidHour { hour, .. } => Some(*hour),
+            _ => None,
+        }
+    }
+
+    pub fn get_minute(&self) -> Option<u8> {
+        match self {
+            Self::SpeciesEncounterInvalidMinute { minute, .. } => Some(*minute),
+            _ => None,
+        }
+    }
+
     pub fn get_source_item_id(&self) -> Option<i32> {
         match self {
             Self::ItemInvalidMaxCount { source_item_id, .. } => Some(*source_item_id),
@@ -124,4 +456,25 @@ impl ConfigValidationError {
             _ => None,
         }
     }
+
+    pub fn get_target_item_id(&self) -> Option<i32> {
+        match self {
+            Self::AchievementItem { target_item_id, .. } => Some(*target_item_id),
+            _ => None,
+        }
+    }
+
+    pub fn get_count(&self) -> Option<u64> {
+        match self {
+            Self::AchievementInvalidCount { count, .. } => Some(*count),
+            _ => None,
+        }
+    }
+
+    pub fn get_min_size_ratio(&self) -> Option<f32> {
+        match self {
+            Self::AchievementInvalidSizeRatio { min_size_ratio, .. } => Some(*min_size_ratio),
+            _ => None,
+        }
+    }
 }
@@ -1,3 +1,4 @@
+pub mod achievement_data;
 pub mod encounter_data;
 pub mod item_data;
 pub mod location_data;
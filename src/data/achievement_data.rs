@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct AchievementData {
+    #[serde(skip, default)]
+    pub id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// What a user has to achieve to unlock this achievement
+    pub requirement: AchievementRequirement,
+}
+
+/// Achievements are checked after every catch, sale, purchase and location unlock
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum AchievementRequirement {
+    /// Catch a total amount of fish, only counting the given species if set
+    CatchCount {
+        count: u64,
+        #[serde(default)]
+        species_id: Option<i32>,
+    },
+    /// Catch a number of different species
+    SpeciesCount { count: u64 },
+    /// Catch a single fish which meets all the set conditions at once
+    Catch {
+        #[serde(default)]
+        species_id: Option<i32>,
+        #[serde(default)]
+        location_id: Option<i32>,
+        /// The minimum rarity level (0-255) of the caught species
+        #[serde(default)]
+        min_rarity_level: Option<u8>,
+        /// The minimum total size ratio (0-1) of the caught specimen
+        #[serde(default)]
+        min_size_ratio: Option<f32>,
+        /// If it has to rain at the location while catching the fish
+        #[serde(default)]
+        needs_rain: bool,
+    },
+    /// Sell a total amount of fish
+    SellCount { count: u64 },
+    /// Own an amount of credits at once
+    Credits { amount: i64 },
+    /// Purchase any item in the shop, or the given item if set
+    Purchase {
+        #[serde(default)]
+        item_id: Option<i32>,
+    },
+    /// Unlock a number of locations, all configured locations if no count is set
+    LocationsUnlocked {
+        #[serde(default)]
+        count: Option<u64>,
+    },
+}
+
+impl Default for AchievementRequirement {
+    fn default() -> Self {
+        Self::CatchCount {
+            count: 1,
+            species_id: None,
+        }
+    }
+}
@@ -1,17 +1,73 @@
+use crate::enums::moon_phase::MoonPhase;
+use crate::enums::season::Season;
+use crate::enums::tide_state::TideState;
+use crate::enums::weather_condition::WeatherCondition;
+use crate::game::systems::encounter_system::EncounterConditions;
+use chrono::{NaiveTime, Timelike};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+const MINUTES_PER_HOUR: u16 = 60;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
 pub struct EncounterData {
     pub location_id: i32,
     /// Minimum and maximum local time (24h-format) this fish can be encountered at
     /// 16-16 means a fish will only appear from 4:00pm to 4:59pm
     /// 16-17 means a fish will only appear from 4:00pm to 5:59pm
+    /// 22-3 wraps around midnight, the fish will appear from 10:00pm to 3:59am
     pub min_time_hour: u8,
     pub max_time_hour: u8,
+    /// Optional minute precision for the start of the time window, defaults to :00
+    #[serde(default)]
+    pub min_time_minute: Option<u8>,
+    /// Optional minute precision for the end of the time window (inclusive), defaults to :59
+    #[serde(default)]
+    pub max_time_minute: Option<u8>,
     /// The higher, the rarer
     pub rarity_level: u8,
+    /// The rarity level while any of the preferred moon phases or tide states apply
+    #[serde(default)]
+    pub preferred_rarity_level: Option<u8>,
+    /// The moon phases this fish can be encountered in, empty means all moon phases
+    #[serde(default)]
+    pub moon_phases: Vec<MoonPhase>,
+    #[serde(default)]
+    pub preferred_moon_phases: Vec<MoonPhase>,
+    /// The tide states this fish can be encountered in, empty means all tide states.
+    /// Only coastal locations have tides.
+    #[serde(default)]
+    pub tide_states: Vec<TideState>,
+    #[serde(default)]
+    pub preferred_tide_states: Vec<TideState>,
     #[serde(default = "default_false")]
     pub needs_rain: bool,
+    /// This fish will only appear if it's not raining
+    #[serde(default = "default_false")]
+    pub needs_no_rain: bool,
+    /// The weather conditions this fish can be encountered in, empty means all conditions
+    #[serde(default)]
+    pub weather_conditions: Vec<WeatherCondition>,
+    /// The seasons this fish can be encountered in, empty means all seasons
+    #[serde(default)]
+    pub seasons: Vec<Season>,
+    /// Minimum and maximum (inclusive) air temperature this fish can be encountered at
+    #[serde(default)]
+    pub min_temperature_c: Option<f32>,
+    #[serde(default)]
+    pub max_temperature_c: Option<f32>,
+    /// Minimum and maximum (inclusive) water temperature this fish can be encountered at
+    #[serde(default)]
+    pub min_water_temperature_c: Option<f32>,
+    #[serde(default)]
+    pub max_water_temperature_c: Option<f32>,
+    /// Minimum and maximum (inclusive) light level (0.0 - 1.0) this fish can be encountered at
+    #[serde(default)]
+    pub min_light_level: Option<f32>,
+    #[serde(default)]
+    pub max_light_level: Option<f32>,
+    /// The minimum rod level needed to encounter this fish
+    #[serde(default)]
+    pub min_rod_level: u64,
 }
 
 fn default_false() -> bool {
@@ -19,7 +75,132 @@ fn default_false() -> bool {
 }
 
 impl EncounterData {
+    pub fn has_preferences(&self) -> bool {
+        !self.preferred_moon_phases.is_empty() || !self.preferred_tide_states.is_empty()
+    }
+
+    pub fn needs_tides(&self) -> bool {
+        !self.tide_states.is_empty() || !self.preferred_tide_states.is_empty()
+    }
+
+    /// The preferred rarity level if any preferred moon phase or tide state applies
+    pub fn get_rarity_level(&self, conditions: &EncounterConditions) -> u8 {
+        let Some(preferred_rarity_level) = self.preferred_rarity_level else {
+            return self.rarity_level;
+        };
+
+        let preferred_moon_phase = self.preferred_moon_phases.contains(&conditions.moon_phase);
+        let preferred_tide_state = conditions
+            .tide_state
+            .is_some_and(|tide_state| self.preferred_tide_states.contains(&tide_state));
+        if preferred_moon_phase || preferred_tide_state {
+            preferred_rarity_level
+        } else {
+            self.rarity_level
+        }
+    }
+
+    pub fn get_min_time_minute(&self) -> u8 {
+        self.min_time_minute.unwrap_or(0)
+    }
+
+    pub fn get_max_time_minute(&self) -> u8 {
+        self.max_time_minute.unwrap_or(59)
+    }
+
+    /// Minute of the day the time window starts at
+    pub fn get_start_minute_of_day(&self) -> u16 {
+        self.min_time_hour as u16 * MINUTES_PER_HOUR + self.get_min_time_minute() as u16
+    }
+
+    /// Minute of the day the time window ends at (inclusive)
+    pub fn get_end_minute_of_day(&self) -> u16 {
+        self.max_time_hour as u16 * MINUTES_PER_HOUR + self.get_max_time_minute() as u16
+    }
+
+    /// If the time window starts before and ends after midnight
+    pub fn wraps_midnight(&self) -> bool {
+        self.get_start_minute_of_day() > self.get_end_minute_of_day()
+    }
+
+    /// All hours of the day the time window overlaps with, in order starting from the first hour
     pub fn get_hours(&self) -> Vec<u8> {
-        (self.min_time_hour..=self.max_time_hour).collect()
+        if !self.wraps_midnight() {
+            (self.min_time_hour..=self.max_time_hour).collect()
+        } else if self.min_time_hour == self.max_time_hour {
+            // E.g. 16:30-16:10, which covers the whole day except for a few minutes
+            (self.min_time_hour..=23)
+                .chain(0..self.min_time_hour)
+                .collect()
+        } else {
+            (self.min_time_hour..=23)
+                .chain(0..=self.max_time_hour)
+                .collect()
+        }
+    }
+
+    pub fn is_active_at(&self, time: NaiveTime) -> bool {
+        let minute_of_day = time.hour() as u16 * MINUTES_PER_HOUR + time.minute() as u16;
+        let start = self.get_start_minute_of_day();
+        let end = self.get_end_minute_of_day();
+
+        if start <= end {
+            (start..=end).contains(&minute_of_day)
+        } else {
+            minute_of_day >= start || minute_of_day <= end
+        }
+    }
+
+    /// If the rain, weather condition, season, moon phase, tide, air and water temperature and light level conditions are met
+    pub fn are_conditions_met(&self, conditions: &EncounterConditions) -> bool {
+        if self.needs_rain && !conditions.is_raining {
+            return false;
+        }
+
+        if self.needs_no_rain && conditions.is_raining {
+            return false;
+        }
+
+        if !self.weather_conditions.is_empty()
+            && !self
+                .weather_conditions
+                .contains(&conditions.weather_condition)
+        {
+            return false;
+        }
+
+        if !self.seasons.is_empty() && !self.seasons.contains(&conditions.season) {
+            return false;
+        }
+
+        if !self.moon_phases.is_empty() && !self.moon_phases.contains(&conditions.moon_phase) {
+            return false;
+        }
+
+        if !self.tide_states.is_empty()
+            && !conditions
+                .tide_state
+                .is_some_and(|tide_state| self.tide_states.contains(&tide_state))
+        {
+            return false;
+        }
+
+        is_within(
+            conditions.temperature_c,
+            self.min_temperature_c,
+            self.max_temperature_c,
+        ) && is_within(
+            conditions.water_temperature_c,
+            self.min_water_temperature_c,
+            self.max_water_temperature_c,
+        ) && is_within(
+            conditions.light_level,
+            self.min_light_level,
+            self.max_light_level,
+        )
     }
 }
+
+fn is_within(value: f32, min: Option<f32>, max: Option<f32>) -> bool {
+    min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+}
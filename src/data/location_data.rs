@@ -1,10 +1,32 @@
 use crate::data::season_data::SeasonData;
 use crate::enums::season::Season;
-use chrono::{DateTime, Utc};
+use crate::enums::tide_state::TideState;
+use crate::game::errors::GameResult;
+use crate::game::game_time::GameTime;
+use crate::utils::lunar::{tide_level, tide_state};
+use crate::utils::solar::{solar_elevation_deg, sunrise_sunset};
+use chrono::{DateTime, NaiveTime, TimeZone, Utc};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
-const SECONDS_PER_YEAR: f64 = 31_556_925.190_8;
+pub(crate) const SECONDS_PER_YEAR: f64 = 31_556_925.190_8;
+/// The March equinox of 2000 (2000-03-20 07:35 UTC), the start of spring in the northern hemisphere
+const MARCH_EQUINOX_2000_TIMESTAMP: i64 = 953_537_700;
+
+/// The time since the UNIX epoch sped up by the multiplier, as seasons were read before [GameTime].
+/// Times which can't be represented anymore stay unscaled.
+fn scale_time(time: DateTime<Tz>, time_multiplier: f32) -> DateTime<Tz> {
+    let scaled_timestamp = time.timestamp() as f64 * f64::from(time_multiplier);
+    DateTime::from_timestamp(scaled_timestamp as i64, 0).map_or(time, |scaled_time| {
+        scaled_time.with_timezone(&time.timezone())
+    })
+}
+
+/// How far (0-1) the given time is into the year, which starts at the March equinox
+pub(crate) fn year_progress_since_march_equinox<T: TimeZone>(time: DateTime<T>) -> f64 {
+    let seconds_since_equinox = (time.timestamp() - MARCH_EQUINOX_2000_TIMESTAMP) as f64;
+    seconds_since_equinox.rem_euclid(SECONDS_PER_YEAR) / SECONDS_PER_YEAR
+}
 
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
 pub struct LocationData {
@@ -13,6 +35,16 @@ pub struct LocationData {
     pub name: String,
     pub timezone: Tz,
     pub weather_seed: u32,
+    /// Optional geographic coordinates in degrees (north and east are positive).
+    /// With them, daylight and seasons follow the real sun, without them the sun rises at 6am
+    /// and sets at 6pm every day and the seasons are the same on both hemispheres.
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    #[serde(default)]
+    pub longitude: Option<f64>,
+    /// Coastal locations have tides
+    #[serde(default)]
+    pub coastal: bool,
     pub spring: SeasonData,
     pub summer: SeasonData,
     pub autumn: SeasonData,
@@ -26,9 +58,30 @@ pub struct LocationData {
 }
 
 impl LocationData {
-    fn season_index_and_progress(&self, time: DateTime<Tz>, time_multiplier: f32) -> (usize, f64) {
-        let year_progress = (time.timestamp() as f64 * time_multiplier as f64) % SECONDS_PER_YEAR
-            / SECONDS_PER_YEAR;
+    /// Latitude and longitude, if both are configured
+    pub fn get_coordinates(&self) -> Option<(f64, f64)> {
+        self.latitude.zip(self.longitude)
+    }
+
+    pub fn is_southern_hemisphere(&self) -> bool {
+        self.latitude.is_some_and(|latitude| latitude < 0.0)
+    }
+
+    fn year_progress(&self, game_time: DateTime<Tz>) -> f64 {
+        if self.latitude.is_none() {
+            return (game_time.timestamp() as f64).rem_euclid(SECONDS_PER_YEAR) / SECONDS_PER_YEAR;
+        }
+
+        let year_progress = year_progress_since_march_equinox(game_time);
+        if self.is_southern_hemisphere() {
+            (year_progress + 0.5) % 1.0
+        } else {
+            year_progress
+        }
+    }
+
+    fn season_index_and_progress(&self, game_time: DateTime<Tz>) -> (usize, f64) {
+        let year_progress = self.year_progress(game_time);
 
         let current_season = year_progress * 4.0;
         let season_index = current_season.floor() as usize;
@@ -37,8 +90,9 @@ impl LocationData {
         (season_index, season_progress)
     }
 
-    pub fn current_season_data(&self, time: DateTime<Tz>, time_multiplier: f32) -> SeasonData {
-        let (index, progress) = self.season_index_and_progress(time, time_multiplier);
+    /// The season data at the given in-game time, interpolated with the neighbouring seasons
+    pub fn season_data_at(&self, game_time: DateTime<Tz>) -> SeasonData {
+        let (index, progress) = self.season_index_and_progress(game_time);
 
         let (prev_data, current_data, next_data) = match index {
             0 => (&self.winter, &self.spring, &self.summer),
@@ -59,23 +113,93 @@ impl LocationData {
         }
     }
 
-    pub fn current_season(&self, time: DateTime<Tz>, time_multiplier: f32) -> (Season, f64) {
-        let (index, progress) = self.season_index_and_progress(time, time_multiplier);
+    /// The season and its progress (0-1) at the given in-game time
+    pub fn season_at(&self, game_time: DateTime<Tz>) -> (Season, f64) {
+        let (index, progress) = self.season_index_and_progress(game_time);
         let season = Season::from_index(index);
         (season, progress)
     }
 
+    pub fn season_information_at(&self, game_time: DateTime<Tz>) -> (SeasonData, Season, f64) {
+        let data = self.season_data_at(game_time);
+        let (season, progress) = self.season_at(game_time);
+        (data, season, progress)
+    }
+
+    #[deprecated(note = "scale the time with `GameTime` and use `season_data_at` instead")]
+    pub fn current_season_data(&self, time: DateTime<Tz>, time_multiplier: f32) -> SeasonData {
+        self.season_data_at(scale_time(time, time_multiplier))
+    }
+
+    #[deprecated(note = "scale the time with `GameTime` and use `season_at` instead")]
+    pub fn current_season(&self, time: DateTime<Tz>, time_multiplier: f32) -> (Season, f64) {
+        self.season_at(scale_time(time, time_multiplier))
+    }
+
+    #[deprecated(note = "scale the time with `GameTime` and use `season_information_at` instead")]
     pub fn full_season_information(
         &self,
         time: DateTime<Tz>,
         time_multiplier: f32,
     ) -> (SeasonData, Season, f64) {
-        let data = self.current_season_data(time, time_multiplier);
-        let (season, progress) = self.current_season(time, time_multiplier);
-        (data, season, progress)
+        self.season_information_at(scale_time(time, time_multiplier))
+    }
+
+    /// The current in-game time at this location
+    pub fn get_local_game_time(&self, game_time: &GameTime) -> GameResult<DateTime<Tz>> {
+        game_time.now_in(&self.timezone)
     }
 
+    /// The current real time at this location
+    #[deprecated(note = "use `get_local_game_time` for the in-game time")]
     pub fn get_local_time(&self) -> DateTime<Tz> {
         Utc::now().with_timezone(&self.timezone)
     }
+
+    /// Sunrise and sunset on the in-game day of the given in-game time,
+    /// None during polar day or polar night
+    pub fn get_sunrise_sunset(
+        &self,
+        game_time: DateTime<Tz>,
+    ) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+        let date = game_time.with_timezone(&self.timezone).date_naive();
+
+        let Some((latitude, longitude)) = self.get_coordinates() else {
+            let at = |hour| {
+                let time = date.and_time(NaiveTime::from_hms_opt(hour, 0, 0)?);
+                self.timezone.from_local_datetime(&time).earliest()
+            };
+            return at(6).zip(at(18));
+        };
+
+        let (sunrise, sunset) = sunrise_sunset(date, latitude, longitude)?;
+        Some((
+            sunrise.with_timezone(&self.timezone),
+            sunset.with_timezone(&self.timezone),
+        ))
+    }
+
+    /// The angle of the sun above the horizon in degrees at the given in-game time,
+    /// None without coordinates
+    pub fn get_solar_elevation_deg(&self, game_time: DateTime<Tz>) -> Option<f64> {
+        let (latitude, longitude) = self.get_coordinates()?;
+        Some(solar_elevation_deg(
+            game_time.with_timezone(&Utc),
+            latitude,
+            longitude,
+        ))
+    }
+
+    /// The water level (-1 to 1) and tide state at the given in-game time, None if not coastal
+    pub fn get_tide(&self, game_time: DateTime<Tz>) -> Option<(f32, TideState)> {
+        if !self.coastal {
+            return None;
+        }
+
+        let longitude = self.longitude.unwrap_or_default();
+        Some((
+            tide_level(&game_time, longitude) as f32,
+            tide_state(&game_time, longitude),
+        ))
+    }
 }
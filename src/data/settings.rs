@@ -1,30 +1,80 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// The fastest in-game time can run, faster speeds push the in-game dates of the
+/// coming centuries out of the range dates can be represented in
+pub const MAX_TIME_SPEED_MULTIPLIER: f32 = 1000.0;
+
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct Settings {
-    /// How much faster game speed is compared to real-time
+    /// How much faster game speed is compared to real-time, has to be greater than 0
+    /// and at most [MAX_TIME_SPEED_MULTIPLIER]
     #[serde(default = "default_time_speed_multiplier")]
     pub time_speed_multiplier: f32,
+    /// The moment at which in-game time equals real time, in-game time runs faster or slower from there on
+    #[serde(default = "default_time_epoch")]
+    pub time_epoch: DateTime<Utc>,
     /// How much rarer the rarest fish will be
     /// The rarest fish will be 1 in 255^(rarity_exponent)
     /// 255^(rarity_exponent) shouldn't exceed 1.7976931348623157e+308
     #[serde(default = "default_rarity_exponent")]
     pub rarity_exponent: f64,
+    /// How much each bait level lowers the rarity exponent
+    /// A lower rarity exponent flattens the weight curve, making rarer fish more likely
+    #[serde(default = "default_bait_level_rarity_exponent_reduction")]
+    pub bait_level_rarity_exponent_reduction: f64,
+    /// How much the total size ratio (0-1) of a specimen adds to its sell price
+    /// The biggest possible specimen sells for base_value * (1 + sell_price_size_multiplier)
+    #[serde(default = "default_sell_price_size_multiplier")]
+    pub sell_price_size_multiplier: f32,
+    /// How much the rarity level (0-255) of a species adds to the sell price of its specimens
+    /// Specimens of the rarest species sell for base_value * (1 + sell_price_rarity_multiplier)
+    #[serde(default = "default_sell_price_rarity_multiplier")]
+    pub sell_price_rarity_multiplier: f32,
+    /// How much the age (0-1) of a specimen adds to its sell price
+    /// Fully grown specimens sell for base_value * (1 + sell_price_age_multiplier)
+    #[serde(default = "default_sell_price_age_multiplier")]
+    pub sell_price_age_multiplier: f32,
 }
 
 fn default_time_speed_multiplier() -> f32 {
     1.0
 }
 
+fn default_time_epoch() -> DateTime<Utc> {
+    DateTime::UNIX_EPOCH
+}
+
 fn default_rarity_exponent() -> f64 {
     2.5
 }
 
+fn default_bait_level_rarity_exponent_reduction() -> f64 {
+    0.1
+}
+
+fn default_sell_price_size_multiplier() -> f32 {
+    1.0
+}
+
+fn default_sell_price_rarity_multiplier() -> f32 {
+    4.0
+}
+
+fn default_sell_price_age_multiplier() -> f32 {
+    0.5
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
             time_speed_multiplier: default_time_speed_multiplier(),
+            time_epoch: default_time_epoch(),
             rarity_exponent: default_rarity_exponent(),
+            bait_level_rarity_exponent_reduction: default_bait_level_rarity_exponent_reduction(),
+            sell_price_size_multiplier: default_sell_price_size_multiplier(),
+            sell_price_rarity_multiplier: default_sell_price_rarity_multiplier(),
+            sell_price_age_multiplier: default_sell_price_age_multiplier(),
         }
     }
 }
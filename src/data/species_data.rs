@@ -30,6 +30,9 @@ pub struct SpeciesData {
     /// Time when fish becomes adult (0 to 1)
     #[serde(default = "default_lifespan_adult_ratio")]
     pub lifespan_adult_ratio: f32,
+    /// How many credits a specimen of this species is worth before size, rarity and age are applied
+    #[serde(default)]
+    pub base_value: u64,
     #[serde(default)]
     pub encounters: Vec<EncounterData>,
 }
@@ -71,6 +74,15 @@ impl SpeciesData {
         )
     }
 
+    /// The lowest rarity level this species can be encountered at, 0 if it has no encounters
+    pub fn get_rarity_level(&self) -> u8 {
+        self.encounters
+            .iter()
+            .map(|encounter| encounter.rarity_level)
+            .min()
+            .unwrap_or_default()
+    }
+
     pub fn get_lifespan_days_by_ratio(&self, ratio: f32) -> f32 {
         float_interpolate(
             self.min_lifespan_days as f32,
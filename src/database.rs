@@ -1,22 +1,312 @@
 use crate::game::errors::database::GameDatabaseError;
-use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
-use diesel::PgConnection;
+use diesel::connection::{AnsiTransactionManager, TransactionManager};
+use diesel::r2d2::{ConnectionManager, Pool, PooledConnection, R2D2Connection};
+use diesel::{Connection, PgConnection, QueryResult};
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
-use std::sync::{Arc, RwLock};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::thread::{self, ThreadId};
+
+#[cfg(feature = "in-memory")]
+pub mod memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 
+/// Thread ID -> Connection of the transaction running on that thread
+/// The connection is None while it is borrowed by a [DatabaseConnection]
+type TransactionConnections<C> =
+    Arc<Mutex<HashMap<ThreadId, Option<PooledConnection<ConnectionManager<C>>>>>>;
+
+/// A callback which is run once the transaction it was registered in is committed
+type CommitCallback = Box<dyn FnOnce()>;
+
+thread_local! {
+    /// Callbacks of every transaction running on the current thread, innermost transaction last
+    static COMMIT_CALLBACKS: RefCell<Vec<Vec<CommitCallback>>> = const { RefCell::new(Vec::new()) };
+}
+
 pub trait DatabaseInterface: Send + Sync {
     fn connect(&mut self, postgres_url: &str) -> Result<(), GameDatabaseError>;
     fn run_migrations(&self) -> Result<(), GameDatabaseError>;
-    fn get_connection(
-        &self,
-    ) -> Result<PooledConnection<ConnectionManager<PgConnection>>, GameDatabaseError>;
+    /// Returns the connection of the transaction running on the current thread,
+    /// or a connection from the pool if there is none
+    fn get_connection(&self) -> Result<DatabaseConnection, GameDatabaseError>;
+    /// Begins a transaction on the current thread, nested transactions use savepoints
+    fn begin_transaction(&self) -> Result<(), GameDatabaseError>;
+    fn commit_transaction(&self) -> Result<(), GameDatabaseError>;
+    fn rollback_transaction(&self) -> Result<(), GameDatabaseError>;
     fn clear(&self) -> Result<(), GameDatabaseError>;
 }
 
+/// Runs the given function inside a transaction on the current thread.
+/// The transaction is committed if the function succeeds and rolled back otherwise.
+///
+/// Every repository call made by the function on the current thread uses the same connection,
+/// so either all of their changes are persisted or none are.
+pub fn transaction<T, E, F>(
+    database: &Arc<RwLock<dyn DatabaseInterface>>,
+    function: F,
+) -> Result<T, E>
+where
+    F: FnOnce() -> Result<T, E>,
+    E: From<GameDatabaseError>,
+{
+    read_lock(database).begin_transaction()?;
+    COMMIT_CALLBACKS.with_borrow_mut(|frames| frames.push(Vec::new()));
+    let mut guard = TransactionGuard {
+        database,
+        finished: false,
+    };
+
+    let result = function();
+    guard.finished = true;
+    let callbacks = COMMIT_CALLBACKS
+        .with_borrow_mut(|frames| frames.pop())
+        .unwrap_or_default();
+    match result {
+        Ok(value) => {
+            read_lock(database).commit_transaction()?;
+            for callback in callbacks {
+                after_commit(callback);
+            }
+            Ok(value)
+        }
+        Err(error) => {
+            // The original error is more meaningful than a failed rollback
+            let _ = read_lock(database).rollback_transaction();
+            Err(error)
+        }
+    }
+}
+
+/// Runs the given callback once the outermost transaction on the current thread is committed,
+/// or right away if there is no transaction running.
+///
+/// The callback is dropped without being run if the transaction is rolled back.
+pub fn after_commit(callback: impl FnOnce() + 'static) {
+    let callback = COMMIT_CALLBACKS.with_borrow_mut(|frames| match frames.last_mut() {
+        Some(callbacks) => {
+            callbacks.push(Box::new(callback));
+            None
+        }
+        None => Some(callback),
+    });
+
+    // Run outside of the borrow, the callback may register further callbacks
+    if let Some(callback) = callback {
+        callback();
+    }
+}
+
+fn read_lock(
+    database: &Arc<RwLock<dyn DatabaseInterface>>,
+) -> std::sync::RwLockReadGuard<'_, dyn DatabaseInterface> {
+    database.read().expect("Failed to get read lock on DB")
+}
+
+/// Rolls back the transaction if the function inside it panics
+struct TransactionGuard<'a> {
+    database: &'a Arc<RwLock<dyn DatabaseInterface>>,
+    finished: bool,
+}
+
+impl Drop for TransactionGuard<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            COMMIT_CALLBACKS.with_borrow_mut(|frames| frames.pop());
+            if let Ok(database) = self.database.read() {
+                let _ = database.rollback_transaction();
+            }
+        }
+    }
+}
+
+/// A database connection which either belongs to the pool
+/// or to the transaction running on the current thread.
+pub struct DatabaseConnection<C: R2D2Connection + 'static = PgConnection> {
+    connection: Option<PooledConnection<ConnectionManager<C>>>,
+    transaction_connections: Option<TransactionConnections<C>>,
+}
+
+impl<C: R2D2Connection + 'static> DatabaseConnection<C> {
+    fn pooled(connection: PooledConnection<ConnectionManager<C>>) -> Self {
+        Self {
+            connection: Some(connection),
+            transaction_connections: None,
+        }
+    }
+
+    fn transaction(
+        connection: PooledConnection<ConnectionManager<C>>,
+        transaction_connections: TransactionConnections<C>,
+    ) -> Self {
+        Self {
+            connection: Some(connection),
+            transaction_connections: Some(transaction_connections),
+        }
+    }
+
+    pub fn is_in_transaction(&self) -> bool {
+        self.transaction_connections.is_some()
+    }
+}
+
+impl<C: R2D2Connection + 'static> Deref for DatabaseConnection<C> {
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        self.connection
+            .as_ref()
+            .expect("Database connection was already released")
+    }
+}
+
+impl<C: R2D2Connection + 'static> DerefMut for DatabaseConnection<C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.connection
+            .as_mut()
+            .expect("Database connection was already released")
+    }
+}
+
+impl<C: R2D2Connection + 'static> Drop for DatabaseConnection<C> {
+    fn drop(&mut self) {
+        // Hand the connection back to the transaction it belongs to
+        if let (Some(connection), Some(transaction_connections)) =
+            (self.connection.take(), &self.transaction_connections)
+        {
+            if let Ok(mut connections) = transaction_connections.lock() {
+                if let Some(slot) = connections.get_mut(&thread::current().id()) {
+                    *slot = Some(connection);
+                }
+            }
+        }
+    }
+}
+
+/// A connection pool which binds a connection to each thread running a transaction,
+/// so every query on that thread takes part in the transaction.
+pub struct ConnectionPool<C: R2D2Connection + 'static> {
+    pool: Pool<ConnectionManager<C>>,
+    transaction_connections: TransactionConnections<C>,
+}
+
+impl<C> ConnectionPool<C>
+where
+    C: R2D2Connection + Connection<TransactionManager = AnsiTransactionManager> + 'static,
+{
+    pub fn new(pool: Pool<ConnectionManager<C>>) -> Self {
+        Self {
+            pool,
+            transaction_connections: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the connection of the transaction running on the current thread,
+    /// or a connection from the pool if there is none
+    pub fn get_connection(&self) -> Result<DatabaseConnection<C>, GameDatabaseError> {
+        match self.take_transaction_connection()? {
+            Some(connection) => Ok(DatabaseConnection::transaction(
+                connection,
+                self.transaction_connections.clone(),
+            )),
+            None => Ok(DatabaseConnection::pooled(self.get_pooled_connection()?)),
+        }
+    }
+
+    pub fn begin_transaction(&self) -> Result<(), GameDatabaseError> {
+        let mut connection = match self.take_transaction_connection()? {
+            Some(connection) => connection,
+            None => self.get_pooled_connection()?,
+        };
+
+        let result = AnsiTransactionManager::begin_transaction(&mut *connection);
+        self.restore_transaction_connection(connection);
+        result.map_err(|e| GameDatabaseError::transaction_failed(&e.to_string()))
+    }
+
+    pub fn commit_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.end_transaction(AnsiTransactionManager::commit_transaction)
+    }
+
+    pub fn rollback_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.end_transaction(AnsiTransactionManager::rollback_transaction)
+    }
+
+    fn get_pooled_connection(
+        &self,
+    ) -> Result<PooledConnection<ConnectionManager<C>>, GameDatabaseError> {
+        self.pool
+            .get()
+            .map_err(|e| GameDatabaseError::connection_failed(&e.to_string()))
+    }
+
+    fn lock_transaction_connections(
+        &self,
+    ) -> MutexGuard<'_, HashMap<ThreadId, Option<PooledConnection<ConnectionManager<C>>>>> {
+        self.transaction_connections
+            .lock()
+            .expect("Failed to get lock on transaction connections")
+    }
+
+    /// Takes the connection of the transaction running on the current thread out of its slot.
+    /// Returns None if there is no transaction running on the current thread.
+    fn take_transaction_connection(
+        &self,
+    ) -> Result<Option<PooledConnection<ConnectionManager<C>>>, GameDatabaseError> {
+        match self
+            .lock_transaction_connections()
+            .get_mut(&thread::current().id())
+        {
+            Some(slot) => slot.take().map(Some).ok_or_else(|| {
+                GameDatabaseError::transaction_failed(
+                    "The connection of the current transaction is already in use",
+                )
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Puts the connection back into the slot of the current thread if it is still in a transaction,
+    /// otherwise the slot is removed and the connection is released back into the pool.
+    fn restore_transaction_connection(
+        &self,
+        mut connection: PooledConnection<ConnectionManager<C>>,
+    ) {
+        let depth = AnsiTransactionManager::transaction_manager_status_mut(&mut *connection)
+            .transaction_depth();
+
+        let mut connections = self.lock_transaction_connections();
+        match depth {
+            Ok(Some(_)) => {
+                connections.insert(thread::current().id(), Some(connection));
+            }
+            _ => {
+                connections.remove(&thread::current().id());
+            }
+        }
+    }
+
+    fn end_transaction(
+        &self,
+        end: impl FnOnce(&mut C) -> QueryResult<()>,
+    ) -> Result<(), GameDatabaseError> {
+        let mut connection = self.take_transaction_connection()?.ok_or_else(|| {
+            GameDatabaseError::transaction_failed("No transaction running on the current thread")
+        })?;
+
+        let result = end(&mut connection);
+        self.restore_transaction_connection(connection);
+        result.map_err(|e| GameDatabaseError::transaction_failed(&e.to_string()))
+    }
+}
+
 pub struct Database {
-    connection_pool: Option<Pool<ConnectionManager<PgConnection>>>,
+    connection_pool: Option<ConnectionPool<PgConnection>>,
 }
 
 impl Database {
@@ -29,6 +319,18 @@ impl Database {
     pub fn create() -> Arc<RwLock<dyn DatabaseInterface>> {
         Arc::new(RwLock::new(Self::new()))
     }
+
+    fn get_connection_pool(&self) -> Result<&ConnectionPool<PgConnection>, GameDatabaseError> {
+        self.connection_pool
+            .as_ref()
+            .ok_or_else(GameDatabaseError::missing_connection)
+    }
+}
+
+impl Default for Database {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DatabaseInterface for Database {
@@ -37,7 +339,7 @@ impl DatabaseInterface for Database {
         let pool = Pool::builder()
             .build(connection_manager)
             .map_err(|e| GameDatabaseError::connection_failed(&e.to_string()))?;
-        self.connection_pool = Some(pool);
+        self.connection_pool = Some(ConnectionPool::new(pool));
         self.run_migrations()?;
         Ok(())
     }
@@ -50,15 +352,20 @@ impl DatabaseInterface for Database {
         Ok(())
     }
 
-    fn get_connection(
-        &self,
-    ) -> Result<PooledConnection<ConnectionManager<PgConnection>>, GameDatabaseError> {
-        match &self.connection_pool {
-            Some(pool) => pool
-                .get()
-                .map_err(|e| GameDatabaseError::connection_failed(&e.to_string())),
-            None => Err(GameDatabaseError::missing_connection()),
-        }
+    fn get_connection(&self) -> Result<DatabaseConnection, GameDatabaseError> {
+        self.get_connection_pool()?.get_connection()
+    }
+
+    fn begin_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.get_connection_pool()?.begin_transaction()
+    }
+
+    fn commit_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.get_connection_pool()?.commit_transaction()
+    }
+
+    fn rollback_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.get_connection_pool()?.rollback_transaction()
     }
 
     fn clear(&self) -> Result<(), GameDatabaseError> {
@@ -0,0 +1,235 @@
+use crate::database::{DatabaseConnection, DatabaseInterface};
+use crate::game::errors::database::GameDatabaseError;
+use crate::models::catch_event::CatchEvent;
+use crate::models::fishing_history_entry::FishingHistoryEntry;
+use crate::models::item::Item;
+use crate::models::pond::Pond;
+use crate::models::sale_event::SaleEvent;
+use crate::models::specimen::Specimen;
+use crate::models::user::User;
+use crate::models::user_achievement::UserAchievement;
+use crate::models::user_location::UserLocation;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, RwLock};
+use std::thread::{self, ThreadId};
+
+/// All rows of the in-memory backend, mirroring the tables of the Postgres schema.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    pub catch_events: BTreeMap<i64, CatchEvent>,
+    pub fishing_history_entries: BTreeMap<i64, FishingHistoryEntry>,
+    pub items: BTreeMap<i64, Item>,
+    pub ponds: BTreeMap<i64, Pond>,
+    pub sale_events: BTreeMap<i64, SaleEvent>,
+    pub specimens: BTreeMap<i64, Specimen>,
+    pub users: BTreeMap<i64, User>,
+    pub user_locations: BTreeMap<(i64, i32), UserLocation>,
+    pub user_achievements: BTreeMap<(i64, i32), UserAchievement>,
+    sequences: HashMap<&'static str, i64>,
+}
+
+impl MemoryStore {
+    /// Returns the next primary key of the given table, like a BIGSERIAL column
+    pub fn next_id(&mut self, table: &'static str) -> i64 {
+        let id = self.sequences.entry(table).or_insert(0);
+        *id += 1;
+        *id
+    }
+
+    /// Enforces the user_id foreign key of the given table
+    pub fn check_user_exists(&self, table: &str, user_id: i64) -> Result<(), GameDatabaseError> {
+        if self.users.contains_key(&user_id) {
+            Ok(())
+        } else {
+            Err(foreign_key_violation(
+                table,
+                &format!("{table}_user_id_fkey"),
+            ))
+        }
+    }
+
+    pub fn user_has_references(&self, user_id: i64) -> bool {
+        self.catch_events
+            .values()
+            .any(|catch_event| catch_event.user_id == user_id)
+            || self
+                .fishing_history_entries
+                .values()
+                .any(|entry| entry.user_id == user_id)
+            || self.items.values().any(|item| item.user_id == user_id)
+            || self.ponds.values().any(|pond| pond.user_id == user_id)
+            || self
+                .sale_events
+                .values()
+                .any(|sale_event| sale_event.user_id == user_id)
+            || self
+                .specimens
+                .values()
+                .any(|specimen| specimen.user_id == user_id)
+            || self
+                .user_locations
+                .values()
+                .any(|user_location| user_location.user_id == user_id)
+            || self
+                .user_achievements
+                .values()
+                .any(|user_achievement| user_achievement.user_id == user_id)
+    }
+}
+
+pub fn foreign_key_violation(table: &str, constraint: &str) -> GameDatabaseError {
+    GameDatabaseError::foreign_key_violation(&format!(
+        "insert or update on table \"{table}\" violates foreign key constraint \"{constraint}\""
+    ))
+}
+
+pub fn unique_constraint_violation(constraint: &str) -> GameDatabaseError {
+    GameDatabaseError::unique_constraint_violation(&format!(
+        "duplicate key value violates unique constraint \"{constraint}\""
+    ))
+}
+
+/// Runs the given function with exclusive access to the store of the given database
+pub fn with_store<T, E>(
+    database: &Arc<RwLock<MemoryDatabase>>,
+    function: impl FnOnce(&mut MemoryStore) -> Result<T, E>,
+) -> Result<T, E> {
+    let database = database.read().expect("Failed to get read lock on DB");
+    let mut store = database.lock_store();
+    function(&mut store)
+}
+
+/// The transaction currently running on the memory database
+#[derive(Default)]
+struct MemoryTransaction {
+    /// The thread running the transaction, None if there is none
+    owner: Option<ThreadId>,
+    /// The store as it was when the transaction and each of its savepoints began
+    snapshots: Vec<MemoryStore>,
+}
+
+impl MemoryTransaction {
+    fn is_owned_by_other_thread(&self) -> bool {
+        self.owner
+            .is_some_and(|owner| owner != thread::current().id())
+    }
+}
+
+/// # Memory Database
+/// A database which keeps all data in memory, for running tests without a Postgres server.
+///
+/// Transactions are emulated by snapshotting the whole store when they begin. While a transaction
+/// is running, other threads wait before accessing the store, so transactions run one after another
+/// and a rollback never discards changes of other threads.
+pub struct MemoryDatabase {
+    store: Mutex<MemoryStore>,
+    transaction: Mutex<MemoryTransaction>,
+    transaction_ended: Condvar,
+}
+
+impl MemoryDatabase {
+    pub fn new() -> Self {
+        Self {
+            store: Mutex::new(MemoryStore::default()),
+            transaction: Mutex::new(MemoryTransaction::default()),
+            transaction_ended: Condvar::new(),
+        }
+    }
+
+    pub fn create() -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self::new()))
+    }
+
+    /// Waits until no other thread is running a transaction
+    fn lock_transaction(&self) -> MutexGuard<'_, MemoryTransaction> {
+        let transaction = self
+            .transaction
+            .lock()
+            .expect("Failed to get lock on memory transaction");
+        self.transaction_ended
+            .wait_while(transaction, |transaction| {
+                transaction.is_owned_by_other_thread()
+            })
+            .expect("Failed to get lock on memory transaction")
+    }
+
+    fn lock_store(&self) -> MutexGuard<'_, MemoryStore> {
+        // The store is locked before the transaction is released,
+        // so no other thread can begin a transaction in between
+        let _transaction = self.lock_transaction();
+        self.store
+            .lock()
+            .expect("Failed to get lock on memory store")
+    }
+
+    fn end_transaction(&self, commit: bool) -> Result<(), GameDatabaseError> {
+        let mut transaction = self.lock_transaction();
+        let snapshot = transaction
+            .snapshots
+            .pop()
+            .ok_or_else(|| GameDatabaseError::transaction_failed("No transaction running"))?;
+        if !commit {
+            *self
+                .store
+                .lock()
+                .expect("Failed to get lock on memory store") = snapshot;
+        }
+
+        if transaction.snapshots.is_empty() {
+            transaction.owner = None;
+            self.transaction_ended.notify_all();
+        }
+        Ok(())
+    }
+}
+
+impl Default for MemoryDatabase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseInterface for MemoryDatabase {
+    fn connect(&mut self, _postgres_url: &str) -> Result<(), GameDatabaseError> {
+        Ok(())
+    }
+
+    fn run_migrations(&self) -> Result<(), GameDatabaseError> {
+        Ok(())
+    }
+
+    fn get_connection(&self) -> Result<DatabaseConnection, GameDatabaseError> {
+        Err(GameDatabaseError::missing_connection())
+    }
+
+    fn begin_transaction(&self) -> Result<(), GameDatabaseError> {
+        let mut transaction = self.lock_transaction();
+        let snapshot = self
+            .store
+            .lock()
+            .expect("Failed to get lock on memory store")
+            .clone();
+        transaction.owner = Some(thread::current().id());
+        transaction.snapshots.push(snapshot);
+        Ok(())
+    }
+
+    fn commit_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.end_transaction(true)
+    }
+
+    fn rollback_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.end_transaction(false)
+    }
+
+    fn clear(&self) -> Result<(), GameDatabaseError> {
+        let mut transaction = self.lock_transaction();
+        *self
+            .store
+            .lock()
+            .expect("Failed to get lock on memory store") = MemoryStore::default();
+        *transaction = MemoryTransaction::default();
+        self.transaction_ended.notify_all();
+        Ok(())
+    }
+}
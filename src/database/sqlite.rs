@@ -0,0 +1,132 @@
+use crate::database::{ConnectionPool, DatabaseConnection, DatabaseInterface};
+use crate::game::errors::database::GameDatabaseError;
+use diesel::connection::SimpleConnection;
+use diesel::r2d2::{ConnectionManager, CustomizeConnection, Pool};
+use diesel::SqliteConnection;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use std::sync::{Arc, RwLock};
+
+pub mod schema;
+
+pub const SQLITE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations_sqlite");
+
+/// Returns the path of the SQLite database if the given URL points to one,
+/// e.g. `sqlite://fish.db` or `sqlite::memory:` for a database which only lives in memory.
+pub fn parse_sqlite_url(database_url: &str) -> Option<&str> {
+    database_url
+        .strip_prefix("sqlite://")
+        .or_else(|| database_url.strip_prefix("sqlite:"))
+}
+
+/// Returns the connection of the transaction running on the current thread,
+/// or the pooled connection if there is none
+pub fn get_connection(
+    database: &Arc<RwLock<SqliteDatabase>>,
+) -> Result<DatabaseConnection<SqliteConnection>, GameDatabaseError> {
+    database
+        .read()
+        .expect("Failed to get read lock on DB")
+        .get_connection_pool()?
+        .get_connection()
+}
+
+/// SQLite doesn't enforce foreign keys unless they are enabled for each connection
+#[derive(Debug)]
+struct SqliteConnectionCustomizer;
+
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for SqliteConnectionCustomizer {
+    fn on_acquire(&self, connection: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        connection
+            .batch_execute("PRAGMA foreign_keys = ON; PRAGMA busy_timeout = 5000;")
+            .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
+
+/// # SQLite Database
+/// A database stored in a single file, for small deployments without a Postgres server.
+///
+/// All queries share a single connection, so transactions of different threads run one after another.
+pub struct SqliteDatabase {
+    connection_pool: Option<ConnectionPool<SqliteConnection>>,
+}
+
+impl SqliteDatabase {
+    pub fn new() -> Self {
+        Self {
+            connection_pool: None,
+        }
+    }
+
+    pub fn create() -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self::new()))
+    }
+
+    fn get_connection_pool(&self) -> Result<&ConnectionPool<SqliteConnection>, GameDatabaseError> {
+        self.connection_pool
+            .as_ref()
+            .ok_or_else(GameDatabaseError::missing_connection)
+    }
+}
+
+impl Default for SqliteDatabase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseInterface for SqliteDatabase {
+    /// Connects to the SQLite database at the given path
+    fn connect(&mut self, sqlite_path: &str) -> Result<(), GameDatabaseError> {
+        let connection_manager = ConnectionManager::<SqliteConnection>::new(sqlite_path);
+        // A single connection which is never closed, in-memory databases only live as long as it does
+        let pool = Pool::builder()
+            .max_size(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connection_customizer(Box::new(SqliteConnectionCustomizer))
+            .build(connection_manager)
+            .map_err(|e| GameDatabaseError::connection_failed(&e.to_string()))?;
+        self.connection_pool = Some(ConnectionPool::new(pool));
+        self.run_migrations()?;
+        Ok(())
+    }
+
+    fn run_migrations(&self) -> Result<(), GameDatabaseError> {
+        let mut connection = self.get_connection_pool()?.get_connection()?;
+        connection
+            .run_pending_migrations(SQLITE_MIGRATIONS)
+            .map_err(|e| GameDatabaseError::migrations_failed(&e.to_string()))?;
+        Ok(())
+    }
+
+    /// Only Postgres connections are handed out here, the SQLite repositories use [get_connection]
+    fn get_connection(&self) -> Result<DatabaseConnection, GameDatabaseError> {
+        Err(GameDatabaseError::missing_connection())
+    }
+
+    fn begin_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.get_connection_pool()?.begin_transaction()
+    }
+
+    fn commit_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.get_connection_pool()?.commit_transaction()
+    }
+
+    fn rollback_transaction(&self) -> Result<(), GameDatabaseError> {
+        self.get_connection_pool()?.rollback_transaction()
+    }
+
+    fn clear(&self) -> Result<(), GameDatabaseError> {
+        let mut connection = self.get_connection_pool()?.get_connection()?;
+
+        connection
+            .revert_all_migrations(SQLITE_MIGRATIONS)
+            .map_err(|e| GameDatabaseError::migrations_failed(&e.to_string()))?;
+
+        connection
+            .run_pending_migrations(SQLITE_MIGRATIONS)
+            .map_err(|e| GameDatabaseError::migrations_failed(&e.to_string()))?;
+
+        Ok(())
+    }
+}
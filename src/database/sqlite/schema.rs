@@ -0,0 +1,138 @@
+diesel::table! {
+    fish_users (id) {
+        id -> BigInt,
+        external_id -> BigInt,
+        credits -> BigInt,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        timezone -> Text,
+        version -> BigInt,
+    }
+}
+
+diesel::table! {
+    fish_user_locations (user_id, location_id) {
+        user_id -> BigInt,
+        location_id -> Integer,
+        unlocked_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    fish_user_achievements (user_id, achievement_id) {
+        user_id -> BigInt,
+        achievement_id -> Integer,
+        unlocked_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    fish_specimens (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+        species_id -> Integer,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        size_baby_ratio -> Float,
+        size_adult_ratio -> Float,
+        lifespan_days_ratio -> Float,
+        catch_age -> Float,
+        pond_id -> Nullable<BigInt>,
+        catch_seed -> Nullable<BigInt>,
+        version -> BigInt,
+    }
+}
+
+diesel::table! {
+    fish_ponds (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        capacity -> Integer,
+        version -> BigInt,
+    }
+}
+
+diesel::table! {
+    fish_fishing_history_entries (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+        species_id -> Integer,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        caught_count -> Integer,
+        sold_count -> Integer,
+        smallest_catch_size_ratio -> Float,
+        largest_catch_size_ratio -> Float,
+        last_catch -> TimestamptzSqlite,
+        first_sell -> Nullable<TimestamptzSqlite>,
+        last_sell -> Nullable<TimestamptzSqlite>,
+        smallest_catch_specimen_id -> Nullable<BigInt>,
+        smallest_catch_size_mm -> Nullable<Float>,
+        smallest_catch_weight_g -> Nullable<Float>,
+        smallest_catch_time -> Nullable<TimestamptzSqlite>,
+        largest_catch_specimen_id -> Nullable<BigInt>,
+        largest_catch_size_mm -> Nullable<Float>,
+        largest_catch_weight_g -> Nullable<Float>,
+        largest_catch_time -> Nullable<TimestamptzSqlite>,
+        version -> BigInt,
+    }
+}
+
+diesel::table! {
+    fish_items (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+        type_id -> Integer,
+        properties -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        version -> BigInt,
+    }
+}
+
+diesel::table! {
+    fish_catch_events (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+        species_id -> Integer,
+        specimen_id -> BigInt,
+        total_size_ratio -> Float,
+        size_mm -> Float,
+        weight_g -> Float,
+        caught_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    fish_sale_events (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+        species_id -> Integer,
+        specimen_id -> BigInt,
+        sold_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::joinable!(fish_user_locations -> fish_users (user_id));
+diesel::joinable!(fish_user_achievements -> fish_users (user_id));
+diesel::joinable!(fish_specimens -> fish_users (user_id));
+diesel::joinable!(fish_specimens -> fish_ponds (pond_id));
+diesel::joinable!(fish_ponds -> fish_users (user_id));
+diesel::joinable!(fish_fishing_history_entries -> fish_users (user_id));
+diesel::joinable!(fish_items -> fish_users (user_id));
+diesel::joinable!(fish_catch_events -> fish_users (user_id));
+diesel::joinable!(fish_sale_events -> fish_users (user_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    fish_users,
+    fish_user_locations,
+    fish_user_achievements,
+    fish_specimens,
+    fish_ponds,
+    fish_fishing_history_entries,
+    fish_items,
+    fish_catch_events,
+    fish_sale_events,
+);
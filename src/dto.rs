@@ -1,3 +1,13 @@
+pub mod achievement_unlock;
+pub mod daily_weather_summary;
+pub mod fishdex;
+pub mod fishing_result;
+pub mod forecast_window;
 pub mod inventory;
+pub mod leaderboard;
 pub mod location_unlock_requirements;
+pub mod location_unlock_result;
+pub mod personal_best;
+pub mod purchase_result;
+pub mod sell_result;
 pub mod user_location_unlock;
@@ -0,0 +1,34 @@
+use crate::data::achievement_data::AchievementData;
+use crate::models::user_achievement::UserAchievement;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AchievementUnlock {
+    pub achievement: Arc<AchievementData>,
+    pub unlocked_at: DateTime<Utc>,
+}
+
+impl AchievementUnlock {
+    pub fn from_user_achievement(
+        user_achievement: UserAchievement,
+        get_achievement: &impl Fn(i32) -> Option<Arc<AchievementData>>,
+    ) -> Option<Self> {
+        get_achievement(user_achievement.achievement_id).map(|achievement| Self {
+            achievement,
+            unlocked_at: user_achievement.unlocked_at,
+        })
+    }
+
+    pub fn from_user_achievements(
+        user_achievements: Vec<UserAchievement>,
+        get_achievement: impl Fn(i32) -> Option<Arc<AchievementData>>,
+    ) -> Vec<Self> {
+        user_achievements
+            .into_iter()
+            .filter_map(|user_achievement| {
+                Self::from_user_achievement(user_achievement, &get_achievement)
+            })
+            .collect()
+    }
+}
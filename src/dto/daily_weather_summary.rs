@@ -0,0 +1,19 @@
+use crate::enums::season::Season;
+use chrono::NaiveDate;
+
+/// The weather of a location over one in-game day, sampled hourly
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyWeatherSummary {
+    pub location_id: i32,
+    /// The in-game date in the timezone of the location
+    pub date: NaiveDate,
+    /// The season at the start of the day
+    pub season: Season,
+    pub min_temperature_c: f32,
+    pub max_temperature_c: f32,
+    pub average_temperature_c: f32,
+    /// The amount of hourly samples in which it was raining
+    pub rain_hours: u32,
+    /// Between 0.0 and 1.0
+    pub average_cloudiness: f32,
+}
@@ -0,0 +1,148 @@
+use crate::data::species_data::SpeciesData;
+use crate::models::fishing_history_entry::FishingHistoryEntry;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+/// A user's collection progress over every configured species
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fishdex {
+    /// One entry per configured species, ordered by species ID
+    pub entries: Vec<FishdexEntry>,
+    pub completion: FishdexCompletion,
+    /// Completion of the species which can be encountered at each location, by location ID
+    pub completion_by_location: BTreeMap<i32, FishdexCompletion>,
+    /// Completion of the species of each rarity level
+    pub completion_by_rarity: BTreeMap<u8, FishdexCompletion>,
+}
+
+impl Fishdex {
+    pub fn new(
+        species: impl IntoIterator<Item = Arc<SpeciesData>>,
+        history_entries: Vec<FishingHistoryEntry>,
+        hide_uncaught_names: bool,
+    ) -> Self {
+        let mut history_by_species: HashMap<i32, FishingHistoryEntry> = history_entries
+            .into_iter()
+            .map(|entry| (entry.species_id, entry))
+            .collect();
+
+        let mut entries: Vec<FishdexEntry> = species
+            .into_iter()
+            .map(|species| {
+                FishdexEntry::new(
+                    &species,
+                    history_by_species.remove(&species.id),
+                    hide_uncaught_names,
+                )
+            })
+            .collect();
+        entries.sort_by_key(|entry| entry.species_id);
+
+        let mut completion = FishdexCompletion::default();
+        let mut completion_by_location: BTreeMap<i32, FishdexCompletion> = BTreeMap::new();
+        let mut completion_by_rarity: BTreeMap<u8, FishdexCompletion> = BTreeMap::new();
+        for entry in &entries {
+            completion.add(entry);
+            completion_by_rarity
+                .entry(entry.rarity_level)
+                .or_default()
+                .add(entry);
+            for location_id in &entry.location_ids {
+                completion_by_location
+                    .entry(*location_id)
+                    .or_default()
+                    .add(entry);
+            }
+        }
+
+        Self {
+            entries,
+            completion,
+            completion_by_location,
+            completion_by_rarity,
+        }
+    }
+
+    pub fn get_entry(&self, species_id: i32) -> Option<&FishdexEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.species_id == species_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FishdexEntry {
+    pub species_id: i32,
+    /// None if the species wasn't caught yet and uncaught names are hidden
+    pub name: Option<String>,
+    pub rarity_level: u8,
+    /// The locations the species can be encountered at, ordered by location ID
+    pub location_ids: Vec<i32>,
+    /// When the species was first caught, None if it wasn't caught yet
+    pub first_catch: Option<DateTime<Utc>>,
+    pub caught_count: i32,
+    pub sold_count: i32,
+}
+
+impl FishdexEntry {
+    fn new(
+        species: &SpeciesData,
+        history_entry: Option<FishingHistoryEntry>,
+        hide_uncaught_names: bool,
+    ) -> Self {
+        let location_ids: BTreeSet<i32> = species
+            .encounters
+            .iter()
+            .map(|encounter| encounter.location_id)
+            .collect();
+        let is_caught = history_entry.is_some();
+
+        Self {
+            species_id: species.id,
+            name: (is_caught || !hide_uncaught_names).then(|| species.name.clone()),
+            rarity_level: species.get_rarity_level(),
+            location_ids: location_ids.into_iter().collect(),
+            first_catch: history_entry.as_ref().map(|entry| entry.get_first_catch()),
+            caught_count: history_entry
+                .as_ref()
+                .map(|entry| entry.caught_count)
+                .unwrap_or_default(),
+            sold_count: history_entry
+                .as_ref()
+                .map(|entry| entry.sold_count)
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn is_caught(&self) -> bool {
+        self.first_catch.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FishdexCompletion {
+    pub caught: u32,
+    pub total: u32,
+}
+
+impl FishdexCompletion {
+    fn add(&mut self, entry: &FishdexEntry) {
+        self.total += 1;
+        if entry.is_caught() {
+            self.caught += 1;
+        }
+    }
+
+    /// Completion from 0 to 100, 100 if there is nothing to catch
+    pub fn get_percentage(&self) -> f32 {
+        if self.total == 0 {
+            return 100.0;
+        }
+        self.caught as f32 / self.total as f32 * 100.0
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.caught >= self.total
+    }
+}
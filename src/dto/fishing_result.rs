@@ -0,0 +1,30 @@
+use crate::data::location_data::LocationData;
+use crate::data::species_data::SpeciesData;
+use crate::dto::achievement_unlock::AchievementUnlock;
+use crate::game::systems::weather_system::weather::Weather;
+use crate::models::fishing_history_entry::FishingHistoryEntry;
+use crate::models::item::Item;
+use crate::models::specimen::Specimen;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct FishingResult {
+    /// The location the user fished at
+    pub location: Arc<LocationData>,
+    /// The species of the caught specimen
+    pub species: Arc<SpeciesData>,
+    /// The local weather at the time of the catch
+    pub weather: Weather,
+    /// The newly caught specimen
+    pub specimen: Specimen,
+    /// The updated fishing history of the user with the caught species
+    pub history_entry: FishingHistoryEntry,
+    /// The used rod after the cast, if a rod was used
+    pub rod: Option<Item>,
+    /// The remaining bait after the cast, None if no bait was used or it was used up
+    pub bait: Option<Item>,
+    /// The seed the catch was rolled with, also recorded on the specimen
+    pub seed: u64,
+    /// The achievements the user unlocked with this catch
+    pub achievements: Vec<AchievementUnlock>,
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+/// A stretch of time in which a species can be encountered at a location
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForecastWindow {
+    pub location_id: i32,
+    pub species_id: i32,
+    /// The in-game time the window starts at, in the timezone of the location
+    pub start: DateTime<Tz>,
+    /// The in-game time the window ends at (exclusive), in the timezone of the location
+    pub end: DateTime<Tz>,
+    /// The real time the window starts at
+    pub real_start: DateTime<Utc>,
+    /// The real time the window ends at (exclusive)
+    pub real_end: DateTime<Utc>,
+    /// The highest chance per cast of encountering the species within the window
+    pub max_probability: f64,
+    /// The average chance per cast of encountering the species within the window
+    pub average_probability: f64,
+}
@@ -0,0 +1,97 @@
+use crate::dto::personal_best::PersonalRecord;
+
+/// Which page of a leaderboard to get, the first page is page 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaderboardPage {
+    pub page: u32,
+    pub page_size: u32,
+}
+
+impl LeaderboardPage {
+    pub fn new(page: u32, page_size: u32) -> Self {
+        Self { page, page_size }
+    }
+
+    /// How many entries come before this page
+    pub fn offset(&self) -> i64 {
+        self.page as i64 * self.page_size as i64
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.page_size as i64
+    }
+
+    /// The rank of the entry at the given index of this page, ranks start at 1
+    pub fn rank_at(&self, index: usize) -> u64 {
+        self.offset() as u64 + index as u64 + 1
+    }
+}
+
+impl Default for LeaderboardPage {
+    fn default() -> Self {
+        Self {
+            page: 0,
+            page_size: 10,
+        }
+    }
+}
+
+/// A single page of a leaderboard
+#[derive(Debug, Clone, PartialEq)]
+pub struct Leaderboard<T = LeaderboardEntry> {
+    /// How many entries the leaderboard has across all pages
+    pub total_entries: u64,
+    pub entries: Vec<T>,
+}
+
+impl<T> Leaderboard<T> {
+    pub fn total_pages(&self, page_size: u32) -> u64 {
+        if page_size == 0 {
+            return 0;
+        }
+        self.total_entries.div_ceil(page_size as u64)
+    }
+}
+
+impl Leaderboard {
+    /// Ranks rows of (user ID, external ID, value) which are already ordered and paginated
+    pub fn from_rows(
+        total_entries: i64,
+        page: LeaderboardPage,
+        rows: Vec<(i64, i64, i64)>,
+    ) -> Self {
+        let entries = rows
+            .into_iter()
+            .enumerate()
+            .map(|(index, (user_id, external_id, value))| LeaderboardEntry {
+                rank: page.rank_at(index),
+                user_id,
+                external_id,
+                value,
+            })
+            .collect();
+        Self {
+            total_entries: total_entries.max(0) as u64,
+            entries,
+        }
+    }
+}
+
+/// A user's position on a leaderboard, users with the same value are ordered by their user ID
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    pub rank: u64,
+    pub user_id: i64,
+    pub external_id: i64,
+    pub value: i64,
+}
+
+/// A user's position on the leaderboard of the largest specimens of a species
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordLeaderboardEntry {
+    pub rank: u64,
+    pub user_id: i64,
+    pub external_id: i64,
+    pub species_id: i32,
+    pub record: PersonalRecord,
+}
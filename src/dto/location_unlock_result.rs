@@ -0,0 +1,10 @@
+use crate::dto::achievement_unlock::AchievementUnlock;
+use crate::dto::user_location_unlock::UserLocationUnlock;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationUnlockResult {
+    /// Information about the newly unlocked location
+    pub location_unlock: UserLocationUnlock,
+    /// The achievements the user unlocked with this location
+    pub achievements: Vec<AchievementUnlock>,
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+
+/// A user's size records for a single species
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalBest {
+    pub species_id: i32,
+    pub smallest: PersonalRecord,
+    pub largest: PersonalRecord,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PersonalRecord {
+    /// The specimen holding the record, it might have been sold since
+    /// None for records which were set before specimens were tracked
+    pub specimen_id: Option<i64>,
+    /// Size ratio (0-1) of the record relative to the species' min/max size range
+    pub total_size_ratio: f32,
+    /// Size of the specimen when it was caught
+    pub size_mm: f32,
+    /// Weight of the specimen when it was caught
+    pub weight_g: f32,
+    /// When the record was set, None for records which were set before specimens were tracked
+    pub set_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,15 @@
+use crate::dto::achievement_unlock::AchievementUnlock;
+use crate::models::item::Item;
+use crate::models::user::User;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PurchaseResult {
+    /// The user after paying for the item
+    pub user: User,
+    /// The purchased item, stacked onto an existing item if the item is stackable
+    pub item: Item,
+    /// The total amount of credits the user paid
+    pub cost: i64,
+    /// The achievements the user unlocked with this purchase, filled in by the game
+    pub achievements: Vec<AchievementUnlock>,
+}
@@ -0,0 +1,21 @@
+use crate::dto::achievement_unlock::AchievementUnlock;
+use crate::models::specimen::Specimen;
+use crate::models::user::User;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SellResult {
+    /// The user after receiving the credits
+    pub user: User,
+    /// The specimens that were sold, they no longer exist in the database
+    pub specimens: Vec<Specimen>,
+    /// The total amount of credits the user received
+    pub credits: i64,
+    /// The achievements the user unlocked with this sale, filled in by the game
+    pub achievements: Vec<AchievementUnlock>,
+}
+
+impl SellResult {
+    pub fn is_empty(&self) -> bool {
+        self.specimens.is_empty()
+    }
+}
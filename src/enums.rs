@@ -1,2 +1,8 @@
 pub mod item_category;
+pub mod leaderboard_kind;
+pub mod leaderboard_window;
+pub mod moon_phase;
+pub mod record_kind;
 pub mod season;
+pub mod tide_state;
+pub mod weather_condition;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The server-wide leaderboards which rank users by a single number
+#[derive(Debug, Clone, Copy, Hash, Eq, Serialize, Deserialize, PartialEq)]
+pub enum LeaderboardKind {
+    /// Number of specimens caught
+    MostCatches,
+    /// Number of distinct species caught
+    MostSpecies,
+    /// Current amount of credits, only available for all-time leaderboards
+    MostCredits,
+    /// Number of specimens sold
+    MostSold,
+}
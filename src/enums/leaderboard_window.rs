@@ -0,0 +1,38 @@
+use crate::data::location_data::{year_progress_since_march_equinox, SECONDS_PER_YEAR};
+use crate::game::errors::GameResult;
+use crate::game::game_time::GameTime;
+use chrono::{DateTime, Datelike, Days, NaiveTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The time span a leaderboard covers, based on the in-game calendar
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, Serialize, Deserialize, PartialEq)]
+pub enum LeaderboardWindow {
+    #[default]
+    AllTime,
+    /// Since the start of the current in-game season, seasons follow the northern hemisphere
+    /// and start at the equinoxes and solstices
+    ThisSeason,
+    /// Since the start of the current in-game week (Monday 00:00 UTC)
+    ThisWeek,
+}
+
+impl LeaderboardWindow {
+    /// The real time this window starts at, None for all-time windows
+    pub fn get_start(&self, game_time: &GameTime) -> GameResult<Option<DateTime<Utc>>> {
+        let now = game_time.now()?;
+        let start = match self {
+            LeaderboardWindow::AllTime => return Ok(None),
+            LeaderboardWindow::ThisSeason => {
+                let seasons_since_equinox = year_progress_since_march_equinox(now) * 4.0;
+                let seconds_into_season = seasons_since_equinox.fract() * SECONDS_PER_YEAR / 4.0;
+                now - TimeDelta::milliseconds((seconds_into_season * 1000.0) as i64)
+            }
+            LeaderboardWindow::ThisWeek => {
+                let days_into_week = now.weekday().num_days_from_monday() as u64;
+                let monday = now.date_naive() - Days::new(days_into_week);
+                monday.and_time(NaiveTime::MIN).and_utc()
+            }
+        };
+        Ok(Some(game_time.to_real_time(start)?))
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, Serialize, Deserialize, PartialEq)]
+pub enum MoonPhase {
+    #[default]
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    /// The phase closest to the given progress (0-1) through the lunar cycle, starting at new moon
+    pub fn from_progress(progress: f64) -> Self {
+        let index = (progress.rem_euclid(1.0) * 8.0).round() as usize % 8;
+        match index {
+            0 => Self::NewMoon,
+            1 => Self::WaxingCrescent,
+            2 => Self::FirstQuarter,
+            3 => Self::WaxingGibbous,
+            4 => Self::FullMoon,
+            5 => Self::WaningGibbous,
+            6 => Self::LastQuarter,
+            7 => Self::WaningCrescent,
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// The personal records kept per user and species
+#[derive(Debug, Clone, Copy, Hash, Eq, Serialize, Deserialize, PartialEq)]
+pub enum RecordKind {
+    /// The specimen with the smallest total size ratio
+    Smallest,
+    /// The specimen with the largest total size ratio
+    Largest,
+}
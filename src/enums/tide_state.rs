@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Hash, Eq, Serialize, Deserialize, PartialEq)]
+pub enum TideState {
+    Low,
+    Rising,
+    High,
+    Falling,
+}
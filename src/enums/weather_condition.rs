@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// The most notable weather phenomenon at a point in time
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, Serialize, Deserialize, PartialEq)]
+pub enum WeatherCondition {
+    #[default]
+    Clear,
+    Cloudy,
+    Fog,
+    Rain,
+    Snow,
+    Thunderstorm,
+}
+
+impl WeatherCondition {
+    /// If the condition comes with any kind of precipitation
+    pub fn is_precipitation(&self) -> bool {
+        matches!(self, Self::Rain | Self::Snow | Self::Thunderstorm)
+    }
+}
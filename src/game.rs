@@ -2,40 +2,71 @@ use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
 use crate::data::item_data::ItemData;
 use crate::data::location_data::LocationData;
 use crate::data::species_data::SpeciesData;
-use crate::database::{Database, DatabaseInterface};
+use crate::database::{transaction, Database, DatabaseInterface};
+use crate::dto::achievement_unlock::AchievementUnlock;
+use crate::dto::daily_weather_summary::DailyWeatherSummary;
+use crate::dto::fishdex::Fishdex;
+use crate::dto::fishing_result::FishingResult;
+use crate::dto::forecast_window::ForecastWindow;
 use crate::dto::inventory::Inventory;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage, RecordLeaderboardEntry};
+use crate::dto::location_unlock_result::LocationUnlockResult;
+use crate::dto::personal_best::PersonalBest;
+use crate::dto::purchase_result::PurchaseResult;
+use crate::dto::sell_result::SellResult;
 use crate::dto::user_location_unlock::UserLocationUnlock;
+use crate::enums::leaderboard_kind::LeaderboardKind;
+use crate::enums::leaderboard_window::LeaderboardWindow;
 use crate::game::asset_server::AssetServerInterface;
+use crate::game::clock::Clock;
+use crate::game::errors::item_event::GameItemEventError;
 use crate::game::errors::resource::GameResourceError;
 use crate::game::errors::GameResult;
+use crate::game::event_bus::EventBusInterface;
+use crate::game::game_time::GameTime;
 use crate::game::interface::GameInterface;
+use crate::game::random_source::RandomSource;
+use crate::game::repositories::catch_event_repository::CatchEventRepositoryInterface;
 use crate::game::repositories::fishing_history_entry_repository::FishingHistoryEntryRepositoryInterface;
 use crate::game::repositories::item_repository::ItemRepositoryInterface;
 use crate::game::repositories::pond_repository::PondRepositoryInterface;
+use crate::game::repositories::sale_event_repository::SaleEventRepositoryInterface;
 use crate::game::repositories::specimen_repository::SpecimenRepositoryInterface;
 use crate::game::repositories::user_repository::UserRepositoryInterface;
-use crate::game::service_provider::{ServiceProvider, ServiceProviderInterface};
+use crate::game::service_provider::{Repositories, ServiceProvider, ServiceProviderInterface};
+use crate::game::services::achievement_service::{AchievementServiceInterface, AchievementTrigger};
 use crate::game::services::encounter_service::EncounterServiceInterface;
 use crate::game::services::fishing_history_service::FishingHistoryServiceInterface;
+use crate::game::services::forecast_service::ForecastServiceInterface;
 use crate::game::services::item_service::ItemServiceInterface;
+use crate::game::services::leaderboard_service::LeaderboardServiceInterface;
 use crate::game::services::location_service::LocationServiceInterface;
 use crate::game::services::pond_service::PondServiceInterface;
+use crate::game::services::shop_service::ShopServiceInterface;
 use crate::game::services::species_service::SpeciesServiceInterface;
 use crate::game::services::specimen_service::SpecimenServiceInterface;
 use crate::game::services::user_service::UserServiceInterface;
 use crate::game::services::weather_service::WeatherServiceInterface;
+use crate::game::systems::encounter_system::{EncounterEquipment, EncounterProbability};
 use crate::game::systems::weather_system::weather::Weather;
 use crate::models::fishing_history_entry::FishingHistoryEntry;
 use crate::models::item::properties_container::ItemPropertiesContainerInterface;
-use crate::models::item::Item;
+use crate::models::item::{Item, ItemInterface};
+use crate::models::pond::Pond;
 use crate::models::specimen::Specimen;
 use crate::models::user::User;
+use chrono::{DateTime, NaiveDate, TimeDelta};
+use chrono_tz::Tz;
 use std::sync::{Arc, RwLock};
 
 pub mod asset_server;
+pub mod clock;
 pub mod errors;
+pub mod event_bus;
+pub mod game_time;
 pub mod interface;
 pub mod prelude;
+pub mod random_source;
 pub mod repositories;
 pub mod service_provider;
 pub mod services;
@@ -52,8 +83,35 @@ pub struct Game {
 }
 
 impl Game {
+    /// Create a game connected to the database at the given URL and run all pending migrations.
+    ///
+    /// Postgres URLs use the Postgres repositories. With the `sqlite` feature enabled,
+    /// URLs starting with `sqlite://` (or `sqlite:` for e.g. `sqlite::memory:`) use a SQLite database instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_url`: The URL of the database to connect to.
+    /// * `config`: The game config, the default config is used if none is given.
+    ///
+    /// # Returns
+    ///
+    /// Result<[Game], [errors::GameError]>
+    /// - The connected game, if the connection and migrations succeeded
+    /// - An error, if connecting or running the migrations failed
     pub fn new(db_url: &str, config: Option<Arc<dyn ConfigInterface>>) -> GameResult<Self> {
         let config = config.unwrap_or(Config::builder().build().unwrap());
+
+        #[cfg(feature = "sqlite")]
+        if let Some(sqlite_path) = crate::database::sqlite::parse_sqlite_url(db_url) {
+            let db = crate::database::sqlite::SqliteDatabase::create();
+            db.write()
+                .expect("Failed to get database write lock")
+                .connect(sqlite_path)?;
+
+            let service_provider = Arc::new(ServiceProvider::new_sqlite(config, db));
+            return Ok(Game { service_provider });
+        }
+
         let db = Database::create();
         db.write()
             .expect("Failed to get database write lock")
@@ -63,6 +121,182 @@ impl Game {
         let game = Game { service_provider };
         Ok(game)
     }
+
+    /// Create a game which keeps all data in memory instead of a Postgres database,
+    /// useful for running tests without a database server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fish_lib::game::prelude::*;
+    ///
+    /// let game = Game::new_in_memory(None);
+    /// let user = game.user_register(1337).unwrap();
+    /// assert_eq!(game.user_find(1337).unwrap(), user);
+    /// ```
+    #[cfg(feature = "in-memory")]
+    pub fn new_in_memory(config: Option<Arc<dyn ConfigInterface>>) -> Self {
+        let config = config.unwrap_or(Config::builder().build().unwrap());
+        let service_provider = Arc::new(ServiceProvider::new_in_memory(config));
+        Game { service_provider }
+    }
+
+    /// Replace the clock which all game time and persisted timestamps are read from, the system time is used by default.
+    /// Subscribers of the [event_bus::EventBus] are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock`: The [Clock] to use, e.g. a [clock::FixedClock] to freeze time
+    ///   or a [clock::OffsetClock] to fast-forward it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// use std::sync::Arc;
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    /// use fish_lib::game::clock::FixedClock;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// let database_url = env::var("DATABASE_URL").unwrap();
+    /// let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    /// let clock = Arc::new(FixedClock::new(start));
+    /// let game = Game::new(&database_url, None).unwrap().with_clock(clock.clone());
+    ///
+    /// clock.advance(TimeDelta::hours(1));
+    /// assert_eq!(game.clock().now(), start + TimeDelta::hours(1));
+    /// ```
+    pub fn with_clock(self, clock: Arc<dyn Clock>) -> Self {
+        let service_provider = Arc::new(ServiceProvider::with_repositories(
+            self.config(),
+            clock.clone(),
+            self.random_source(),
+            self.database(),
+            self.event_bus(),
+            Repositories::of(&self).with_clock(clock),
+        ));
+        Game { service_provider }
+    }
+
+    /// Replace the source of the seeds which all catches are rolled with, the thread RNG is used by default.
+    /// Subscribers of the [event_bus::EventBus] are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `random_source`: The [RandomSource] to use, e.g. a [random_source::SeededRandomSource]
+    ///   to draw the same sequence of seeds on every run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// use std::sync::Arc;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::random_source::{RandomSource, SeededRandomSource};
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// let database_url = env::var("DATABASE_URL").unwrap();
+    /// let game = Game::new(&database_url, None)
+    ///     .unwrap()
+    ///     .with_random_source(Arc::new(SeededRandomSource::new(42)));
+    ///
+    /// let expected_seed = SeededRandomSource::new(42).next_seed();
+    /// assert_eq!(game.random_source().next_seed(), expected_seed);
+    /// ```
+    pub fn with_random_source(self, random_source: Arc<dyn RandomSource>) -> Self {
+        let service_provider = Arc::new(ServiceProvider::with_repositories(
+            self.config(),
+            self.clock(),
+            random_source,
+            self.database(),
+            self.event_bus(),
+            Repositories::of(&self),
+        ));
+        Game { service_provider }
+    }
+}
+
+impl Game {
+    /// The location and weather are only known when fishing at a location
+    fn catch_specimen(
+        &self,
+        user: &User,
+        species: Arc<SpeciesData>,
+        seed: u64,
+        location_id: Option<i32>,
+        weather: Option<&Weather>,
+    ) -> GameResult<(Specimen, FishingHistoryEntry, Vec<AchievementUnlock>)> {
+        transaction(&self.database(), || {
+            let specimen = self
+                .specimen_service()
+                .process_catch(user, species.clone(), seed)?;
+            let entry = self.fishing_history_service().register_catch(&specimen)?;
+            let achievements = self.achievement_service().process_trigger(
+                user,
+                AchievementTrigger::Catch {
+                    specimen: &specimen,
+                    species: &species,
+                    location_id,
+                    weather,
+                },
+            )?;
+            Ok((specimen, entry, achievements))
+        })
+    }
+
+    /// Unlocks the achievements fulfilled by a sale in the same transaction as the sale
+    fn sell_with_achievements(
+        &self,
+        sell: impl FnOnce() -> GameResult<SellResult>,
+    ) -> GameResult<SellResult> {
+        transaction(&self.database(), || {
+            let mut result = sell()?;
+            if !result.is_empty() {
+                result.achievements = self
+                    .achievement_service()
+                    .process_trigger(&result.user, AchievementTrigger::Sell)?;
+            }
+            Ok(result)
+        })
+    }
+
+    /// Reloads an item the user wants to use, the given one might be outdated or not theirs
+    fn find_owned_item(&self, user: &User, item: Option<&Item>) -> GameResult<Option<Item>> {
+        let Some(item) = item else {
+            return Ok(None);
+        };
+        match self.item_repository().find(item.id)? {
+            Some(stored_item) if stored_item.user_id == user.id => Ok(Some(stored_item)),
+            _ => Err(GameResourceError::item_not_owned(item.id, user.external_id).into()),
+        }
+    }
+
+    fn get_encounter_equipment(
+        &self,
+        rod: Option<&Item>,
+        bait: Option<&Item>,
+    ) -> GameResult<EncounterEquipment> {
+        let mut equipment = EncounterEquipment::default();
+
+        if let Some(rod) = rod {
+            equipment.rod_level = rod
+                .attributes(self.config())
+                .ok_or(GameItemEventError::invalid_item_type(rod.type_id))?
+                .get_rod_level()
+                .ok_or(GameItemEventError::not_a_rod(rod.type_id))?;
+        }
+
+        if let Some(bait) = bait {
+            equipment.bait_level = bait
+                .attributes(self.config())
+                .ok_or(GameItemEventError::invalid_item_type(bait.type_id))?
+                .get_bait_level()
+                .ok_or(GameItemEventError::not_a_bait(bait.type_id))?;
+        }
+
+        Ok(equipment)
+    }
 }
 
 impl GameInterface for Game {
@@ -127,6 +361,173 @@ impl GameInterface for Game {
         }
     }
 
+    /// Get a page of a server-wide leaderboard.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind`: What the users are ranked by
+    /// * `window`: The time span the leaderboard covers, based on the in-game calendar
+    /// * `page`: Which page to get, the first page is page 0
+    /// * `page_size`: How many entries a page has
+    ///
+    /// # Returns
+    /// Result<[Leaderboard], [errors::GameError]>
+    /// - The entries on the page and the total number of entries across all pages.
+    ///   Users with the same value are ordered by their user ID.
+    ///   Within a window, [LeaderboardKind::MostSpecies] counts the distinct species caught in it.
+    /// - An error, if:
+    ///     - The kind can't be limited to the window, credits are only ranked [LeaderboardWindow::AllTime]
+    ///     - Database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::enums::leaderboard_kind::LeaderboardKind;
+    /// use fish_lib::enums::leaderboard_window::LeaderboardWindow;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const SPECIES_ID: i32 = 1;
+    ///
+    /// let species_data = SpeciesData {
+    ///     name: "Koi".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let user = game.user_register(1337).unwrap();
+    /// let user2 = game.user_register(1338).unwrap();
+    /// game.user_catch_specific_specimen(&user, species.clone()).unwrap();
+    /// game.user_catch_specific_specimen(&user2, species.clone()).unwrap();
+    /// game.user_catch_specific_specimen(&user2, species).unwrap();
+    ///
+    /// let leaderboard = game
+    ///     .leaderboard_get(LeaderboardKind::MostCatches, LeaderboardWindow::ThisWeek, 0, 10)
+    ///     .unwrap();
+    /// assert_eq!(leaderboard.total_entries, 2);
+    /// assert_eq!(leaderboard.entries[0].external_id, 1338);
+    /// assert_eq!(leaderboard.entries[0].value, 2);
+    /// assert_eq!(leaderboard.entries[1].rank, 2);
+    ///
+    /// // One entry per page
+    /// let second_page = game
+    ///     .leaderboard_get(LeaderboardKind::MostCatches, LeaderboardWindow::AllTime, 1, 1)
+    ///     .unwrap();
+    /// assert_eq!(second_page.entries.len(), 1);
+    /// assert_eq!(second_page.entries[0].external_id, 1337);
+    /// assert_eq!(second_page.entries[0].rank, 2);
+    /// ```
+    fn leaderboard_get(
+        &self,
+        kind: LeaderboardKind,
+        window: LeaderboardWindow,
+        page: u32,
+        page_size: u32,
+    ) -> GameResult<Leaderboard> {
+        self.leaderboard_service().get_leaderboard(
+            kind,
+            window,
+            LeaderboardPage::new(page, page_size),
+        )
+    }
+
+    /// Get every species that can be encountered at a location in the given [Weather], together
+    /// with the exact probability of catching it with a single cast.
+    ///
+    /// # Arguments
+    ///
+    /// * `location`: The location to get the possible encounters of. (See [Config])
+    /// * `weather`: The [Weather] at the location, its time and conditions decide which species are available.
+    /// * `equipment`: The rod and bait levels to fish with, no equipment if None.
+    ///
+    /// # Returns
+    /// Vec<[EncounterProbability]>, ordered from most to least likely. Empty if nothing can be caught.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::encounter_data::EncounterData;
+    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::game::systems::encounter_system::EncounterEquipment;
+    ///
+    /// const LOCATION_ID: i32 = 1;
+    ///
+    /// let encounter = |rarity_level| EncounterData {
+    ///     location_id: LOCATION_ID,
+    ///     min_time_hour: 0,
+    ///     max_time_hour: 23,
+    ///     rarity_level,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let common_species = SpeciesData {
+    ///     encounters: vec![encounter(10)],
+    ///     ..Default::default()
+    /// };
+    /// let rare_species = SpeciesData {
+    ///     encounters: vec![encounter(200)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let config = Config::builder()
+    ///     .locations(HashMap::from([(LOCATION_ID, LocationData::default())]))
+    ///     .species(HashMap::from([(1, common_species), (2, rare_species)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let location = game.location_find(LOCATION_ID).unwrap();
+    /// let weather = game.location_weather_current(location.clone()).unwrap();
+    /// let probabilities = game.location_encounter_probabilities(location.clone(), &weather, None);
+    /// assert_eq!(probabilities.len(), 2);
+    /// assert_eq!(probabilities[0].species_id, 1);
+    /// assert!(probabilities[0].probability > probabilities[1].probability);
+    ///
+    /// let total: f64 = probabilities.iter().map(|encounter| encounter.probability).sum();
+    /// assert!((total - 1.0).abs() < 1e-9);
+    ///
+    /// // Bait makes rarer species more likely
+    /// let equipment = EncounterEquipment { rod_level: 0, bait_level: 1 };
+    /// let with_bait = game.location_encounter_probabilities(location, &weather, Some(equipment));
+    /// assert!(with_bait[1].probability > probabilities[1].probability);
+    /// ```
+    fn location_encounter_probabilities(
+        &self,
+        location: Arc<LocationData>,
+        weather: &Weather,
+        equipment: Option<EncounterEquipment>,
+    ) -> Vec<EncounterProbability> {
+        self.encounter_service().get_encounter_probabilities(
+            weather.time,
+            weather,
+            location.id,
+            equipment.unwrap_or_default(),
+        )
+    }
+
     /// Get [LocationData] for the specified location ID.
     ///
     /// # Arguments
@@ -188,6 +589,80 @@ impl GameInterface for Game {
         }
     }
 
+    /// Forecast when species can be encountered at a location over the next in-game days.
+    /// The forecast is based on the deterministic weather of the location, sampled every 15 in-game minutes.
+    ///
+    /// # Arguments
+    ///
+    /// * `location`: The location to forecast the encounters of. (See [Config])
+    /// * `days`: The amount of in-game days to look ahead starting now, at most [services::forecast_service::MAX_FORECAST_DAYS].
+    /// * `equipment`: The rod and bait levels to fish with, no equipment if None.
+    ///
+    /// # Returns
+    /// Result<Vec<[ForecastWindow]>, [errors::GameError]>
+    /// - The windows in which species can be encountered, ordered by their start
+    /// - An error, if:
+    ///     - The location has no weather
+    ///     - More than [services::forecast_service::MAX_FORECAST_DAYS] days are requested
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use chrono::Timelike;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::encounter_data::EncounterData;
+    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const LOCATION_ID: i32 = 1;
+    /// const SPECIES_ID: i32 = 1;
+    ///
+    /// // A species that can only be caught between 10pm and 3:59am
+    /// let species_data = SpeciesData {
+    ///     encounters: vec![EncounterData {
+    ///         location_id: LOCATION_ID,
+    ///         min_time_hour: 22,
+    ///         max_time_hour: 3,
+    ///         ..Default::default()
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let config = Config::builder()
+    ///     .locations(HashMap::from([(LOCATION_ID, LocationData::default())]))
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let location = game.location_find(LOCATION_ID).unwrap();
+    /// let windows = game.location_forecast(location, 3, None).unwrap();
+    /// assert!(windows.len() >= 3);
+    /// for window in windows {
+    ///     assert_eq!(window.species_id, SPECIES_ID);
+    ///     assert!(window.start < window.end);
+    ///     assert_eq!(window.max_probability, 1.0);
+    ///     assert!(window.start.hour() >= 22 || window.start.hour() < 4);
+    /// }
+    /// ```
+    fn location_forecast(
+        &self,
+        location: Arc<LocationData>,
+        days: u32,
+        equipment: Option<EncounterEquipment>,
+    ) -> GameResult<Vec<ForecastWindow>> {
+        self.forecast_service()
+            .forecast_location(location, days, equipment.unwrap_or_default())
+    }
+
     /// Get the current [Weather] of a specified location.
     /// You will be able to get the weather for all locations specified by you in your [Config].
     ///
@@ -243,17 +718,21 @@ impl GameInterface for Game {
         self.weather_service().get_current_weather(location)
     }
 
-    /// Get [SpeciesData] for the specified species ID.
+    /// Get a summary of the [Weather] of a location for each in-game day, in the past or future.
+    /// Each day is sampled hourly in the timezone of the location.
     ///
     /// # Arguments
     ///
-    /// * `species_id`: The ID of the species to get the data of. (See [Config])
+    /// * `location`: The location to summarize the weather of. (See [Config])
+    /// * `start_date`: The first in-game date to summarize.
+    /// * `days`: The amount of consecutive in-game days to summarize, at most [services::weather_service::MAX_SUMMARY_DAYS].
     ///
     /// # Returns
-    ///
-    /// Result<Arc<[SpeciesData], Global>, [errors::GameError]>
-    /// - The [SpeciesData], if the species with the given ID exists
-    /// - An error, if no species with the given ID exists
+    /// Result<Vec<[DailyWeatherSummary]>, [errors::GameError]>
+    /// - One summary per day, in order
+    /// - An error, if:
+    ///     - The location has no weather
+    ///     - More than [services::weather_service::MAX_SUMMARY_DAYS] days are requested
     ///
     /// # Examples
     ///
@@ -261,140 +740,1513 @@ impl GameInterface for Game {
     /// use std::collections::HashMap;
     /// use std::env;
     /// use fish_lib::config::{Config, ConfigBuilderInterface};
-    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::data::season_data::SeasonData;
     /// use fish_lib::game::prelude::*;
     /// use fish_lib::game::service_provider::ServiceProviderInterface;
     ///
-    /// const SPECIES_ID: i32 = 1;
-    /// const SPECIES_NAME: &str = "Salmon";
+    /// const LOCATION_ID: i32 = 1;
     ///
-    /// // Define some species data
-    /// let species_data = SpeciesData {
-    ///     name: SPECIES_NAME.to_string(),
+    /// // For simplicity in testing, create a location with constant temperature that never rains
+    /// let every_season = SeasonData {
+    ///     min_temp_c: 10.0,
+    ///     max_temp_c: 10.0,
+    ///     rain_intensity_raining_threshold: 2.0,
     ///     ..Default::default()
     /// };
-    /// let species_data_map = HashMap::from([(SPECIES_ID, species_data)]);
     ///
-    /// // Add the species data to the config
-    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    /// let location_data = LocationData {
+    ///     spring: every_season.clone(),
+    ///     summer: every_season.clone(),
+    ///     autumn: every_season.clone(),
+    ///     winter: every_season.clone(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let location_data_map = HashMap::from([(LOCATION_ID, location_data)]);
+    /// let config = Config::builder().locations(location_data_map).build().unwrap();
     ///
     /// // Create game and clear database for a blank test state
     /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
     /// let game = Game::new(&database_url, Some(config)).unwrap();
     /// game.database().write().unwrap().clear().unwrap();
     ///
-    /// // Finding the species data
-    /// let found_species_data = game.species_find(SPECIES_ID).unwrap();
-    /// assert_eq!(&found_species_data.name, SPECIES_NAME);
-    ///
-    /// // Searching for non-existent species data
-    /// let error = game.species_find(SPECIES_ID + 1).unwrap_err();
-    /// assert!(error.is_not_found());
-    /// if let Some(resource_error) = error.as_resource_error() {
-    ///     assert!(resource_error.is_species_not_found());
-    ///     assert_eq!(resource_error.get_species_id(), Some(SPECIES_ID + 1));
-    /// } else {
-    ///     panic!("{:?}", error);
-    /// }
+    /// // Summarize today and the next two days
+    /// let location = game.location_find(LOCATION_ID).unwrap();
+    /// let today = game.location_weather_current(location.clone()).unwrap().time.date_naive();
+    /// let summaries = game.location_weather_daily_summaries(location, today, 3).unwrap();
+    /// assert_eq!(summaries.len(), 3);
+    /// assert_eq!(summaries[0].date, today);
+    /// assert_eq!(summaries[2].date, today + chrono::Days::new(2));
+    /// assert!(summaries.iter().all(|summary| summary.max_temperature_c == 10.0));
+    /// assert!(summaries.iter().all(|summary| summary.rain_hours == 0));
     /// ```
-    fn species_find(&self, species_id: i32) -> GameResult<Arc<SpeciesData>> {
-        match self.config().get_species_data(species_id) {
-            Some(data) => Ok(data.clone()),
-            None => Err(GameResourceError::species_not_found(species_id).into()),
-        }
+    fn location_weather_daily_summaries(
+        &self,
+        location: Arc<LocationData>,
+        start_date: NaiveDate,
+        days: u32,
+    ) -> GameResult<Vec<DailyWeatherSummary>> {
+        self.weather_service()
+            .get_daily_weather_summaries(location, start_date, days)
     }
 
-    /// Generate a random [Specimen] of the given species ID and assign it to the given [User].
+    /// Get the [Weather] of a location sampled over a range of in-game time, in the past or future.
     ///
     /// # Arguments
     ///
-    /// * `user`: The [User] for which the catch is to be registered
-    /// * `species_id`: The species ID of the [Specimen] to be caught (See [Config])
+    /// * `location`: The location to get the weather of. (See [Config])
+    /// * `start`: The in-game time of the first sample.
+    /// * `end`: The in-game time the timeline ends at (exclusive).
+    /// * `step`: The in-game time between two samples, raised to at least one minute. Hourly if None.
     ///
     /// # Returns
-    /// Result<([Specimen], [FishingHistoryEntry]), [errors::GameError]>
+    /// Result<Vec<[Weather]>, [errors::GameError]>
+    /// - The sampled weather, in order
+    /// - An error, if:
+    ///     - The location has no weather
+    ///     - The step isn't positive
+    ///     - The timeline would have more than [services::weather_service::MAX_TIMELINE_SAMPLES] samples
     ///
     /// # Examples
     ///
     /// ```
     /// use std::collections::HashMap;
     /// use std::env;
+    /// use chrono::TimeDelta;
     /// use fish_lib::config::{Config, ConfigBuilderInterface};
-    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::data::location_data::LocationData;
     /// use fish_lib::game::prelude::*;
-    /// use fish_lib::game::repositories::user_repository::UserRepository;
     /// use fish_lib::game::service_provider::ServiceProviderInterface;
-    /// use fish_lib::models::user::User;
     ///
-    /// const USER_EXTERNAL_ID: i64 = 1337;
-    /// const SPECIES_ID: i32 = 1;
-    /// const SPECIES_NAME: &str = "Salmon";
+    /// const LOCATION_ID: i32 = 1;
     ///
-    /// // Define some species data
-    /// let species_data = SpeciesData {
-    ///     name: SPECIES_NAME.to_string(),
-    ///     ..Default::default()
-    /// };
-    /// let species_data_map = HashMap::from([(SPECIES_ID, species_data)]);
+    /// let location_data_map = HashMap::from([(LOCATION_ID, LocationData::default())]);
+    /// let config = Config::builder().locations(location_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // The weather of the last and the next 6 in-game hours
+    /// let location = game.location_find(LOCATION_ID).unwrap();
+    /// let now = game.location_weather_current(location.clone()).unwrap().time;
+    /// let start = now - TimeDelta::hours(6);
+    /// let end = now + TimeDelta::hours(6);
+    ///
+    /// let hourly = game.location_weather_timeline(location.clone(), start, end, None).unwrap();
+    /// assert_eq!(hourly.len(), 12);
+    /// assert_eq!(hourly[0].time, start);
+    /// assert_eq!(hourly[6].time, now);
+    ///
+    /// let step = Some(TimeDelta::minutes(30));
+    /// let half_hourly = game.location_weather_timeline(location, start, end, step).unwrap();
+    /// assert_eq!(half_hourly.len(), 24);
+    /// ```
+    fn location_weather_timeline(
+        &self,
+        location: Arc<LocationData>,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        step: Option<TimeDelta>,
+    ) -> GameResult<Vec<Weather>> {
+        self.weather_service().get_weather_timeline(
+            location,
+            start,
+            end,
+            step.unwrap_or(TimeDelta::hours(1)),
+        )
+    }
+
+    /// Get all specimens which are kept in a given [Pond].
+    ///
+    /// # Arguments
+    ///
+    /// * `pond`: The [Pond] to get the contents of
+    ///
+    /// # Returns
+    /// Result<Vec<[Specimen]>, [errors::GameError]>
+    /// - The specimens in the [Pond], ordered by their ID
+    /// - An error, if database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    ///
+    /// let species_data = SpeciesData {
+    ///     name: "Koi".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    ///
+    /// let pond = game.user_pond_create(&user, 10).unwrap();
+    /// assert!(game.pond_get_specimens(&pond).unwrap().is_empty());
+    ///
+    /// let (specimen, _) = game.user_catch_specific_specimen(&user, species).unwrap();
+    /// let specimen = game.user_pond_add_specimen(&user, &pond, specimen).unwrap();
+    /// assert_eq!(game.pond_get_specimens(&pond).unwrap(), vec![specimen]);
+    /// ```
+    fn pond_get_specimens(&self, pond: &Pond) -> GameResult<Vec<Specimen>> {
+        self.pond_service().get_pond_specimens(pond)
+    }
+
+    /// Get all items which can be bought in the shop, ordered by their item ID.
+    ///
+    /// # Returns
+    /// Vec<Arc<[ItemData]>>
+    /// - All items with purchasable attributes (See [Config])
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::item_data::ItemData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::models::item::attributes_container::ItemAttributesContainer;
+    /// use fish_lib::models::item::properties_container::{ItemPropertiesContainer, ItemPropertiesContainerInterface};
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const ROD_ID: i32 = 1;
+    /// const BAIT_ID: i32 = 2;
+    /// const TROPHY_ID: i32 = 3;
+    ///
+    /// // Define a unique rod and a stackable bait which can be bought, and a trophy which can't
+    /// let rod_data = ItemData {
+    ///     name: "Wooden Rod".to_string(),
+    ///     attributes: ItemAttributesContainer::new().with_rod(1).with_purchasable(100),
+    ///     default_properties: ItemPropertiesContainer::new().with_usage(0),
+    ///     ..Default::default()
+    /// };
+    /// let bait_data = ItemData {
+    ///     name: "Worm".to_string(),
+    ///     attributes: ItemAttributesContainer::new().with_bait(1).with_purchasable(5),
+    ///     default_properties: ItemPropertiesContainer::new().with_stackable(1),
+    ///     ..Default::default()
+    /// };
+    /// let trophy_data = ItemData {
+    ///     name: "Golden Trophy".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let config = Config::builder()
+    ///     .items(HashMap::from([
+    ///         (ROD_ID, rod_data),
+    ///         (BAIT_ID, bait_data),
+    ///         (TROPHY_ID, trophy_data),
+    ///     ]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let shop_items = game.shop_get_items();
+    /// let shop_item_ids: Vec<i32> = shop_items.iter().map(|item| item.id).collect();
+    /// assert_eq!(shop_item_ids, vec![ROD_ID, BAIT_ID]);
+    /// ```
+    fn shop_get_items(&self) -> Vec<Arc<ItemData>> {
+        self.shop_service().get_purchasable_items()
+    }
+
+    /// Forecast when a species can be encountered at any of its locations over the next in-game days.
+    /// The forecast is based on the deterministic weather of the locations, sampled every 15 in-game minutes.
+    ///
+    /// # Arguments
+    ///
+    /// * `species`: The species to forecast the encounters of. (See [Config])
+    /// * `days`: The amount of in-game days to look ahead starting now, at most [services::forecast_service::MAX_FORECAST_DAYS].
+    /// * `equipment`: The rod and bait levels to fish with, no equipment if None.
+    ///
+    /// # Returns
+    /// Result<Vec<[ForecastWindow]>, [errors::GameError]>
+    /// - The windows in which the species can be encountered, ordered by their real start time
+    /// - An error, if:
+    ///     - One of the species locations has no weather
+    ///     - More than [services::forecast_service::MAX_FORECAST_DAYS] days are requested
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use chrono::TimeDelta;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::encounter_data::EncounterData;
+    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const SPECIES_ID: i32 = 1;
+    ///
+    /// // A species that can be caught all day at two locations
+    /// let encounter = |location_id| EncounterData {
+    ///     location_id,
+    ///     min_time_hour: 0,
+    ///     max_time_hour: 23,
+    ///     ..Default::default()
+    /// };
+    /// let species_data = SpeciesData {
+    ///     encounters: vec![encounter(1), encounter(2)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let locations = HashMap::from([(1, LocationData::default()), (2, LocationData::default())]);
+    /// let config = Config::builder()
+    ///     .locations(locations)
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // One window spanning the whole forecast per location
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let windows = game.species_forecast(species, 1, None).unwrap();
+    /// assert_eq!(windows.len(), 2);
+    /// assert_eq!(windows[0].location_id, 1);
+    /// assert_eq!(windows[1].location_id, 2);
+    /// assert_eq!(windows[0].end - windows[0].start, TimeDelta::days(1));
+    /// ```
+    fn species_forecast(
+        &self,
+        species: Arc<SpeciesData>,
+        days: u32,
+        equipment: Option<EncounterEquipment>,
+    ) -> GameResult<Vec<ForecastWindow>> {
+        self.forecast_service()
+            .forecast_species(species, days, equipment.unwrap_or_default())
+    }
+
+    /// Get a page of the leaderboard of the largest specimens caught of a species.
+    ///
+    /// # Arguments
+    ///
+    /// * `species`: The species to rank the catches of. (See [Config])
+    /// * `window`: The time span the leaderboard covers, only catches within it are ranked
+    /// * `page`: Which page to get, the first page is page 0
+    /// * `page_size`: How many entries a page has
+    ///
+    /// # Returns
+    /// Result<[Leaderboard]<[RecordLeaderboardEntry]>, [errors::GameError]>
+    /// - The largest catch of every user on the page, ordered by size ratio.
+    ///   Of equally large catches within a window, the earliest one is ranked.
+    /// - An error, if:
+    ///     - The species does not exist
+    ///     - Database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::enums::leaderboard_window::LeaderboardWindow;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const SPECIES_ID: i32 = 1;
+    ///
+    /// let species_data = SpeciesData {
+    ///     name: "Koi".to_string(),
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 100,
+    ///     max_size_adult_mm: 200,
+    ///     min_lifespan_days: 100,
+    ///     max_lifespan_days: 200,
+    ///     ..Default::default()
+    /// };
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let user = game.user_register(1337).unwrap();
+    /// let user2 = game.user_register(1338).unwrap();
+    /// let (specimen, _) = game.user_catch_specific_specimen(&user, species.clone()).unwrap();
+    /// let (specimen2, _) = game.user_catch_specific_specimen(&user2, species.clone()).unwrap();
+    ///
+    /// let leaderboard = game
+    ///     .species_leaderboard(species, LeaderboardWindow::AllTime, 0, 10)
+    ///     .unwrap();
+    /// assert_eq!(leaderboard.total_entries, 2);
+    /// let first = &leaderboard.entries[0];
+    /// let second = &leaderboard.entries[1];
+    /// assert!(first.record.total_size_ratio >= second.record.total_size_ratio);
+    /// assert!(first.record.specimen_id == Some(specimen.id) || first.record.specimen_id == Some(specimen2.id));
+    /// ```
+    fn species_leaderboard(
+        &self,
+        species: Arc<SpeciesData>,
+        window: LeaderboardWindow,
+        page: u32,
+        page_size: u32,
+    ) -> GameResult<Leaderboard<RecordLeaderboardEntry>> {
+        self.leaderboard_service().get_species_leaderboard(
+            species.id,
+            window,
+            LeaderboardPage::new(page, page_size),
+        )
+    }
+
+    /// Get [SpeciesData] for the specified species ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `species_id`: The ID of the species to get the data of. (See [Config])
+    ///
+    /// # Returns
+    ///
+    /// Result<Arc<[SpeciesData], Global>, [errors::GameError]>
+    /// - The [SpeciesData], if the species with the given ID exists
+    /// - An error, if no species with the given ID exists
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const SPECIES_ID: i32 = 1;
+    /// const SPECIES_NAME: &str = "Salmon";
+    ///
+    /// // Define some species data
+    /// let species_data = SpeciesData {
+    ///     name: SPECIES_NAME.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let species_data_map = HashMap::from([(SPECIES_ID, species_data)]);
+    ///
+    /// // Add the species data to the config
+    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // Finding the species data
+    /// let found_species_data = game.species_find(SPECIES_ID).unwrap();
+    /// assert_eq!(&found_species_data.name, SPECIES_NAME);
+    ///
+    /// // Searching for non-existent species data
+    /// let error = game.species_find(SPECIES_ID + 1).unwrap_err();
+    /// assert!(error.is_not_found());
+    /// if let Some(resource_error) = error.as_resource_error() {
+    ///     assert!(resource_error.is_species_not_found());
+    ///     assert_eq!(resource_error.get_species_id(), Some(SPECIES_ID + 1));
+    /// } else {
+    ///     panic!("{:?}", error);
+    /// }
+    /// ```
+    fn species_find(&self, species_id: i32) -> GameResult<Arc<SpeciesData>> {
+        match self.config().get_species_data(species_id) {
+            Some(data) => Ok(data.clone()),
+            None => Err(GameResourceError::species_not_found(species_id).into()),
+        }
+    }
+
+    /// Get the amount of credits a [Specimen] would currently sell for.
+    ///
+    /// The price is based on the base value of its species, its total size ratio,
+    /// the rarity of its species and its age (See [Config] settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `specimen`: The [Specimen] to get the sell price of
+    ///
+    /// # Returns
+    /// Result<i64, [errors::GameError]>
+    /// - The current sell price of the [Specimen] in credits
+    /// - An error, if the species of the [Specimen] does not exist
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::models::user::User;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    /// const OTHER_SPECIES_ID: i32 = 2;
+    ///
+    /// // Define some species with a base value
+    /// let species_data = SpeciesData {
+    ///     name: "Salmon".to_string(),
+    ///     base_value: 100,
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 50,
+    ///     max_size_adult_mm: 80,
+    ///     min_lifespan_days: 300,
+    ///     max_lifespan_days: 400,
+    ///     ..Default::default()
+    /// };
+    /// let other_species_data = SpeciesData {
+    ///     name: "Trout".to_string(),
+    ///     base_value: 50,
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 30,
+    ///     max_size_adult_mm: 40,
+    ///     min_lifespan_days: 300,
+    ///     max_lifespan_days: 400,
+    ///     ..Default::default()
+    /// };
+    /// let species_data_map = HashMap::from([
+    ///     (SPECIES_ID, species_data),
+    ///     (OTHER_SPECIES_ID, other_species_data),
+    /// ]);
+    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let other_species = game.species_find(OTHER_SPECIES_ID).unwrap();
+    ///
+    /// // The price is at least the base value of the species
+    /// let (specimen, _) = game.user_catch_specific_specimen(&user, species).unwrap();
+    /// let price = game.specimen_sell_price(&specimen).unwrap();
+    /// assert!(price >= 100);
+    /// ```
+    fn specimen_sell_price(&self, specimen: &Specimen) -> GameResult<i64> {
+        self.shop_service().get_sell_price(specimen)
+    }
+
+    /// Generate a random [Specimen] of the given species ID and assign it to the given [User].
+    ///
+    /// The [Specimen] and the fishing history are updated in a single transaction,
+    /// so a failed catch leaves no trace in the database.
+    /// Achievements fulfilled by the catch are unlocked as well, use
+    /// [GameInterface::user_get_achievements] to look them up.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] for which the catch is to be registered
+    /// * `species_id`: The species ID of the [Specimen] to be caught (See [Config])
+    ///
+    /// # Returns
+    /// Result<([Specimen], [FishingHistoryEntry]), [errors::GameError]>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::repositories::user_repository::UserRepository;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::models::user::User;
+    ///
+    /// const USER_EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    /// const SPECIES_NAME: &str = "Salmon";
+    ///
+    /// // Define some species data
+    /// let species_data = SpeciesData {
+    ///     name: SPECIES_NAME.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let species_data_map = HashMap::from([(SPECIES_ID, species_data)]);
+    ///
+    /// // Add the species data to the config
+    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // Fetch the species data
+    /// let species = game.species_find(1).unwrap();
+    ///
+    /// // Create a user
+    /// let user = game.user_register(USER_EXTERNAL_ID).unwrap();
+    ///
+    /// // Let the user catch a specimen of the specified species ID
+    /// let (specimen, history_entry) = game.user_catch_specific_specimen(&user, species.clone()).unwrap();
+    /// assert_eq!(specimen.species_id, SPECIES_ID);
+    /// assert_eq!(specimen.user_id, user.id);
+    /// assert_eq!(history_entry.species_id, SPECIES_ID);
+    /// assert_eq!(history_entry.caught_count, 1);
+    ///
+    /// // Catch a specimen for a user that doesn't exist
+    /// let dummy_user = User {
+    ///     id: -1,
+    ///     external_id: USER_EXTERNAL_ID + 1,
+    ///     ..Default::default()
+    /// };
+    /// let user_error = game.user_catch_specific_specimen(&dummy_user, species).unwrap_err();
+    /// if let Some(resource_error) = user_error.as_resource_error() {
+    ///     assert!(resource_error.is_user_not_found());
+    ///     assert_eq!(resource_error.get_external_id(), Some(USER_EXTERNAL_ID + 1));
+    /// } else {
+    ///     panic!("{:?}", user_error);
+    /// }
+    ///
+    /// ```
+    fn user_catch_specific_specimen(
+        &self,
+        user: &User,
+        species: Arc<SpeciesData>,
+    ) -> GameResult<(Specimen, FishingHistoryEntry)> {
+        let seed = self.random_source().next_seed();
+        let (specimen, entry, _) = self.catch_specimen(user, species, seed, None, None)?;
+        Ok((specimen, entry))
+    }
+
+    /// Check the fishing history of a [User] with a specified species ID
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] to check the fishing history of
+    /// * `species_id`: Fishing history species ID to check for the giving [User] (See [Config])
+    ///
+    /// # Returns
+    ///
+    /// Result<[FishingHistoryEntry], [errors::GameError]>
+    /// - The fishing history of the given [User] with a specified species, if it exists
+    /// - An error, if it does not exist, aka if the [User] did not catch that species yet or the [User] does not exist
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::repositories::user_repository::UserRepository;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::models::user::User;
+    ///
+    /// const USER_EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    /// const SPECIES_NAME: &str = "Salmon";
+    ///
+    /// // Define some species data
+    /// let species_data = SpeciesData {
+    ///     name: SPECIES_NAME.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let species_data2 = SpeciesData {
+    ///     ..Default::default()
+    ///  };
+    /// let species_data_map = HashMap::from([(SPECIES_ID, species_data), (SPECIES_ID + 1, species_data2)]);
+    ///
+    /// // Add the species data to the config
+    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // Create a user
+    /// let user = game.user_register(USER_EXTERNAL_ID).unwrap();
+    ///
+    /// // Get species data
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let species2 = game.species_find(SPECIES_ID + 1).unwrap();
+    ///
+    /// // Let the user catch a specimen
+    /// game.user_catch_specific_specimen(&user, species.clone()).unwrap();
+    ///
+    /// // Fetch the fishing history of the user with the given species ID
+    /// let history_entry = game.user_get_fishing_history(&user, species.clone()).unwrap();
+    /// assert_eq!(history_entry.species_id, SPECIES_ID);
+    /// assert_eq!(history_entry.user_id, user.id);
+    /// assert_eq!(history_entry.caught_count, 1);
+    /// assert_eq!(history_entry.sold_count, 0);
+    ///
+    /// // Trying to fetch the fishing history with a species the user didn't catch yet
+    /// let error = game.user_get_fishing_history(&user, species2).unwrap_err();
+    /// assert!(error.is_not_found());
+    /// if let Some(resource_error) = error.as_resource_error() {
+    ///     assert!(resource_error.is_no_fishing_history());
+    ///     assert_eq!(resource_error.get_external_id(), Some(USER_EXTERNAL_ID));
+    ///     assert_eq!(resource_error.get_species_id(), Some(SPECIES_ID + 1));
+    /// } else {
+    ///     panic!("{:?}", error);
+    /// }
+    /// ```
+    fn user_get_fishing_history(
+        &self,
+        user: &User,
+        species: Arc<SpeciesData>,
+    ) -> GameResult<FishingHistoryEntry> {
+        match self
+            .fishing_history_entry_repository()
+            .find_by_user_and_species_id(user.id, species.id)?
+        {
+            Some(entry) => Ok(entry),
+            None => Err(GameResourceError::no_fishing_history(user.external_id, species.id).into()),
+        }
+    }
+
+    /// Get the size records of a [User] for every species they caught.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] to get the records of
+    ///
+    /// # Returns
+    /// Result<Vec<[PersonalBest]>, [errors::GameError]>
+    /// - The smallest and largest catch per species, ordered by species ID.
+    ///   Each record references the specimen that set it, its size and weight when it was caught and when the record was set.
+    /// - An error, if database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    ///
+    /// let species_data = SpeciesData {
+    ///     name: "Koi".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// assert!(game.user_get_personal_bests(&user).unwrap().is_empty());
+    ///
+    /// // The first catch of a species holds both records
+    /// let (specimen, _) = game.user_catch_specific_specimen(&user, species).unwrap();
+    /// let personal_bests = game.user_get_personal_bests(&user).unwrap();
+    /// assert_eq!(personal_bests.len(), 1);
+    /// assert_eq!(personal_bests[0].species_id, SPECIES_ID);
+    /// assert_eq!(personal_bests[0].smallest.specimen_id, Some(specimen.id));
+    /// assert_eq!(personal_bests[0].largest.specimen_id, Some(specimen.id));
+    /// assert_eq!(personal_bests[0].largest.set_at, Some(specimen.created_at));
+    /// ```
+    fn user_get_personal_bests(&self, user: &User) -> GameResult<Vec<PersonalBest>> {
+        self.fishing_history_service().get_personal_bests(user.id)
+    }
+
+    /// Get the collection progress of a [User] over every species in the [Config].
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] to get the collection progress of
+    /// * `hide_uncaught_names`: If the names of species the [User] didn't catch yet should be left out
+    ///
+    /// # Returns
+    /// Result<[Fishdex], [errors::GameError]>
+    /// - Every species as caught or uncaught, with its first catch and counts.
+    ///   The completion is also broken down by location and rarity level.
+    /// - An error, if database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::encounter_data::EncounterData;
+    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const LOCATION_ID: i32 = 1;
+    ///
+    /// let species = |name: &str, rarity_level| SpeciesData {
+    ///     name: name.to_string(),
+    ///     encounters: vec![EncounterData {
+    ///         location_id: LOCATION_ID,
+    ///         rarity_level,
+    ///         ..Default::default()
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    /// let config = Config::builder()
+    ///     .locations(HashMap::from([(LOCATION_ID, LocationData::default())]))
+    ///     .species(HashMap::from([(1, species("Koi", 1)), (2, species("Carp", 10))]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(1337).unwrap();
+    /// let koi = game.species_find(1).unwrap();
+    /// game.user_catch_specific_specimen(&user, koi).unwrap();
+    ///
+    /// let fishdex = game.user_get_fishdex(&user, true).unwrap();
+    /// assert_eq!(fishdex.completion.caught, 1);
+    /// assert_eq!(fishdex.completion.total, 2);
+    /// assert_eq!(fishdex.completion.get_percentage(), 50.0);
+    /// assert_eq!(fishdex.completion_by_location[&LOCATION_ID].total, 2);
+    /// assert!(fishdex.completion_by_rarity[&1].is_complete());
+    /// assert!(!fishdex.completion_by_rarity[&10].is_complete());
+    ///
+    /// // The carp wasn't caught yet, so its name is hidden
+    /// let carp = fishdex.get_entry(2).unwrap();
+    /// assert!(!carp.is_caught());
+    /// assert_eq!(carp.name, None);
+    /// assert_eq!(fishdex.get_entry(1).unwrap().caught_count, 1);
+    /// ```
+    fn user_get_fishdex(&self, user: &User, hide_uncaught_names: bool) -> GameResult<Fishdex> {
+        self.fishing_history_service()
+            .get_fishdex(user.id, hide_uncaught_names)
+    }
+
+    /// Get all achievements a [User] has unlocked so far.
+    ///
+    /// Achievements are defined in the [Config] and unlocked automatically after catches,
+    /// sales, purchases and location unlocks. The results of these actions contain
+    /// the achievements they unlocked.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] to get the achievements of
+    ///
+    /// # Returns
+    /// Result<Vec<[AchievementUnlock]>, [errors::GameError]>
+    /// - The unlocked achievements with their unlock time, ordered by achievement ID
+    /// - An error, if database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::achievement_data::{AchievementData, AchievementRequirement};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    /// const FIRST_CATCH_ID: i32 = 1;
+    /// const RICH_ID: i32 = 2;
+    ///
+    /// let species_data = SpeciesData {
+    ///     name: "Salmon".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let first_catch = AchievementData {
+    ///     name: "First catch".to_string(),
+    ///     requirement: AchievementRequirement::CatchCount {
+    ///         count: 1,
+    ///         species_id: None,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    /// let rich = AchievementData {
+    ///     name: "Rich".to_string(),
+    ///     requirement: AchievementRequirement::Credits { amount: 1_000_000 },
+    ///     ..Default::default()
+    /// };
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .achievements(HashMap::from([(FIRST_CATCH_ID, first_catch), (RICH_ID, rich)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// assert!(game.user_get_achievements(&user).unwrap().is_empty());
+    ///
+    /// // The first catch unlocks the first achievement
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// game.user_catch_specific_specimen(&user, species).unwrap();
+    ///
+    /// let achievements = game.user_get_achievements(&user).unwrap();
+    /// assert_eq!(achievements.len(), 1);
+    /// assert_eq!(achievements[0].achievement.id, FIRST_CATCH_ID);
+    /// ```
+    fn user_get_achievements(&self, user: &User) -> GameResult<Vec<AchievementUnlock>> {
+        self.achievement_service().get_achievements(user)
+    }
+
+    /// Find a [User] by their external ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `external_id`: A freely selectable ID that your system will use to identify this [User].
+    ///
+    /// # Returns
+    ///
+    /// Result<[User], [errors::GameError]>
+    /// - A [User] with the given external ID
+    /// - An error, if:
+    ///     - The [User] is not found
+    ///     - Database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, None).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // Finding an existing user
+    /// let new_user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let found_user = game.user_find(EXTERNAL_ID).unwrap();
+    /// assert_eq!(new_user, found_user);
+    ///
+    /// // Searching for a non-existent user
+    /// let error = game.user_find(EXTERNAL_ID + 1).unwrap_err();
+    /// assert!(error.is_not_found());
+    /// if let Some(resource_error) = error.as_resource_error() {
+    ///     assert!(resource_error.is_user_not_found());
+    ///     assert_eq!(resource_error.get_external_id(), Some(EXTERNAL_ID + 1));
+    /// } else {
+    ///     panic!("{:?}", error);
+    /// }
+    /// ```
+    fn user_find(&self, external_id: i64) -> GameResult<User> {
+        match self.user_repository().find_by_external_id(external_id)? {
+            Some(user) => Ok(user),
+            None => Err(GameResourceError::user_not_found(external_id).into()),
+        }
+    }
+
+    /// Let a [User] cast a line at a given location, optionally using a rod and bait.
+    ///
+    /// The location has to be unlocked by the [User]. The encounter is rolled using the current
+    /// local time and [Weather] of the location. The rod level determines which fish can be
+    /// encountered at all, while the bait level makes rarer fish more likely.
+    /// The caught [Specimen] and the updated [FishingHistoryEntry] are persisted,
+    /// the rod's usage is tracked and one piece of bait is consumed.
+    /// All of these changes are made in a single transaction, either all of them persist or none do.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] who is fishing
+    /// * `location`: The location to fish at (See [Config])
+    /// * `rod`: The rod [Item] of the [User] to fish with, if any
+    /// * `bait`: The bait [Item] of the [User] to fish with, if any
+    ///
+    /// # Returns
+    /// Result<[FishingResult], [errors::GameError]>
+    /// - The caught [Specimen] with its updated [FishingHistoryEntry], the used equipment and the conditions it was caught in
+    /// - An error, if:
+    ///     - the [User] does not exist
+    ///     - the [User] has not unlocked the location
+    ///     - the rod or bait is not (or no longer) owned by the [User] or is not a rod or bait
+    ///     - there is nothing to catch at the location right now
+    ///     - database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::encounter_data::EncounterData;
+    /// use fish_lib::data::item_data::ItemData;
+    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::data::season_data::SeasonData;
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::models::item::attributes_container::ItemAttributesContainer;
+    /// use fish_lib::models::item::properties_container::{ItemPropertiesContainer, ItemPropertiesContainerInterface};
+    /// use fish_lib::models::user::User;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const LOCATION_ID: i32 = 1;
+    /// const DEEP_SEA_LOCATION_ID: i32 = 2;
+    /// const SPECIES_ID: i32 = 1;
+    /// const DEEP_SEA_SPECIES_ID: i32 = 2;
+    /// const ROD_ID: i32 = 1;
+    /// const BAIT_ID: i32 = 2;
+    ///
+    /// // For simplicity in testing, create locations where it never rains
+    /// let every_season = SeasonData {
+    ///     rain_intensity_raining_threshold: 2.0,
+    ///     ..Default::default()
+    /// };
+    /// let location_data = LocationData {
+    ///     spring: every_season.clone(),
+    ///     summer: every_season.clone(),
+    ///     autumn: every_season.clone(),
+    ///     winter: every_season.clone(),
+    ///     ..Default::default()
+    /// };
+    /// let deep_sea_location_data = LocationData {
+    ///     spring: every_season.clone(),
+    ///     summer: every_season.clone(),
+    ///     autumn: every_season.clone(),
+    ///     winter: every_season.clone(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // Define a species which can be encountered at the first location at any time
+    /// let species_data = SpeciesData {
+    ///     name: "Salmon".to_string(),
+    ///     encounters: vec![EncounterData {
+    ///         location_id: LOCATION_ID,
+    ///         min_time_hour: 0,
+    ///         max_time_hour: 23,
+    ///         ..Default::default()
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // Define a species which needs a better rod than the user has
+    /// let deep_sea_species_data = SpeciesData {
+    ///     name: "Anglerfish".to_string(),
+    ///     encounters: vec![EncounterData {
+    ///         location_id: DEEP_SEA_LOCATION_ID,
+    ///         min_time_hour: 0,
+    ///         max_time_hour: 23,
+    ///         min_rod_level: 5,
+    ///         ..Default::default()
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // Define a rod and a stackable bait
+    /// let rod_data = ItemData {
+    ///     name: "Wooden Rod".to_string(),
+    ///     attributes: ItemAttributesContainer::new().with_rod(1),
+    ///     default_properties: ItemPropertiesContainer::new().with_usage(0),
+    ///     ..Default::default()
+    /// };
+    /// let bait_data = ItemData {
+    ///     name: "Worm".to_string(),
+    ///     attributes: ItemAttributesContainer::new().with_bait(1),
+    ///     default_properties: ItemPropertiesContainer::new().with_stackable(1),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let config = Config::builder()
+    ///     .locations(HashMap::from([
+    ///         (LOCATION_ID, location_data),
+    ///         (DEEP_SEA_LOCATION_ID, deep_sea_location_data),
+    ///     ]))
+    ///     .species(HashMap::from([
+    ///         (SPECIES_ID, species_data),
+    ///         (DEEP_SEA_SPECIES_ID, deep_sea_species_data),
+    ///     ]))
+    ///     .items(HashMap::from([(ROD_ID, rod_data), (BAIT_ID, bait_data)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let location = game.location_find(LOCATION_ID).unwrap();
+    ///
+    /// // Fishing at a location that wasn't unlocked yet
+    /// let locked_error = game.user_fish(&user, location.clone(), None, None).unwrap_err();
+    /// if let Some(resource_error) = locked_error.as_resource_error() {
+    ///     assert!(resource_error.is_location_locked());
+    ///     assert_eq!(resource_error.get_external_id(), Some(EXTERNAL_ID));
+    ///     assert_eq!(resource_error.get_location_id(), Some(LOCATION_ID));
+    /// } else {
+    ///     panic!("{:?}", locked_error);
+    /// }
+    ///
+    /// // Unlock the location and fish there without any equipment
+    /// game.user_unlock_location(&user, location.clone()).unwrap();
+    /// let result = game.user_fish(&user, location.clone(), None, None).unwrap();
+    /// assert_eq!(result.specimen.species_id, SPECIES_ID);
+    /// assert_eq!(result.specimen.user_id, user.id);
+    /// assert_eq!(result.history_entry.caught_count, 1);
+    /// assert_eq!(result.species.id, SPECIES_ID);
+    /// assert_eq!(result.location.id, LOCATION_ID);
+    /// assert!(!result.weather.is_raining);
+    ///
+    /// // Fish with a rod and two pieces of bait
+    /// let rod = game.user_item_give(&user, game.item_find(ROD_ID).unwrap(), 1).unwrap();
+    /// let bait = game.user_item_give(&user, game.item_find(BAIT_ID).unwrap(), 2).unwrap();
+    /// let result = game.user_fish(&user, location.clone(), Some(rod), Some(bait)).unwrap();
+    /// assert_eq!(result.history_entry.caught_count, 2);
+    /// let used_rod = result.rod.unwrap();
+    /// assert_eq!(used_rod.get_times_used(), Some(1));
+    /// let used_bait = result.bait.unwrap();
+    /// assert_eq!(used_bait.get_count(), Some(1));
+    ///
+    /// // The last piece of bait is consumed
+    /// let result = game.user_fish(&user, location.clone(), Some(used_rod), Some(used_bait)).unwrap();
+    /// assert_eq!(result.rod.unwrap().get_times_used(), Some(2));
+    /// assert_eq!(result.bait, None);
+    ///
+    /// // The rod is not good enough for the fish at the deep sea location
+    /// let deep_sea = game.location_find(DEEP_SEA_LOCATION_ID).unwrap();
+    /// game.user_unlock_location(&user, deep_sea.clone()).unwrap();
+    /// let empty_error = game.user_fish(&user, deep_sea, None, None).unwrap_err();
+    /// if let Some(resource_error) = empty_error.as_resource_error() {
+    ///     assert!(resource_error.is_no_available_encounters());
+    /// } else {
+    ///     panic!("{:?}", empty_error);
+    /// }
+    ///
+    /// // Fishing with a user that doesn't exist
+    /// let dummy_user = User {
+    ///     id: -1,
+    ///     external_id: EXTERNAL_ID + 1,
+    ///     ..Default::default()
+    /// };
+    /// let user_error = game.user_fish(&dummy_user, location, None, None).unwrap_err();
+    /// if let Some(resource_error) = user_error.as_resource_error() {
+    ///     assert!(resource_error.is_user_not_found());
+    ///     assert_eq!(resource_error.get_external_id(), Some(EXTERNAL_ID + 1));
+    /// } else {
+    ///     panic!("{:?}", user_error);
+    /// }
+    /// ```
+    fn user_fish(
+        &self,
+        user: &User,
+        location: Arc<LocationData>,
+        rod: Option<Item>,
+        bait: Option<Item>,
+    ) -> GameResult<FishingResult> {
+        let seed = self.random_source().next_seed();
+        self.user_fish_with_seed(user, location, rod, bait, seed)
+    }
+
+    /// Let a user fish at a location like [Game::user_fish], with the given seed instead of one from the [RandomSource].
+    ///
+    /// The same seed rolls the same encounter and generates the same specimen
+    /// for the same time, location and equipment, e.g. to reproduce a disputed catch.
+    /// The seed is recorded on the caught specimen.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] who fishes
+    /// * `location`: The location to fish at (See [Config])
+    /// * `rod`: The rod to fish with, if any
+    /// * `bait`: The bait to fish with, if any
+    /// * `seed`: The seed to roll the catch with
+    ///
+    /// # Returns
+    /// Result<[FishingResult], [errors::GameError]>
+    /// - The result of the catch, same as [Game::user_fish]
+    /// - An error, same as [Game::user_fish]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::encounter_data::EncounterData;
+    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::data::season_data::SeasonData;
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// // A location where it never rains and a species which can always be encountered there
+    /// let every_season = SeasonData {
+    ///     rain_intensity_raining_threshold: 2.0,
+    ///     ..Default::default()
+    /// };
+    /// let location_data = LocationData {
+    ///     spring: every_season.clone(),
+    ///     summer: every_season.clone(),
+    ///     autumn: every_season.clone(),
+    ///     winter: every_season,
+    ///     ..Default::default()
+    /// };
+    /// let species_data = SpeciesData {
+    ///     name: "Salmon".to_string(),
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 50,
+    ///     max_size_adult_mm: 100,
+    ///     min_lifespan_days: 10,
+    ///     max_lifespan_days: 20,
+    ///     encounters: vec![EncounterData {
+    ///         location_id: 1,
+    ///         min_time_hour: 0,
+    ///         max_time_hour: 23,
+    ///         ..Default::default()
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    /// let config = Config::builder()
+    ///     .locations(HashMap::from([(1, location_data)]))
+    ///     .species(HashMap::from([(1, species_data)]))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(1337).unwrap();
+    /// let location = game.location_find(1).unwrap();
+    /// game.user_unlock_location(&user, location.clone()).unwrap();
+    ///
+    /// // The same seed generates the same specimen
+    /// let result = game.user_fish_with_seed(&user, location.clone(), None, None, 42).unwrap();
+    /// let result2 = game.user_fish_with_seed(&user, location, None, None, 42).unwrap();
+    /// assert_eq!(result.specimen.get_catch_seed(), Some(42));
+    /// assert_eq!(result.specimen.catch_age, result2.specimen.catch_age);
+    /// assert_eq!(result.specimen.size_adult_ratio, result2.specimen.size_adult_ratio);
+    /// ```
+    fn user_fish_with_seed(
+        &self,
+        user: &User,
+        location: Arc<LocationData>,
+        rod: Option<Item>,
+        bait: Option<Item>,
+        seed: u64,
+    ) -> GameResult<FishingResult> {
+        if self.user_repository().find(user.id)?.is_none() {
+            return Err(GameResourceError::user_not_found(user.external_id).into());
+        }
+
+        let unlocked_location_ids = self.user_service().get_unlocked_location_ids(user)?;
+        if !unlocked_location_ids.contains(&location.id) {
+            return Err(GameResourceError::location_locked(user.external_id, location.id).into());
+        }
+
+        transaction(&self.database(), || {
+            // Only the stored items are trusted for the ownership and the equipment levels
+            let rod = self.find_owned_item(user, rod.as_ref())?;
+            let bait = self.find_owned_item(user, bait.as_ref())?;
+            let equipment = self.get_encounter_equipment(rod.as_ref(), bait.as_ref())?;
+
+            let weather = self
+                .weather_service()
+                .get_current_weather(location.clone())?;
+            let species_id = self.encounter_service().roll_encounter(
+                weather.time,
+                weather.clone(),
+                location.id,
+                equipment,
+                seed,
+            )?;
+            let species = self.species_find(species_id)?;
+
+            let rod = match rod {
+                Some(rod) => self.item_service().use_as_rod(rod)?,
+                None => None,
+            };
+            let bait = match bait {
+                Some(bait) => self.item_service().use_as_bait(bait)?,
+                None => None,
+            };
+
+            let (specimen, history_entry, achievements) = self.catch_specimen(
+                user,
+                species.clone(),
+                seed,
+                Some(location.id),
+                Some(&weather),
+            )?;
+
+            Ok(FishingResult {
+                location,
+                species,
+                weather,
+                specimen,
+                history_entry,
+                rod,
+                bait,
+                seed,
+                achievements,
+            })
+        })
+    }
+
+    /// Fetch the unlocked locations of a given user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] to get the unlocked locations ([UserLocationUnlock]) from.
+    ///
+    /// # Returns
+    /// Result<Vec<[UserLocationUnlock], Global>, [errors::GameError]>
+    /// - A vector with information about all location unlocks
+    /// - An error, if database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const LOCATION_ID: i32 = 13;
+    /// const LOCATION_NAME: &str = "Island";
+    ///
+    /// // Define some location data
+    /// let location_data = LocationData {
+    ///     name: LOCATION_NAME.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let location_data_map = HashMap::from([(LOCATION_ID, location_data)]);
+    ///
+    /// // Add the location data to the config
+    /// let config = Config::builder().locations(location_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // Registering a new user
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    ///
+    /// // Let the user unlock a location
+    /// let island_location = game.location_find(LOCATION_ID).unwrap();
+    /// let unlocked_location = game.user_unlock_location(&user, island_location).unwrap();
+    ///
+    /// // Get unlocked locations
+    /// let unlocked_locations = game.user_get_unlocked_locations(&user).unwrap();
+    /// assert_eq!(unlocked_locations.len(), 1);
+    /// assert_eq!(unlocked_locations[0], unlocked_location.location_unlock);
+    /// ```
+    fn user_get_unlocked_locations(&self, user: &User) -> GameResult<Vec<UserLocationUnlock>> {
+        let user_locations = self.user_service().get_unlocked_locations(user)?;
+        let location_unlocks =
+            UserLocationUnlock::from_user_locations(user_locations, |location_id| {
+                self.location_find(location_id).ok()
+            });
+        Ok(location_unlocks)
+    }
+
+    /// Get the [Inventory] of a specified [User].
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] to get the [Inventory] from.
+    ///
+    /// # Returns
+    ///
+    /// Result<[Inventory], [errors::GameError]>
+    /// - The user's inventory, if the user exists
+    /// - An error, if a database error occurred
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::item_data::ItemData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const ITEM_ID: i32 = 1;
+    /// const ITEM_NAME: &str = "Super Bad Rod";
+    ///
+    /// // Define some item data
+    /// let item_data = ItemData {
+    ///     name: ITEM_NAME.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let item_data_map = HashMap::from([(ITEM_ID, item_data)]);
+    ///
+    /// // Add the location data to the config
+    /// let config = Config::builder().items(item_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // Registering a new user
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// assert_eq!(user.external_id, EXTERNAL_ID);
+    ///
+    /// /// Giving the user an item
+    /// let item_data = game.item_find(ITEM_ID).unwrap();
+    /// let item = game.user_item_give(&user, item_data, 1).unwrap();
+    ///
+    /// let inventory = game.user_inventory(&user).unwrap();
+    /// let items = inventory.get_items();
+    /// assert_eq!(items[0], item);
+    ///
+    /// ```
+    fn user_inventory(&self, user: &User) -> GameResult<Inventory> {
+        self.item_service().get_inventory(user)
+    }
+
+    /// Give a [User] a specified [ItemData].
     ///
-    /// // Add the species data to the config
-    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    /// # Arguments
     ///
-    /// // Create game and clear database for a blank test state
-    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    /// let game = Game::new(&database_url, Some(config)).unwrap();
-    /// game.database().write().unwrap().clear().unwrap();
+    /// * `user`: The [User] to give the item to.
+    /// * `item_data`: The [ItemData] to give the user. (See [Config])
+    /// * `count`: How much of the item to give the user.
+    ///   0 or 1 results in the default specified count if the item is stackable.
+    ///   If the item is not stackable (unique) it'll be added once (no matter the specified count).
     ///
-    /// // Fetch the species data
-    /// let species = game.species_find(1).unwrap();
+    /// # Returns
     ///
-    /// // Create a user
-    /// let user = game.user_register(USER_EXTERNAL_ID).unwrap();
+    /// Result<[Item], [errors::GameError]>
+    /// - The newly created item when the operation was successful
+    /// - An error, if there was an error stacking the item or database operations failed
     ///
-    /// // Let the user catch a specimen of the specified species ID
-    /// let (specimen, history_entry) = game.user_catch_specific_specimen(&user, species.clone()).unwrap();
-    /// assert_eq!(specimen.species_id, SPECIES_ID);
-    /// assert_eq!(specimen.user_id, user.id);
-    /// assert_eq!(history_entry.species_id, SPECIES_ID);
-    /// assert_eq!(history_entry.caught_count, 1);
+    /// # Examples
     ///
-    /// // Catch a specimen for a user that doesn't exist
-    /// let dummy_user = User {
-    ///     id: -1,
-    ///     external_id: USER_EXTERNAL_ID + 1,
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::item_data::ItemData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const ITEM_ID: i32 = 1;
+    /// const ITEM_NAME: &str = "Super Bad Rod";
+    ///
+    /// // Define some item data
+    /// let item_data = ItemData {
+    ///     name: ITEM_NAME.to_string(),
     ///     ..Default::default()
     /// };
-    /// let user_error = game.user_catch_specific_specimen(&dummy_user, species).unwrap_err();
-    /// if let Some(resource_error) = user_error.as_resource_error() {
-    ///     assert!(resource_error.is_user_not_found());
-    ///     assert_eq!(resource_error.get_external_id(), Some(USER_EXTERNAL_ID + 1));
-    /// } else {
-    ///     panic!("{:?}", user_error);
-    /// }
+    /// let item_data_map = HashMap::from([(ITEM_ID, item_data)]);
+    ///
+    /// // Add the location data to the config
+    /// let config = Config::builder().items(item_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// // Registering a new user
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// assert_eq!(user.external_id, EXTERNAL_ID);
+    ///
+    /// // Giving the user an item
+    /// let item_data = game.item_find(ITEM_ID).unwrap();
+    /// let item = game.user_item_give(&user, item_data, 1).unwrap();
     ///
+    /// let inventory = game.user_inventory(&user).unwrap();
+    /// let items = inventory.get_items();
+    /// assert_eq!(items[0], item);
     /// ```
-    fn user_catch_specific_specimen(
+    fn user_item_give(
         &self,
         user: &User,
-        species: Arc<SpeciesData>,
-    ) -> GameResult<(Specimen, FishingHistoryEntry)> {
-        let specimen = self.specimen_service().process_catch(user, species)?;
-        let entry = self.fishing_history_service().register_catch(&specimen)?;
-        Ok((specimen, entry))
+        item_data: Arc<ItemData>,
+        count: u64,
+    ) -> GameResult<Item> {
+        let item = if count <= 1 || !item_data.is_stackable() {
+            self.item_service().create_and_save_item(item_data, user)?
+        } else {
+            self.item_service()
+                .create_and_save_item_with_count(item_data, user, count)?
+        };
+        Ok(item)
     }
 
-    /// Check the fishing history of a [User] with a specified species ID
+    /// Let a [User] buy an item from the shop (See [GameInterface::shop_get_items]).
+    ///
+    /// The cost of the item is deducted from the credits of the [User] and the item is added
+    /// to their inventory. Either both happen or neither does.
     ///
     /// # Arguments
     ///
-    /// * `user`: The [User] to check the fishing history of
-    /// * `species_id`: Fishing history species ID to check for the giving [User] (See [Config])
+    /// * `user`: The [User] who buys the item
+    /// * `item_data`: The [ItemData] to buy (See [Config])
+    /// * `count`: How many units of the item to buy, each unit costs the item's cost.
+    ///   If the item is not stackable (unique) it'll be bought once (no matter the specified count).
     ///
     /// # Returns
-    ///
-    /// Result<[FishingHistoryEntry], [errors::GameError]>
-    /// - The fishing history of the given [User] with a specified species, if it exists
-    /// - An error, if it does not exist, aka if the [User] did not catch that species yet or the [User] does not exist
+    /// Result<[PurchaseResult], [errors::GameError]>
+    /// - The updated [User], the purchased [Item] and the total cost
+    /// - An error, if:
+    ///     - the item can't be purchased
+    ///     - the [User] has insufficient credits
+    ///     - the [User] already owns the maximum amount of this item
+    ///     - the [User] does not exist
+    ///     - database operations fail
     ///
     /// # Examples
     ///
@@ -402,136 +2254,181 @@ impl GameInterface for Game {
     /// use std::collections::HashMap;
     /// use std::env;
     /// use fish_lib::config::{Config, ConfigBuilderInterface};
-    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::data::item_data::ItemData;
     /// use fish_lib::game::prelude::*;
-    /// use fish_lib::game::repositories::user_repository::UserRepository;
     /// use fish_lib::game::service_provider::ServiceProviderInterface;
-    /// use fish_lib::models::user::User;
-    ///
-    /// const USER_EXTERNAL_ID: i64 = 1337;
-    /// const SPECIES_ID: i32 = 1;
-    /// const SPECIES_NAME: &str = "Salmon";
+    /// use fish_lib::models::item::attributes_container::ItemAttributesContainer;
+    /// use fish_lib::models::item::properties_container::{ItemPropertiesContainer, ItemPropertiesContainerInterface};
     ///
-    /// // Define some species data
-    /// let species_data = SpeciesData {
-    ///     name: SPECIES_NAME.to_string(),
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const ROD_ID: i32 = 1;
+    /// const BAIT_ID: i32 = 2;
+    /// const TROPHY_ID: i32 = 3;
+    ///
+    /// // Define a unique rod and a stackable bait which can be bought, and a trophy which can't
+    /// let rod_data = ItemData {
+    ///     name: "Wooden Rod".to_string(),
+    ///     attributes: ItemAttributesContainer::new().with_rod(1).with_purchasable(100),
+    ///     default_properties: ItemPropertiesContainer::new().with_usage(0),
     ///     ..Default::default()
     /// };
-    /// let species_data2 = SpeciesData {
+    /// let bait_data = ItemData {
+    ///     name: "Worm".to_string(),
+    ///     attributes: ItemAttributesContainer::new().with_bait(1).with_purchasable(5),
+    ///     default_properties: ItemPropertiesContainer::new().with_stackable(1),
     ///     ..Default::default()
-    ///  };
-    /// let species_data_map = HashMap::from([(SPECIES_ID, species_data), (SPECIES_ID + 1, species_data2)]);
+    /// };
+    /// let trophy_data = ItemData {
+    ///     name: "Golden Trophy".to_string(),
+    ///     ..Default::default()
+    /// };
     ///
-    /// // Add the species data to the config
-    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    /// let config = Config::builder()
+    ///     .items(HashMap::from([
+    ///         (ROD_ID, rod_data),
+    ///         (BAIT_ID, bait_data),
+    ///         (TROPHY_ID, trophy_data),
+    ///     ]))
+    ///     .build()
+    ///     .unwrap();
     ///
     /// // Create game and clear database for a blank test state
     /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
     /// let game = Game::new(&database_url, Some(config)).unwrap();
     /// game.database().write().unwrap().clear().unwrap();
     ///
-    /// // Create a user
-    /// let user = game.user_register(USER_EXTERNAL_ID).unwrap();
-    ///
-    /// // Get species data
-    /// let species = game.species_find(SPECIES_ID).unwrap();
-    /// let species2 = game.species_find(SPECIES_ID + 1).unwrap();
-    ///
-    /// // Let the user catch a specimen
-    /// game.user_catch_specific_specimen(&user, species.clone()).unwrap();
-    ///
-    /// // Fetch the fishing history of the user with the given species ID
-    /// let history_entry = game.user_get_fishing_history(&user, species.clone()).unwrap();
-    /// assert_eq!(history_entry.species_id, SPECIES_ID);
-    /// assert_eq!(history_entry.user_id, user.id);
-    /// assert_eq!(history_entry.caught_count, 1);
-    /// assert_eq!(history_entry.sold_count, 0);
+    /// // Give the user some credits
+    /// let mut user = game.user_register(EXTERNAL_ID).unwrap();
+    /// user.credits = 250;
+    /// let user = game.user_save(user).unwrap();
+    ///
+    /// // Buy a rod
+    /// let rod_data = game.item_find(ROD_ID).unwrap();
+    /// let result = game.user_item_purchase(&user, rod_data.clone(), 1).unwrap();
+    /// assert_eq!(result.cost, 100);
+    /// assert_eq!(result.user.credits, 150);
+    /// assert_eq!(result.item.type_id, ROD_ID);
+    ///
+    /// // Buy 4 pieces of bait, then another 2 which are stacked onto the first ones
+    /// let bait_data = game.item_find(BAIT_ID).unwrap();
+    /// let result = game.user_item_purchase(&user, bait_data.clone(), 4).unwrap();
+    /// assert_eq!(result.cost, 20);
+    /// assert_eq!(result.item.get_count(), Some(4));
+    /// let result = game.user_item_purchase(&user, bait_data.clone(), 2).unwrap();
+    /// assert_eq!(result.user.credits, 120);
+    /// assert_eq!(result.item.get_count(), Some(6));
+    ///
+    /// // The rod is unique, the user can't own it twice and is not charged for it
+    /// let max_count_error = game.user_item_purchase(&result.user, rod_data.clone(), 1).unwrap_err();
+    /// if let Some(resource_error) = max_count_error.as_resource_error() {
+    ///     assert!(resource_error.is_item_max_count_exceeded());
+    ///     assert_eq!(resource_error.get_item_type_id(), Some(ROD_ID));
+    /// } else {
+    ///     panic!("{:?}", max_count_error);
+    /// }
     ///
-    /// // Trying to fetch the fishing history with a species the user didn't catch yet
-    /// let error = game.user_get_fishing_history(&user, species2).unwrap_err();
-    /// assert!(error.is_not_found());
-    /// if let Some(resource_error) = error.as_resource_error() {
-    ///     assert!(resource_error.is_no_fishing_history());
-    ///     assert_eq!(resource_error.get_external_id(), Some(USER_EXTERNAL_ID));
-    ///     assert_eq!(resource_error.get_species_id(), Some(SPECIES_ID + 1));
+    /// // The user doesn't have enough credits left, nothing is deducted
+    /// let credits_error = game.user_item_purchase(&user, bait_data, 30).unwrap_err();
+    /// if let Some(resource_error) = credits_error.as_resource_error() {
+    ///     assert!(resource_error.is_insufficient_credits());
+    ///     assert_eq!(resource_error.get_cost(), Some(150));
+    ///     assert_eq!(resource_error.get_credits(), Some(120));
     /// } else {
-    ///     panic!("{:?}", error);
+    ///     panic!("{:?}", credits_error);
+    /// }
+    /// assert_eq!(game.user_find(EXTERNAL_ID).unwrap().credits, 120);
+    ///
+    /// // The trophy can't be bought
+    /// let trophy_data = game.item_find(TROPHY_ID).unwrap();
+    /// let purchasable_error = game.user_item_purchase(&user, trophy_data, 1).unwrap_err();
+    /// if let Some(resource_error) = purchasable_error.as_resource_error() {
+    ///     assert!(resource_error.is_item_not_purchasable());
+    ///     assert_eq!(resource_error.get_item_type_id(), Some(TROPHY_ID));
+    /// } else {
+    ///     panic!("{:?}", purchasable_error);
     /// }
     /// ```
-    fn user_get_fishing_history(
+    fn user_item_purchase(
         &self,
         user: &User,
-        species: Arc<SpeciesData>,
-    ) -> GameResult<FishingHistoryEntry> {
-        match self
-            .fishing_history_entry_repository()
-            .find_by_user_and_species_id(user.id, species.id)?
-        {
-            Some(entry) => Ok(entry),
-            None => Err(GameResourceError::no_fishing_history(user.external_id, species.id).into()),
-        }
+        item_data: Arc<ItemData>,
+        count: u64,
+    ) -> GameResult<PurchaseResult> {
+        transaction(&self.database(), || {
+            let mut result = self.shop_service().purchase_item(user, item_data, count)?;
+            result.achievements = self.achievement_service().process_trigger(
+                &result.user,
+                AchievementTrigger::Purchase {
+                    item_id: result.item.type_id,
+                },
+            )?;
+            Ok(result)
+        })
     }
 
-    /// Find a [User] by their external ID.
+    /// Get all ponds of a [User].
     ///
     /// # Arguments
     ///
-    /// * `external_id`: A freely selectable ID that your system will use to identify this [User].
+    /// * `user`: The [User] to get the ponds of
     ///
     /// # Returns
-    ///
-    /// Result<[User], [errors::GameError]>
-    /// - A [User] with the given external ID
-    /// - An error, if:
-    ///     - The [User] is not found
-    ///     - Database operations fail
+    /// Result<Vec<[Pond]>, [errors::GameError]>
+    /// - All ponds owned by the [User]
+    /// - An error, if database operations fail
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::collections::HashMap;
     /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
     /// use fish_lib::game::prelude::*;
     /// use fish_lib::game::service_provider::ServiceProviderInterface;
     ///
     /// const EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    ///
+    /// let species_data = SpeciesData {
+    ///     name: "Koi".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
     ///
     /// // Create game and clear database for a blank test state
     /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    /// let game = Game::new(&database_url, None).unwrap();
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
     /// game.database().write().unwrap().clear().unwrap();
     ///
-    /// // Finding an existing user
-    /// let new_user = game.user_register(EXTERNAL_ID).unwrap();
-    /// let found_user = game.user_find(EXTERNAL_ID).unwrap();
-    /// assert_eq!(new_user, found_user);
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let species = game.species_find(SPECIES_ID).unwrap();
     ///
-    /// // Searching for a non-existent user
-    /// let error = game.user_find(EXTERNAL_ID + 1).unwrap_err();
-    /// assert!(error.is_not_found());
-    /// if let Some(resource_error) = error.as_resource_error() {
-    ///     assert!(resource_error.is_user_not_found());
-    ///     assert_eq!(resource_error.get_external_id(), Some(EXTERNAL_ID + 1));
-    /// } else {
-    ///     panic!("{:?}", error);
-    /// }
+    /// assert!(game.user_get_ponds(&user).unwrap().is_empty());
+    ///
+    /// let pond = game.user_pond_create(&user, 10).unwrap();
+    /// assert_eq!(game.user_get_ponds(&user).unwrap(), vec![pond]);
     /// ```
-    fn user_find(&self, external_id: i64) -> GameResult<User> {
-        match self.user_repository().find_by_external_id(external_id)? {
-            Some(user) => Ok(user),
-            None => Err(GameResourceError::user_not_found(external_id).into()),
-        }
+    fn user_get_ponds(&self, user: &User) -> GameResult<Vec<Pond>> {
+        self.pond_service().get_ponds(user)
     }
 
-    /// Fetch the unlocked locations of a given user.
+    /// Create a new, empty [Pond] for a [User].
     ///
     /// # Arguments
     ///
-    /// * `user`: The [User] to get the unlocked locations ([UserLocationUnlock]) from.
+    /// * `user`: The [User] who will own the [Pond]
+    /// * `capacity`: How many specimens fit in the [Pond]
     ///
     /// # Returns
-    /// Result<Vec<[UserLocationUnlock], Global>, [errors::GameError]>
-    /// - A vector with information about all location unlocks
-    /// - An error, if database operations fail
+    /// Result<[Pond], [errors::GameError]>
+    /// - The newly created [Pond]
+    /// - An error, if:
+    ///     - the capacity is less than 1
+    ///     - database operations fail
     ///
     /// # Examples
     ///
@@ -539,61 +2436,56 @@ impl GameInterface for Game {
     /// use std::collections::HashMap;
     /// use std::env;
     /// use fish_lib::config::{Config, ConfigBuilderInterface};
-    /// use fish_lib::data::location_data::LocationData;
+    /// use fish_lib::data::species_data::SpeciesData;
     /// use fish_lib::game::prelude::*;
     /// use fish_lib::game::service_provider::ServiceProviderInterface;
     ///
     /// const EXTERNAL_ID: i64 = 1337;
-    /// const LOCATION_ID: i32 = 13;
-    /// const LOCATION_NAME: &str = "Island";
+    /// const SPECIES_ID: i32 = 1;
     ///
-    /// // Define some location data
-    /// let location_data = LocationData {
-    ///     name: LOCATION_NAME.to_string(),
+    /// let species_data = SpeciesData {
+    ///     name: "Koi".to_string(),
     ///     ..Default::default()
     /// };
-    /// let location_data_map = HashMap::from([(LOCATION_ID, location_data)]);
-    ///
-    /// // Add the location data to the config
-    /// let config = Config::builder().locations(location_data_map).build().unwrap();
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
     ///
     /// // Create game and clear database for a blank test state
     /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
     /// let game = Game::new(&database_url, Some(config)).unwrap();
     /// game.database().write().unwrap().clear().unwrap();
     ///
-    /// // Registering a new user
     /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let species = game.species_find(SPECIES_ID).unwrap();
     ///
-    /// // Let the user unlock a location
-    /// let island_location = game.location_find(LOCATION_ID).unwrap();
-    /// let unlocked_location = game.user_unlock_location(&user, island_location).unwrap();
-    ///
-    /// // Get unlocked locations
-    /// let unlocked_locations = game.user_get_unlocked_locations(&user).unwrap();
-    /// assert_eq!(unlocked_locations.len(), 1);
-    /// assert_eq!(unlocked_locations[0], unlocked_location);
+    /// let pond = game.user_pond_create(&user, 10).unwrap();
+    /// assert_eq!(pond.user_id, user.id);
+    /// assert_eq!(pond.capacity, 10);
     /// ```
-    fn user_get_unlocked_locations(&self, user: &User) -> GameResult<Vec<UserLocationUnlock>> {
-        let user_locations = self.user_service().get_unlocked_locations(user)?;
-        let location_unlocks =
-            UserLocationUnlock::from_user_locations(user_locations, |location_id| {
-                self.location_find(location_id).ok()
-            });
-        Ok(location_unlocks)
+    fn user_pond_create(&self, user: &User, capacity: i32) -> GameResult<Pond> {
+        self.pond_service().create_and_save_pond(user, capacity)
     }
 
-    /// Get the [Inventory] of a specified [User].
+    /// Move a [Specimen] of a [User] into one of their ponds.
+    ///
+    /// A [Specimen] which already is in another [Pond] is moved over.
     ///
     /// # Arguments
     ///
-    /// * `user`: The [User] to get the [Inventory] from.
+    /// * `user`: The [User] who owns the [Specimen] and the [Pond]
+    /// * `pond`: The [Pond] to move the [Specimen] into
+    /// * `specimen`: The [Specimen] to move
     ///
     /// # Returns
-    ///
-    /// Result<[Inventory], [errors::GameError]>
-    /// - The user's inventory, if the user exists
-    /// - An error, if a database error occurred
+    /// Result<[Specimen], [errors::GameError]>
+    /// - The updated [Specimen]
+    /// - An error, if:
+    ///     - the [Pond] or the [Specimen] is not owned by the [User]
+    ///     - the [Pond] or the [Specimen] does not exist
+    ///     - the [Pond] is full
+    ///     - database operations fail
     ///
     /// # Examples
     ///
@@ -601,61 +2493,83 @@ impl GameInterface for Game {
     /// use std::collections::HashMap;
     /// use std::env;
     /// use fish_lib::config::{Config, ConfigBuilderInterface};
-    /// use fish_lib::data::item_data::ItemData;
+    /// use fish_lib::data::species_data::SpeciesData;
     /// use fish_lib::game::prelude::*;
     /// use fish_lib::game::service_provider::ServiceProviderInterface;
     ///
     /// const EXTERNAL_ID: i64 = 1337;
-    /// const ITEM_ID: i32 = 1;
-    /// const ITEM_NAME: &str = "Super Bad Rod";
+    /// const SPECIES_ID: i32 = 1;
     ///
-    /// // Define some item data
-    /// let item_data = ItemData {
-    ///     name: ITEM_NAME.to_string(),
+    /// let species_data = SpeciesData {
+    ///     name: "Koi".to_string(),
     ///     ..Default::default()
     /// };
-    /// let item_data_map = HashMap::from([(ITEM_ID, item_data)]);
-    ///
-    /// // Add the location data to the config
-    /// let config = Config::builder().items(item_data_map).build().unwrap();
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
     ///
     /// // Create game and clear database for a blank test state
     /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
     /// let game = Game::new(&database_url, Some(config)).unwrap();
     /// game.database().write().unwrap().clear().unwrap();
     ///
-    /// // Registering a new user
     /// let user = game.user_register(EXTERNAL_ID).unwrap();
-    /// assert_eq!(user.external_id, EXTERNAL_ID);
+    /// let species = game.species_find(SPECIES_ID).unwrap();
     ///
-    /// /// Giving the user an item
-    /// let item_data = game.item_find(ITEM_ID).unwrap();
-    /// let item = game.user_item_give(&user, item_data, 1).unwrap();
+    /// // Create a pond which can only hold one specimen
+    /// let pond = game.user_pond_create(&user, 1).unwrap();
     ///
-    /// let inventory = game.user_inventory(&user).unwrap();
-    /// let items = inventory.get_items();
-    /// assert_eq!(items[0], item);
+    /// let (specimen, _) = game.user_catch_specific_specimen(&user, species.clone()).unwrap();
+    /// let specimen = game.user_pond_add_specimen(&user, &pond, specimen).unwrap();
+    /// assert_eq!(specimen.pond_id, Some(pond.id));
     ///
+    /// // The pond is full
+    /// let (other_specimen, _) = game.user_catch_specific_specimen(&user, species.clone()).unwrap();
+    /// let full_error = game.user_pond_add_specimen(&user, &pond, other_specimen).unwrap_err();
+    /// if let Some(resource_error) = full_error.as_resource_error() {
+    ///     assert!(resource_error.is_pond_full());
+    ///     assert_eq!(resource_error.get_pond_id(), Some(pond.id));
+    ///     assert_eq!(resource_error.get_capacity(), Some(1));
+    /// } else {
+    ///     panic!("{:?}", full_error);
+    /// }
+    ///
+    /// // Ponds of other users can't be used
+    /// let other_user = game.user_register(EXTERNAL_ID + 1).unwrap();
+    /// let other_pond = game.user_pond_create(&other_user, 10).unwrap();
+    /// let owner_error = game.user_pond_add_specimen(&user, &other_pond, specimen).unwrap_err();
+    /// if let Some(resource_error) = owner_error.as_resource_error() {
+    ///     assert!(resource_error.is_pond_not_owned());
+    ///     assert_eq!(resource_error.get_pond_id(), Some(other_pond.id));
+    ///     assert_eq!(resource_error.get_external_id(), Some(EXTERNAL_ID));
+    /// } else {
+    ///     panic!("{:?}", owner_error);
+    /// }
     /// ```
-    fn user_inventory(&self, user: &User) -> GameResult<Inventory> {
-        self.item_service().get_inventory(user)
+    fn user_pond_add_specimen(
+        &self,
+        user: &User,
+        pond: &Pond,
+        specimen: Specimen,
+    ) -> GameResult<Specimen> {
+        self.pond_service().add_specimen(user, pond, specimen)
     }
 
-    /// Give a [User] a specified [ItemData].
+    /// Take a [Specimen] of a [User] out of its [Pond].
     ///
     /// # Arguments
     ///
-    /// * `user`: The [User] to give the item to.
-    /// * `item_data`: The [ItemData] to give the user. (See [Config])
-    /// * `count`: How much of the item to give the user.
-    ///     0 or 1 results in the default specified count if the item is stackable.
-    ///     If the item is not stackable (unique) it'll be added once (no matter the specified count).
+    /// * `user`: The [User] who owns the [Specimen]
+    /// * `specimen`: The [Specimen] to take out of its [Pond]
     ///
     /// # Returns
-    ///
-    /// Result<[Item], [errors::GameError]>
-    /// - The newly created item when the operation was successful
-    /// - An error, if there was an error stacking the item or database operations failed
+    /// Result<[Specimen], [errors::GameError]>
+    /// - The updated [Specimen]
+    /// - An error, if:
+    ///     - the [Specimen] is not owned by the [User]
+    ///     - the [Specimen] does not exist
+    ///     - database operations fail
     ///
     /// # Examples
     ///
@@ -663,54 +2577,41 @@ impl GameInterface for Game {
     /// use std::collections::HashMap;
     /// use std::env;
     /// use fish_lib::config::{Config, ConfigBuilderInterface};
-    /// use fish_lib::data::item_data::ItemData;
+    /// use fish_lib::data::species_data::SpeciesData;
     /// use fish_lib::game::prelude::*;
     /// use fish_lib::game::service_provider::ServiceProviderInterface;
     ///
     /// const EXTERNAL_ID: i64 = 1337;
-    /// const ITEM_ID: i32 = 1;
-    /// const ITEM_NAME: &str = "Super Bad Rod";
+    /// const SPECIES_ID: i32 = 1;
     ///
-    /// // Define some item data
-    /// let item_data = ItemData {
-    ///     name: ITEM_NAME.to_string(),
+    /// let species_data = SpeciesData {
+    ///     name: "Koi".to_string(),
     ///     ..Default::default()
     /// };
-    /// let item_data_map = HashMap::from([(ITEM_ID, item_data)]);
-    ///
-    /// // Add the location data to the config
-    /// let config = Config::builder().items(item_data_map).build().unwrap();
+    /// let config = Config::builder()
+    ///     .species(HashMap::from([(SPECIES_ID, species_data)]))
+    ///     .build()
+    ///     .unwrap();
     ///
     /// // Create game and clear database for a blank test state
     /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
     /// let game = Game::new(&database_url, Some(config)).unwrap();
     /// game.database().write().unwrap().clear().unwrap();
     ///
-    /// // Registering a new user
     /// let user = game.user_register(EXTERNAL_ID).unwrap();
-    /// assert_eq!(user.external_id, EXTERNAL_ID);
+    /// let species = game.species_find(SPECIES_ID).unwrap();
     ///
-    /// // Giving the user an item
-    /// let item_data = game.item_find(ITEM_ID).unwrap();
-    /// let item = game.user_item_give(&user, item_data, 1).unwrap();
+    /// let pond = game.user_pond_create(&user, 1).unwrap();
+    /// let (specimen, _) = game.user_catch_specific_specimen(&user, species).unwrap();
+    /// let specimen = game.user_pond_add_specimen(&user, &pond, specimen).unwrap();
     ///
-    /// let inventory = game.user_inventory(&user).unwrap();
-    /// let items = inventory.get_items();
-    /// assert_eq!(items[0], item);
+    /// // Taking the specimen out frees up space in the pond
+    /// let specimen = game.user_pond_remove_specimen(&user, specimen).unwrap();
+    /// assert_eq!(specimen.pond_id, None);
+    /// assert!(game.pond_get_specimens(&pond).unwrap().is_empty());
     /// ```
-    fn user_item_give(
-        &self,
-        user: &User,
-        item_data: Arc<ItemData>,
-        count: u64,
-    ) -> GameResult<Item> {
-        let item = if count <= 1 || !item_data.is_stackable() {
-            self.item_service().create_and_save_item(item_data, user)?
-        } else {
-            self.item_service()
-                .create_and_save_item_with_count(item_data, user, count)?
-        };
-        Ok(item)
+    fn user_pond_remove_specimen(&self, user: &User, specimen: Specimen) -> GameResult<Specimen> {
+        self.pond_service().remove_specimen(user, specimen)
     }
 
     /// Register a new [User] by their external ID.
@@ -764,6 +2665,9 @@ impl GameInterface for Game {
 
     /// Save a [User].
     ///
+    /// The [User] overwrites its stored row, even if it was modified since it was loaded.
+    /// Use [GameInterface::user_save_checked] to reject outdated copies instead.
+    ///
     /// # Arguments
     ///
     /// * `user`: The [User] to save
@@ -816,6 +2720,345 @@ impl GameInterface for Game {
         Ok(self.user_repository().save(user)?)
     }
 
+    /// Save a [User], unless it was modified since it was loaded.
+    ///
+    /// Every save increments the version of the [User], a copy with an outdated version is
+    /// rejected (See [errors::GameError::is_stale_entity]) and the [User] should be loaded again.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] to save
+    ///
+    /// # Returns
+    /// Result<[User], [errors::GameError]>
+    /// - The updated [User] entity, if saving succeeded
+    /// - An error, if saving failed (database errors, the user doesn't exist or was modified in the meantime)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    ///
+    /// const USER_EXTERNAL_ID: i64 = 1337;
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, None).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(USER_EXTERNAL_ID).unwrap();
+    ///
+    /// // Two copies of the same user are modified
+    /// let mut first_copy = user.clone();
+    /// first_copy.credits = 100;
+    /// let mut second_copy = user;
+    /// second_copy.credits = 0;
+    ///
+    /// // The first save wins, the outdated second copy is rejected
+    /// let saved_user = game.user_save_checked(first_copy).unwrap();
+    /// assert_eq!(saved_user.credits, 100);
+    /// let stale_error = game.user_save_checked(second_copy).unwrap_err();
+    /// assert!(stale_error.is_stale_entity());
+    ///
+    /// // Retrying with the reloaded user succeeds
+    /// let mut reloaded_user = game.user_find(USER_EXTERNAL_ID).unwrap();
+    /// reloaded_user.credits -= 10;
+    /// let saved_user = game.user_save_checked(reloaded_user).unwrap();
+    /// assert_eq!(saved_user.credits, 90);
+    /// ```
+    fn user_save_checked(&self, user: User) -> GameResult<User> {
+        Ok(self.user_repository().save_checked(user)?)
+    }
+
+    /// Sell a [Specimen] of a [User] for credits.
+    ///
+    /// The [Specimen] is removed, its sell price (See [GameInterface::specimen_sell_price]) is
+    /// added to the credits of the [User] and the sale is registered in the fishing history.
+    /// All of these changes are made in a single transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] who sells the [Specimen]
+    /// * `specimen`: The [Specimen] to sell
+    ///
+    /// # Returns
+    /// Result<[SellResult], [errors::GameError]>
+    /// - The updated [User], the sold [Specimen] and the credits received
+    /// - An error, if:
+    ///     - the [Specimen] is not owned by the [User]
+    ///     - the [Specimen] does not exist (anymore)
+    ///     - the [User] does not exist
+    ///     - database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::models::user::User;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    /// const OTHER_SPECIES_ID: i32 = 2;
+    ///
+    /// // Define some species with a base value
+    /// let species_data = SpeciesData {
+    ///     name: "Salmon".to_string(),
+    ///     base_value: 100,
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 50,
+    ///     max_size_adult_mm: 80,
+    ///     min_lifespan_days: 300,
+    ///     max_lifespan_days: 400,
+    ///     ..Default::default()
+    /// };
+    /// let other_species_data = SpeciesData {
+    ///     name: "Trout".to_string(),
+    ///     base_value: 50,
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 30,
+    ///     max_size_adult_mm: 40,
+    ///     min_lifespan_days: 300,
+    ///     max_lifespan_days: 400,
+    ///     ..Default::default()
+    /// };
+    /// let species_data_map = HashMap::from([
+    ///     (SPECIES_ID, species_data),
+    ///     (OTHER_SPECIES_ID, other_species_data),
+    /// ]);
+    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let other_species = game.species_find(OTHER_SPECIES_ID).unwrap();
+    ///
+    /// // Catch and sell a specimen
+    /// let (specimen, _) = game.user_catch_specific_specimen(&user, species).unwrap();
+    /// let price = game.specimen_sell_price(&specimen).unwrap();
+    /// let result = game.user_sell_specimen(&user, specimen.clone()).unwrap();
+    /// assert_eq!(result.specimens, vec![specimen.clone()]);
+    /// assert_eq!(result.credits, price);
+    /// assert_eq!(result.user.credits, user.credits + price);
+    ///
+    /// // The sale is registered in the fishing history
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let history_entry = game.user_get_fishing_history(&user, species).unwrap();
+    /// assert_eq!(history_entry.sold_count, 1);
+    ///
+    /// // A specimen can only be sold once
+    /// let sold_error = game.user_sell_specimen(&user, specimen).unwrap_err();
+    /// if let Some(resource_error) = sold_error.as_resource_error() {
+    ///     assert!(resource_error.is_specimen_not_found());
+    /// } else {
+    ///     panic!("{:?}", sold_error);
+    /// }
+    ///
+    /// // Specimens of other users can't be sold
+    /// let other_user = game.user_register(EXTERNAL_ID + 1).unwrap();
+    /// let (other_specimen, _) = game.user_catch_specific_specimen(&other_user, other_species).unwrap();
+    /// let owner_error = game.user_sell_specimen(&user, other_specimen.clone()).unwrap_err();
+    /// if let Some(resource_error) = owner_error.as_resource_error() {
+    ///     assert!(resource_error.is_specimen_not_owned());
+    ///     assert_eq!(resource_error.get_specimen_id(), Some(other_specimen.id));
+    ///     assert_eq!(resource_error.get_external_id(), Some(EXTERNAL_ID));
+    /// } else {
+    ///     panic!("{:?}", owner_error);
+    /// }
+    /// ```
+    fn user_sell_specimen(&self, user: &User, specimen: Specimen) -> GameResult<SellResult> {
+        self.sell_with_achievements(|| self.shop_service().sell_specimens(user, vec![specimen]))
+    }
+
+    /// Sell all specimens of a given species owned by a [User].
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] who sells the specimens
+    /// * `species`: The species of which all specimens should be sold (See [Config])
+    ///
+    /// # Returns
+    /// Result<[SellResult], [errors::GameError]>
+    /// - The updated [User], the sold specimens and the total credits received
+    /// - An error, if:
+    ///     - the [User] does not exist
+    ///     - database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::models::user::User;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    /// const OTHER_SPECIES_ID: i32 = 2;
+    ///
+    /// // Define some species with a base value
+    /// let species_data = SpeciesData {
+    ///     name: "Salmon".to_string(),
+    ///     base_value: 100,
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 50,
+    ///     max_size_adult_mm: 80,
+    ///     min_lifespan_days: 300,
+    ///     max_lifespan_days: 400,
+    ///     ..Default::default()
+    /// };
+    /// let other_species_data = SpeciesData {
+    ///     name: "Trout".to_string(),
+    ///     base_value: 50,
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 30,
+    ///     max_size_adult_mm: 40,
+    ///     min_lifespan_days: 300,
+    ///     max_lifespan_days: 400,
+    ///     ..Default::default()
+    /// };
+    /// let species_data_map = HashMap::from([
+    ///     (SPECIES_ID, species_data),
+    ///     (OTHER_SPECIES_ID, other_species_data),
+    /// ]);
+    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let other_species = game.species_find(OTHER_SPECIES_ID).unwrap();
+    ///
+    /// // Catch some specimens of different species
+    /// game.user_catch_specific_specimen(&user, species.clone()).unwrap();
+    /// game.user_catch_specific_specimen(&user, species.clone()).unwrap();
+    /// game.user_catch_specific_specimen(&user, other_species.clone()).unwrap();
+    ///
+    /// // Sell all specimens of one species
+    /// let result = game.user_sell_species(&user, species.clone()).unwrap();
+    /// assert_eq!(result.specimens.len(), 2);
+    /// assert!(result.specimens.iter().all(|specimen| specimen.species_id == SPECIES_ID));
+    /// assert_eq!(result.user.credits, result.credits);
+    ///
+    /// // Nothing left to sell
+    /// let result = game.user_sell_species(&user, species).unwrap();
+    /// assert!(result.is_empty());
+    /// assert_eq!(result.credits, 0);
+    /// ```
+    fn user_sell_species(&self, user: &User, species: Arc<SpeciesData>) -> GameResult<SellResult> {
+        self.sell_with_achievements(|| self.shop_service().sell_species(user, species.id))
+    }
+
+    /// Sell all specimens of a [User] which are smaller than a given total size ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `user`: The [User] who sells the specimens
+    /// * `max_size_ratio`: Only specimens with a total size ratio (0-1) below this are sold
+    ///
+    /// # Returns
+    /// Result<[SellResult], [errors::GameError]>
+    /// - The updated [User], the sold specimens and the total credits received
+    /// - An error, if:
+    ///     - the [User] does not exist
+    ///     - database operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::env;
+    /// use fish_lib::config::{Config, ConfigBuilderInterface};
+    /// use fish_lib::data::species_data::SpeciesData;
+    /// use fish_lib::game::prelude::*;
+    /// use fish_lib::game::service_provider::ServiceProviderInterface;
+    /// use fish_lib::models::user::User;
+    ///
+    /// const EXTERNAL_ID: i64 = 1337;
+    /// const SPECIES_ID: i32 = 1;
+    /// const OTHER_SPECIES_ID: i32 = 2;
+    ///
+    /// // Define some species with a base value
+    /// let species_data = SpeciesData {
+    ///     name: "Salmon".to_string(),
+    ///     base_value: 100,
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 50,
+    ///     max_size_adult_mm: 80,
+    ///     min_lifespan_days: 300,
+    ///     max_lifespan_days: 400,
+    ///     ..Default::default()
+    /// };
+    /// let other_species_data = SpeciesData {
+    ///     name: "Trout".to_string(),
+    ///     base_value: 50,
+    ///     min_size_baby_mm: 10,
+    ///     max_size_baby_mm: 20,
+    ///     min_size_adult_mm: 30,
+    ///     max_size_adult_mm: 40,
+    ///     min_lifespan_days: 300,
+    ///     max_lifespan_days: 400,
+    ///     ..Default::default()
+    /// };
+    /// let species_data_map = HashMap::from([
+    ///     (SPECIES_ID, species_data),
+    ///     (OTHER_SPECIES_ID, other_species_data),
+    /// ]);
+    /// let config = Config::builder().species(species_data_map).build().unwrap();
+    ///
+    /// // Create game and clear database for a blank test state
+    /// let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    /// let game = Game::new(&database_url, Some(config)).unwrap();
+    /// game.database().write().unwrap().clear().unwrap();
+    ///
+    /// let user = game.user_register(EXTERNAL_ID).unwrap();
+    /// let species = game.species_find(SPECIES_ID).unwrap();
+    /// let other_species = game.species_find(OTHER_SPECIES_ID).unwrap();
+    ///
+    /// game.user_catch_specific_specimen(&user, species).unwrap();
+    /// game.user_catch_specific_specimen(&user, other_species).unwrap();
+    ///
+    /// // No specimen can be smaller than the smallest possible size
+    /// let result = game.user_sell_below_size_ratio(&user, 0.0).unwrap();
+    /// assert!(result.is_empty());
+    ///
+    /// // Every specimen is smaller than a ratio above the biggest possible size
+    /// let result = game.user_sell_below_size_ratio(&user, 1.1).unwrap();
+    /// assert_eq!(result.specimens.len(), 2);
+    /// assert_eq!(result.user.credits, result.credits);
+    /// ```
+    fn user_sell_below_size_ratio(
+        &self,
+        user: &User,
+        max_size_ratio: f32,
+    ) -> GameResult<SellResult> {
+        self.sell_with_achievements(|| {
+            self.shop_service()
+                .sell_below_size_ratio(user, max_size_ratio)
+        })
+    }
+
     /// Unlocks a given location for a given user
     ///
     /// # Arguments
@@ -824,8 +3067,8 @@ impl GameInterface for Game {
     /// * `location`: The location to unlock for the given [User]
     ///
     /// # Returns
-    /// Result<[UserLocationUnlock], [errors::GameError]>
-    /// - Information about the location unlock, if it succeeded
+    /// Result<[LocationUnlockResult], [errors::GameError]>
+    /// - Information about the location unlock and the achievements it unlocked, if it succeeded
     /// - An error, if:
     ///     - unlock conditions were not met
     ///     - the location was already unlocked
@@ -877,12 +3120,13 @@ impl GameInterface for Game {
     ///
     /// // Unlock a location for the user
     /// let island = game.location_find(LOCATION_ID).unwrap();
-    /// let location_unlock = game.user_unlock_location(&user, island).unwrap();
+    /// let unlock_result = game.user_unlock_location(&user, island).unwrap();
+    /// assert!(unlock_result.achievements.is_empty());
     ///
     /// // Find unlocked locations
     /// let unlocked_locations = game.user_get_unlocked_locations(&user).unwrap();
     /// assert_eq!(unlocked_locations.len(), 1);
-    /// assert_eq!(unlocked_locations[0], location_unlock);
+    /// assert_eq!(unlocked_locations[0], unlock_result.location_unlock);
     ///
     /// // Unmet requirements
     /// let location2 = game.location_find(LOCATION_ID + 1).unwrap();
@@ -900,18 +3144,27 @@ impl GameInterface for Game {
         &self,
         user: &User,
         location: Arc<LocationData>,
-    ) -> GameResult<UserLocationUnlock> {
-        let user_location = self
-            .user_service()
-            .unlock_location(user, location.clone())?;
-        let user_location_unlock =
-            UserLocationUnlock::from_user_location(user_location, &|location_id| {
-                self.location_find(location_id).ok()
-            });
-        match user_location_unlock {
-            Some(location_unlock) => Ok(location_unlock),
-            None => Err(GameResourceError::location_not_found(location.id).into()),
-        }
+    ) -> GameResult<LocationUnlockResult> {
+        transaction(&self.database(), || {
+            let user_location = self
+                .user_service()
+                .unlock_location(user, location.clone())?;
+            let user_location_unlock =
+                UserLocationUnlock::from_user_location(user_location, &|location_id| {
+                    self.location_find(location_id).ok()
+                });
+            let Some(location_unlock) = user_location_unlock else {
+                return Err(GameResourceError::location_not_found(location.id).into());
+            };
+
+            let achievements = self
+                .achievement_service()
+                .process_trigger(user, AchievementTrigger::LocationUnlock)?;
+            Ok(LocationUnlockResult {
+                location_unlock,
+                achievements,
+            })
+        })
     }
 }
 
@@ -920,14 +3173,34 @@ impl ServiceProviderInterface for Game {
         self.service_provider.asset_server()
     }
 
+    fn clock(&self) -> Arc<dyn Clock> {
+        self.service_provider.clock()
+    }
+
     fn config(&self) -> Arc<dyn ConfigInterface> {
         self.service_provider.config()
     }
 
+    fn game_time(&self) -> Arc<GameTime> {
+        self.service_provider.game_time()
+    }
+
+    fn random_source(&self) -> Arc<dyn RandomSource> {
+        self.service_provider.random_source()
+    }
+
     fn database(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
         self.service_provider.database()
     }
 
+    fn event_bus(&self) -> Arc<dyn EventBusInterface> {
+        self.service_provider.event_bus()
+    }
+
+    fn catch_event_repository(&self) -> Arc<dyn CatchEventRepositoryInterface> {
+        self.service_provider.catch_event_repository()
+    }
+
     fn fishing_history_entry_repository(&self) -> Arc<dyn FishingHistoryEntryRepositoryInterface> {
         self.service_provider.fishing_history_entry_repository()
     }
@@ -940,6 +3213,10 @@ impl ServiceProviderInterface for Game {
         self.service_provider.pond_repository()
     }
 
+    fn sale_event_repository(&self) -> Arc<dyn SaleEventRepositoryInterface> {
+        self.service_provider.sale_event_repository()
+    }
+
     fn specimen_repository(&self) -> Arc<dyn SpecimenRepositoryInterface> {
         self.service_provider.specimen_repository()
     }
@@ -948,6 +3225,10 @@ impl ServiceProviderInterface for Game {
         self.service_provider.user_repository()
     }
 
+    fn achievement_service(&self) -> Arc<dyn AchievementServiceInterface> {
+        self.service_provider.achievement_service()
+    }
+
     fn encounter_service(&self) -> Arc<dyn EncounterServiceInterface> {
         self.service_provider.encounter_service()
     }
@@ -956,10 +3237,18 @@ impl ServiceProviderInterface for Game {
         self.service_provider.fishing_history_service()
     }
 
+    fn forecast_service(&self) -> Arc<dyn ForecastServiceInterface> {
+        self.service_provider.forecast_service()
+    }
+
     fn item_service(&self) -> Arc<dyn ItemServiceInterface> {
         self.service_provider.item_service()
     }
 
+    fn leaderboard_service(&self) -> Arc<dyn LeaderboardServiceInterface> {
+        self.service_provider.leaderboard_service()
+    }
+
     fn location_service(&self) -> Arc<dyn LocationServiceInterface> {
         self.service_provider.location_service()
     }
@@ -968,6 +3257,10 @@ impl ServiceProviderInterface for Game {
         self.service_provider.pond_service()
     }
 
+    fn shop_service(&self) -> Arc<dyn ShopServiceInterface> {
+        self.service_provider.shop_service()
+    }
+
     fn species_service(&self) -> Arc<dyn SpeciesServiceInterface> {
         self.service_provider.species_service()
     }
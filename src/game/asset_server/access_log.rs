@@ -8,6 +8,7 @@ pub struct AccessLog {
 }
 
 impl AccessLog {
+    #[allow(dead_code)]
     pub fn new() -> Self {
         Self::default()
     }
@@ -16,6 +17,7 @@ impl AccessLog {
         self.entries.push(entry, Reverse(Instant::now()));
     }
 
+    #[allow(dead_code)]
     pub fn has_entry(&self, entry: &str) -> bool {
         self.entries.get(entry).is_some()
     }
@@ -29,6 +29,7 @@ impl Asset {
         }
     }
 
+    #[allow(unreachable_patterns)]
     pub fn as_png(&self) -> Option<&ImagePngAsset> {
         match self {
             Self::ImagePng(asset) => Some(asset),
@@ -0,0 +1,104 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use std::sync::RwLock;
+
+/// # Clock
+/// Source of the current time for everything that depends on it,
+/// e.g. the age of specimens, the weather, the local time of locations and users
+/// and the timestamps the repositories persist.
+///
+/// The [`crate::game::service_provider::ServiceProvider`] owns the clock, so swapping it
+/// allows tests to freeze time or to fast-forward it.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The real system time, used by default
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock which stands still until it is set or advanced manually
+#[derive(Debug)]
+pub struct FixedClock {
+    time: RwLock<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    pub fn new(time: DateTime<Utc>) -> Self {
+        Self {
+            time: RwLock::new(time),
+        }
+    }
+
+    pub fn set(&self, time: DateTime<Utc>) {
+        *self
+            .time
+            .write()
+            .expect("Failed to get write lock on clock") = time;
+    }
+
+    pub fn advance(&self, duration: TimeDelta) {
+        *self
+            .time
+            .write()
+            .expect("Failed to get write lock on clock") += duration;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.time.read().expect("Failed to get read lock on clock")
+    }
+}
+
+/// The system time shifted by an offset, which can be changed to fast-forward time
+#[derive(Debug, Default)]
+pub struct OffsetClock {
+    offset: RwLock<TimeDelta>,
+}
+
+impl OffsetClock {
+    pub fn new(offset: TimeDelta) -> Self {
+        Self {
+            offset: RwLock::new(offset),
+        }
+    }
+
+    pub fn get_offset(&self) -> TimeDelta {
+        *self
+            .offset
+            .read()
+            .expect("Failed to get read lock on clock")
+    }
+
+    pub fn set_offset(&self, offset: TimeDelta) {
+        *self
+            .offset
+            .write()
+            .expect("Failed to get write lock on clock") = offset;
+    }
+
+    pub fn advance(&self, duration: TimeDelta) {
+        *self
+            .offset
+            .write()
+            .expect("Failed to get write lock on clock") += duration;
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.get_offset()
+    }
+}
@@ -1,10 +1,12 @@
 use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::input::GameInputError;
 use crate::game::errors::item_event::GameItemEventError;
 use crate::game::errors::repository::GameRepositoryError;
 use crate::game::errors::resource::GameResourceError;
 use thiserror::Error;
 
 pub mod database;
+pub mod input;
 pub mod item_event;
 pub mod repository;
 pub mod resource;
@@ -16,6 +18,8 @@ pub enum GameError {
     #[error(transparent)]
     Database(#[from] GameDatabaseError),
     #[error(transparent)]
+    Input(#[from] GameInputError),
+    #[error(transparent)]
     ItemEvent(#[from] GameItemEventError),
     #[error(transparent)]
     Repository(#[from] GameRepositoryError),
@@ -44,6 +48,13 @@ impl GameError {
         }
     }
 
+    pub fn as_input_error(&self) -> Option<&GameInputError> {
+        match self {
+            Self::Input(e) => Some(e),
+            _ => None,
+        }
+    }
+
     pub fn as_item_event_error(&self) -> Option<&GameItemEventError> {
         match self {
             Self::ItemEvent(e) => Some(e),
@@ -69,6 +80,10 @@ impl GameError {
         matches!(self, Self::Database(_))
     }
 
+    pub fn is_input_error(&self) -> bool {
+        matches!(self, Self::Input(_))
+    }
+
     pub fn is_item_event_error(&self) -> bool {
         matches!(self, Self::ItemEvent(_))
     }
@@ -96,12 +111,21 @@ impl GameError {
                 | Self::Resource(GameResourceError::FishingHistoryNotFound { .. })
                 | Self::Resource(GameResourceError::LocationNotFound { .. })
                 | Self::Resource(GameResourceError::SpeciesNotFound { .. })
+                | Self::Resource(GameResourceError::SpecimenNotFound { .. })
+                | Self::Resource(GameResourceError::PondNotFound { .. })
                 | Self::Resource(GameResourceError::NoFishingHistory { .. })
                 | Self::Repository(GameRepositoryError::Database(GameDatabaseError::NotFound))
                 | Self::Resource(GameResourceError::ItemNotFound { .. })
         )
     }
 
+    pub fn is_stale_entity(&self) -> bool {
+        matches!(
+            self,
+            Self::Repository(GameRepositoryError::StaleEntity { .. })
+        )
+    }
+
     pub fn is_unmet_requirements(&self) -> bool {
         matches!(
             self,
@@ -117,6 +141,9 @@ impl From<Box<dyn std::error::Error>> for GameError {
             e if e.is::<GameDatabaseError>() => {
                 GameError::Database(*e.downcast::<GameDatabaseError>().unwrap())
             }
+            e if e.is::<GameInputError>() => {
+                GameError::Input(*e.downcast::<GameInputError>().unwrap())
+            }
             e if e.is::<GameRepositoryError>() => {
                 GameError::Repository(*e.downcast::<GameRepositoryError>().unwrap())
             }
@@ -14,6 +14,8 @@ pub enum GameDatabaseError {
     NotFound,
     #[error("Database error: {msg}")]
     Other { msg: String },
+    #[error("Database transaction failed: {msg}")]
+    TransactionFailed { msg: String },
     #[error("Database unique constraint violation: {msg}")]
     UniqueConstraintViolation { msg: String },
 }
@@ -51,6 +53,12 @@ impl GameDatabaseError {
         }
     }
 
+    pub fn transaction_failed(msg: &str) -> Self {
+        Self::TransactionFailed {
+            msg: msg.to_string(),
+        }
+    }
+
     pub fn unique_constraint_violation(msg: &str) -> Self {
         Self::UniqueConstraintViolation {
             msg: msg.to_string(),
@@ -81,6 +89,10 @@ impl GameDatabaseError {
         matches!(self, Self::Other { .. })
     }
 
+    pub fn is_transaction_failed(&self) -> bool {
+        matches!(self, Self::TransactionFailed { .. })
+    }
+
     pub fn is_unique_constraint_violation(&self) -> bool {
         matches!(self, Self::UniqueConstraintViolation { .. })
     }
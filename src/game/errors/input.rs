@@ -0,0 +1,154 @@
+use crate::enums::leaderboard_kind::LeaderboardKind;
+use crate::enums::leaderboard_window::LeaderboardWindow;
+use chrono::{DateTime, TimeDelta, Utc};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum GameInputError {
+    #[error("Forecasts can look at most {max_days} days ahead, got '{days}'")]
+    ForecastTooLong { days: u32, max_days: u32 },
+    #[error("Pond capacity '{capacity}' is invalid, it has to be at least 1")]
+    InvalidPondCapacity { capacity: i32 },
+    #[error("Weather summaries can cover at most {max_days} days, got '{days}'")]
+    SummaryTooLong { days: u32, max_days: u32 },
+    #[error("Timeline step '{step}' is invalid, it has to be positive")]
+    InvalidTimelineStep { step: TimeDelta },
+    #[error("Timelines can have at most {max_samples} samples, got '{samples}'")]
+    TimelineTooLong { samples: i64, max_samples: i64 },
+    #[error("The time '{time}' can't be converted between real and in-game time, the result is out of range")]
+    TimeOutOfRange { time: DateTime<Utc> },
+    #[error("The {kind:?} leaderboard can't be limited to the {window:?} window")]
+    UnsupportedLeaderboardWindow {
+        kind: LeaderboardKind,
+        window: LeaderboardWindow,
+    },
+}
+
+impl GameInputError {
+    pub fn forecast_too_long(days: u32, max_days: u32) -> Self {
+        Self::ForecastTooLong { days, max_days }
+    }
+
+    pub fn invalid_pond_capacity(capacity: i32) -> Self {
+        Self::InvalidPondCapacity { capacity }
+    }
+
+    pub fn summary_too_long(days: u32, max_days: u32) -> Self {
+        Self::SummaryTooLong { days, max_days }
+    }
+
+    pub fn invalid_timeline_step(step: TimeDelta) -> Self {
+        Self::InvalidTimelineStep { step }
+    }
+
+    pub fn timeline_too_long(samples: i64, max_samples: i64) -> Self {
+        Self::TimelineTooLong {
+            samples,
+            max_samples,
+        }
+    }
+
+    pub fn time_out_of_range(time: DateTime<Utc>) -> Self {
+        Self::TimeOutOfRange { time }
+    }
+
+    pub fn unsupported_leaderboard_window(
+        kind: LeaderboardKind,
+        window: LeaderboardWindow,
+    ) -> Self {
+        Self::UnsupportedLeaderboardWindow { kind, window }
+    }
+
+    pub fn is_forecast_too_long(&self) -> bool {
+        matches!(self, Self::ForecastTooLong { .. })
+    }
+
+    pub fn is_invalid_pond_capacity(&self) -> bool {
+        matches!(self, Self::InvalidPondCapacity { .. })
+    }
+
+    pub fn is_summary_too_long(&self) -> bool {
+        matches!(self, Self::SummaryTooLong { .. })
+    }
+
+    pub fn is_invalid_timeline_step(&self) -> bool {
+        matches!(self, Self::InvalidTimelineStep { .. })
+    }
+
+    pub fn is_timeline_too_long(&self) -> bool {
+        matches!(self, Self::TimelineTooLong { .. })
+    }
+
+    pub fn is_time_out_of_range(&self) -> bool {
+        matches!(self, Self::TimeOutOfRange { .. })
+    }
+
+    pub fn is_unsupported_leaderboard_window(&self) -> bool {
+        matches!(self, Self::UnsupportedLeaderboardWindow { .. })
+    }
+
+    pub fn get_capacity(&self) -> Option<i32> {
+        match self {
+            Self::InvalidPondCapacity { capacity } => Some(*capacity),
+            _ => None,
+        }
+    }
+
+    pub fn get_days(&self) -> Option<u32> {
+        match self {
+            Self::ForecastTooLong { days, .. } | Self::SummaryTooLong { days, .. } => Some(*days),
+            _ => None,
+        }
+    }
+
+    pub fn get_max_days(&self) -> Option<u32> {
+        match self {
+            Self::ForecastTooLong { max_days, .. } | Self::SummaryTooLong { max_days, .. } => {
+                Some(*max_days)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_step(&self) -> Option<TimeDelta> {
+        match self {
+            Self::InvalidTimelineStep { step } => Some(*step),
+            _ => None,
+        }
+    }
+
+    pub fn get_samples(&self) -> Option<i64> {
+        match self {
+            Self::TimelineTooLong { samples, .. } => Some(*samples),
+            _ => None,
+        }
+    }
+
+    pub fn get_max_samples(&self) -> Option<i64> {
+        match self {
+            Self::TimelineTooLong { max_samples, .. } => Some(*max_samples),
+            _ => None,
+        }
+    }
+
+    pub fn get_time(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::TimeOutOfRange { time } => Some(*time),
+            _ => None,
+        }
+    }
+
+    pub fn get_leaderboard_kind(&self) -> Option<LeaderboardKind> {
+        match self {
+            Self::UnsupportedLeaderboardWindow { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+
+    pub fn get_leaderboard_window(&self) -> Option<LeaderboardWindow> {
+        match self {
+            Self::UnsupportedLeaderboardWindow { window, .. } => Some(*window),
+            _ => None,
+        }
+    }
+}
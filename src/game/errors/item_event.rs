@@ -4,6 +4,8 @@ use thiserror::Error;
 pub enum GameItemEventError {
     #[error("Item with type_id '{type_id}' does not exist")]
     InvalidItemType { type_id: i32 },
+    #[error("Item with type_id '{type_id}' is not a bait")]
+    NotABait { type_id: i32 },
     #[error("Item with type_id '{type_id}' is not a rod")]
     NotARod { type_id: i32 },
 }
@@ -13,6 +15,10 @@ impl GameItemEventError {
         Self::InvalidItemType { type_id }
     }
 
+    pub fn not_a_bait(type_id: i32) -> Self {
+        Self::NotABait { type_id }
+    }
+
     pub fn not_a_rod(type_id: i32) -> Self {
         Self::NotARod { type_id }
     }
@@ -21,6 +27,10 @@ impl GameItemEventError {
         matches!(self, Self::InvalidItemType { .. })
     }
 
+    pub fn is_not_a_bait(&self) -> bool {
+        matches!(self, Self::NotABait { .. })
+    }
+
     pub fn is_not_a_rod(&self) -> bool {
         matches!(self, Self::NotARod { .. })
     }
@@ -28,8 +38,8 @@ impl GameItemEventError {
     pub fn get_type_id(&self) -> Option<i32> {
         match self {
             Self::InvalidItemType { type_id } => Some(*type_id),
+            Self::NotABait { type_id } => Some(*type_id),
             Self::NotARod { type_id } => Some(*type_id),
-            _ => None,
         }
     }
 }
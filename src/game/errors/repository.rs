@@ -7,6 +7,8 @@ use thiserror::Error;
 pub enum GameRepositoryError {
     #[error("Database error: {0}")]
     Database(#[from] GameDatabaseError),
+    #[error("{entity} with id {id} was modified since it was loaded")]
+    StaleEntity { entity: String, id: i64 },
     #[error("Unexpected error: {msg}")]
     Unexpected {
         msg: String,
@@ -20,6 +22,13 @@ impl GameRepositoryError {
         Self::Database(error)
     }
 
+    pub fn stale_entity(entity: &str, id: i64) -> Self {
+        Self::StaleEntity {
+            entity: entity.to_string(),
+            id,
+        }
+    }
+
     pub fn unexpected(error: Box<dyn std::error::Error>) -> Self {
         Self::Unexpected {
             msg: error.to_string(),
@@ -31,6 +40,19 @@ impl GameRepositoryError {
         matches!(self, Self::Database(_))
     }
 
+    /// The entity was saved by someone else in the meantime,
+    /// reload it and apply the changes again to retry
+    pub fn is_stale_entity(&self) -> bool {
+        matches!(self, Self::StaleEntity { .. })
+    }
+
+    pub fn get_entity_id(&self) -> Option<i64> {
+        match self {
+            Self::StaleEntity { id, .. } => Some(*id),
+            _ => None,
+        }
+    }
+
     pub fn get_database_error(&self) -> Option<&GameDatabaseError> {
         match self {
             Self::Database(database_error) => Some(database_error),
@@ -4,22 +4,50 @@ use thiserror::Error;
 pub enum GameResourceError {
     #[error("User with id '{user_id}' has no fishing history with species with id '{species_id}'")]
     FishingHistoryNotFound { user_id: i64, species_id: i32 },
+    #[error(
+        "User with external id '{external_id}' has '{credits}' credits, but '{cost}' are required"
+    )]
+    InsufficientCredits {
+        external_id: i64,
+        cost: i64,
+        credits: i64,
+    },
     #[error("User with external id '{external_id}' has reached the maximum amount of instances for item of type id '{item_type_id}'")]
     ItemMaxCountExceeded { item_type_id: i32, external_id: i64 },
     #[error("Item of type id '{item_type_id}' does not exist")]
     ItemNotFound { item_type_id: i32 },
+    #[error("Item of type id '{item_type_id}' can not be purchased")]
+    ItemNotPurchasable { item_type_id: i32 },
+    #[error("Item with id '{item_id}' is not owned by user with external id '{external_id}'")]
+    ItemNotOwned { item_id: i64, external_id: i64 },
     #[error("Item of type id '{item_type_id}' was unable to be stacked: {msg}")]
     ItemUnstackable { msg: String, item_type_id: i32 },
     #[error("User with external id '{external_id}' has already unlocked location with id '{location_id}'")]
     LocationAlreadyUnlocked { external_id: i64, location_id: i32 },
+    #[error(
+        "User with external id '{external_id}' has not unlocked location with id '{location_id}'"
+    )]
+    LocationLocked { external_id: i64, location_id: i32 },
     #[error("Location with id '{location_id}' does not exist")]
     LocationNotFound { location_id: i32 },
     #[error("No available encounters for the specified conditions")]
     NoAvailableEncounters,
     #[error("User with external id '{external_id}' has no fishing history with species with id '{species_id}'")]
     NoFishingHistory { external_id: i64, species_id: i32 },
+    #[error("Pond with id '{pond_id}' is full, it can only hold '{capacity}' specimens")]
+    PondFull { pond_id: i64, capacity: i32 },
+    #[error("Pond with id '{pond_id}' does not exist")]
+    PondNotFound { pond_id: i64 },
+    #[error("Pond with id '{pond_id}' is not owned by user with external id '{external_id}'")]
+    PondNotOwned { pond_id: i64, external_id: i64 },
     #[error("Species with id '{species_id}' does not exist")]
     SpeciesNotFound { species_id: i32 },
+    #[error("Specimen with id '{specimen_id}' does not exist")]
+    SpecimenNotFound { specimen_id: i64 },
+    #[error(
+        "Specimen with id '{specimen_id}' is not owned by user with external id '{external_id}'"
+    )]
+    SpecimenNotOwned { specimen_id: i64, external_id: i64 },
     #[error(
         "Unable to unlock location with id '{location_id}' because of unmet unlock requirements"
     )]
@@ -38,6 +66,14 @@ impl GameResourceError {
         }
     }
 
+    pub fn insufficient_credits(external_id: i64, cost: i64, credits: i64) -> Self {
+        Self::InsufficientCredits {
+            external_id,
+            cost,
+            credits,
+        }
+    }
+
     pub fn item_max_count_exceeded(item_type_id: i32, external_id: i64) -> Self {
         Self::ItemMaxCountExceeded {
             item_type_id,
@@ -49,6 +85,17 @@ impl GameResourceError {
         Self::ItemNotFound { item_type_id }
     }
 
+    pub fn item_not_purchasable(item_type_id: i32) -> Self {
+        Self::ItemNotPurchasable { item_type_id }
+    }
+
+    pub fn item_not_owned(item_id: i64, external_id: i64) -> Self {
+        Self::ItemNotOwned {
+            item_id,
+            external_id,
+        }
+    }
+
     pub fn item_unstackable(item_type_id: i32, message: &str) -> Self {
         Self::ItemUnstackable {
             item_type_id,
@@ -63,6 +110,13 @@ impl GameResourceError {
         }
     }
 
+    pub fn location_locked(external_id: i64, location_id: i32) -> Self {
+        Self::LocationLocked {
+            external_id,
+            location_id,
+        }
+    }
+
     pub fn location_not_found(location_id: i32) -> Self {
         Self::LocationNotFound { location_id }
     }
@@ -78,10 +132,36 @@ impl GameResourceError {
         }
     }
 
+    pub fn pond_full(pond_id: i64, capacity: i32) -> Self {
+        Self::PondFull { pond_id, capacity }
+    }
+
+    pub fn pond_not_found(pond_id: i64) -> Self {
+        Self::PondNotFound { pond_id }
+    }
+
+    pub fn pond_not_owned(pond_id: i64, external_id: i64) -> Self {
+        Self::PondNotOwned {
+            pond_id,
+            external_id,
+        }
+    }
+
     pub fn species_not_found(species_id: i32) -> Self {
         Self::SpeciesNotFound { species_id }
     }
 
+    pub fn specimen_not_found(specimen_id: i64) -> Self {
+        Self::SpecimenNotFound { specimen_id }
+    }
+
+    pub fn specimen_not_owned(specimen_id: i64, external_id: i64) -> Self {
+        Self::SpecimenNotOwned {
+            specimen_id,
+            external_id,
+        }
+    }
+
     pub fn unmet_location_unlock_requirements(location_id: i32) -> Self {
         Self::UnmetLocationUnlockRequirements { location_id }
     }
@@ -98,6 +178,10 @@ impl GameResourceError {
         matches!(self, Self::FishingHistoryNotFound { .. })
     }
 
+    pub fn is_insufficient_credits(&self) -> bool {
+        matches!(self, Self::InsufficientCredits { .. })
+    }
+
     pub fn is_item_max_count_exceeded(&self) -> bool {
         matches!(self, Self::ItemMaxCountExceeded { .. })
     }
@@ -106,6 +190,14 @@ impl GameResourceError {
         matches!(self, Self::ItemNotFound { .. })
     }
 
+    pub fn is_item_not_purchasable(&self) -> bool {
+        matches!(self, Self::ItemNotPurchasable { .. })
+    }
+
+    pub fn is_item_not_owned(&self) -> bool {
+        matches!(self, Self::ItemNotOwned { .. })
+    }
+
     pub fn is_item_unstackable(&self) -> bool {
         matches!(self, Self::ItemUnstackable { .. })
     }
@@ -114,6 +206,10 @@ impl GameResourceError {
         matches!(self, Self::LocationAlreadyUnlocked { .. })
     }
 
+    pub fn is_location_locked(&self) -> bool {
+        matches!(self, Self::LocationLocked { .. })
+    }
+
     pub fn is_location_not_found(&self) -> bool {
         matches!(self, Self::LocationNotFound { .. })
     }
@@ -126,10 +222,30 @@ impl GameResourceError {
         matches!(self, Self::NoFishingHistory { .. })
     }
 
+    pub fn is_pond_full(&self) -> bool {
+        matches!(self, Self::PondFull { .. })
+    }
+
+    pub fn is_pond_not_found(&self) -> bool {
+        matches!(self, Self::PondNotFound { .. })
+    }
+
+    pub fn is_pond_not_owned(&self) -> bool {
+        matches!(self, Self::PondNotOwned { .. })
+    }
+
     pub fn is_species_not_found(&self) -> bool {
         matches!(self, Self::SpeciesNotFound { .. })
     }
 
+    pub fn is_specimen_not_found(&self) -> bool {
+        matches!(self, Self::SpecimenNotFound { .. })
+    }
+
+    pub fn is_specimen_not_owned(&self) -> bool {
+        matches!(self, Self::SpecimenNotOwned { .. })
+    }
+
     pub fn is_unmet_location_unlock_requirements(&self) -> bool {
         matches!(self, Self::UnmetLocationUnlockRequirements { .. })
     }
@@ -142,21 +258,55 @@ impl GameResourceError {
         matches!(self, Self::UserNotFound { .. })
     }
 
+    pub fn get_cost(&self) -> Option<i64> {
+        match self {
+            Self::InsufficientCredits { cost, .. } => Some(*cost),
+            _ => None,
+        }
+    }
+
+    pub fn get_capacity(&self) -> Option<i32> {
+        match self {
+            Self::PondFull { capacity, .. } => Some(*capacity),
+            _ => None,
+        }
+    }
+
+    pub fn get_credits(&self) -> Option<i64> {
+        match self {
+            Self::InsufficientCredits { credits, .. } => Some(*credits),
+            _ => None,
+        }
+    }
+
     pub fn get_external_id(&self) -> Option<i64> {
         match self {
+            Self::InsufficientCredits { external_id, .. } => Some(*external_id),
             Self::ItemMaxCountExceeded { external_id, .. } => Some(*external_id),
+            Self::ItemNotOwned { external_id, .. } => Some(*external_id),
             Self::LocationAlreadyUnlocked { external_id, .. } => Some(*external_id),
+            Self::LocationLocked { external_id, .. } => Some(*external_id),
             Self::NoFishingHistory { external_id, .. } => Some(*external_id),
+            Self::PondNotOwned { external_id, .. } => Some(*external_id),
+            Self::SpecimenNotOwned { external_id, .. } => Some(*external_id),
             Self::UserAlreadyExists { external_id } => Some(*external_id),
             Self::UserNotFound { external_id } => Some(*external_id),
             _ => None,
         }
     }
 
+    pub fn get_item_id(&self) -> Option<i64> {
+        match self {
+            Self::ItemNotOwned { item_id, .. } => Some(*item_id),
+            _ => None,
+        }
+    }
+
     pub fn get_item_type_id(&self) -> Option<i32> {
         match self {
             Self::ItemMaxCountExceeded { item_type_id, .. } => Some(*item_type_id),
             Self::ItemNotFound { item_type_id, .. } => Some(*item_type_id),
+            Self::ItemNotPurchasable { item_type_id } => Some(*item_type_id),
             Self::ItemUnstackable { item_type_id, .. } => Some(*item_type_id),
             _ => None,
         }
@@ -165,6 +315,7 @@ impl GameResourceError {
     pub fn get_location_id(&self) -> Option<i32> {
         match self {
             Self::LocationAlreadyUnlocked { location_id, .. } => Some(*location_id),
+            Self::LocationLocked { location_id, .. } => Some(*location_id),
             Self::LocationNotFound { location_id } => Some(*location_id),
             Self::UnmetLocationUnlockRequirements { location_id, .. } => Some(*location_id),
             _ => None,
@@ -178,6 +329,15 @@ impl GameResourceError {
         }
     }
 
+    pub fn get_pond_id(&self) -> Option<i64> {
+        match self {
+            Self::PondFull { pond_id, .. } => Some(*pond_id),
+            Self::PondNotFound { pond_id } => Some(*pond_id),
+            Self::PondNotOwned { pond_id, .. } => Some(*pond_id),
+            _ => None,
+        }
+    }
+
     pub fn get_species_id(&self) -> Option<i32> {
         match self {
             Self::FishingHistoryNotFound { species_id, .. } => Some(*species_id),
@@ -187,6 +347,14 @@ impl GameResourceError {
         }
     }
 
+    pub fn get_specimen_id(&self) -> Option<i64> {
+        match self {
+            Self::SpecimenNotFound { specimen_id } => Some(*specimen_id),
+            Self::SpecimenNotOwned { specimen_id, .. } => Some(*specimen_id),
+            _ => None,
+        }
+    }
+
     pub fn get_user_id(&self) -> Option<i64> {
         match self {
             Self::FishingHistoryNotFound { user_id, .. } => Some(*user_id),
@@ -0,0 +1,63 @@
+use crate::database::after_commit;
+use crate::game::event_bus::game_event::GameEvent;
+use std::sync::{Arc, RwLock};
+
+pub mod game_event;
+
+/// Receives every [`GameEvent`] emitted by the services
+///
+/// Subscribers are called synchronously on the thread which caused the event,
+/// so they should hand long-running work off to somewhere else.
+pub trait GameEventSubscriber: Send + Sync {
+    fn on_event(&self, event: &GameEvent);
+}
+
+impl<F> GameEventSubscriber for F
+where
+    F: Fn(&GameEvent) + Send + Sync,
+{
+    fn on_event(&self, event: &GameEvent) {
+        self(event)
+    }
+}
+
+pub trait EventBusInterface: Send + Sync {
+    fn subscribe(&self, subscriber: Arc<dyn GameEventSubscriber>);
+    /// Delivers the event to all subscribers once the current transaction is committed,
+    /// or right away if there is none. Events of rolled back transactions are discarded.
+    fn emit(&self, event: GameEvent);
+}
+
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Arc<RwLock<Vec<Arc<dyn GameEventSubscriber>>>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl EventBusInterface for EventBus {
+    fn subscribe(&self, subscriber: Arc<dyn GameEventSubscriber>) {
+        self.subscribers
+            .write()
+            .expect("Failed to get write lock on event subscribers")
+            .push(subscriber);
+    }
+
+    fn emit(&self, event: GameEvent) {
+        let subscribers = self.subscribers.clone();
+        after_commit(move || {
+            // Released before delivery, so subscribers are able to subscribe themselves
+            let subscribers = subscribers
+                .read()
+                .expect("Failed to get read lock on event subscribers")
+                .clone();
+            for subscriber in subscribers {
+                subscriber.on_event(&event);
+            }
+        });
+    }
+}
@@ -0,0 +1,54 @@
+use crate::enums::record_kind::RecordKind;
+use crate::models::item::Item;
+use crate::models::specimen::Specimen;
+use crate::models::user::User;
+use crate::models::user_location::UserLocation;
+use chrono::{DateTime, Utc};
+
+/// Something that happened in the game, emitted by the services once it was persisted
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    UserRegistered {
+        user: User,
+    },
+    SpecimenCaught {
+        specimen: Specimen,
+    },
+    /// The user caught a specimen of a species for the first time
+    SpeciesFirstCaught {
+        user_id: i64,
+        species_id: i32,
+    },
+    /// A caught specimen broke the personal record of the user for its species,
+    /// not emitted for the first catch of a species
+    RecordBroken {
+        user_id: i64,
+        species_id: i32,
+        specimen_id: i64,
+        kind: RecordKind,
+    },
+    SpecimenSold {
+        specimen: Specimen,
+        sell_time: DateTime<Utc>,
+    },
+    LocationUnlocked {
+        location: UserLocation,
+    },
+    AchievementUnlocked {
+        user_id: i64,
+        achievement_id: i32,
+        unlocked_at: DateTime<Utc>,
+    },
+    /// An item was added to an inventory, either as a new item or by stacking onto an existing one
+    ItemReceived {
+        item: Item,
+    },
+    /// An item was changed by an item event, e.g. a rod losing durability
+    ItemChanged {
+        item: Item,
+    },
+    /// An item was used up by an item event and removed from the inventory
+    ItemConsumed {
+        item: Item,
+    },
+}
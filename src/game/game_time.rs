@@ -0,0 +1,124 @@
+use crate::data::settings::Settings;
+use crate::game::clock::Clock;
+use crate::game::errors::input::GameInputError;
+use crate::game::errors::GameResult;
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use std::sync::Arc;
+
+/// # Game Time
+/// Converts real time into in-game time.
+///
+/// In-game time starts at the same moment as real time at the configured epoch
+/// and runs `speed` times as fast from there on:
+/// `game_time = epoch + (real_time - epoch) * speed`.
+///
+/// Weather, light level, encounter hours, seasons and the aging of specimens are all based on in-game time.
+/// Timestamps which are stored in the database (e.g. when a specimen was caught) stay in real time.
+pub struct GameTime {
+    clock: Arc<dyn Clock>,
+    epoch: DateTime<Utc>,
+    speed: f64,
+}
+
+impl GameTime {
+    pub fn new(clock: Arc<dyn Clock>, epoch: DateTime<Utc>, speed: f64) -> Self {
+        Self {
+            clock,
+            epoch,
+            speed,
+        }
+    }
+
+    /// Uses the `time_epoch` and `time_speed_multiplier` of the given settings
+    pub fn from_settings(clock: Arc<dyn Clock>, settings: &Settings) -> Self {
+        Self::new(
+            clock,
+            settings.time_epoch,
+            settings.time_speed_multiplier as f64,
+        )
+    }
+
+    pub fn get_clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone()
+    }
+
+    pub fn get_epoch(&self) -> DateTime<Utc> {
+        self.epoch
+    }
+
+    pub fn get_speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// The current real time of the clock
+    pub fn real_now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    /// The current in-game time
+    pub fn now(&self) -> GameResult<DateTime<Utc>> {
+        self.to_game_time(self.real_now())
+    }
+
+    /// The current in-game time in the given timezone
+    pub fn now_in<T: TimeZone>(&self, timezone: &T) -> GameResult<DateTime<T>> {
+        Ok(self.now()?.with_timezone(timezone))
+    }
+
+    /// Converts a real time into the in-game time at that moment,
+    /// an error if the in-game time is out of the supported date range
+    pub fn to_game_time<T: TimeZone>(&self, real_time: DateTime<T>) -> GameResult<DateTime<T>> {
+        let timezone = real_time.timezone();
+        let real_time = real_time.with_timezone(&Utc);
+        let since_epoch = real_time - self.epoch;
+        let game_time = self
+            .epoch
+            .checked_add_signed(self.to_game_duration(since_epoch))
+            .ok_or_else(|| GameInputError::time_out_of_range(real_time))?;
+        Ok(game_time.with_timezone(&timezone))
+    }
+
+    /// Converts an in-game time into the real time at which it occurs,
+    /// an error if the real time is out of the supported date range
+    pub fn to_real_time<T: TimeZone>(&self, game_time: DateTime<T>) -> GameResult<DateTime<T>> {
+        let timezone = game_time.timezone();
+        let game_time = game_time.with_timezone(&Utc);
+        let since_epoch = game_time - self.epoch;
+        let real_time = self
+            .epoch
+            .checked_add_signed(self.to_real_duration(since_epoch))
+            .ok_or_else(|| GameInputError::time_out_of_range(game_time))?;
+        Ok(real_time.with_timezone(&timezone))
+    }
+
+    /// How much in-game time passes during the given real duration
+    pub fn to_game_duration(&self, real_duration: TimeDelta) -> TimeDelta {
+        scale_duration(real_duration, self.speed)
+    }
+
+    /// How much real time passes during the given in-game duration
+    pub fn to_real_duration(&self, game_duration: TimeDelta) -> TimeDelta {
+        scale_duration(game_duration, 1.0 / self.speed)
+    }
+
+    /// How much in-game time passed since the given real time
+    pub fn elapsed_since(&self, real_time: DateTime<Utc>) -> TimeDelta {
+        self.to_game_duration(self.real_now() - real_time)
+    }
+}
+
+fn scale_duration(duration: TimeDelta, factor: f64) -> TimeDelta {
+    // Seconds and nanoseconds are scaled separately to keep nanosecond precision
+    let seconds = duration.num_seconds() as f64 * factor;
+    let whole_seconds = seconds.trunc();
+    let nanoseconds =
+        ((seconds - whole_seconds) * 1e9 + duration.subsec_nanos() as f64 * factor).round();
+
+    TimeDelta::try_seconds(whole_seconds as i64)
+        .and_then(|delta| delta.checked_add(&TimeDelta::nanoseconds(nanoseconds as i64)))
+        .unwrap_or(if seconds < 0.0 {
+            TimeDelta::MIN
+        } else {
+            TimeDelta::MAX
+        })
+}
@@ -1,14 +1,30 @@
 use crate::data::item_data::ItemData;
 use crate::data::location_data::LocationData;
 use crate::data::species_data::SpeciesData;
+use crate::dto::achievement_unlock::AchievementUnlock;
+use crate::dto::daily_weather_summary::DailyWeatherSummary;
+use crate::dto::fishdex::Fishdex;
+use crate::dto::fishing_result::FishingResult;
+use crate::dto::forecast_window::ForecastWindow;
 use crate::dto::inventory::Inventory;
+use crate::dto::leaderboard::{Leaderboard, RecordLeaderboardEntry};
+use crate::dto::location_unlock_result::LocationUnlockResult;
+use crate::dto::personal_best::PersonalBest;
+use crate::dto::purchase_result::PurchaseResult;
+use crate::dto::sell_result::SellResult;
 use crate::dto::user_location_unlock::UserLocationUnlock;
+use crate::enums::leaderboard_kind::LeaderboardKind;
+use crate::enums::leaderboard_window::LeaderboardWindow;
 use crate::game::errors::GameResult;
+use crate::game::systems::encounter_system::{EncounterEquipment, EncounterProbability};
 use crate::game::systems::weather_system::weather::Weather;
 use crate::models::fishing_history_entry::FishingHistoryEntry;
 use crate::models::item::Item;
+use crate::models::pond::Pond;
 use crate::models::specimen::Specimen;
 use crate::models::user::User;
+use chrono::{DateTime, NaiveDate, TimeDelta};
+use chrono_tz::Tz;
 use std::sync::Arc;
 
 /// # Game Interface
@@ -19,9 +35,57 @@ use std::sync::Arc;
 /// accidental breaking changes.
 pub trait GameInterface: Send + Sync {
     fn item_find(&self, item_id: i32) -> GameResult<Arc<ItemData>>;
+    fn leaderboard_get(
+        &self,
+        kind: LeaderboardKind,
+        window: LeaderboardWindow,
+        page: u32,
+        page_size: u32,
+    ) -> GameResult<Leaderboard>;
+    fn location_encounter_probabilities(
+        &self,
+        location: Arc<LocationData>,
+        weather: &Weather,
+        equipment: Option<EncounterEquipment>,
+    ) -> Vec<EncounterProbability>;
     fn location_find(&self, location_id: i32) -> GameResult<Arc<LocationData>>;
+    fn location_forecast(
+        &self,
+        location: Arc<LocationData>,
+        days: u32,
+        equipment: Option<EncounterEquipment>,
+    ) -> GameResult<Vec<ForecastWindow>>;
     fn location_weather_current(&self, location: Arc<LocationData>) -> GameResult<Weather>;
+    fn location_weather_daily_summaries(
+        &self,
+        location: Arc<LocationData>,
+        start_date: NaiveDate,
+        days: u32,
+    ) -> GameResult<Vec<DailyWeatherSummary>>;
+    fn location_weather_timeline(
+        &self,
+        location: Arc<LocationData>,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        step: Option<TimeDelta>,
+    ) -> GameResult<Vec<Weather>>;
+    fn pond_get_specimens(&self, pond: &Pond) -> GameResult<Vec<Specimen>>;
+    fn shop_get_items(&self) -> Vec<Arc<ItemData>>;
+    fn species_forecast(
+        &self,
+        species: Arc<SpeciesData>,
+        days: u32,
+        equipment: Option<EncounterEquipment>,
+    ) -> GameResult<Vec<ForecastWindow>>;
+    fn species_leaderboard(
+        &self,
+        species: Arc<SpeciesData>,
+        window: LeaderboardWindow,
+        page: u32,
+        page_size: u32,
+    ) -> GameResult<Leaderboard<RecordLeaderboardEntry>>;
     fn species_find(&self, species_id: i32) -> GameResult<Arc<SpeciesData>>;
+    fn specimen_sell_price(&self, specimen: &Specimen) -> GameResult<i64>;
     fn user_catch_specific_specimen(
         &self,
         user: &User,
@@ -32,15 +96,56 @@ pub trait GameInterface: Send + Sync {
         user: &User,
         species: Arc<SpeciesData>,
     ) -> GameResult<FishingHistoryEntry>;
+    fn user_get_personal_bests(&self, user: &User) -> GameResult<Vec<PersonalBest>>;
+    fn user_get_fishdex(&self, user: &User, hide_uncaught_names: bool) -> GameResult<Fishdex>;
+    fn user_get_achievements(&self, user: &User) -> GameResult<Vec<AchievementUnlock>>;
     fn user_find(&self, external_id: i64) -> GameResult<User>;
+    fn user_fish(
+        &self,
+        user: &User,
+        location: Arc<LocationData>,
+        rod: Option<Item>,
+        bait: Option<Item>,
+    ) -> GameResult<FishingResult>;
+    fn user_fish_with_seed(
+        &self,
+        user: &User,
+        location: Arc<LocationData>,
+        rod: Option<Item>,
+        bait: Option<Item>,
+        seed: u64,
+    ) -> GameResult<FishingResult>;
     fn user_get_unlocked_locations(&self, user: &User) -> GameResult<Vec<UserLocationUnlock>>;
     fn user_inventory(&self, user: &User) -> GameResult<Inventory>;
     fn user_item_give(&self, user: &User, item: Arc<ItemData>, count: u64) -> GameResult<Item>;
+    fn user_item_purchase(
+        &self,
+        user: &User,
+        item_data: Arc<ItemData>,
+        count: u64,
+    ) -> GameResult<PurchaseResult>;
+    fn user_get_ponds(&self, user: &User) -> GameResult<Vec<Pond>>;
+    fn user_pond_create(&self, user: &User, capacity: i32) -> GameResult<Pond>;
+    fn user_pond_add_specimen(
+        &self,
+        user: &User,
+        pond: &Pond,
+        specimen: Specimen,
+    ) -> GameResult<Specimen>;
+    fn user_pond_remove_specimen(&self, user: &User, specimen: Specimen) -> GameResult<Specimen>;
     fn user_register(&self, external_id: i64) -> GameResult<User>;
     fn user_save(&self, user: User) -> GameResult<User>;
+    fn user_save_checked(&self, user: User) -> GameResult<User>;
+    fn user_sell_specimen(&self, user: &User, specimen: Specimen) -> GameResult<SellResult>;
+    fn user_sell_species(&self, user: &User, species: Arc<SpeciesData>) -> GameResult<SellResult>;
+    fn user_sell_below_size_ratio(
+        &self,
+        user: &User,
+        max_size_ratio: f32,
+    ) -> GameResult<SellResult>;
     fn user_unlock_location(
         &self,
         user: &User,
         location: Arc<LocationData>,
-    ) -> GameResult<UserLocationUnlock>;
+    ) -> GameResult<LocationUnlockResult>;
 }
@@ -0,0 +1,52 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::Mutex;
+
+/// # Random Source
+/// Source of the seeds for everything that is left to chance, e.g. encounters and generated specimens.
+///
+/// The [`crate::game::service_provider::ServiceProvider`] owns the random source.
+/// Every catch draws one seed from it, all random values of the catch are derived from that seed
+/// and the seed is recorded on the caught specimen, so the catch can be reproduced later on.
+pub trait RandomSource: Send + Sync {
+    fn next_seed(&self) -> u64;
+}
+
+/// Draws seeds from the thread RNG, used by default
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThreadRandomSource;
+
+impl ThreadRandomSource {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl RandomSource for ThreadRandomSource {
+    fn next_seed(&self) -> u64 {
+        rand::random()
+    }
+}
+
+/// Draws seeds from an RNG with a fixed seed, so the same sequence of seeds is drawn on every run
+#[derive(Debug)]
+pub struct SeededRandomSource {
+    rng: Mutex<StdRng>,
+}
+
+impl SeededRandomSource {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+}
+
+impl RandomSource for SeededRandomSource {
+    fn next_seed(&self) -> u64 {
+        self.rng
+            .lock()
+            .expect("Failed to get lock on random source")
+            .random()
+    }
+}
@@ -1,5 +1,11 @@
+pub mod catch_event_repository;
 pub mod fishing_history_entry_repository;
 pub mod item_repository;
+#[cfg(feature = "in-memory")]
+pub mod memory;
 pub mod pond_repository;
+pub mod sale_event_repository;
 pub mod specimen_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod user_repository;
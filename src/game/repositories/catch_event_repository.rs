@@ -0,0 +1,211 @@
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::models::catch_event::{CatchEvent, NewCatchEvent};
+use crate::schema::{fish_catch_events, fish_users};
+use crate::traits::repository::Repository;
+use chrono::{DateTime, Utc};
+use diesel::dsl::{count, max};
+use diesel::prelude::*;
+use std::sync::{Arc, RwLock};
+
+pub trait CatchEventRepositoryInterface: Repository<CatchEvent> + Send + Sync {
+    /// Users ranked by how many specimens they caught since the given time
+    fn rank_users_by_catches_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError>;
+
+    /// Users ranked by how many distinct species they caught since the given time
+    fn rank_users_by_species_caught_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError>;
+
+    /// The largest catch of a species of each user since the given time, ranked by size,
+    /// with the external ID of their user
+    fn rank_by_largest_catch_since(
+        &self,
+        species_id: i32,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard<(CatchEvent, i64)>, GameRepositoryError>;
+}
+
+/// Pairs the ranked rows of (user ID, external ID) with the largest catch of their user,
+/// the catches have to be ordered by size descending
+pub(crate) fn largest_catch_per_row(
+    rows: Vec<(i64, i64)>,
+    catches: Vec<CatchEvent>,
+) -> Vec<(CatchEvent, i64)> {
+    rows.into_iter()
+        .filter_map(|(user_id, external_id)| {
+            let catch = catches.iter().find(|catch| catch.user_id == user_id)?;
+            Some((catch.clone(), external_id))
+        })
+        .collect()
+}
+
+pub struct CatchEventRepository {
+    db: Arc<RwLock<dyn DatabaseInterface>>,
+}
+
+impl CatchEventRepository {
+    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>) -> Self {
+        Self { db }
+    }
+}
+
+impl CatchEventRepositoryInterface for CatchEventRepository {
+    fn rank_users_by_catches_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_catch_events::table
+            .filter(fish_catch_events::caught_at.ge(since))
+            .select(count(fish_catch_events::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let catch_count = count(fish_catch_events::id);
+        let rows = fish_catch_events::table
+            .inner_join(fish_users::table)
+            .filter(fish_catch_events::caught_at.ge(since))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, catch_count))
+            .order((catch_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_users_by_species_caught_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_catch_events::table
+            .filter(fish_catch_events::caught_at.ge(since))
+            .select(count(fish_catch_events::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let species_count = || count(fish_catch_events::species_id).aggregate_distinct();
+        let rows = fish_catch_events::table
+            .inner_join(fish_users::table)
+            .filter(fish_catch_events::caught_at.ge(since))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, species_count()))
+            .order((species_count().desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_by_largest_catch_since(
+        &self,
+        species_id: i32,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard<(CatchEvent, i64)>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_catch_events::table
+            .filter(fish_catch_events::species_id.eq(species_id))
+            .filter(fish_catch_events::caught_at.ge(since))
+            .select(count(fish_catch_events::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let largest_size_ratio = max(fish_catch_events::total_size_ratio);
+        let rows = fish_catch_events::table
+            .inner_join(fish_users::table)
+            .filter(fish_catch_events::species_id.eq(species_id))
+            .filter(fish_catch_events::caught_at.ge(since))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id))
+            .order((largest_size_ratio.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64)>(&mut *connection)?;
+
+        // Of equally large catches, the earliest one holds the record
+        let user_ids: Vec<i64> = rows.iter().map(|(user_id, _)| *user_id).collect();
+        let catches = fish_catch_events::table
+            .filter(fish_catch_events::species_id.eq(species_id))
+            .filter(fish_catch_events::caught_at.ge(since))
+            .filter(fish_catch_events::user_id.eq_any(user_ids))
+            .order((
+                fish_catch_events::total_size_ratio.desc(),
+                fish_catch_events::caught_at.asc(),
+                fish_catch_events::id.asc(),
+            ))
+            .load::<CatchEvent>(&mut *connection)?;
+
+        Ok(Leaderboard {
+            total_entries: total_entries.max(0) as u64,
+            entries: largest_catch_per_row(rows, catches),
+        })
+    }
+}
+
+impl Repository<CatchEvent> for CatchEventRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewCatchEvent) -> Result<CatchEvent, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let catch_event = diesel::insert_into(fish_catch_events::table)
+            .values(new_entity)
+            .get_result::<CatchEvent>(&mut *connection)?;
+
+        Ok(catch_event)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<CatchEvent>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+        let catch_event = fish_catch_events::table
+            .find(id)
+            .first::<CatchEvent>(&mut *connection)
+            .optional()?;
+        Ok(catch_event)
+    }
+
+    fn update(
+        &self,
+        entity: CatchEvent,
+        _check_stale: bool,
+    ) -> Result<CatchEvent, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let updated_catch_event = diesel::update(fish_catch_events::table)
+            .filter(fish_catch_events::id.eq(entity.id))
+            .set(entity)
+            .get_result::<CatchEvent>(&mut *connection)
+            .optional()?
+            .ok_or_else(GameDatabaseError::not_found)?;
+
+        Ok(updated_catch_event)
+    }
+
+    fn delete(&self, entity: CatchEvent) -> Result<bool, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let deleted_count = diesel::delete(fish_catch_events::table)
+            .filter(fish_catch_events::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
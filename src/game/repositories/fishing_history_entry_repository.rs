@@ -1,15 +1,20 @@
 use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::clock::Clock;
 use crate::game::errors::repository::GameRepositoryError;
 use crate::models::fishing_history_entry::{FishingHistoryEntry, NewFishingHistoryEntry};
-use crate::schema::fish_fishing_history_entries;
+use crate::schema::{fish_fishing_history_entries, fish_users};
 use crate::traits::repository::Repository;
-use chrono::Utc;
+use diesel::dsl::{count, sum};
 use diesel::prelude::*;
+use diesel::sql_types::Bool;
 use std::sync::{Arc, RwLock};
 
 pub trait FishingHistoryEntryRepositoryInterface:
     Repository<FishingHistoryEntry> + Send + Sync
 {
+    /// The same repository, persisting the timestamps of the given clock
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn FishingHistoryEntryRepositoryInterface>;
     fn find_by_user_and_species_id(
         &self,
         user_id: i64,
@@ -20,19 +25,52 @@ pub trait FishingHistoryEntryRepositoryInterface:
         &self,
         user_id: i64,
     ) -> Result<Vec<i32>, GameRepositoryError>;
+
+    /// All fishing history entries of a user, ordered by species ID
+    fn find_by_user(&self, user_id: i64) -> Result<Vec<FishingHistoryEntry>, GameRepositoryError>;
+
+    /// Users ranked by how many specimens they caught in total
+    fn rank_users_by_caught_count(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError>;
+
+    /// Users ranked by how many distinct species they caught in total
+    fn rank_users_by_caught_species(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError>;
+
+    /// Users ranked by how many specimens they sold in total, users who never sold anything aren't ranked
+    fn rank_users_by_sold_count(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError>;
+
+    /// Entries of a species ranked by their largest catch, with the external ID of their user
+    fn rank_by_largest_catch(
+        &self,
+        species_id: i32,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard<(FishingHistoryEntry, i64)>, GameRepositoryError>;
 }
 
 pub struct FishingHistoryEntryRepository {
     db: Arc<RwLock<dyn DatabaseInterface>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl FishingHistoryEntryRepository {
-    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
     }
 }
 
 impl FishingHistoryEntryRepositoryInterface for FishingHistoryEntryRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn FishingHistoryEntryRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
     fn find_by_user_and_species_id(
         &self,
         user_id: i64,
@@ -45,7 +83,7 @@ impl FishingHistoryEntryRepositoryInterface for FishingHistoryEntryRepository {
                     .eq(user_id)
                     .and(fish_fishing_history_entries::species_id.eq(species_id)),
             )
-            .first::<FishingHistoryEntry>(&mut connection)
+            .first::<FishingHistoryEntry>(&mut *connection)
             .optional()?;
         Ok(entry)
     }
@@ -59,10 +97,130 @@ impl FishingHistoryEntryRepositoryInterface for FishingHistoryEntryRepository {
         let species_ids = fish_fishing_history_entries::table
             .filter(fish_fishing_history_entries::user_id.eq(user_id))
             .select(fish_fishing_history_entries::species_id)
-            .load::<i32>(&mut connection)?;
+            .load::<i32>(&mut *connection)?;
 
         Ok(species_ids)
     }
+
+    fn find_by_user(&self, user_id: i64) -> Result<Vec<FishingHistoryEntry>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let entries = fish_fishing_history_entries::table
+            .filter(fish_fishing_history_entries::user_id.eq(user_id))
+            .order(fish_fishing_history_entries::species_id.asc())
+            .load::<FishingHistoryEntry>(&mut *connection)?;
+
+        Ok(entries)
+    }
+
+    fn rank_users_by_caught_count(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_fishing_history_entries::table
+            .select(count(fish_fishing_history_entries::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let caught_count = sum(fish_fishing_history_entries::caught_count);
+        let rows = fish_fishing_history_entries::table
+            .inner_join(fish_users::table)
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, caught_count))
+            .order((caught_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, Option<i64>)>(&mut *connection)?
+            .into_iter()
+            .map(|(id, external_id, count)| (id, external_id, count.unwrap_or_default()))
+            .collect();
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_users_by_caught_species(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_fishing_history_entries::table
+            .select(count(fish_fishing_history_entries::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let species_count = count(fish_fishing_history_entries::species_id);
+        let rows = fish_fishing_history_entries::table
+            .inner_join(fish_users::table)
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, species_count))
+            .order((species_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_users_by_sold_count(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_fishing_history_entries::table
+            .filter(fish_fishing_history_entries::sold_count.gt(0))
+            .select(count(fish_fishing_history_entries::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let sold_count = sum(fish_fishing_history_entries::sold_count);
+        let rows = fish_fishing_history_entries::table
+            .inner_join(fish_users::table)
+            .filter(fish_fishing_history_entries::sold_count.gt(0))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, sold_count))
+            .order((sold_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, Option<i64>)>(&mut *connection)?
+            .into_iter()
+            .map(|(id, external_id, count)| (id, external_id, count.unwrap_or_default()))
+            .collect();
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_by_largest_catch(
+        &self,
+        species_id: i32,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard<(FishingHistoryEntry, i64)>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_fishing_history_entries::table
+            .filter(fish_fishing_history_entries::species_id.eq(species_id))
+            .count()
+            .get_result::<i64>(&mut *connection)?;
+        let entries = fish_fishing_history_entries::table
+            .inner_join(fish_users::table)
+            .filter(fish_fishing_history_entries::species_id.eq(species_id))
+            .select((
+                fish_fishing_history_entries::all_columns,
+                fish_users::external_id,
+            ))
+            .order((
+                fish_fishing_history_entries::largest_catch_size_ratio.desc(),
+                fish_fishing_history_entries::user_id.asc(),
+            ))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(FishingHistoryEntry, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard {
+            total_entries: total_entries.max(0) as u64,
+            entries,
+        })
+    }
 }
 
 impl Repository<FishingHistoryEntry> for FishingHistoryEntryRepository {
@@ -75,10 +233,16 @@ impl Repository<FishingHistoryEntry> for FishingHistoryEntryRepository {
         new_entity: NewFishingHistoryEntry,
     ) -> Result<FishingHistoryEntry, GameRepositoryError> {
         let mut connection = self.get_connection()?;
+        let now = self.clock.now();
 
         let new_result = diesel::insert_into(fish_fishing_history_entries::table)
-            .values(new_entity)
-            .get_result::<FishingHistoryEntry>(&mut connection)?;
+            .values((
+                new_entity,
+                fish_fishing_history_entries::created_at.eq(now),
+                fish_fishing_history_entries::updated_at.eq(now),
+                fish_fishing_history_entries::last_catch.eq(now),
+            ))
+            .get_result::<FishingHistoryEntry>(&mut *connection)?;
 
         Ok(new_result)
     }
@@ -87,24 +251,38 @@ impl Repository<FishingHistoryEntry> for FishingHistoryEntryRepository {
         let mut connection = self.get_connection()?;
         let result = fish_fishing_history_entries::table
             .find(id)
-            .first::<FishingHistoryEntry>(&mut connection)
+            .first::<FishingHistoryEntry>(&mut *connection)
             .optional()?;
         Ok(result)
     }
 
-    fn save(
+    fn update(
         &self,
         mut entity: FishingHistoryEntry,
+        check_stale: bool,
     ) -> Result<FishingHistoryEntry, GameRepositoryError> {
         let mut connection = self.get_connection()?;
-        entity.updated_at = Utc::now();
+        let id = entity.id;
+        let loaded_version = entity.version;
+        entity.updated_at = self.clock.now();
 
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
         let update_result = diesel::update(fish_fishing_history_entries::table)
-            .filter(fish_fishing_history_entries::id.eq(entity.id))
-            .set(entity)
-            .get_result::<FishingHistoryEntry>(&mut connection)?;
+            .filter(fish_fishing_history_entries::id.eq(id))
+            .filter(
+                fish_fishing_history_entries::version
+                    .eq(loaded_version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((
+                entity,
+                fish_fishing_history_entries::version.eq(fish_fishing_history_entries::version + 1),
+            ))
+            .get_result::<FishingHistoryEntry>(&mut *connection)
+            .optional()?;
+        drop(connection);
 
-        Ok(update_result)
+        update_result.ok_or_else(|| self.save_conflict_error(id))
     }
 
     fn delete(&self, entity: FishingHistoryEntry) -> Result<bool, GameRepositoryError> {
@@ -112,7 +290,7 @@ impl Repository<FishingHistoryEntry> for FishingHistoryEntryRepository {
 
         let deleted_count = diesel::delete(fish_fishing_history_entries::table)
             .filter(fish_fishing_history_entries::id.eq(entity.id))
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(deleted_count > 0)
     }
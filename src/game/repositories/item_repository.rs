@@ -1,13 +1,16 @@
 use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
 use crate::game::errors::repository::GameRepositoryError;
 use crate::models::item::{Item, NewItem};
 use crate::schema::fish_items;
 use crate::traits::repository::Repository;
-use chrono::Utc;
 use diesel::prelude::*;
+use diesel::sql_types::Bool;
 use std::sync::{Arc, RwLock};
 
 pub trait ItemRepositoryInterface: Repository<Item> + Send + Sync {
+    /// The same repository, persisting the timestamps of the given clock
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn ItemRepositoryInterface>;
     fn find_by_user(&self, user_id: i64) -> Result<Vec<Item>, GameRepositoryError>;
     fn find_by_type_and_user(
         &self,
@@ -18,21 +21,26 @@ pub trait ItemRepositoryInterface: Repository<Item> + Send + Sync {
 
 pub struct ItemRepository {
     db: Arc<RwLock<dyn DatabaseInterface>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl ItemRepository {
-    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
     }
 }
 
 impl ItemRepositoryInterface for ItemRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn ItemRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
     fn find_by_user(&self, user_id: i64) -> Result<Vec<Item>, GameRepositoryError> {
         let mut connection = self.get_connection()?;
 
         let results = fish_items::table
             .filter(fish_items::user_id.eq(user_id))
-            .load::<Item>(&mut connection)?;
+            .load::<Item>(&mut *connection)?;
 
         Ok(results)
     }
@@ -50,7 +58,7 @@ impl ItemRepositoryInterface for ItemRepository {
                     .eq(type_id)
                     .and(fish_items::user_id.eq(user_id)),
             )
-            .load::<Item>(&mut connection)?;
+            .load::<Item>(&mut *connection)?;
 
         Ok(results)
     }
@@ -63,10 +71,15 @@ impl Repository<Item> for ItemRepository {
 
     fn create(&self, new_entity: NewItem) -> Result<Item, GameRepositoryError> {
         let mut connection = self.get_connection()?;
+        let now = self.clock.now();
 
         let new_result = diesel::insert_into(fish_items::table)
-            .values(new_entity)
-            .get_result::<Item>(&mut connection)?;
+            .values((
+                new_entity,
+                fish_items::created_at.eq(now),
+                fish_items::updated_at.eq(now),
+            ))
+            .get_result::<Item>(&mut *connection)?;
 
         Ok(new_result)
     }
@@ -75,21 +88,31 @@ impl Repository<Item> for ItemRepository {
         let mut connection = self.get_connection()?;
         let item = fish_items::table
             .find(id)
-            .first::<Item>(&mut connection)
+            .first::<Item>(&mut *connection)
             .optional()?;
         Ok(item)
     }
 
-    fn save(&self, mut entity: Item) -> Result<Item, GameRepositoryError> {
+    fn update(&self, mut entity: Item, check_stale: bool) -> Result<Item, GameRepositoryError> {
         let mut connection = self.get_connection()?;
-        entity.updated_at = Utc::now();
+        let id = entity.id;
+        let loaded_version = entity.version;
+        entity.updated_at = self.clock.now();
 
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
         let updated_item = diesel::update(fish_items::table)
-            .filter(fish_items::id.eq(entity.id))
-            .set(entity)
-            .get_result::<Item>(&mut connection)?;
+            .filter(fish_items::id.eq(id))
+            .filter(
+                fish_items::version
+                    .eq(loaded_version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((entity, fish_items::version.eq(fish_items::version + 1)))
+            .get_result::<Item>(&mut *connection)
+            .optional()?;
+        drop(connection);
 
-        Ok(updated_item)
+        updated_item.ok_or_else(|| self.save_conflict_error(id))
     }
 
     fn delete(&self, entity: Item) -> Result<bool, GameRepositoryError> {
@@ -97,7 +120,7 @@ impl Repository<Item> for ItemRepository {
 
         let deleted_count = diesel::delete(fish_items::table)
             .filter(fish_items::id.eq(entity.id))
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(deleted_count > 0)
     }
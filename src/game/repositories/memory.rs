@@ -0,0 +1,44 @@
+pub mod catch_event_repository;
+pub mod fishing_history_entry_repository;
+pub mod item_repository;
+pub mod pond_repository;
+pub mod sale_event_repository;
+pub mod specimen_repository;
+pub mod user_repository;
+
+use crate::database::memory::MemoryStore;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use std::collections::BTreeMap;
+
+/// Sums up the values per user and ranks the users like the SQL leaderboards do,
+/// by their total descending and then by their user ID
+pub(crate) fn rank_users(
+    store: &MemoryStore,
+    values: impl Iterator<Item = (i64, i64)>,
+    page: LeaderboardPage,
+) -> Leaderboard {
+    let mut totals: BTreeMap<i64, i64> = BTreeMap::new();
+    for (user_id, value) in values {
+        *totals.entry(user_id).or_default() += value;
+    }
+
+    let mut rows: Vec<(i64, i64, i64)> = totals
+        .into_iter()
+        .filter_map(|(user_id, total)| {
+            let user = store.users.get(&user_id)?;
+            Some((user_id, user.external_id, total))
+        })
+        .collect();
+    rows.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+
+    let total_entries = rows.len() as i64;
+    Leaderboard::from_rows(total_entries, page, page_of(rows, page))
+}
+
+/// The rows on the given page of already ordered rows
+pub(crate) fn page_of<T>(rows: Vec<T>, page: LeaderboardPage) -> Vec<T> {
+    rows.into_iter()
+        .skip(page.offset() as usize)
+        .take(page.limit() as usize)
+        .collect()
+}
@@ -0,0 +1,156 @@
+use crate::database::memory::{with_store, MemoryDatabase};
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::catch_event_repository::CatchEventRepositoryInterface;
+use crate::game::repositories::memory::{page_of, rank_users};
+use crate::models::catch_event::{CatchEvent, NewCatchEvent};
+use crate::traits::repository::Repository;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, RwLock};
+
+const TABLE: &str = "fish_catch_events";
+
+pub struct MemoryCatchEventRepository {
+    db: Arc<RwLock<MemoryDatabase>>,
+}
+
+impl MemoryCatchEventRepository {
+    pub fn new(db: Arc<RwLock<MemoryDatabase>>) -> Self {
+        Self { db }
+    }
+}
+
+impl CatchEventRepositoryInterface for MemoryCatchEventRepository {
+    fn rank_users_by_catches_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let values = store
+                .catch_events
+                .values()
+                .filter(|catch_event| catch_event.caught_at >= since)
+                .map(|catch_event| (catch_event.user_id, 1));
+            Ok(rank_users(store, values, page))
+        })
+    }
+
+    fn rank_users_by_species_caught_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let species: BTreeSet<(i64, i32)> = store
+                .catch_events
+                .values()
+                .filter(|catch_event| catch_event.caught_at >= since)
+                .map(|catch_event| (catch_event.user_id, catch_event.species_id))
+                .collect();
+            let values = species.into_iter().map(|(user_id, _)| (user_id, 1));
+            Ok(rank_users(store, values, page))
+        })
+    }
+
+    fn rank_by_largest_catch_since(
+        &self,
+        species_id: i32,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard<(CatchEvent, i64)>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            // Of equally large catches, the earliest one holds the record
+            let mut largest_catches: BTreeMap<i64, &CatchEvent> = BTreeMap::new();
+            for catch_event in store.catch_events.values().filter(|catch_event| {
+                catch_event.species_id == species_id && catch_event.caught_at >= since
+            }) {
+                let largest = largest_catches
+                    .entry(catch_event.user_id)
+                    .or_insert(catch_event);
+                let is_larger = catch_event
+                    .total_size_ratio
+                    .total_cmp(&largest.total_size_ratio)
+                    .then(largest.caught_at.cmp(&catch_event.caught_at))
+                    .is_gt();
+                if is_larger {
+                    *largest = catch_event;
+                }
+            }
+
+            let mut entries: Vec<(CatchEvent, i64)> = largest_catches
+                .into_values()
+                .filter_map(|catch_event| {
+                    let user = store.users.get(&catch_event.user_id)?;
+                    Some((catch_event.clone(), user.external_id))
+                })
+                .collect();
+            entries.sort_by(|(a, _), (b, _)| {
+                b.total_size_ratio
+                    .total_cmp(&a.total_size_ratio)
+                    .then(a.user_id.cmp(&b.user_id))
+            });
+
+            Ok(Leaderboard {
+                total_entries: entries.len() as u64,
+                entries: page_of(entries, page),
+            })
+        })
+    }
+}
+
+impl Repository<CatchEvent> for MemoryCatchEventRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewCatchEvent) -> Result<CatchEvent, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            store.check_user_exists(TABLE, new_entity.user_id)?;
+
+            let catch_event = CatchEvent {
+                id: store.next_id(TABLE),
+                user_id: new_entity.user_id,
+                species_id: new_entity.species_id,
+                specimen_id: new_entity.specimen_id,
+                total_size_ratio: new_entity.total_size_ratio,
+                size_mm: new_entity.size_mm,
+                weight_g: new_entity.weight_g,
+                caught_at: new_entity.caught_at,
+            };
+            store
+                .catch_events
+                .insert(catch_event.id, catch_event.clone());
+            Ok(catch_event)
+        })
+    }
+
+    fn find(&self, id: i64) -> Result<Option<CatchEvent>, GameRepositoryError> {
+        with_store(&self.db, |store| Ok(store.catch_events.get(&id).cloned()))
+    }
+
+    fn update(
+        &self,
+        entity: CatchEvent,
+        _check_stale: bool,
+    ) -> Result<CatchEvent, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            if !store.catch_events.contains_key(&entity.id) {
+                return Err(GameDatabaseError::not_found().into());
+            }
+            store.check_user_exists(TABLE, entity.user_id)?;
+
+            store.catch_events.insert(entity.id, entity.clone());
+            Ok(entity)
+        })
+    }
+
+    fn delete(&self, entity: CatchEvent) -> Result<bool, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store.catch_events.remove(&entity.id).is_some())
+        })
+    }
+}
@@ -0,0 +1,223 @@
+use crate::database::memory::{with_store, MemoryDatabase};
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::clock::Clock;
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::fishing_history_entry_repository::FishingHistoryEntryRepositoryInterface;
+use crate::game::repositories::memory::{page_of, rank_users};
+use crate::models::fishing_history_entry::{FishingHistoryEntry, NewFishingHistoryEntry};
+use crate::traits::repository::Repository;
+use std::sync::{Arc, RwLock};
+
+const TABLE: &str = "fish_fishing_history_entries";
+
+pub struct MemoryFishingHistoryEntryRepository {
+    db: Arc<RwLock<MemoryDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MemoryFishingHistoryEntryRepository {
+    pub fn new(db: Arc<RwLock<MemoryDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl FishingHistoryEntryRepositoryInterface for MemoryFishingHistoryEntryRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn FishingHistoryEntryRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_user_and_species_id(
+        &self,
+        user_id: i64,
+        species_id: i32,
+    ) -> Result<Option<FishingHistoryEntry>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .fishing_history_entries
+                .values()
+                .find(|entry| entry.user_id == user_id && entry.species_id == species_id)
+                .cloned())
+        })
+    }
+
+    fn find_caught_species_ids_by_user(
+        &self,
+        user_id: i64,
+    ) -> Result<Vec<i32>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .fishing_history_entries
+                .values()
+                .filter(|entry| entry.user_id == user_id)
+                .map(|entry| entry.species_id)
+                .collect())
+        })
+    }
+
+    fn find_by_user(&self, user_id: i64) -> Result<Vec<FishingHistoryEntry>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let mut entries: Vec<FishingHistoryEntry> = store
+                .fishing_history_entries
+                .values()
+                .filter(|entry| entry.user_id == user_id)
+                .cloned()
+                .collect();
+            entries.sort_by_key(|entry| entry.species_id);
+            Ok(entries)
+        })
+    }
+
+    fn rank_users_by_caught_count(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let values = store
+                .fishing_history_entries
+                .values()
+                .map(|entry| (entry.user_id, entry.caught_count as i64));
+            Ok(rank_users(store, values, page))
+        })
+    }
+
+    fn rank_users_by_caught_species(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let values = store
+                .fishing_history_entries
+                .values()
+                .map(|entry| (entry.user_id, 1));
+            Ok(rank_users(store, values, page))
+        })
+    }
+
+    fn rank_users_by_sold_count(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let values = store
+                .fishing_history_entries
+                .values()
+                .filter(|entry| entry.sold_count > 0)
+                .map(|entry| (entry.user_id, entry.sold_count as i64));
+            Ok(rank_users(store, values, page))
+        })
+    }
+
+    fn rank_by_largest_catch(
+        &self,
+        species_id: i32,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard<(FishingHistoryEntry, i64)>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let mut entries: Vec<(FishingHistoryEntry, i64)> = store
+                .fishing_history_entries
+                .values()
+                .filter(|entry| entry.species_id == species_id)
+                .filter_map(|entry| {
+                    let user = store.users.get(&entry.user_id)?;
+                    Some((entry.clone(), user.external_id))
+                })
+                .collect();
+            entries.sort_by(|(a, _), (b, _)| {
+                b.largest_catch_size_ratio
+                    .total_cmp(&a.largest_catch_size_ratio)
+                    .then(a.user_id.cmp(&b.user_id))
+            });
+
+            Ok(Leaderboard {
+                total_entries: entries.len() as u64,
+                entries: page_of(entries, page),
+            })
+        })
+    }
+}
+
+impl Repository<FishingHistoryEntry> for MemoryFishingHistoryEntryRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(
+        &self,
+        new_entity: NewFishingHistoryEntry,
+    ) -> Result<FishingHistoryEntry, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            store.check_user_exists(TABLE, new_entity.user_id)?;
+
+            let now = self.clock.now();
+            let entry = FishingHistoryEntry {
+                id: store.next_id(TABLE),
+                user_id: new_entity.user_id,
+                species_id: new_entity.species_id,
+                created_at: now,
+                updated_at: now,
+                version: 0,
+                caught_count: new_entity.caught_count,
+                sold_count: new_entity.sold_count,
+                smallest_catch_size_ratio: new_entity.smallest_catch_size_ratio,
+                largest_catch_size_ratio: new_entity.largest_catch_size_ratio,
+                last_catch: now,
+                first_sell: None,
+                last_sell: None,
+                smallest_catch_specimen_id: new_entity.smallest_catch_specimen_id,
+                smallest_catch_size_mm: new_entity.smallest_catch_size_mm,
+                smallest_catch_weight_g: new_entity.smallest_catch_weight_g,
+                smallest_catch_time: new_entity.smallest_catch_time,
+                largest_catch_specimen_id: new_entity.largest_catch_specimen_id,
+                largest_catch_size_mm: new_entity.largest_catch_size_mm,
+                largest_catch_weight_g: new_entity.largest_catch_weight_g,
+                largest_catch_time: new_entity.largest_catch_time,
+            };
+            store
+                .fishing_history_entries
+                .insert(entry.id, entry.clone());
+            Ok(entry)
+        })
+    }
+
+    fn find(&self, id: i64) -> Result<Option<FishingHistoryEntry>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store.fishing_history_entries.get(&id).cloned())
+        })
+    }
+
+    fn update(
+        &self,
+        mut entity: FishingHistoryEntry,
+        check_stale: bool,
+    ) -> Result<FishingHistoryEntry, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let existing = store
+                .fishing_history_entries
+                .get(&entity.id)
+                .ok_or_else(GameDatabaseError::not_found)?;
+            if check_stale && existing.version != entity.version {
+                return Err(GameRepositoryError::stale_entity(
+                    "FishingHistoryEntry",
+                    entity.id,
+                ));
+            }
+            store.check_user_exists(TABLE, entity.user_id)?;
+
+            entity.updated_at = self.clock.now();
+
+            entity.version = existing.version + 1;
+            store
+                .fishing_history_entries
+                .insert(entity.id, entity.clone());
+            Ok(entity)
+        })
+    }
+
+    fn delete(&self, entity: FishingHistoryEntry) -> Result<bool, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store.fishing_history_entries.remove(&entity.id).is_some())
+        })
+    }
+}
@@ -0,0 +1,108 @@
+use crate::database::memory::{with_store, MemoryDatabase};
+use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::item_repository::ItemRepositoryInterface;
+use crate::models::item::{Item, NewItem};
+use crate::traits::repository::Repository;
+use std::sync::{Arc, RwLock};
+
+const TABLE: &str = "fish_items";
+
+pub struct MemoryItemRepository {
+    db: Arc<RwLock<MemoryDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MemoryItemRepository {
+    pub fn new(db: Arc<RwLock<MemoryDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl ItemRepositoryInterface for MemoryItemRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn ItemRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_user(&self, user_id: i64) -> Result<Vec<Item>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .items
+                .values()
+                .filter(|item| item.user_id == user_id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn find_by_type_and_user(
+        &self,
+        type_id: i32,
+        user_id: i64,
+    ) -> Result<Vec<Item>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .items
+                .values()
+                .filter(|item| item.type_id == type_id && item.user_id == user_id)
+                .cloned()
+                .collect())
+        })
+    }
+}
+
+impl Repository<Item> for MemoryItemRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewItem) -> Result<Item, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            store.check_user_exists(TABLE, new_entity.user_id)?;
+
+            let now = self.clock.now();
+            let item = Item {
+                id: store.next_id(TABLE),
+                user_id: new_entity.user_id,
+                type_id: new_entity.type_id,
+                properties: new_entity.properties,
+                created_at: now,
+                updated_at: now,
+                version: 0,
+            };
+            store.items.insert(item.id, item.clone());
+            Ok(item)
+        })
+    }
+
+    fn find(&self, id: i64) -> Result<Option<Item>, GameRepositoryError> {
+        with_store(&self.db, |store| Ok(store.items.get(&id).cloned()))
+    }
+
+    fn update(&self, mut entity: Item, check_stale: bool) -> Result<Item, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let existing = store
+                .items
+                .get(&entity.id)
+                .ok_or_else(GameDatabaseError::not_found)?;
+            if check_stale && existing.version != entity.version {
+                return Err(GameRepositoryError::stale_entity("Item", entity.id));
+            }
+            store.check_user_exists(TABLE, entity.user_id)?;
+
+            entity.updated_at = self.clock.now();
+
+            entity.version = existing.version + 1;
+            store.items.insert(entity.id, entity.clone());
+            Ok(entity)
+        })
+    }
+
+    fn delete(&self, entity: Item) -> Result<bool, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store.items.remove(&entity.id).is_some())
+        })
+    }
+}
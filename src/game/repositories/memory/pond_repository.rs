@@ -0,0 +1,108 @@
+use crate::database::memory::{with_store, MemoryDatabase};
+use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::pond_repository::PondRepositoryInterface;
+use crate::models::pond::{NewPond, Pond};
+use crate::models::user::User;
+use crate::traits::repository::Repository;
+use std::sync::{Arc, RwLock};
+
+const TABLE: &str = "fish_ponds";
+
+pub struct MemoryPondRepository {
+    db: Arc<RwLock<MemoryDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MemoryPondRepository {
+    pub fn new(db: Arc<RwLock<MemoryDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl PondRepositoryInterface for MemoryPondRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn PondRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_user(&self, owner_user: &User) -> Result<Vec<Pond>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .ponds
+                .values()
+                .filter(|pond| pond.user_id == owner_user.id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    /// The store is locked for the whole transaction, so the transactions already run one after another
+    fn find_for_update(&self, id: i64) -> Result<Option<Pond>, GameRepositoryError> {
+        self.find(id)
+    }
+}
+
+impl Repository<Pond> for MemoryPondRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewPond) -> Result<Pond, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            store.check_user_exists(TABLE, new_entity.user_id)?;
+
+            let now = self.clock.now();
+            let pond = Pond {
+                id: store.next_id(TABLE),
+                user_id: new_entity.user_id,
+                created_at: now,
+                updated_at: now,
+                version: 0,
+                capacity: new_entity.capacity,
+            };
+            store.ponds.insert(pond.id, pond.clone());
+            Ok(pond)
+        })
+    }
+
+    fn find(&self, id: i64) -> Result<Option<Pond>, GameRepositoryError> {
+        with_store(&self.db, |store| Ok(store.ponds.get(&id).cloned()))
+    }
+
+    fn update(&self, mut entity: Pond, check_stale: bool) -> Result<Pond, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let existing = store
+                .ponds
+                .get(&entity.id)
+                .ok_or_else(GameDatabaseError::not_found)?;
+            if check_stale && existing.version != entity.version {
+                return Err(GameRepositoryError::stale_entity("Pond", entity.id));
+            }
+            store.check_user_exists(TABLE, entity.user_id)?;
+
+            entity.updated_at = self.clock.now();
+
+            entity.version = existing.version + 1;
+            store.ponds.insert(entity.id, entity.clone());
+            Ok(entity)
+        })
+    }
+
+    fn delete(&self, entity: Pond) -> Result<bool, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            if store.ponds.remove(&entity.id).is_none() {
+                return Ok(false);
+            }
+
+            // ON DELETE SET NULL
+            for specimen in store.specimens.values_mut() {
+                if specimen.pond_id == Some(entity.id) {
+                    specimen.pond_id = None;
+                }
+            }
+            Ok(true)
+        })
+    }
+}
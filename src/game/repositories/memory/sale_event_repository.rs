@@ -0,0 +1,88 @@
+use crate::database::memory::{with_store, MemoryDatabase};
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::memory::rank_users;
+use crate::game::repositories::sale_event_repository::SaleEventRepositoryInterface;
+use crate::models::sale_event::{NewSaleEvent, SaleEvent};
+use crate::traits::repository::Repository;
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, RwLock};
+
+const TABLE: &str = "fish_sale_events";
+
+pub struct MemorySaleEventRepository {
+    db: Arc<RwLock<MemoryDatabase>>,
+}
+
+impl MemorySaleEventRepository {
+    pub fn new(db: Arc<RwLock<MemoryDatabase>>) -> Self {
+        Self { db }
+    }
+}
+
+impl SaleEventRepositoryInterface for MemorySaleEventRepository {
+    fn rank_users_by_sales_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let values = store
+                .sale_events
+                .values()
+                .filter(|sale_event| sale_event.sold_at >= since)
+                .map(|sale_event| (sale_event.user_id, 1));
+            Ok(rank_users(store, values, page))
+        })
+    }
+}
+
+impl Repository<SaleEvent> for MemorySaleEventRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewSaleEvent) -> Result<SaleEvent, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            store.check_user_exists(TABLE, new_entity.user_id)?;
+
+            let sale_event = SaleEvent {
+                id: store.next_id(TABLE),
+                user_id: new_entity.user_id,
+                species_id: new_entity.species_id,
+                specimen_id: new_entity.specimen_id,
+                sold_at: new_entity.sold_at,
+            };
+            store.sale_events.insert(sale_event.id, sale_event.clone());
+            Ok(sale_event)
+        })
+    }
+
+    fn find(&self, id: i64) -> Result<Option<SaleEvent>, GameRepositoryError> {
+        with_store(&self.db, |store| Ok(store.sale_events.get(&id).cloned()))
+    }
+
+    fn update(
+        &self,
+        entity: SaleEvent,
+        _check_stale: bool,
+    ) -> Result<SaleEvent, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            if !store.sale_events.contains_key(&entity.id) {
+                return Err(GameDatabaseError::not_found().into());
+            }
+            store.check_user_exists(TABLE, entity.user_id)?;
+
+            store.sale_events.insert(entity.id, entity.clone());
+            Ok(entity)
+        })
+    }
+
+    fn delete(&self, entity: SaleEvent) -> Result<bool, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store.sale_events.remove(&entity.id).is_some())
+        })
+    }
+}
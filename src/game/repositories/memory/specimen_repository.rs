@@ -0,0 +1,165 @@
+use crate::database::memory::{foreign_key_violation, with_store, MemoryDatabase, MemoryStore};
+use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::specimen_repository::SpecimenRepositoryInterface;
+use crate::models::specimen::{NewSpecimen, Specimen};
+use crate::models::user::User;
+use crate::traits::repository::Repository;
+use std::sync::{Arc, RwLock};
+
+const TABLE: &str = "fish_specimens";
+
+pub struct MemorySpecimenRepository {
+    db: Arc<RwLock<MemoryDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MemorySpecimenRepository {
+    pub fn new(db: Arc<RwLock<MemoryDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+fn check_pond_exists(store: &MemoryStore, pond_id: Option<i64>) -> Result<(), GameDatabaseError> {
+    match pond_id {
+        Some(pond_id) if !store.ponds.contains_key(&pond_id) => {
+            Err(foreign_key_violation(TABLE, "fish_specimens_pond_id_fkey"))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl SpecimenRepositoryInterface for MemorySpecimenRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn SpecimenRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_user(&self, owner_user: &User) -> Result<Vec<Specimen>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .specimens
+                .values()
+                .filter(|specimen| specimen.user_id == owner_user.id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn find_by_user_and_species(
+        &self,
+        owner_user: &User,
+        species_id: i32,
+    ) -> Result<Vec<Specimen>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .specimens
+                .values()
+                .filter(|specimen| {
+                    specimen.user_id == owner_user.id && specimen.species_id == species_id
+                })
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn find_by_pond(&self, pond_id: i64) -> Result<Vec<Specimen>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .specimens
+                .values()
+                .filter(|specimen| specimen.pond_id == Some(pond_id))
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn count_by_pond(&self, pond_id: i64) -> Result<i64, GameRepositoryError> {
+        Ok(self.find_by_pond(pond_id)?.len() as i64)
+    }
+
+    fn delete_many(
+        &self,
+        owner_user: &User,
+        ids: &[i64],
+    ) -> Result<Vec<Specimen>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let mut deleted_specimens = Vec::new();
+            for id in ids {
+                let is_owned = store
+                    .specimens
+                    .get(id)
+                    .is_some_and(|specimen| specimen.user_id == owner_user.id);
+                if is_owned {
+                    deleted_specimens.extend(store.specimens.remove(id));
+                }
+            }
+            Ok(deleted_specimens)
+        })
+    }
+}
+
+impl Repository<Specimen> for MemorySpecimenRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewSpecimen) -> Result<Specimen, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            store.check_user_exists(TABLE, new_entity.user_id)?;
+
+            let now = self.clock.now();
+            let specimen = Specimen {
+                id: store.next_id(TABLE),
+                user_id: new_entity.user_id,
+                species_id: new_entity.species_id,
+                created_at: now,
+                updated_at: now,
+                version: 0,
+                size_baby_ratio: new_entity.size_baby_ratio,
+                size_adult_ratio: new_entity.size_adult_ratio,
+                lifespan_days_ratio: new_entity.lifespan_days_ratio,
+                catch_age: new_entity.catch_age,
+                pond_id: None,
+                catch_seed: new_entity.catch_seed,
+            };
+            store.specimens.insert(specimen.id, specimen.clone());
+            Ok(specimen)
+        })
+    }
+
+    fn find(&self, id: i64) -> Result<Option<Specimen>, GameRepositoryError> {
+        with_store(&self.db, |store| Ok(store.specimens.get(&id).cloned()))
+    }
+
+    fn update(
+        &self,
+        mut entity: Specimen,
+        check_stale: bool,
+    ) -> Result<Specimen, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let existing = store
+                .specimens
+                .get(&entity.id)
+                .ok_or_else(GameDatabaseError::not_found)?;
+            if check_stale && existing.version != entity.version {
+                return Err(GameRepositoryError::stale_entity("Specimen", entity.id));
+            }
+            store.check_user_exists(TABLE, entity.user_id)?;
+            check_pond_exists(store, entity.pond_id)?;
+
+            entity.updated_at = self.clock.now();
+
+            entity.version = existing.version + 1;
+            store.specimens.insert(entity.id, entity.clone());
+            Ok(entity)
+        })
+    }
+
+    fn delete(&self, entity: Specimen) -> Result<bool, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store.specimens.remove(&entity.id).is_some())
+        })
+    }
+}
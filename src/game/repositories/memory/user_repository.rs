@@ -0,0 +1,236 @@
+use crate::database::memory::{
+    unique_constraint_violation, with_store, MemoryDatabase, MemoryStore,
+};
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::clock::Clock;
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::memory::rank_users;
+use crate::game::repositories::user_repository::UserRepositoryInterface;
+use crate::models::user::{NewUser, User};
+use crate::models::user_achievement::UserAchievement;
+use crate::models::user_location::UserLocation;
+use crate::traits::repository::Repository;
+use std::sync::{Arc, RwLock};
+
+const TABLE: &str = "fish_users";
+
+pub struct MemoryUserRepository {
+    db: Arc<RwLock<MemoryDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MemoryUserRepository {
+    pub fn new(db: Arc<RwLock<MemoryDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+fn check_external_id_unique(store: &MemoryStore, user: &User) -> Result<(), GameDatabaseError> {
+    let is_taken = store
+        .users
+        .values()
+        .any(|other| other.id != user.id && other.external_id == user.external_id);
+    if is_taken {
+        Err(unique_constraint_violation("fish_users_external_id_key"))
+    } else {
+        Ok(())
+    }
+}
+
+impl UserRepositoryInterface for MemoryUserRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn UserRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_external_id(&self, external_id: i64) -> Result<Option<User>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .users
+                .values()
+                .find(|user| user.external_id == external_id)
+                .cloned())
+        })
+    }
+
+    fn find_unlocked_locations(&self, id: i64) -> Result<Vec<UserLocation>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .user_locations
+                .values()
+                .filter(|user_location| user_location.user_id == id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn find_unlocked_location_ids(&self, id: i64) -> Result<Vec<i32>, GameRepositoryError> {
+        Ok(self
+            .find_unlocked_locations(id)?
+            .into_iter()
+            .map(|user_location| user_location.location_id)
+            .collect())
+    }
+
+    fn unlock_location(
+        &self,
+        id: i64,
+        location_id: i32,
+    ) -> Result<UserLocation, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            store.check_user_exists("fish_user_locations", id)?;
+            if store.user_locations.contains_key(&(id, location_id)) {
+                return Err(unique_constraint_violation("fish_user_locations_pkey").into());
+            }
+
+            let user_location = UserLocation {
+                user_id: id,
+                location_id,
+                unlocked_at: self.clock.now(),
+            };
+            store
+                .user_locations
+                .insert((id, location_id), user_location.clone());
+            Ok(user_location)
+        })
+    }
+
+    fn find_achievements(&self, id: i64) -> Result<Vec<UserAchievement>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            Ok(store
+                .user_achievements
+                .values()
+                .filter(|user_achievement| user_achievement.user_id == id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn find_unlocked_achievement_ids(&self, id: i64) -> Result<Vec<i32>, GameRepositoryError> {
+        Ok(self
+            .find_achievements(id)?
+            .into_iter()
+            .map(|user_achievement| user_achievement.achievement_id)
+            .collect())
+    }
+
+    fn unlock_achievement(
+        &self,
+        id: i64,
+        achievement_id: i32,
+    ) -> Result<Option<UserAchievement>, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            store.check_user_exists("fish_user_achievements", id)?;
+            if store.user_achievements.contains_key(&(id, achievement_id)) {
+                return Ok(None);
+            }
+
+            let user_achievement = UserAchievement {
+                user_id: id,
+                achievement_id,
+                unlocked_at: self.clock.now(),
+            };
+            store
+                .user_achievements
+                .insert((id, achievement_id), user_achievement.clone());
+            Ok(Some(user_achievement))
+        })
+    }
+
+    fn add_credits(&self, id: i64, amount: i64) -> Result<User, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let user = store
+                .users
+                .get_mut(&id)
+                .ok_or_else(GameDatabaseError::not_found)?;
+            user.credits += amount;
+            user.updated_at = self.clock.now();
+            user.version += 1;
+            Ok(user.clone())
+        })
+    }
+
+    fn remove_credits(&self, id: i64, amount: i64) -> Result<Option<User>, GameRepositoryError> {
+        with_store(&self.db, |store| match store.users.get_mut(&id) {
+            Some(user) if user.credits >= amount => {
+                user.credits -= amount;
+                user.updated_at = self.clock.now();
+                user.version += 1;
+                Ok(Some(user.clone()))
+            }
+            _ => Ok(None),
+        })
+    }
+
+    fn rank_by_credits(&self, page: LeaderboardPage) -> Result<Leaderboard, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let values: Vec<(i64, i64)> = store
+                .users
+                .values()
+                .map(|user| (user.id, user.credits))
+                .collect();
+            Ok(rank_users(store, values.into_iter(), page))
+        })
+    }
+}
+
+impl Repository<User> for MemoryUserRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewUser) -> Result<User, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let now = self.clock.now();
+            let user = User {
+                id: store.next_id(TABLE),
+                external_id: new_entity.external_id,
+                credits: 0,
+                created_at: now,
+                updated_at: now,
+                version: 0,
+                timezone: "UTC".to_string(),
+            };
+            check_external_id_unique(store, &user)?;
+
+            store.users.insert(user.id, user.clone());
+            Ok(user)
+        })
+    }
+
+    fn find(&self, id: i64) -> Result<Option<User>, GameRepositoryError> {
+        with_store(&self.db, |store| Ok(store.users.get(&id).cloned()))
+    }
+
+    fn update(&self, mut entity: User, check_stale: bool) -> Result<User, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            let existing = store
+                .users
+                .get(&entity.id)
+                .ok_or_else(GameDatabaseError::not_found)?;
+            if check_stale && existing.version != entity.version {
+                return Err(GameRepositoryError::stale_entity("User", entity.id));
+            }
+            check_external_id_unique(store, &entity)?;
+
+            entity.updated_at = self.clock.now();
+
+            entity.version = existing.version + 1;
+            store.users.insert(entity.id, entity.clone());
+            Ok(entity)
+        })
+    }
+
+    fn delete(&self, entity: User) -> Result<bool, GameRepositoryError> {
+        with_store(&self.db, |store| {
+            if store.user_has_references(entity.id) {
+                return Err(GameDatabaseError::foreign_key_violation(&format!(
+                    "update or delete on table \"{TABLE}\" violates a foreign key constraint"
+                ))
+                .into());
+            }
+            Ok(store.users.remove(&entity.id).is_some())
+        })
+    }
+}
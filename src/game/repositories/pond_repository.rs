@@ -1,37 +1,57 @@
 use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
 use crate::game::errors::repository::GameRepositoryError;
 use crate::models::pond::{NewPond, Pond};
 use crate::models::user::User;
 use crate::schema::fish_ponds;
 use crate::traits::repository::Repository;
-use chrono::Utc;
 use diesel::prelude::*;
+use diesel::sql_types::Bool;
 use std::sync::{Arc, RwLock};
 
 pub trait PondRepositoryInterface: Repository<Pond> + Send + Sync {
+    /// The same repository, persisting the timestamps of the given clock
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn PondRepositoryInterface>;
     fn find_by_user(&self, owner_user: &User) -> Result<Vec<Pond>, GameRepositoryError>;
+    /// Finds the pond and locks it until the transaction running on the current thread ends
+    fn find_for_update(&self, id: i64) -> Result<Option<Pond>, GameRepositoryError>;
 }
 
 pub struct PondRepository {
     db: Arc<RwLock<dyn DatabaseInterface>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl PondRepository {
-    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>) -> Self {
-        PondRepository { db }
+    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>, clock: Arc<dyn Clock>) -> Self {
+        PondRepository { db, clock }
     }
 }
 
 impl PondRepositoryInterface for PondRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn PondRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
     fn find_by_user(&self, owner_user: &User) -> Result<Vec<Pond>, GameRepositoryError> {
         let mut connection = self.get_connection()?;
 
         let pond = fish_ponds::table
             .filter(fish_ponds::user_id.eq(owner_user.id))
-            .load::<Pond>(&mut connection)?;
+            .load::<Pond>(&mut *connection)?;
 
         Ok(pond)
     }
+
+    fn find_for_update(&self, id: i64) -> Result<Option<Pond>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+        let pond = fish_ponds::table
+            .find(id)
+            .for_update()
+            .first::<Pond>(&mut *connection)
+            .optional()?;
+        Ok(pond)
+    }
 }
 
 impl Repository<Pond> for PondRepository {
@@ -41,10 +61,15 @@ impl Repository<Pond> for PondRepository {
 
     fn create(&self, new_entity: NewPond) -> Result<Pond, GameRepositoryError> {
         let mut connection = self.get_connection()?;
+        let now = self.clock.now();
 
         let new_result = diesel::insert_into(fish_ponds::table)
-            .values(new_entity)
-            .get_result::<Pond>(&mut connection)?;
+            .values((
+                new_entity,
+                fish_ponds::created_at.eq(now),
+                fish_ponds::updated_at.eq(now),
+            ))
+            .get_result::<Pond>(&mut *connection)?;
 
         Ok(new_result)
     }
@@ -53,21 +78,31 @@ impl Repository<Pond> for PondRepository {
         let mut connection = self.get_connection()?;
         let pond = fish_ponds::table
             .find(id)
-            .first::<Pond>(&mut connection)
+            .first::<Pond>(&mut *connection)
             .optional()?;
         Ok(pond)
     }
 
-    fn save(&self, mut entity: Pond) -> Result<Pond, GameRepositoryError> {
+    fn update(&self, mut entity: Pond, check_stale: bool) -> Result<Pond, GameRepositoryError> {
         let mut connection = self.get_connection()?;
-        entity.updated_at = Utc::now();
+        let id = entity.id;
+        let loaded_version = entity.version;
+        entity.updated_at = self.clock.now();
 
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
         let updated_pond = diesel::update(fish_ponds::table)
-            .filter(fish_ponds::id.eq(entity.id))
-            .set(entity)
-            .get_result::<Pond>(&mut connection)?;
+            .filter(fish_ponds::id.eq(id))
+            .filter(
+                fish_ponds::version
+                    .eq(loaded_version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((entity, fish_ponds::version.eq(fish_ponds::version + 1)))
+            .get_result::<Pond>(&mut *connection)
+            .optional()?;
+        drop(connection);
 
-        Ok(updated_pond)
+        updated_pond.ok_or_else(|| self.save_conflict_error(id))
     }
 
     fn delete(&self, entity: Pond) -> Result<bool, GameRepositoryError> {
@@ -75,7 +110,7 @@ impl Repository<Pond> for PondRepository {
 
         let deleted_count = diesel::delete(fish_ponds::table)
             .filter(fish_ponds::id.eq(entity.id))
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(deleted_count > 0)
     }
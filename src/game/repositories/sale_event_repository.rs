@@ -0,0 +1,110 @@
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::models::sale_event::{NewSaleEvent, SaleEvent};
+use crate::schema::{fish_sale_events, fish_users};
+use crate::traits::repository::Repository;
+use chrono::{DateTime, Utc};
+use diesel::dsl::count;
+use diesel::prelude::*;
+use std::sync::{Arc, RwLock};
+
+pub trait SaleEventRepositoryInterface: Repository<SaleEvent> + Send + Sync {
+    /// Users ranked by how many specimens they sold since the given time
+    fn rank_users_by_sales_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError>;
+}
+
+pub struct SaleEventRepository {
+    db: Arc<RwLock<dyn DatabaseInterface>>,
+}
+
+impl SaleEventRepository {
+    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>) -> Self {
+        Self { db }
+    }
+}
+
+impl SaleEventRepositoryInterface for SaleEventRepository {
+    fn rank_users_by_sales_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_sale_events::table
+            .filter(fish_sale_events::sold_at.ge(since))
+            .select(count(fish_sale_events::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let sale_count = count(fish_sale_events::id);
+        let rows = fish_sale_events::table
+            .inner_join(fish_users::table)
+            .filter(fish_sale_events::sold_at.ge(since))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, sale_count))
+            .order((sale_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+}
+
+impl Repository<SaleEvent> for SaleEventRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewSaleEvent) -> Result<SaleEvent, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let sale_event = diesel::insert_into(fish_sale_events::table)
+            .values(new_entity)
+            .get_result::<SaleEvent>(&mut *connection)?;
+
+        Ok(sale_event)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<SaleEvent>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+        let sale_event = fish_sale_events::table
+            .find(id)
+            .first::<SaleEvent>(&mut *connection)
+            .optional()?;
+        Ok(sale_event)
+    }
+
+    fn update(
+        &self,
+        entity: SaleEvent,
+        _check_stale: bool,
+    ) -> Result<SaleEvent, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let updated_sale_event = diesel::update(fish_sale_events::table)
+            .filter(fish_sale_events::id.eq(entity.id))
+            .set(entity)
+            .get_result::<SaleEvent>(&mut *connection)
+            .optional()?
+            .ok_or_else(GameDatabaseError::not_found)?;
+
+        Ok(updated_sale_event)
+    }
+
+    fn delete(&self, entity: SaleEvent) -> Result<bool, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let deleted_count = diesel::delete(fish_sale_events::table)
+            .filter(fish_sale_events::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
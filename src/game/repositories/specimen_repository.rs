@@ -1,37 +1,110 @@
 use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
 use crate::game::errors::repository::GameRepositoryError;
 use crate::models::specimen::{NewSpecimen, Specimen};
 use crate::models::user::User;
 use crate::schema::fish_specimens;
 use crate::traits::repository::Repository;
-use chrono::Utc;
 use diesel::prelude::*;
+use diesel::sql_types::Bool;
 use std::sync::{Arc, RwLock};
 
 pub trait SpecimenRepositoryInterface: Repository<Specimen> + Send + Sync {
+    /// The same repository, persisting the timestamps of the given clock
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn SpecimenRepositoryInterface>;
     fn find_by_user(&self, owner_user: &User) -> Result<Vec<Specimen>, GameRepositoryError>;
+    fn find_by_user_and_species(
+        &self,
+        owner_user: &User,
+        species_id: i32,
+    ) -> Result<Vec<Specimen>, GameRepositoryError>;
+    fn find_by_pond(&self, pond_id: i64) -> Result<Vec<Specimen>, GameRepositoryError>;
+    fn count_by_pond(&self, pond_id: i64) -> Result<i64, GameRepositoryError>;
+    /// Deletes the specimens with the given IDs which belong to the user and returns them
+    fn delete_many(
+        &self,
+        owner_user: &User,
+        ids: &[i64],
+    ) -> Result<Vec<Specimen>, GameRepositoryError>;
 }
 
 pub struct SpecimenRepository {
     db: Arc<RwLock<dyn DatabaseInterface>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl SpecimenRepository {
-    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
     }
 }
 
 impl SpecimenRepositoryInterface for SpecimenRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn SpecimenRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
     fn find_by_user(&self, owner_user: &User) -> Result<Vec<Specimen>, GameRepositoryError> {
         let mut connection = self.get_connection()?;
 
         let specimens = fish_specimens::table
             .filter(fish_specimens::user_id.eq(owner_user.id))
-            .load::<Specimen>(&mut connection)?;
+            .load::<Specimen>(&mut *connection)?;
+
+        Ok(specimens)
+    }
+
+    fn find_by_user_and_species(
+        &self,
+        owner_user: &User,
+        species_id: i32,
+    ) -> Result<Vec<Specimen>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let specimens = fish_specimens::table
+            .filter(fish_specimens::user_id.eq(owner_user.id))
+            .filter(fish_specimens::species_id.eq(species_id))
+            .load::<Specimen>(&mut *connection)?;
+
+        Ok(specimens)
+    }
+
+    fn find_by_pond(&self, pond_id: i64) -> Result<Vec<Specimen>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let specimens = fish_specimens::table
+            .filter(fish_specimens::pond_id.eq(pond_id))
+            .order(fish_specimens::id)
+            .load::<Specimen>(&mut *connection)?;
 
         Ok(specimens)
     }
+
+    fn count_by_pond(&self, pond_id: i64) -> Result<i64, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let count = fish_specimens::table
+            .filter(fish_specimens::pond_id.eq(pond_id))
+            .count()
+            .get_result::<i64>(&mut *connection)?;
+
+        Ok(count)
+    }
+
+    fn delete_many(
+        &self,
+        owner_user: &User,
+        ids: &[i64],
+    ) -> Result<Vec<Specimen>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let deleted_specimens = diesel::delete(fish_specimens::table)
+            .filter(fish_specimens::user_id.eq(owner_user.id))
+            .filter(fish_specimens::id.eq_any(ids))
+            .get_results::<Specimen>(&mut *connection)?;
+
+        Ok(deleted_specimens)
+    }
 }
 
 impl Repository<Specimen> for SpecimenRepository {
@@ -41,10 +114,15 @@ impl Repository<Specimen> for SpecimenRepository {
 
     fn create(&self, new_entity: NewSpecimen) -> Result<Specimen, GameRepositoryError> {
         let mut connection = self.get_connection()?;
+        let now = self.clock.now();
 
         let specimen = diesel::insert_into(fish_specimens::table)
-            .values(new_entity)
-            .get_result::<Specimen>(&mut connection)?;
+            .values((
+                new_entity,
+                fish_specimens::created_at.eq(now),
+                fish_specimens::updated_at.eq(now),
+            ))
+            .get_result::<Specimen>(&mut *connection)?;
 
         Ok(specimen)
     }
@@ -53,21 +131,38 @@ impl Repository<Specimen> for SpecimenRepository {
         let mut connection = self.get_connection()?;
         let specimen = fish_specimens::table
             .find(id)
-            .first::<Specimen>(&mut connection)
+            .first::<Specimen>(&mut *connection)
             .optional()?;
         Ok(specimen)
     }
 
-    fn save(&self, mut entity: Specimen) -> Result<Specimen, GameRepositoryError> {
+    fn update(
+        &self,
+        mut entity: Specimen,
+        check_stale: bool,
+    ) -> Result<Specimen, GameRepositoryError> {
         let mut connection = self.get_connection()?;
-        entity.updated_at = Utc::now();
+        let id = entity.id;
+        let loaded_version = entity.version;
+        entity.updated_at = self.clock.now();
 
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
         let updated_specimen = diesel::update(fish_specimens::table)
-            .filter(fish_specimens::id.eq(entity.id))
-            .set(entity)
-            .get_result::<Specimen>(&mut connection)?;
+            .filter(fish_specimens::id.eq(id))
+            .filter(
+                fish_specimens::version
+                    .eq(loaded_version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((
+                entity,
+                fish_specimens::version.eq(fish_specimens::version + 1),
+            ))
+            .get_result::<Specimen>(&mut *connection)
+            .optional()?;
+        drop(connection);
 
-        Ok(updated_specimen)
+        updated_specimen.ok_or_else(|| self.save_conflict_error(id))
     }
 
     fn delete(&self, entity: Specimen) -> Result<bool, GameRepositoryError> {
@@ -75,7 +170,7 @@ impl Repository<Specimen> for SpecimenRepository {
 
         let deleted_count = diesel::delete(fish_specimens::table)
             .filter(fish_specimens::id.eq(entity.id))
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(deleted_count > 0)
     }
@@ -0,0 +1,7 @@
+pub mod catch_event_repository;
+pub mod fishing_history_entry_repository;
+pub mod item_repository;
+pub mod pond_repository;
+pub mod sale_event_repository;
+pub mod specimen_repository;
+pub mod user_repository;
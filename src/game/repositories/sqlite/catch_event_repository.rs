@@ -0,0 +1,192 @@
+use crate::database::sqlite::schema::{fish_catch_events, fish_users};
+use crate::database::sqlite::{get_connection, SqliteDatabase};
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::catch_event_repository::{
+    largest_catch_per_row, CatchEventRepositoryInterface,
+};
+use crate::models::catch_event::{CatchEvent, NewCatchEvent};
+use crate::traits::repository::Repository;
+use chrono::{DateTime, Utc};
+use diesel::dsl::{count, max};
+use diesel::prelude::*;
+use std::sync::{Arc, RwLock};
+
+pub struct SqliteCatchEventRepository {
+    db: Arc<RwLock<SqliteDatabase>>,
+}
+
+impl SqliteCatchEventRepository {
+    pub fn new(db: Arc<RwLock<SqliteDatabase>>) -> Self {
+        Self { db }
+    }
+}
+
+impl CatchEventRepositoryInterface for SqliteCatchEventRepository {
+    fn rank_users_by_catches_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_catch_events::table
+            .filter(fish_catch_events::caught_at.ge(since))
+            .select(count(fish_catch_events::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let catch_count = count(fish_catch_events::id);
+        let rows = fish_catch_events::table
+            .inner_join(fish_users::table)
+            .filter(fish_catch_events::caught_at.ge(since))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, catch_count))
+            .order((catch_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_users_by_species_caught_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_catch_events::table
+            .filter(fish_catch_events::caught_at.ge(since))
+            .select(count(fish_catch_events::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let species_count = || count(fish_catch_events::species_id).aggregate_distinct();
+        let rows = fish_catch_events::table
+            .inner_join(fish_users::table)
+            .filter(fish_catch_events::caught_at.ge(since))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, species_count()))
+            .order((species_count().desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_by_largest_catch_since(
+        &self,
+        species_id: i32,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard<(CatchEvent, i64)>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_catch_events::table
+            .filter(fish_catch_events::species_id.eq(species_id))
+            .filter(fish_catch_events::caught_at.ge(since))
+            .select(count(fish_catch_events::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let largest_size_ratio = max(fish_catch_events::total_size_ratio);
+        let rows = fish_catch_events::table
+            .inner_join(fish_users::table)
+            .filter(fish_catch_events::species_id.eq(species_id))
+            .filter(fish_catch_events::caught_at.ge(since))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id))
+            .order((largest_size_ratio.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64)>(&mut *connection)?;
+
+        // Of equally large catches, the earliest one holds the record
+        let user_ids: Vec<i64> = rows.iter().map(|(user_id, _)| *user_id).collect();
+        let catches = fish_catch_events::table
+            .filter(fish_catch_events::species_id.eq(species_id))
+            .filter(fish_catch_events::caught_at.ge(since))
+            .filter(fish_catch_events::user_id.eq_any(user_ids))
+            .order((
+                fish_catch_events::total_size_ratio.desc(),
+                fish_catch_events::caught_at.asc(),
+                fish_catch_events::id.asc(),
+            ))
+            .load::<CatchEvent>(&mut *connection)?;
+
+        Ok(Leaderboard {
+            total_entries: total_entries.max(0) as u64,
+            entries: largest_catch_per_row(rows, catches),
+        })
+    }
+}
+
+impl Repository<CatchEvent> for SqliteCatchEventRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewCatchEvent) -> Result<CatchEvent, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let catch_event = diesel::insert_into(fish_catch_events::table)
+            .values((
+                fish_catch_events::user_id.eq(new_entity.user_id),
+                fish_catch_events::species_id.eq(new_entity.species_id),
+                fish_catch_events::specimen_id.eq(new_entity.specimen_id),
+                fish_catch_events::total_size_ratio.eq(new_entity.total_size_ratio),
+                fish_catch_events::size_mm.eq(new_entity.size_mm),
+                fish_catch_events::weight_g.eq(new_entity.weight_g),
+                fish_catch_events::caught_at.eq(new_entity.caught_at),
+            ))
+            .get_result::<CatchEvent>(&mut *connection)?;
+
+        Ok(catch_event)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<CatchEvent>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let catch_event = fish_catch_events::table
+            .find(id)
+            .first::<CatchEvent>(&mut *connection)
+            .optional()?;
+        Ok(catch_event)
+    }
+
+    fn update(
+        &self,
+        entity: CatchEvent,
+        _check_stale: bool,
+    ) -> Result<CatchEvent, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let updated_catch_event = diesel::update(fish_catch_events::table)
+            .filter(fish_catch_events::id.eq(entity.id))
+            .set((
+                fish_catch_events::user_id.eq(entity.user_id),
+                fish_catch_events::species_id.eq(entity.species_id),
+                fish_catch_events::specimen_id.eq(entity.specimen_id),
+                fish_catch_events::total_size_ratio.eq(entity.total_size_ratio),
+                fish_catch_events::size_mm.eq(entity.size_mm),
+                fish_catch_events::weight_g.eq(entity.weight_g),
+                fish_catch_events::caught_at.eq(entity.caught_at),
+            ))
+            .get_result::<CatchEvent>(&mut *connection)
+            .optional()?
+            .ok_or_else(GameDatabaseError::not_found)?;
+
+        Ok(updated_catch_event)
+    }
+
+    fn delete(&self, entity: CatchEvent) -> Result<bool, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let deleted_count = diesel::delete(fish_catch_events::table)
+            .filter(fish_catch_events::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
@@ -0,0 +1,299 @@
+use crate::database::sqlite::schema::{fish_fishing_history_entries, fish_users};
+use crate::database::sqlite::{get_connection, SqliteDatabase};
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::clock::Clock;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::fishing_history_entry_repository::FishingHistoryEntryRepositoryInterface;
+use crate::models::fishing_history_entry::{FishingHistoryEntry, NewFishingHistoryEntry};
+use crate::traits::repository::Repository;
+use diesel::dsl::{count, sum};
+use diesel::prelude::*;
+use diesel::sql_types::Bool;
+use std::sync::{Arc, RwLock};
+
+pub struct SqliteFishingHistoryEntryRepository {
+    db: Arc<RwLock<SqliteDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SqliteFishingHistoryEntryRepository {
+    pub fn new(db: Arc<RwLock<SqliteDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl FishingHistoryEntryRepositoryInterface for SqliteFishingHistoryEntryRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn FishingHistoryEntryRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_user_and_species_id(
+        &self,
+        user_id: i64,
+        species_id: i32,
+    ) -> Result<Option<FishingHistoryEntry>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let entry = fish_fishing_history_entries::table
+            .filter(
+                fish_fishing_history_entries::user_id
+                    .eq(user_id)
+                    .and(fish_fishing_history_entries::species_id.eq(species_id)),
+            )
+            .first::<FishingHistoryEntry>(&mut *connection)
+            .optional()?;
+        Ok(entry)
+    }
+
+    fn find_caught_species_ids_by_user(
+        &self,
+        user_id: i64,
+    ) -> Result<Vec<i32>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let species_ids = fish_fishing_history_entries::table
+            .filter(fish_fishing_history_entries::user_id.eq(user_id))
+            .select(fish_fishing_history_entries::species_id)
+            .load::<i32>(&mut *connection)?;
+
+        Ok(species_ids)
+    }
+
+    fn find_by_user(&self, user_id: i64) -> Result<Vec<FishingHistoryEntry>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let entries = fish_fishing_history_entries::table
+            .filter(fish_fishing_history_entries::user_id.eq(user_id))
+            .order(fish_fishing_history_entries::species_id.asc())
+            .load::<FishingHistoryEntry>(&mut *connection)?;
+
+        Ok(entries)
+    }
+
+    fn rank_users_by_caught_count(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_fishing_history_entries::table
+            .select(count(fish_fishing_history_entries::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let caught_count = sum(fish_fishing_history_entries::caught_count);
+        let rows = fish_fishing_history_entries::table
+            .inner_join(fish_users::table)
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, caught_count))
+            .order((caught_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, Option<i64>)>(&mut *connection)?
+            .into_iter()
+            .map(|(id, external_id, count)| (id, external_id, count.unwrap_or_default()))
+            .collect();
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_users_by_caught_species(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_fishing_history_entries::table
+            .select(count(fish_fishing_history_entries::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let species_count = count(fish_fishing_history_entries::species_id);
+        let rows = fish_fishing_history_entries::table
+            .inner_join(fish_users::table)
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, species_count))
+            .order((species_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_users_by_sold_count(
+        &self,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_fishing_history_entries::table
+            .filter(fish_fishing_history_entries::sold_count.gt(0))
+            .select(count(fish_fishing_history_entries::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let sold_count = sum(fish_fishing_history_entries::sold_count);
+        let rows = fish_fishing_history_entries::table
+            .inner_join(fish_users::table)
+            .filter(fish_fishing_history_entries::sold_count.gt(0))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, sold_count))
+            .order((sold_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, Option<i64>)>(&mut *connection)?
+            .into_iter()
+            .map(|(id, external_id, count)| (id, external_id, count.unwrap_or_default()))
+            .collect();
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+
+    fn rank_by_largest_catch(
+        &self,
+        species_id: i32,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard<(FishingHistoryEntry, i64)>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_fishing_history_entries::table
+            .filter(fish_fishing_history_entries::species_id.eq(species_id))
+            .count()
+            .get_result::<i64>(&mut *connection)?;
+        let entries = fish_fishing_history_entries::table
+            .inner_join(fish_users::table)
+            .filter(fish_fishing_history_entries::species_id.eq(species_id))
+            .select((
+                fish_fishing_history_entries::all_columns,
+                fish_users::external_id,
+            ))
+            .order((
+                fish_fishing_history_entries::largest_catch_size_ratio.desc(),
+                fish_fishing_history_entries::user_id.asc(),
+            ))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(FishingHistoryEntry, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard {
+            total_entries: total_entries.max(0) as u64,
+            entries,
+        })
+    }
+}
+
+impl Repository<FishingHistoryEntry> for SqliteFishingHistoryEntryRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(
+        &self,
+        new_entity: NewFishingHistoryEntry,
+    ) -> Result<FishingHistoryEntry, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let now = self.clock.now();
+
+        let new_result = diesel::insert_into(fish_fishing_history_entries::table)
+            .values((
+                fish_fishing_history_entries::user_id.eq(new_entity.user_id),
+                fish_fishing_history_entries::species_id.eq(new_entity.species_id),
+                fish_fishing_history_entries::created_at.eq(now),
+                fish_fishing_history_entries::updated_at.eq(now),
+                fish_fishing_history_entries::caught_count.eq(new_entity.caught_count),
+                fish_fishing_history_entries::sold_count.eq(new_entity.sold_count),
+                fish_fishing_history_entries::smallest_catch_size_ratio
+                    .eq(new_entity.smallest_catch_size_ratio),
+                fish_fishing_history_entries::largest_catch_size_ratio
+                    .eq(new_entity.largest_catch_size_ratio),
+                fish_fishing_history_entries::last_catch.eq(now),
+                fish_fishing_history_entries::smallest_catch_specimen_id
+                    .eq(new_entity.smallest_catch_specimen_id),
+                fish_fishing_history_entries::smallest_catch_size_mm
+                    .eq(new_entity.smallest_catch_size_mm),
+                fish_fishing_history_entries::smallest_catch_weight_g
+                    .eq(new_entity.smallest_catch_weight_g),
+                fish_fishing_history_entries::smallest_catch_time
+                    .eq(new_entity.smallest_catch_time),
+                fish_fishing_history_entries::largest_catch_specimen_id
+                    .eq(new_entity.largest_catch_specimen_id),
+                fish_fishing_history_entries::largest_catch_size_mm
+                    .eq(new_entity.largest_catch_size_mm),
+                fish_fishing_history_entries::largest_catch_weight_g
+                    .eq(new_entity.largest_catch_weight_g),
+                fish_fishing_history_entries::largest_catch_time.eq(new_entity.largest_catch_time),
+            ))
+            .get_result::<FishingHistoryEntry>(&mut *connection)?;
+
+        Ok(new_result)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<FishingHistoryEntry>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let result = fish_fishing_history_entries::table
+            .find(id)
+            .first::<FishingHistoryEntry>(&mut *connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    fn update(
+        &self,
+        entity: FishingHistoryEntry,
+        check_stale: bool,
+    ) -> Result<FishingHistoryEntry, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
+        let update_result = diesel::update(fish_fishing_history_entries::table)
+            .filter(fish_fishing_history_entries::id.eq(entity.id))
+            .filter(
+                fish_fishing_history_entries::version
+                    .eq(entity.version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((
+                fish_fishing_history_entries::user_id.eq(entity.user_id),
+                fish_fishing_history_entries::species_id.eq(entity.species_id),
+                fish_fishing_history_entries::created_at.eq(entity.created_at),
+                fish_fishing_history_entries::updated_at.eq(self.clock.now()),
+                fish_fishing_history_entries::version.eq(fish_fishing_history_entries::version + 1),
+                fish_fishing_history_entries::caught_count.eq(entity.caught_count),
+                fish_fishing_history_entries::sold_count.eq(entity.sold_count),
+                fish_fishing_history_entries::smallest_catch_size_ratio
+                    .eq(entity.smallest_catch_size_ratio),
+                fish_fishing_history_entries::largest_catch_size_ratio
+                    .eq(entity.largest_catch_size_ratio),
+                fish_fishing_history_entries::last_catch.eq(entity.last_catch),
+                fish_fishing_history_entries::first_sell.eq(entity.first_sell),
+                fish_fishing_history_entries::last_sell.eq(entity.last_sell),
+                fish_fishing_history_entries::smallest_catch_specimen_id
+                    .eq(entity.smallest_catch_specimen_id),
+                fish_fishing_history_entries::smallest_catch_size_mm
+                    .eq(entity.smallest_catch_size_mm),
+                fish_fishing_history_entries::smallest_catch_weight_g
+                    .eq(entity.smallest_catch_weight_g),
+                fish_fishing_history_entries::smallest_catch_time.eq(entity.smallest_catch_time),
+                fish_fishing_history_entries::largest_catch_specimen_id
+                    .eq(entity.largest_catch_specimen_id),
+                fish_fishing_history_entries::largest_catch_size_mm
+                    .eq(entity.largest_catch_size_mm),
+                fish_fishing_history_entries::largest_catch_weight_g
+                    .eq(entity.largest_catch_weight_g),
+                fish_fishing_history_entries::largest_catch_time.eq(entity.largest_catch_time),
+            ))
+            .get_result::<FishingHistoryEntry>(&mut *connection)
+            .optional()?;
+        drop(connection);
+
+        update_result.ok_or_else(|| self.save_conflict_error(entity.id))
+    }
+
+    fn delete(&self, entity: FishingHistoryEntry) -> Result<bool, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let deleted_count = diesel::delete(fish_fishing_history_entries::table)
+            .filter(fish_fishing_history_entries::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::database::sqlite::schema::fish_items;
+use crate::database::sqlite::{get_connection, SqliteDatabase};
+use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::item_repository::ItemRepositoryInterface;
+use crate::models::item::{Item, NewItem};
+use crate::traits::repository::Repository;
+use diesel::prelude::*;
+use diesel::sql_types::Bool;
+use std::sync::{Arc, RwLock};
+
+pub struct SqliteItemRepository {
+    db: Arc<RwLock<SqliteDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SqliteItemRepository {
+    pub fn new(db: Arc<RwLock<SqliteDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl ItemRepositoryInterface for SqliteItemRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn ItemRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_user(&self, user_id: i64) -> Result<Vec<Item>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let results = fish_items::table
+            .filter(fish_items::user_id.eq(user_id))
+            .load::<Item>(&mut *connection)?;
+
+        Ok(results)
+    }
+
+    fn find_by_type_and_user(
+        &self,
+        type_id: i32,
+        user_id: i64,
+    ) -> Result<Vec<Item>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let results = fish_items::table
+            .filter(
+                fish_items::type_id
+                    .eq(type_id)
+                    .and(fish_items::user_id.eq(user_id)),
+            )
+            .load::<Item>(&mut *connection)?;
+
+        Ok(results)
+    }
+}
+
+impl Repository<Item> for SqliteItemRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewItem) -> Result<Item, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let now = self.clock.now();
+
+        let new_result = diesel::insert_into(fish_items::table)
+            .values((
+                fish_items::user_id.eq(new_entity.user_id),
+                fish_items::type_id.eq(new_entity.type_id),
+                fish_items::properties.eq(new_entity.properties),
+                fish_items::created_at.eq(now),
+                fish_items::updated_at.eq(now),
+            ))
+            .get_result::<Item>(&mut *connection)?;
+
+        Ok(new_result)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<Item>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let item = fish_items::table
+            .find(id)
+            .first::<Item>(&mut *connection)
+            .optional()?;
+        Ok(item)
+    }
+
+    fn update(&self, entity: Item, check_stale: bool) -> Result<Item, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
+        let updated_item = diesel::update(fish_items::table)
+            .filter(fish_items::id.eq(entity.id))
+            .filter(
+                fish_items::version
+                    .eq(entity.version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((
+                fish_items::user_id.eq(entity.user_id),
+                fish_items::type_id.eq(entity.type_id),
+                fish_items::properties.eq(&entity.properties),
+                fish_items::created_at.eq(entity.created_at),
+                fish_items::updated_at.eq(self.clock.now()),
+                fish_items::version.eq(fish_items::version + 1),
+            ))
+            .get_result::<Item>(&mut *connection)
+            .optional()?;
+        drop(connection);
+
+        updated_item.ok_or_else(|| self.save_conflict_error(entity.id))
+    }
+
+    fn delete(&self, entity: Item) -> Result<bool, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let deleted_count = diesel::delete(fish_items::table)
+            .filter(fish_items::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
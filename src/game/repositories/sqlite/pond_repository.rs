@@ -0,0 +1,110 @@
+use crate::database::sqlite::schema::fish_ponds;
+use crate::database::sqlite::{get_connection, SqliteDatabase};
+use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::pond_repository::PondRepositoryInterface;
+use crate::models::pond::{NewPond, Pond};
+use crate::models::user::User;
+use crate::traits::repository::Repository;
+use diesel::prelude::*;
+use diesel::sql_types::Bool;
+use std::sync::{Arc, RwLock};
+
+pub struct SqlitePondRepository {
+    db: Arc<RwLock<SqliteDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SqlitePondRepository {
+    pub fn new(db: Arc<RwLock<SqliteDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl PondRepositoryInterface for SqlitePondRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn PondRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_user(&self, owner_user: &User) -> Result<Vec<Pond>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let pond = fish_ponds::table
+            .filter(fish_ponds::user_id.eq(owner_user.id))
+            .load::<Pond>(&mut *connection)?;
+
+        Ok(pond)
+    }
+
+    /// SQLite has no row locks, the transactions already run one after another
+    fn find_for_update(&self, id: i64) -> Result<Option<Pond>, GameRepositoryError> {
+        self.find(id)
+    }
+}
+
+impl Repository<Pond> for SqlitePondRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewPond) -> Result<Pond, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let now = self.clock.now();
+
+        let new_result = diesel::insert_into(fish_ponds::table)
+            .values((
+                fish_ponds::user_id.eq(new_entity.user_id),
+                fish_ponds::capacity.eq(new_entity.capacity),
+                fish_ponds::created_at.eq(now),
+                fish_ponds::updated_at.eq(now),
+            ))
+            .get_result::<Pond>(&mut *connection)?;
+
+        Ok(new_result)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<Pond>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let pond = fish_ponds::table
+            .find(id)
+            .first::<Pond>(&mut *connection)
+            .optional()?;
+        Ok(pond)
+    }
+
+    fn update(&self, entity: Pond, check_stale: bool) -> Result<Pond, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
+        let updated_pond = diesel::update(fish_ponds::table)
+            .filter(fish_ponds::id.eq(entity.id))
+            .filter(
+                fish_ponds::version
+                    .eq(entity.version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((
+                fish_ponds::user_id.eq(entity.user_id),
+                fish_ponds::created_at.eq(entity.created_at),
+                fish_ponds::updated_at.eq(self.clock.now()),
+                fish_ponds::version.eq(fish_ponds::version + 1),
+                fish_ponds::capacity.eq(entity.capacity),
+            ))
+            .get_result::<Pond>(&mut *connection)
+            .optional()?;
+        drop(connection);
+
+        updated_pond.ok_or_else(|| self.save_conflict_error(entity.id))
+    }
+
+    fn delete(&self, entity: Pond) -> Result<bool, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let deleted_count = diesel::delete(fish_ponds::table)
+            .filter(fish_ponds::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
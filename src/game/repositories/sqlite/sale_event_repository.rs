@@ -0,0 +1,113 @@
+use crate::database::sqlite::schema::{fish_sale_events, fish_users};
+use crate::database::sqlite::{get_connection, SqliteDatabase};
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::errors::database::GameDatabaseError;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::sale_event_repository::SaleEventRepositoryInterface;
+use crate::models::sale_event::{NewSaleEvent, SaleEvent};
+use crate::traits::repository::Repository;
+use chrono::{DateTime, Utc};
+use diesel::dsl::count;
+use diesel::prelude::*;
+use std::sync::{Arc, RwLock};
+
+pub struct SqliteSaleEventRepository {
+    db: Arc<RwLock<SqliteDatabase>>,
+}
+
+impl SqliteSaleEventRepository {
+    pub fn new(db: Arc<RwLock<SqliteDatabase>>) -> Self {
+        Self { db }
+    }
+}
+
+impl SaleEventRepositoryInterface for SqliteSaleEventRepository {
+    fn rank_users_by_sales_since(
+        &self,
+        since: DateTime<Utc>,
+        page: LeaderboardPage,
+    ) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_sale_events::table
+            .filter(fish_sale_events::sold_at.ge(since))
+            .select(count(fish_sale_events::user_id).aggregate_distinct())
+            .first::<i64>(&mut *connection)?;
+
+        let sale_count = count(fish_sale_events::id);
+        let rows = fish_sale_events::table
+            .inner_join(fish_users::table)
+            .filter(fish_sale_events::sold_at.ge(since))
+            .group_by((fish_users::id, fish_users::external_id))
+            .select((fish_users::id, fish_users::external_id, sale_count))
+            .order((sale_count.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+}
+
+impl Repository<SaleEvent> for SqliteSaleEventRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewSaleEvent) -> Result<SaleEvent, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let sale_event = diesel::insert_into(fish_sale_events::table)
+            .values((
+                fish_sale_events::user_id.eq(new_entity.user_id),
+                fish_sale_events::species_id.eq(new_entity.species_id),
+                fish_sale_events::specimen_id.eq(new_entity.specimen_id),
+                fish_sale_events::sold_at.eq(new_entity.sold_at),
+            ))
+            .get_result::<SaleEvent>(&mut *connection)?;
+
+        Ok(sale_event)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<SaleEvent>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let sale_event = fish_sale_events::table
+            .find(id)
+            .first::<SaleEvent>(&mut *connection)
+            .optional()?;
+        Ok(sale_event)
+    }
+
+    fn update(
+        &self,
+        entity: SaleEvent,
+        _check_stale: bool,
+    ) -> Result<SaleEvent, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let updated_sale_event = diesel::update(fish_sale_events::table)
+            .filter(fish_sale_events::id.eq(entity.id))
+            .set((
+                fish_sale_events::user_id.eq(entity.user_id),
+                fish_sale_events::species_id.eq(entity.species_id),
+                fish_sale_events::specimen_id.eq(entity.specimen_id),
+                fish_sale_events::sold_at.eq(entity.sold_at),
+            ))
+            .get_result::<SaleEvent>(&mut *connection)
+            .optional()?
+            .ok_or_else(GameDatabaseError::not_found)?;
+
+        Ok(updated_sale_event)
+    }
+
+    fn delete(&self, entity: SaleEvent) -> Result<bool, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let deleted_count = diesel::delete(fish_sale_events::table)
+            .filter(fish_sale_events::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::database::sqlite::schema::fish_specimens;
+use crate::database::sqlite::{get_connection, SqliteDatabase};
+use crate::database::DatabaseInterface;
+use crate::game::clock::Clock;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::specimen_repository::SpecimenRepositoryInterface;
+use crate::models::specimen::{NewSpecimen, Specimen};
+use crate::models::user::User;
+use crate::traits::repository::Repository;
+use diesel::prelude::*;
+use diesel::sql_types::Bool;
+use std::sync::{Arc, RwLock};
+
+pub struct SqliteSpecimenRepository {
+    db: Arc<RwLock<SqliteDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SqliteSpecimenRepository {
+    pub fn new(db: Arc<RwLock<SqliteDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl SpecimenRepositoryInterface for SqliteSpecimenRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn SpecimenRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_user(&self, owner_user: &User) -> Result<Vec<Specimen>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let specimens = fish_specimens::table
+            .filter(fish_specimens::user_id.eq(owner_user.id))
+            .load::<Specimen>(&mut *connection)?;
+
+        Ok(specimens)
+    }
+
+    fn find_by_user_and_species(
+        &self,
+        owner_user: &User,
+        species_id: i32,
+    ) -> Result<Vec<Specimen>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let specimens = fish_specimens::table
+            .filter(fish_specimens::user_id.eq(owner_user.id))
+            .filter(fish_specimens::species_id.eq(species_id))
+            .load::<Specimen>(&mut *connection)?;
+
+        Ok(specimens)
+    }
+
+    fn find_by_pond(&self, pond_id: i64) -> Result<Vec<Specimen>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let specimens = fish_specimens::table
+            .filter(fish_specimens::pond_id.eq(pond_id))
+            .order(fish_specimens::id)
+            .load::<Specimen>(&mut *connection)?;
+
+        Ok(specimens)
+    }
+
+    fn count_by_pond(&self, pond_id: i64) -> Result<i64, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let count = fish_specimens::table
+            .filter(fish_specimens::pond_id.eq(pond_id))
+            .count()
+            .get_result::<i64>(&mut *connection)?;
+
+        Ok(count)
+    }
+
+    fn delete_many(
+        &self,
+        owner_user: &User,
+        ids: &[i64],
+    ) -> Result<Vec<Specimen>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let deleted_specimens = diesel::delete(fish_specimens::table)
+            .filter(fish_specimens::user_id.eq(owner_user.id))
+            .filter(fish_specimens::id.eq_any(ids))
+            .get_results::<Specimen>(&mut *connection)?;
+
+        Ok(deleted_specimens)
+    }
+}
+
+impl Repository<Specimen> for SqliteSpecimenRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewSpecimen) -> Result<Specimen, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let now = self.clock.now();
+
+        let new_result = diesel::insert_into(fish_specimens::table)
+            .values((
+                fish_specimens::user_id.eq(new_entity.user_id),
+                fish_specimens::species_id.eq(new_entity.species_id),
+                fish_specimens::created_at.eq(now),
+                fish_specimens::updated_at.eq(now),
+                fish_specimens::size_baby_ratio.eq(new_entity.size_baby_ratio),
+                fish_specimens::size_adult_ratio.eq(new_entity.size_adult_ratio),
+                fish_specimens::lifespan_days_ratio.eq(new_entity.lifespan_days_ratio),
+                fish_specimens::catch_age.eq(new_entity.catch_age),
+                fish_specimens::catch_seed.eq(new_entity.catch_seed),
+            ))
+            .get_result::<Specimen>(&mut *connection)?;
+
+        Ok(new_result)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<Specimen>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let specimen = fish_specimens::table
+            .find(id)
+            .first::<Specimen>(&mut *connection)
+            .optional()?;
+        Ok(specimen)
+    }
+
+    fn update(&self, entity: Specimen, check_stale: bool) -> Result<Specimen, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
+        let updated_specimen = diesel::update(fish_specimens::table)
+            .filter(fish_specimens::id.eq(entity.id))
+            .filter(
+                fish_specimens::version
+                    .eq(entity.version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((
+                fish_specimens::user_id.eq(entity.user_id),
+                fish_specimens::species_id.eq(entity.species_id),
+                fish_specimens::created_at.eq(entity.created_at),
+                fish_specimens::updated_at.eq(self.clock.now()),
+                fish_specimens::version.eq(fish_specimens::version + 1),
+                fish_specimens::size_baby_ratio.eq(entity.size_baby_ratio),
+                fish_specimens::size_adult_ratio.eq(entity.size_adult_ratio),
+                fish_specimens::lifespan_days_ratio.eq(entity.lifespan_days_ratio),
+                fish_specimens::catch_age.eq(entity.catch_age),
+                fish_specimens::pond_id.eq(entity.pond_id),
+                fish_specimens::catch_seed.eq(entity.catch_seed),
+            ))
+            .get_result::<Specimen>(&mut *connection)
+            .optional()?;
+        drop(connection);
+
+        updated_specimen.ok_or_else(|| self.save_conflict_error(entity.id))
+    }
+
+    fn delete(&self, entity: Specimen) -> Result<bool, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let deleted_count = diesel::delete(fish_specimens::table)
+            .filter(fish_specimens::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
@@ -0,0 +1,234 @@
+use crate::database::sqlite::schema::{fish_user_achievements, fish_user_locations, fish_users};
+use crate::database::sqlite::{get_connection, SqliteDatabase};
+use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::clock::Clock;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::repositories::user_repository::UserRepositoryInterface;
+use crate::models::user::{NewUser, User};
+use crate::models::user_achievement::UserAchievement;
+use crate::models::user_location::UserLocation;
+use crate::traits::repository::Repository;
+use diesel::prelude::*;
+use diesel::sql_types::Bool;
+use std::sync::{Arc, RwLock};
+
+pub struct SqliteUserRepository {
+    db: Arc<RwLock<SqliteDatabase>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SqliteUserRepository {
+    pub fn new(db: Arc<RwLock<SqliteDatabase>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl UserRepositoryInterface for SqliteUserRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn UserRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
+    fn find_by_external_id(&self, external_id: i64) -> Result<Option<User>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let user = fish_users::table
+            .filter(fish_users::external_id.eq(external_id))
+            .first::<User>(&mut *connection)
+            .optional()?;
+        Ok(user)
+    }
+
+    fn find_unlocked_locations(&self, id: i64) -> Result<Vec<UserLocation>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let locations = fish_user_locations::table
+            .filter(fish_user_locations::user_id.eq(id))
+            .load::<UserLocation>(&mut *connection)?;
+
+        Ok(locations)
+    }
+
+    fn find_unlocked_location_ids(&self, id: i64) -> Result<Vec<i32>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let locations = fish_user_locations::table
+            .filter(fish_user_locations::user_id.eq(id))
+            .select(fish_user_locations::location_id)
+            .load::<i32>(&mut *connection)?;
+
+        Ok(locations)
+    }
+
+    fn unlock_location(
+        &self,
+        id: i64,
+        location_id: i32,
+    ) -> Result<UserLocation, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let user_location = diesel::insert_into(fish_user_locations::table)
+            .values((
+                fish_user_locations::user_id.eq(id),
+                fish_user_locations::location_id.eq(location_id),
+                fish_user_locations::unlocked_at.eq(self.clock.now()),
+            ))
+            .get_result(&mut *connection)?;
+        Ok(user_location)
+    }
+
+    fn find_achievements(&self, id: i64) -> Result<Vec<UserAchievement>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let achievements = fish_user_achievements::table
+            .filter(fish_user_achievements::user_id.eq(id))
+            .order(fish_user_achievements::achievement_id.asc())
+            .load::<UserAchievement>(&mut *connection)?;
+
+        Ok(achievements)
+    }
+
+    fn find_unlocked_achievement_ids(&self, id: i64) -> Result<Vec<i32>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let achievement_ids = fish_user_achievements::table
+            .filter(fish_user_achievements::user_id.eq(id))
+            .select(fish_user_achievements::achievement_id)
+            .load::<i32>(&mut *connection)?;
+
+        Ok(achievement_ids)
+    }
+
+    fn unlock_achievement(
+        &self,
+        id: i64,
+        achievement_id: i32,
+    ) -> Result<Option<UserAchievement>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let user_achievement = diesel::insert_into(fish_user_achievements::table)
+            .values((
+                fish_user_achievements::user_id.eq(id),
+                fish_user_achievements::achievement_id.eq(achievement_id),
+                fish_user_achievements::unlocked_at.eq(self.clock.now()),
+            ))
+            .on_conflict_do_nothing()
+            .get_result(&mut *connection)
+            .optional()?;
+        Ok(user_achievement)
+    }
+
+    fn add_credits(&self, id: i64, amount: i64) -> Result<User, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let updated_user = diesel::update(fish_users::table)
+            .filter(fish_users::id.eq(id))
+            .set((
+                fish_users::credits.eq(fish_users::credits + amount),
+                fish_users::updated_at.eq(self.clock.now()),
+                fish_users::version.eq(fish_users::version + 1),
+            ))
+            .get_result::<User>(&mut *connection)?;
+
+        Ok(updated_user)
+    }
+
+    fn remove_credits(&self, id: i64, amount: i64) -> Result<Option<User>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let updated_user = diesel::update(fish_users::table)
+            .filter(fish_users::id.eq(id))
+            .filter(fish_users::credits.ge(amount))
+            .set((
+                fish_users::credits.eq(fish_users::credits - amount),
+                fish_users::updated_at.eq(self.clock.now()),
+                fish_users::version.eq(fish_users::version + 1),
+            ))
+            .get_result::<User>(&mut *connection)
+            .optional()?;
+
+        Ok(updated_user)
+    }
+
+    fn rank_by_credits(&self, page: LeaderboardPage) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let total_entries = fish_users::table
+            .count()
+            .get_result::<i64>(&mut *connection)?;
+
+        let rows = fish_users::table
+            .select((fish_users::id, fish_users::external_id, fish_users::credits))
+            .order((fish_users::credits.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
+}
+
+impl Repository<User> for SqliteUserRepository {
+    fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
+        self.db.clone()
+    }
+
+    fn create(&self, new_entity: NewUser) -> Result<User, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let now = self.clock.now();
+
+        let new_result = diesel::insert_into(fish_users::table)
+            .values((
+                fish_users::external_id.eq(new_entity.external_id),
+                fish_users::created_at.eq(now),
+                fish_users::updated_at.eq(now),
+            ))
+            .get_result::<User>(&mut *connection)?;
+
+        Ok(new_result)
+    }
+
+    fn find(&self, id: i64) -> Result<Option<User>, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+        let user = fish_users::table
+            .find(id)
+            .first::<User>(&mut *connection)
+            .optional()?;
+        Ok(user)
+    }
+
+    fn update(&self, entity: User, check_stale: bool) -> Result<User, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
+        let updated_user = diesel::update(fish_users::table)
+            .filter(fish_users::id.eq(entity.id))
+            .filter(
+                fish_users::version
+                    .eq(entity.version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((
+                fish_users::external_id.eq(entity.external_id),
+                fish_users::credits.eq(entity.credits),
+                fish_users::created_at.eq(entity.created_at),
+                fish_users::updated_at.eq(self.clock.now()),
+                fish_users::version.eq(fish_users::version + 1),
+                fish_users::timezone.eq(&entity.timezone),
+            ))
+            .get_result::<User>(&mut *connection)
+            .optional()?;
+        drop(connection);
+
+        updated_user.ok_or_else(|| self.save_conflict_error(entity.id))
+    }
+
+    fn delete(&self, entity: User) -> Result<bool, GameRepositoryError> {
+        let mut connection = get_connection(&self.db)?;
+
+        let deleted_count = diesel::delete(fish_users::table)
+            .filter(fish_users::id.eq(entity.id))
+            .execute(&mut *connection)?;
+
+        Ok(deleted_count > 0)
+    }
+}
@@ -1,14 +1,19 @@
 use crate::database::DatabaseInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage};
+use crate::game::clock::Clock;
 use crate::game::errors::repository::GameRepositoryError;
 use crate::models::user::{NewUser, User};
+use crate::models::user_achievement::{NewUserAchievement, UserAchievement};
 use crate::models::user_location::{NewUserLocation, UserLocation};
-use crate::schema::{fish_user_locations, fish_users};
+use crate::schema::{fish_user_achievements, fish_user_locations, fish_users};
 use crate::traits::repository::Repository;
-use chrono::Utc;
 use diesel::prelude::*;
+use diesel::sql_types::Bool;
 use std::sync::{Arc, RwLock};
 
 pub trait UserRepositoryInterface: Repository<User> + Send + Sync {
+    /// The same repository, persisting the timestamps of the given clock
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn UserRepositoryInterface>;
     fn find_by_external_id(&self, external_id: i64) -> Result<Option<User>, GameRepositoryError>;
     fn find_unlocked_locations(&self, id: i64) -> Result<Vec<UserLocation>, GameRepositoryError>;
     fn find_unlocked_location_ids(&self, id: i64) -> Result<Vec<i32>, GameRepositoryError>;
@@ -17,24 +22,43 @@ pub trait UserRepositoryInterface: Repository<User> + Send + Sync {
         id: i64,
         location_id: i32,
     ) -> Result<UserLocation, GameRepositoryError>;
+    fn find_achievements(&self, id: i64) -> Result<Vec<UserAchievement>, GameRepositoryError>;
+    fn find_unlocked_achievement_ids(&self, id: i64) -> Result<Vec<i32>, GameRepositoryError>;
+    /// Unlocks the achievement for the user.
+    /// Returns None if the user already had it unlocked.
+    fn unlock_achievement(
+        &self,
+        id: i64,
+        achievement_id: i32,
+    ) -> Result<Option<UserAchievement>, GameRepositoryError>;
+    fn add_credits(&self, id: i64, amount: i64) -> Result<User, GameRepositoryError>;
+    /// Only removes the credits if the user has enough, returns None otherwise
+    fn remove_credits(&self, id: i64, amount: i64) -> Result<Option<User>, GameRepositoryError>;
+    /// Users ranked by their current credits
+    fn rank_by_credits(&self, page: LeaderboardPage) -> Result<Leaderboard, GameRepositoryError>;
 }
 
 pub struct UserRepository {
     db: Arc<RwLock<dyn DatabaseInterface>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl UserRepository {
-    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RwLock<dyn DatabaseInterface>>, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
     }
 }
 
 impl UserRepositoryInterface for UserRepository {
+    fn with_clock(&self, clock: Arc<dyn Clock>) -> Arc<dyn UserRepositoryInterface> {
+        Arc::new(Self::new(self.db.clone(), clock))
+    }
+
     fn find_by_external_id(&self, external_id: i64) -> Result<Option<User>, GameRepositoryError> {
         let mut connection = self.get_connection()?;
         let user = fish_users::table
             .filter(fish_users::external_id.eq(external_id))
-            .first::<User>(&mut connection)
+            .first::<User>(&mut *connection)
             .optional()?;
         Ok(user)
     }
@@ -44,7 +68,7 @@ impl UserRepositoryInterface for UserRepository {
 
         let locations = fish_user_locations::table
             .filter(fish_user_locations::user_id.eq(id))
-            .load::<UserLocation>(&mut connection)?;
+            .load::<UserLocation>(&mut *connection)?;
 
         Ok(locations)
     }
@@ -55,7 +79,7 @@ impl UserRepositoryInterface for UserRepository {
         let locations = fish_user_locations::table
             .filter(fish_user_locations::user_id.eq(id))
             .select(fish_user_locations::location_id)
-            .load::<i32>(&mut connection)?;
+            .load::<i32>(&mut *connection)?;
 
         Ok(locations)
     }
@@ -68,13 +92,108 @@ impl UserRepositoryInterface for UserRepository {
         let mut connection = self.get_connection()?;
 
         let user_location = diesel::insert_into(fish_user_locations::table)
-            .values(NewUserLocation {
-                user_id: id,
-                location_id,
-            })
-            .get_result(&mut connection)?;
+            .values((
+                NewUserLocation {
+                    user_id: id,
+                    location_id,
+                },
+                fish_user_locations::unlocked_at.eq(self.clock.now()),
+            ))
+            .get_result(&mut *connection)?;
         Ok(user_location)
     }
+
+    fn find_achievements(&self, id: i64) -> Result<Vec<UserAchievement>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let achievements = fish_user_achievements::table
+            .filter(fish_user_achievements::user_id.eq(id))
+            .order(fish_user_achievements::achievement_id.asc())
+            .load::<UserAchievement>(&mut *connection)?;
+
+        Ok(achievements)
+    }
+
+    fn find_unlocked_achievement_ids(&self, id: i64) -> Result<Vec<i32>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let achievement_ids = fish_user_achievements::table
+            .filter(fish_user_achievements::user_id.eq(id))
+            .select(fish_user_achievements::achievement_id)
+            .load::<i32>(&mut *connection)?;
+
+        Ok(achievement_ids)
+    }
+
+    fn unlock_achievement(
+        &self,
+        id: i64,
+        achievement_id: i32,
+    ) -> Result<Option<UserAchievement>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let user_achievement = diesel::insert_into(fish_user_achievements::table)
+            .values((
+                NewUserAchievement {
+                    user_id: id,
+                    achievement_id,
+                },
+                fish_user_achievements::unlocked_at.eq(self.clock.now()),
+            ))
+            .on_conflict_do_nothing()
+            .get_result(&mut *connection)
+            .optional()?;
+        Ok(user_achievement)
+    }
+
+    fn add_credits(&self, id: i64, amount: i64) -> Result<User, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let updated_user = diesel::update(fish_users::table)
+            .filter(fish_users::id.eq(id))
+            .set((
+                fish_users::credits.eq(fish_users::credits + amount),
+                fish_users::updated_at.eq(self.clock.now()),
+                fish_users::version.eq(fish_users::version + 1),
+            ))
+            .get_result::<User>(&mut *connection)?;
+
+        Ok(updated_user)
+    }
+
+    fn remove_credits(&self, id: i64, amount: i64) -> Result<Option<User>, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let updated_user = diesel::update(fish_users::table)
+            .filter(fish_users::id.eq(id))
+            .filter(fish_users::credits.ge(amount))
+            .set((
+                fish_users::credits.eq(fish_users::credits - amount),
+                fish_users::updated_at.eq(self.clock.now()),
+                fish_users::version.eq(fish_users::version + 1),
+            ))
+            .get_result::<User>(&mut *connection)
+            .optional()?;
+
+        Ok(updated_user)
+    }
+
+    fn rank_by_credits(&self, page: LeaderboardPage) -> Result<Leaderboard, GameRepositoryError> {
+        let mut connection = self.get_connection()?;
+
+        let total_entries = fish_users::table
+            .count()
+            .get_result::<i64>(&mut *connection)?;
+
+        let rows = fish_users::table
+            .select((fish_users::id, fish_users::external_id, fish_users::credits))
+            .order((fish_users::credits.desc(), fish_users::id.asc()))
+            .offset(page.offset())
+            .limit(page.limit())
+            .load::<(i64, i64, i64)>(&mut *connection)?;
+
+        Ok(Leaderboard::from_rows(total_entries, page, rows))
+    }
 }
 
 impl Repository<User> for UserRepository {
@@ -84,10 +203,15 @@ impl Repository<User> for UserRepository {
 
     fn create(&self, new_entity: NewUser) -> Result<User, GameRepositoryError> {
         let mut connection = self.get_connection()?;
+        let now = self.clock.now();
 
         let new_result = diesel::insert_into(fish_users::table)
-            .values(new_entity)
-            .get_result::<User>(&mut connection)?;
+            .values((
+                new_entity,
+                fish_users::created_at.eq(now),
+                fish_users::updated_at.eq(now),
+            ))
+            .get_result::<User>(&mut *connection)?;
 
         Ok(new_result)
     }
@@ -96,21 +220,31 @@ impl Repository<User> for UserRepository {
         let mut connection = self.get_connection()?;
         let user = fish_users::table
             .find(id)
-            .first::<User>(&mut connection)
+            .first::<User>(&mut *connection)
             .optional()?;
         Ok(user)
     }
 
-    fn save(&self, mut entity: User) -> Result<User, GameRepositoryError> {
+    fn update(&self, mut entity: User, check_stale: bool) -> Result<User, GameRepositoryError> {
         let mut connection = self.get_connection()?;
-        entity.updated_at = Utc::now();
+        let id = entity.id;
+        let loaded_version = entity.version;
+        entity.updated_at = self.clock.now();
 
+        // Unless the check is skipped, only update the row if nobody else saved it since it was loaded
         let updated_user = diesel::update(fish_users::table)
-            .filter(fish_users::id.eq(entity.id))
-            .set(entity)
-            .get_result::<User>(&mut connection)?;
+            .filter(fish_users::id.eq(id))
+            .filter(
+                fish_users::version
+                    .eq(loaded_version)
+                    .or((!check_stale).into_sql::<Bool>()),
+            )
+            .set((entity, fish_users::version.eq(fish_users::version + 1)))
+            .get_result::<User>(&mut *connection)
+            .optional()?;
+        drop(connection);
 
-        Ok(updated_user)
+        updated_user.ok_or_else(|| self.save_conflict_error(id))
     }
 
     fn delete(&self, entity: User) -> Result<bool, GameRepositoryError> {
@@ -118,7 +252,7 @@ impl Repository<User> for UserRepository {
 
         let deleted_count = diesel::delete(fish_users::table)
             .filter(fish_users::id.eq(entity.id))
-            .execute(&mut connection)?;
+            .execute(&mut *connection)?;
 
         Ok(deleted_count > 0)
     }
@@ -1,22 +1,56 @@
 use crate::config::ConfigInterface;
+#[cfg(feature = "in-memory")]
+use crate::database::memory::MemoryDatabase;
+#[cfg(feature = "sqlite")]
+use crate::database::sqlite::SqliteDatabase;
 use crate::database::DatabaseInterface;
 use crate::game::asset_server::{AssetServer, AssetServerInterface};
+use crate::game::clock::{Clock, SystemClock};
+use crate::game::event_bus::{EventBus, EventBusInterface};
+use crate::game::game_time::GameTime;
+use crate::game::random_source::{RandomSource, ThreadRandomSource};
+use crate::game::repositories::catch_event_repository::{
+    CatchEventRepository, CatchEventRepositoryInterface,
+};
 use crate::game::repositories::fishing_history_entry_repository::{
     FishingHistoryEntryRepository, FishingHistoryEntryRepositoryInterface,
 };
 use crate::game::repositories::item_repository::{ItemRepository, ItemRepositoryInterface};
+#[cfg(feature = "in-memory")]
+use crate::game::repositories::memory::{
+    catch_event_repository::MemoryCatchEventRepository,
+    fishing_history_entry_repository::MemoryFishingHistoryEntryRepository,
+    item_repository::MemoryItemRepository, pond_repository::MemoryPondRepository,
+    sale_event_repository::MemorySaleEventRepository,
+    specimen_repository::MemorySpecimenRepository, user_repository::MemoryUserRepository,
+};
 use crate::game::repositories::pond_repository::{PondRepository, PondRepositoryInterface};
+use crate::game::repositories::sale_event_repository::{
+    SaleEventRepository, SaleEventRepositoryInterface,
+};
 use crate::game::repositories::specimen_repository::{
     SpecimenRepository, SpecimenRepositoryInterface,
 };
+#[cfg(feature = "sqlite")]
+use crate::game::repositories::sqlite::{
+    catch_event_repository::SqliteCatchEventRepository,
+    fishing_history_entry_repository::SqliteFishingHistoryEntryRepository,
+    item_repository::SqliteItemRepository, pond_repository::SqlitePondRepository,
+    sale_event_repository::SqliteSaleEventRepository,
+    specimen_repository::SqliteSpecimenRepository, user_repository::SqliteUserRepository,
+};
 use crate::game::repositories::user_repository::{UserRepository, UserRepositoryInterface};
+use crate::game::services::achievement_service::{AchievementService, AchievementServiceInterface};
 use crate::game::services::encounter_service::{EncounterService, EncounterServiceInterface};
 use crate::game::services::fishing_history_service::{
     FishingHistoryService, FishingHistoryServiceInterface,
 };
+use crate::game::services::forecast_service::{ForecastService, ForecastServiceInterface};
 use crate::game::services::item_service::{ItemService, ItemServiceInterface};
+use crate::game::services::leaderboard_service::{LeaderboardService, LeaderboardServiceInterface};
 use crate::game::services::location_service::{LocationService, LocationServiceInterface};
 use crate::game::services::pond_service::{PondService, PondServiceInterface};
+use crate::game::services::shop_service::{ShopService, ShopServiceInterface};
 use crate::game::services::species_service::{SpeciesService, SpeciesServiceInterface};
 use crate::game::services::specimen_service::{SpecimenService, SpecimenServiceInterface};
 use crate::game::services::user_service::{UserService, UserServiceInterface};
@@ -25,38 +59,102 @@ use std::sync::{Arc, RwLock};
 
 pub trait ServiceProviderInterface: Send + Sync {
     fn asset_server(&self) -> Arc<dyn AssetServerInterface>;
+    fn clock(&self) -> Arc<dyn Clock>;
     fn config(&self) -> Arc<dyn ConfigInterface>;
+    fn game_time(&self) -> Arc<GameTime>;
+    fn random_source(&self) -> Arc<dyn RandomSource>;
     fn database(&self) -> Arc<RwLock<dyn DatabaseInterface>>;
+    /// Integrators can subscribe to the events emitted by the services here
+    fn event_bus(&self) -> Arc<dyn EventBusInterface>;
+    fn catch_event_repository(&self) -> Arc<dyn CatchEventRepositoryInterface>;
     fn fishing_history_entry_repository(&self) -> Arc<dyn FishingHistoryEntryRepositoryInterface>;
     fn item_repository(&self) -> Arc<dyn ItemRepositoryInterface>;
     fn pond_repository(&self) -> Arc<dyn PondRepositoryInterface>;
+    fn sale_event_repository(&self) -> Arc<dyn SaleEventRepositoryInterface>;
     fn specimen_repository(&self) -> Arc<dyn SpecimenRepositoryInterface>;
     fn user_repository(&self) -> Arc<dyn UserRepositoryInterface>;
+    fn achievement_service(&self) -> Arc<dyn AchievementServiceInterface>;
     fn encounter_service(&self) -> Arc<dyn EncounterServiceInterface>;
     fn fishing_history_service(&self) -> Arc<dyn FishingHistoryServiceInterface>;
+    fn forecast_service(&self) -> Arc<dyn ForecastServiceInterface>;
     fn item_service(&self) -> Arc<dyn ItemServiceInterface>;
+    fn leaderboard_service(&self) -> Arc<dyn LeaderboardServiceInterface>;
     fn location_service(&self) -> Arc<dyn LocationServiceInterface>;
     fn pond_service(&self) -> Arc<dyn PondServiceInterface>;
+    fn shop_service(&self) -> Arc<dyn ShopServiceInterface>;
     fn species_service(&self) -> Arc<dyn SpeciesServiceInterface>;
     fn specimen_service(&self) -> Arc<dyn SpecimenServiceInterface>;
     fn user_service(&self) -> Arc<dyn UserServiceInterface>;
     fn weather_service(&self) -> Arc<dyn WeatherServiceInterface>;
 }
 
+/// The repositories of one storage backend
+#[derive(Clone)]
+pub struct Repositories {
+    pub catch_event_repository: Arc<dyn CatchEventRepositoryInterface>,
+    pub fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
+    pub item_repository: Arc<dyn ItemRepositoryInterface>,
+    pub pond_repository: Arc<dyn PondRepositoryInterface>,
+    pub sale_event_repository: Arc<dyn SaleEventRepositoryInterface>,
+    pub specimen_repository: Arc<dyn SpecimenRepositoryInterface>,
+    pub user_repository: Arc<dyn UserRepositoryInterface>,
+}
+
+impl Repositories {
+    /// The repositories used by the given service provider
+    pub fn of(service_provider: &dyn ServiceProviderInterface) -> Self {
+        Self {
+            catch_event_repository: service_provider.catch_event_repository(),
+            fishing_history_entry_repository: service_provider.fishing_history_entry_repository(),
+            item_repository: service_provider.item_repository(),
+            pond_repository: service_provider.pond_repository(),
+            sale_event_repository: service_provider.sale_event_repository(),
+            specimen_repository: service_provider.specimen_repository(),
+            user_repository: service_provider.user_repository(),
+        }
+    }
+
+    /// The same repositories, persisting the timestamps of the given clock.
+    /// The event repositories are kept, their timestamps are set by the services.
+    pub fn with_clock(&self, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            catch_event_repository: self.catch_event_repository.clone(),
+            fishing_history_entry_repository: self
+                .fishing_history_entry_repository
+                .with_clock(clock.clone()),
+            item_repository: self.item_repository.with_clock(clock.clone()),
+            pond_repository: self.pond_repository.with_clock(clock.clone()),
+            sale_event_repository: self.sale_event_repository.clone(),
+            specimen_repository: self.specimen_repository.with_clock(clock.clone()),
+            user_repository: self.user_repository.with_clock(clock),
+        }
+    }
+}
+
 pub struct ServiceProvider {
     asset_server: Arc<dyn AssetServerInterface>,
+    clock: Arc<dyn Clock>,
     config: Arc<dyn ConfigInterface>,
+    game_time: Arc<GameTime>,
+    random_source: Arc<dyn RandomSource>,
     database: Arc<RwLock<dyn DatabaseInterface>>,
+    event_bus: Arc<dyn EventBusInterface>,
+    catch_event_repository: Arc<dyn CatchEventRepositoryInterface>,
     fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
     item_repository: Arc<dyn ItemRepositoryInterface>,
     pond_repository: Arc<dyn PondRepositoryInterface>,
+    sale_event_repository: Arc<dyn SaleEventRepositoryInterface>,
     specimen_repository: Arc<dyn SpecimenRepositoryInterface>,
     user_repository: Arc<dyn UserRepositoryInterface>,
+    achievement_service: Arc<dyn AchievementServiceInterface>,
     encounter_service: Arc<dyn EncounterServiceInterface>,
     fishing_history_service: Arc<dyn FishingHistoryServiceInterface>,
+    forecast_service: Arc<dyn ForecastServiceInterface>,
     item_service: Arc<dyn ItemServiceInterface>,
+    leaderboard_service: Arc<dyn LeaderboardServiceInterface>,
     location_service: Arc<dyn LocationServiceInterface>,
     pond_service: Arc<dyn PondServiceInterface>,
+    shop_service: Arc<dyn ShopServiceInterface>,
     species_service: Arc<dyn SpeciesServiceInterface>,
     specimen_service: Arc<dyn SpecimenServiceInterface>,
     user_service: Arc<dyn UserServiceInterface>,
@@ -68,43 +166,205 @@ impl ServiceProvider {
         config: Arc<dyn ConfigInterface>,
         database: Arc<RwLock<dyn DatabaseInterface>>,
     ) -> Self {
-        let fishing_history_entry_repository =
-            Arc::new(FishingHistoryEntryRepository::new(database.clone()));
-        let item_repository = Arc::new(ItemRepository::new(database.clone()));
-        let pond_repository = Arc::new(PondRepository::new(database.clone()));
-        let specimen_repository = Arc::new(SpecimenRepository::new(database.clone()));
-        let user_repository = Arc::new(UserRepository::new(database.clone()));
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock::new());
+        Self::with_repositories(
+            config,
+            clock.clone(),
+            Arc::new(ThreadRandomSource::new()),
+            database.clone(),
+            Arc::new(EventBus::new()),
+            Repositories {
+                catch_event_repository: Arc::new(CatchEventRepository::new(database.clone())),
+                fishing_history_entry_repository: Arc::new(FishingHistoryEntryRepository::new(
+                    database.clone(),
+                    clock.clone(),
+                )),
+                item_repository: Arc::new(ItemRepository::new(database.clone(), clock.clone())),
+                pond_repository: Arc::new(PondRepository::new(database.clone(), clock.clone())),
+                sale_event_repository: Arc::new(SaleEventRepository::new(database.clone())),
+                specimen_repository: Arc::new(SpecimenRepository::new(
+                    database.clone(),
+                    clock.clone(),
+                )),
+                user_repository: Arc::new(UserRepository::new(database, clock.clone())),
+            },
+        )
+    }
 
+    /// Uses the in-memory repositories instead of the Postgres ones
+    #[cfg(feature = "in-memory")]
+    pub fn new_in_memory(config: Arc<dyn ConfigInterface>) -> Self {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock::new());
+        let database = MemoryDatabase::create();
+        Self::with_repositories(
+            config,
+            clock.clone(),
+            Arc::new(ThreadRandomSource::new()),
+            database.clone(),
+            Arc::new(EventBus::new()),
+            Repositories {
+                catch_event_repository: Arc::new(MemoryCatchEventRepository::new(database.clone())),
+                fishing_history_entry_repository: Arc::new(
+                    MemoryFishingHistoryEntryRepository::new(database.clone(), clock.clone()),
+                ),
+                item_repository: Arc::new(MemoryItemRepository::new(
+                    database.clone(),
+                    clock.clone(),
+                )),
+                pond_repository: Arc::new(MemoryPondRepository::new(
+                    database.clone(),
+                    clock.clone(),
+                )),
+                sale_event_repository: Arc::new(MemorySaleEventRepository::new(database.clone())),
+                specimen_repository: Arc::new(MemorySpecimenRepository::new(
+                    database.clone(),
+                    clock.clone(),
+                )),
+                user_repository: Arc::new(MemoryUserRepository::new(database, clock.clone())),
+            },
+        )
+    }
+
+    /// Uses the SQLite repositories instead of the Postgres ones
+    #[cfg(feature = "sqlite")]
+    pub fn new_sqlite(
+        config: Arc<dyn ConfigInterface>,
+        database: Arc<RwLock<SqliteDatabase>>,
+    ) -> Self {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock::new());
+        Self::with_repositories(
+            config,
+            clock.clone(),
+            Arc::new(ThreadRandomSource::new()),
+            database.clone(),
+            Arc::new(EventBus::new()),
+            Repositories {
+                catch_event_repository: Arc::new(SqliteCatchEventRepository::new(database.clone())),
+                fishing_history_entry_repository: Arc::new(
+                    SqliteFishingHistoryEntryRepository::new(database.clone(), clock.clone()),
+                ),
+                item_repository: Arc::new(SqliteItemRepository::new(
+                    database.clone(),
+                    clock.clone(),
+                )),
+                pond_repository: Arc::new(SqlitePondRepository::new(
+                    database.clone(),
+                    clock.clone(),
+                )),
+                sale_event_repository: Arc::new(SqliteSaleEventRepository::new(database.clone())),
+                specimen_repository: Arc::new(SqliteSpecimenRepository::new(
+                    database.clone(),
+                    clock.clone(),
+                )),
+                user_repository: Arc::new(SqliteUserRepository::new(database, clock.clone())),
+            },
+        )
+    }
+
+    pub fn with_repositories(
+        config: Arc<dyn ConfigInterface>,
+        clock: Arc<dyn Clock>,
+        random_source: Arc<dyn RandomSource>,
+        database: Arc<RwLock<dyn DatabaseInterface>>,
+        event_bus: Arc<dyn EventBusInterface>,
+        repositories: Repositories,
+    ) -> Self {
+        let Repositories {
+            catch_event_repository,
+            fishing_history_entry_repository,
+            item_repository,
+            pond_repository,
+            sale_event_repository,
+            specimen_repository,
+            user_repository,
+        } = repositories;
+        let game_time = Arc::new(GameTime::from_settings(clock.clone(), &config.settings()));
+        let achievement_service = Arc::new(AchievementService::new(
+            config.clone(),
+            game_time.clone(),
+            event_bus.clone(),
+            fishing_history_entry_repository.clone(),
+            user_repository.clone(),
+        ));
         let encounter_service = Arc::new(EncounterService::new(config.clone()));
         let fishing_history_service = Arc::new(FishingHistoryService::new(
             config.clone(),
+            game_time.clone(),
+            event_bus.clone(),
+            catch_event_repository.clone(),
             fishing_history_entry_repository.clone(),
+            sale_event_repository.clone(),
+        ));
+        let item_service = Arc::new(ItemService::new(
+            config.clone(),
+            event_bus.clone(),
+            item_repository.clone(),
+        ));
+        let leaderboard_service = Arc::new(LeaderboardService::new(
+            config.clone(),
+            game_time.clone(),
+            catch_event_repository.clone(),
+            fishing_history_entry_repository.clone(),
+            sale_event_repository.clone(),
+            user_repository.clone(),
         ));
-        let item_service = Arc::new(ItemService::new(config.clone(), item_repository.clone()));
         let location_service = Arc::new(LocationService::new(config.clone()));
-        let pond_service = Arc::new(PondService::new(pond_repository.clone()));
+        let pond_service = Arc::new(PondService::new(
+            database.clone(),
+            pond_repository.clone(),
+            specimen_repository.clone(),
+        ));
+        let shop_service = Arc::new(ShopService::new(
+            config.clone(),
+            game_time.clone(),
+            database.clone(),
+            specimen_repository.clone(),
+            user_repository.clone(),
+            fishing_history_service.clone(),
+            item_service.clone(),
+        ));
         let species_service = Arc::new(SpeciesService::new(config.clone()));
-        let specimen_service = Arc::new(SpecimenService::new(specimen_repository.clone()));
+        let specimen_service = Arc::new(SpecimenService::new(
+            event_bus.clone(),
+            specimen_repository.clone(),
+        ));
         let user_service = Arc::new(UserService::new(
+            event_bus.clone(),
             fishing_history_entry_repository.clone(),
             user_repository.clone(),
         ));
-        let weather_service = Arc::new(WeatherService::new(config.clone()));
+        let weather_service = Arc::new(WeatherService::new(config.clone(), game_time.clone()));
+        let forecast_service = Arc::new(ForecastService::new(
+            config.clone(),
+            game_time.clone(),
+            encounter_service.clone(),
+            weather_service.clone(),
+        ));
 
         Self {
             asset_server: Arc::new(AssetServer::new(1024 * 1024)),
+            clock,
             config,
+            game_time,
+            random_source,
             database,
+            event_bus,
+            catch_event_repository,
             fishing_history_entry_repository,
             item_repository,
             pond_repository,
+            sale_event_repository,
             specimen_repository,
             user_repository,
+            achievement_service,
             encounter_service,
             fishing_history_service,
+            forecast_service,
             item_service,
+            leaderboard_service,
             location_service,
             pond_service,
+            shop_service,
             species_service,
             specimen_service,
             user_service,
@@ -125,14 +385,34 @@ impl ServiceProviderInterface for ServiceProvider {
         self.asset_server.clone()
     }
 
+    fn clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone()
+    }
+
     fn config(&self) -> Arc<dyn ConfigInterface> {
         self.config.clone()
     }
 
+    fn game_time(&self) -> Arc<GameTime> {
+        self.game_time.clone()
+    }
+
+    fn random_source(&self) -> Arc<dyn RandomSource> {
+        self.random_source.clone()
+    }
+
     fn database(&self) -> Arc<RwLock<dyn DatabaseInterface>> {
         self.database.clone()
     }
 
+    fn event_bus(&self) -> Arc<dyn EventBusInterface> {
+        self.event_bus.clone()
+    }
+
+    fn catch_event_repository(&self) -> Arc<dyn CatchEventRepositoryInterface> {
+        self.catch_event_repository.clone()
+    }
+
     fn fishing_history_entry_repository(&self) -> Arc<dyn FishingHistoryEntryRepositoryInterface> {
         self.fishing_history_entry_repository.clone()
     }
@@ -145,6 +425,10 @@ impl ServiceProviderInterface for ServiceProvider {
         self.pond_repository.clone()
     }
 
+    fn sale_event_repository(&self) -> Arc<dyn SaleEventRepositoryInterface> {
+        self.sale_event_repository.clone()
+    }
+
     fn specimen_repository(&self) -> Arc<dyn SpecimenRepositoryInterface> {
         self.specimen_repository.clone()
     }
@@ -153,6 +437,10 @@ impl ServiceProviderInterface for ServiceProvider {
         self.user_repository.clone()
     }
 
+    fn achievement_service(&self) -> Arc<dyn AchievementServiceInterface> {
+        self.achievement_service.clone()
+    }
+
     fn encounter_service(&self) -> Arc<dyn EncounterServiceInterface> {
         self.encounter_service.clone()
     }
@@ -161,10 +449,18 @@ impl ServiceProviderInterface for ServiceProvider {
         self.fishing_history_service.clone()
     }
 
+    fn forecast_service(&self) -> Arc<dyn ForecastServiceInterface> {
+        self.forecast_service.clone()
+    }
+
     fn item_service(&self) -> Arc<dyn ItemServiceInterface> {
         self.item_service.clone()
     }
 
+    fn leaderboard_service(&self) -> Arc<dyn LeaderboardServiceInterface> {
+        self.leaderboard_service.clone()
+    }
+
     fn location_service(&self) -> Arc<dyn LocationServiceInterface> {
         self.location_service.clone()
     }
@@ -173,6 +469,10 @@ impl ServiceProviderInterface for ServiceProvider {
         self.pond_service.clone()
     }
 
+    fn shop_service(&self) -> Arc<dyn ShopServiceInterface> {
+        self.shop_service.clone()
+    }
+
     fn species_service(&self) -> Arc<dyn SpeciesServiceInterface> {
         self.species_service.clone()
     }
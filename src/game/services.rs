@@ -1,8 +1,12 @@
+pub mod achievement_service;
 pub mod encounter_service;
 pub mod fishing_history_service;
+pub mod forecast_service;
 pub mod item_service;
+pub mod leaderboard_service;
 pub mod location_service;
 pub mod pond_service;
+pub mod shop_service;
 pub mod species_service;
 pub mod specimen_service;
 pub mod user_service;
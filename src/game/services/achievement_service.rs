@@ -0,0 +1,234 @@
+use crate::config::ConfigInterface;
+use crate::data::achievement_data::{AchievementData, AchievementRequirement};
+use crate::data::species_data::SpeciesData;
+use crate::dto::achievement_unlock::AchievementUnlock;
+use crate::game::errors::GameResult;
+use crate::game::event_bus::game_event::GameEvent;
+use crate::game::event_bus::EventBusInterface;
+use crate::game::game_time::GameTime;
+use crate::game::repositories::fishing_history_entry_repository::FishingHistoryEntryRepositoryInterface;
+use crate::game::repositories::user_repository::UserRepositoryInterface;
+use crate::game::systems::weather_system::weather::Weather;
+use crate::models::fishing_history_entry::FishingHistoryEntry;
+use crate::models::specimen::Specimen;
+use crate::models::user::User;
+use std::sync::Arc;
+
+/// The action after which the achievements of a user are checked
+#[derive(Debug, Clone, Copy)]
+pub enum AchievementTrigger<'a> {
+    /// A specimen was caught, the location and weather are only known when fishing at a location
+    Catch {
+        specimen: &'a Specimen,
+        species: &'a SpeciesData,
+        location_id: Option<i32>,
+        weather: Option<&'a Weather>,
+    },
+    Sell,
+    Purchase {
+        item_id: i32,
+    },
+    LocationUnlock,
+}
+
+pub trait AchievementServiceInterface: Send + Sync {
+    /// Unlocks every achievement the user fulfills after the given action
+    /// and returns the newly unlocked ones
+    fn process_trigger(
+        &self,
+        user: &User,
+        trigger: AchievementTrigger,
+    ) -> GameResult<Vec<AchievementUnlock>>;
+    fn get_achievements(&self, user: &User) -> GameResult<Vec<AchievementUnlock>>;
+}
+
+pub struct AchievementService {
+    config: Arc<dyn ConfigInterface>,
+    game_time: Arc<GameTime>,
+    event_bus: Arc<dyn EventBusInterface>,
+    fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
+    user_repository: Arc<dyn UserRepositoryInterface>,
+}
+
+/// The state of a user all count based requirements are checked against
+struct AchievementProgress {
+    history_entries: Vec<FishingHistoryEntry>,
+    unlocked_location_count: u64,
+    credits: i64,
+}
+
+impl AchievementService {
+    pub fn new(
+        config: Arc<dyn ConfigInterface>,
+        game_time: Arc<GameTime>,
+        event_bus: Arc<dyn EventBusInterface>,
+        fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
+        user_repository: Arc<dyn UserRepositoryInterface>,
+    ) -> Self {
+        Self {
+            config,
+            game_time,
+            event_bus,
+            fishing_history_entry_repository,
+            user_repository,
+        }
+    }
+
+    fn get_progress(&self, user: &User) -> GameResult<AchievementProgress> {
+        let history_entries = self
+            .fishing_history_entry_repository
+            .find_by_user(user.id)?;
+        let unlocked_location_count = self
+            .user_repository
+            .find_unlocked_location_ids(user.id)?
+            .len() as u64;
+        // The given user might be outdated, e.g. if it was loaded before a sale
+        let credits = self
+            .user_repository
+            .find(user.id)?
+            .map(|user| user.credits)
+            .unwrap_or(user.credits);
+
+        Ok(AchievementProgress {
+            history_entries,
+            unlocked_location_count,
+            credits,
+        })
+    }
+
+    fn is_fulfilled(
+        &self,
+        requirement: &AchievementRequirement,
+        progress: &AchievementProgress,
+        trigger: AchievementTrigger,
+    ) -> GameResult<bool> {
+        let fulfilled = match requirement {
+            AchievementRequirement::CatchCount { count, species_id } => {
+                let caught_count: u64 = progress
+                    .history_entries
+                    .iter()
+                    .filter(|entry| species_id.is_none_or(|id| entry.species_id == id))
+                    .map(|entry| entry.caught_count.max(0) as u64)
+                    .sum();
+                caught_count >= *count
+            }
+            AchievementRequirement::SpeciesCount { count } => {
+                let species_count = progress
+                    .history_entries
+                    .iter()
+                    .filter(|entry| entry.caught_count > 0)
+                    .count() as u64;
+                species_count >= *count
+            }
+            AchievementRequirement::Catch {
+                species_id,
+                location_id,
+                min_rarity_level,
+                min_size_ratio,
+                needs_rain,
+            } => {
+                let AchievementTrigger::Catch {
+                    specimen,
+                    species,
+                    location_id: catch_location_id,
+                    weather,
+                } = trigger
+                else {
+                    return Ok(false);
+                };
+
+                let size_ratio_met = match min_size_ratio {
+                    Some(min_size_ratio) => {
+                        specimen.get_total_size_ratio(self.config.clone(), &self.game_time)?
+                            >= *min_size_ratio
+                    }
+                    None => true,
+                };
+
+                size_ratio_met
+                    && species_id.is_none_or(|id| species.id == id)
+                    && location_id.is_none_or(|id| catch_location_id == Some(id))
+                    && min_rarity_level.is_none_or(|level| species.get_rarity_level() >= level)
+                    && (!needs_rain || weather.is_some_and(|weather| weather.is_raining))
+            }
+            AchievementRequirement::SellCount { count } => {
+                let sold_count: u64 = progress
+                    .history_entries
+                    .iter()
+                    .map(|entry| entry.sold_count.max(0) as u64)
+                    .sum();
+                sold_count >= *count
+            }
+            AchievementRequirement::Credits { amount } => progress.credits >= *amount,
+            AchievementRequirement::Purchase { item_id } => match trigger {
+                AchievementTrigger::Purchase {
+                    item_id: purchased_item_id,
+                } => item_id.is_none_or(|id| purchased_item_id == id),
+                _ => false,
+            },
+            AchievementRequirement::LocationsUnlocked { count } => {
+                let count = count.unwrap_or(self.config.locations().len() as u64);
+                progress.unlocked_location_count >= count
+            }
+        };
+        Ok(fulfilled)
+    }
+}
+
+impl AchievementServiceInterface for AchievementService {
+    fn process_trigger(
+        &self,
+        user: &User,
+        trigger: AchievementTrigger,
+    ) -> GameResult<Vec<AchievementUnlock>> {
+        let unlocked_ids = self
+            .user_repository
+            .find_unlocked_achievement_ids(user.id)?;
+        let mut locked_achievements: Vec<Arc<AchievementData>> = self
+            .config
+            .achievements()
+            .values()
+            .filter(|achievement| !unlocked_ids.contains(&achievement.id))
+            .cloned()
+            .collect();
+        if locked_achievements.is_empty() {
+            return Ok(Vec::new());
+        }
+        locked_achievements.sort_by_key(|achievement| achievement.id);
+
+        let progress = self.get_progress(user)?;
+        let mut unlocks = Vec::new();
+        for achievement in locked_achievements {
+            if !self.is_fulfilled(&achievement.requirement, &progress, trigger)? {
+                continue;
+            }
+
+            // A concurrent action may have unlocked it since the ids were loaded
+            let Some(user_achievement) = self
+                .user_repository
+                .unlock_achievement(user.id, achievement.id)?
+            else {
+                continue;
+            };
+            self.event_bus.emit(GameEvent::AchievementUnlocked {
+                user_id: user.id,
+                achievement_id: achievement.id,
+                unlocked_at: user_achievement.unlocked_at,
+            });
+            unlocks.push(AchievementUnlock {
+                achievement,
+                unlocked_at: user_achievement.unlocked_at,
+            });
+        }
+
+        Ok(unlocks)
+    }
+
+    fn get_achievements(&self, user: &User) -> GameResult<Vec<AchievementUnlock>> {
+        let user_achievements = self.user_repository.find_achievements(user.id)?;
+        Ok(AchievementUnlock::from_user_achievements(
+            user_achievements,
+            |achievement_id| self.config.get_achievement_data(achievement_id),
+        ))
+    }
+}
@@ -1,19 +1,34 @@
 use crate::config::ConfigInterface;
 use crate::game::errors::resource::GameResourceError;
 use crate::game::errors::GameResult;
-use crate::game::systems::encounter_system::{EncounterSystem, EncounterWeather};
+use crate::game::systems::encounter_system::{
+    EncounterConditions, EncounterEquipment, EncounterProbability, EncounterSystem,
+};
 use crate::game::systems::weather_system::weather::Weather;
+use crate::utils::random::{seeded_rng, ENCOUNTER_STREAM};
 use chrono::DateTime;
 use chrono_tz::Tz;
 use std::sync::Arc;
 
 pub trait EncounterServiceInterface: Send + Sync {
+    /// Rolls the same encounter for the same seed
     fn roll_encounter(
         &self,
         time: DateTime<Tz>,
         weather: Weather,
         location_id: i32,
+        equipment: EncounterEquipment,
+        seed: u64,
     ) -> GameResult<i32>;
+
+    /// Every species that can currently be encountered with its chance per cast
+    fn get_encounter_probabilities(
+        &self,
+        time: DateTime<Tz>,
+        weather: &Weather,
+        location_id: i32,
+        equipment: EncounterEquipment,
+    ) -> Vec<EncounterProbability>;
 }
 
 pub struct EncounterService {
@@ -22,7 +37,12 @@ pub struct EncounterService {
 
 impl EncounterService {
     pub fn new(config: Arc<dyn ConfigInterface>) -> Self {
-        let system = EncounterSystem::new(config.species(), config.settings().rarity_exponent);
+        let settings = config.settings();
+        let system = EncounterSystem::new(
+            config.species(),
+            settings.rarity_exponent,
+            settings.bait_level_rarity_exponent_reduction,
+        );
 
         Self { system }
     }
@@ -34,15 +54,32 @@ impl EncounterServiceInterface for EncounterService {
         time: DateTime<Tz>,
         weather: Weather,
         location_id: i32,
+        equipment: EncounterEquipment,
+        seed: u64,
     ) -> GameResult<i32> {
-        let encounter_weather = if weather.is_raining {
-            EncounterWeather::Rain
-        } else {
-            EncounterWeather::Any
-        };
-
         self.system
-            .roll_encounter(time, encounter_weather, location_id)
+            .roll_encounter(
+                time,
+                &EncounterConditions::from(&weather),
+                location_id,
+                &equipment,
+                &mut seeded_rng(seed, ENCOUNTER_STREAM),
+            )
             .ok_or_else(|| GameResourceError::no_available_encounters().into())
     }
+
+    fn get_encounter_probabilities(
+        &self,
+        time: DateTime<Tz>,
+        weather: &Weather,
+        location_id: i32,
+        equipment: EncounterEquipment,
+    ) -> Vec<EncounterProbability> {
+        self.system.get_encounter_probabilities(
+            time,
+            &EncounterConditions::from(weather),
+            location_id,
+            &equipment,
+        )
+    }
 }
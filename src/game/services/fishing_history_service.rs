@@ -1,14 +1,28 @@
 use crate::config::ConfigInterface;
+use crate::dto::fishdex::Fishdex;
+use crate::dto::personal_best::PersonalBest;
+use crate::enums::record_kind::RecordKind;
 use crate::game::errors::resource::GameResourceError;
 use crate::game::errors::GameResult;
+use crate::game::event_bus::game_event::GameEvent;
+use crate::game::event_bus::EventBusInterface;
+use crate::game::game_time::GameTime;
+use crate::game::repositories::catch_event_repository::CatchEventRepositoryInterface;
 use crate::game::repositories::fishing_history_entry_repository::FishingHistoryEntryRepositoryInterface;
-use crate::models::fishing_history_entry::{FishingHistoryEntry, NewFishingHistoryEntry};
+use crate::game::repositories::sale_event_repository::SaleEventRepositoryInterface;
+use crate::models::catch_event::NewCatchEvent;
+use crate::models::fishing_history_entry::{
+    CatchRecord, FishingHistoryEntry, NewFishingHistoryEntry,
+};
+use crate::models::sale_event::NewSaleEvent;
 use crate::models::specimen::Specimen;
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
 
 pub trait FishingHistoryServiceInterface: Send + Sync {
     fn register_catch(&self, fish: &Specimen) -> GameResult<FishingHistoryEntry>;
+    fn get_personal_bests(&self, user_id: i64) -> GameResult<Vec<PersonalBest>>;
+    fn get_fishdex(&self, user_id: i64, hide_uncaught_names: bool) -> GameResult<Fishdex>;
     fn register_sell(
         &self,
         fish: &Specimen,
@@ -18,17 +32,54 @@ pub trait FishingHistoryServiceInterface: Send + Sync {
 
 pub struct FishingHistoryService {
     config: Arc<dyn ConfigInterface>,
+    game_time: Arc<GameTime>,
+    event_bus: Arc<dyn EventBusInterface>,
+    catch_event_repository: Arc<dyn CatchEventRepositoryInterface>,
     fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
+    sale_event_repository: Arc<dyn SaleEventRepositoryInterface>,
 }
 
 impl FishingHistoryService {
     pub fn new(
         config: Arc<dyn ConfigInterface>,
+        game_time: Arc<GameTime>,
+        event_bus: Arc<dyn EventBusInterface>,
+        catch_event_repository: Arc<dyn CatchEventRepositoryInterface>,
         fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
+        sale_event_repository: Arc<dyn SaleEventRepositoryInterface>,
     ) -> Self {
         Self {
             config,
+            game_time,
+            event_bus,
+            catch_event_repository,
             fishing_history_entry_repository,
+            sale_event_repository,
+        }
+    }
+
+    /// Emits an event for every record whose holding specimen changed with the latest catch
+    fn emit_broken_records(
+        &self,
+        entry: &FishingHistoryEntry,
+        previous_records: [(RecordKind, Option<i64>); 2],
+    ) {
+        for (kind, previous_specimen_id) in previous_records {
+            let specimen_id = match kind {
+                RecordKind::Smallest => entry.smallest_catch_specimen_id,
+                RecordKind::Largest => entry.largest_catch_specimen_id,
+            };
+            let Some(specimen_id) = specimen_id else {
+                continue;
+            };
+            if previous_specimen_id != Some(specimen_id) {
+                self.event_bus.emit(GameEvent::RecordBroken {
+                    user_id: entry.user_id,
+                    species_id: entry.species_id,
+                    specimen_id,
+                    kind,
+                });
+            }
         }
     }
 }
@@ -37,32 +88,68 @@ impl FishingHistoryServiceInterface for FishingHistoryService {
     fn register_catch(&self, fish: &Specimen) -> GameResult<FishingHistoryEntry> {
         let config = self.config.clone();
 
+        // Measured right when the specimen is registered, which is when it was caught
+        let catch = CatchRecord {
+            specimen_id: fish.id,
+            total_size_ratio: fish.get_total_size_ratio(config.clone(), &self.game_time)?,
+            size_mm: fish.get_size_mm(config.clone(), &self.game_time)?,
+            weight_g: fish.get_weight_g(config, &self.game_time)?,
+            catch_time: fish.created_at,
+        };
+        // The history entry only keeps totals, the event lets leaderboards count within time windows
+        self.catch_event_repository.create(NewCatchEvent::new(
+            fish.user_id,
+            fish.species_id,
+            &catch,
+        ))?;
+
         let existing_entry = self
             .fishing_history_entry_repository
             .find_by_user_and_species_id(fish.user_id, fish.species_id)?;
 
-        let time_multiplier = self.config.settings().time_speed_multiplier;
-
         if let Some(mut entry) = existing_entry {
-            let total_size_ratio = fish.get_total_size_ratio(config, time_multiplier)?;
-            entry.register_catch(total_size_ratio, fish.created_at);
-            let saved_entry = self.fishing_history_entry_repository.save(entry)?;
+            let previous_records = [
+                (RecordKind::Smallest, entry.smallest_catch_specimen_id),
+                (RecordKind::Largest, entry.largest_catch_specimen_id),
+            ];
+            entry.register_catch(&catch);
+            let saved_entry = self.fishing_history_entry_repository.save_checked(entry)?;
+            self.emit_broken_records(&saved_entry, previous_records);
             Ok(saved_entry)
         } else {
-            let total_size_ratio = fish.get_total_size_ratio(config, time_multiplier)?;
-            let new_entry = NewFishingHistoryEntry {
-                user_id: fish.user_id,
-                species_id: fish.species_id,
-                caught_count: 1,
-                sold_count: 0,
-                smallest_catch_size_ratio: total_size_ratio,
-                largest_catch_size_ratio: total_size_ratio,
-            };
+            let new_entry =
+                NewFishingHistoryEntry::first_catch(fish.user_id, fish.species_id, &catch);
             let saved_entry = self.fishing_history_entry_repository.create(new_entry)?;
+            self.event_bus.emit(GameEvent::SpeciesFirstCaught {
+                user_id: saved_entry.user_id,
+                species_id: saved_entry.species_id,
+            });
             Ok(saved_entry)
         }
     }
 
+    fn get_personal_bests(&self, user_id: i64) -> GameResult<Vec<PersonalBest>> {
+        self.fishing_history_entry_repository
+            .find_by_user(user_id)?
+            .into_iter()
+            .map(|entry| {
+                Ok(PersonalBest {
+                    species_id: entry.species_id,
+                    smallest: entry.get_smallest_record(self.config.clone())?,
+                    largest: entry.get_largest_record(self.config.clone())?,
+                })
+            })
+            .collect()
+    }
+
+    fn get_fishdex(&self, user_id: i64, hide_uncaught_names: bool) -> GameResult<Fishdex> {
+        let history_entries = self
+            .fishing_history_entry_repository
+            .find_by_user(user_id)?;
+        let species = self.config.species().values().cloned().collect::<Vec<_>>();
+        Ok(Fishdex::new(species, history_entries, hide_uncaught_names))
+    }
+
     fn register_sell(
         &self,
         fish: &Specimen,
@@ -76,6 +163,15 @@ impl FishingHistoryServiceInterface for FishingHistoryService {
             })?;
 
         existing_entry.register_sell(sell_time);
-        Ok(self.fishing_history_entry_repository.save(existing_entry)?)
+        let saved_entry = self
+            .fishing_history_entry_repository
+            .save_checked(existing_entry)?;
+        self.sale_event_repository
+            .create(NewSaleEvent::new(fish, sell_time))?;
+        self.event_bus.emit(GameEvent::SpecimenSold {
+            specimen: fish.clone(),
+            sell_time,
+        });
+        Ok(saved_entry)
     }
 }
@@ -0,0 +1,292 @@
+use crate::config::ConfigInterface;
+use crate::data::location_data::LocationData;
+use crate::data::species_data::SpeciesData;
+use crate::dto::forecast_window::ForecastWindow;
+use crate::game::errors::input::GameInputError;
+use crate::game::errors::GameResult;
+use crate::game::game_time::GameTime;
+use crate::game::services::encounter_service::EncounterServiceInterface;
+use crate::game::services::weather_service::WeatherServiceInterface;
+use crate::game::systems::encounter_system::EncounterEquipment;
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+/// In-game minutes between two forecast samples,
+/// the minutes at which encounter windows open or close are sampled as well
+const FORECAST_STEP_MINUTES: i64 = 15;
+const MINUTES_PER_DAY: u16 = 24 * 60;
+/// The most in-game days a forecast can look ahead
+pub const MAX_FORECAST_DAYS: u32 = 14;
+
+pub trait ForecastServiceInterface: Send + Sync {
+    /// The upcoming windows in which species can be encountered at the location,
+    /// scanning the next given amount of in-game days starting now, at most [MAX_FORECAST_DAYS]
+    fn forecast_location(
+        &self,
+        location_data: Arc<LocationData>,
+        days: u32,
+        equipment: EncounterEquipment,
+    ) -> GameResult<Vec<ForecastWindow>>;
+    /// The upcoming windows in which the species can be encountered at any of its locations,
+    /// scanning the next given amount of in-game days starting now, at most [MAX_FORECAST_DAYS]
+    fn forecast_species(
+        &self,
+        species_data: Arc<SpeciesData>,
+        days: u32,
+        equipment: EncounterEquipment,
+    ) -> GameResult<Vec<ForecastWindow>>;
+}
+
+pub struct ForecastService {
+    config: Arc<dyn ConfigInterface>,
+    game_time: Arc<GameTime>,
+    encounter_service: Arc<dyn EncounterServiceInterface>,
+    weather_service: Arc<dyn WeatherServiceInterface>,
+}
+
+impl ForecastService {
+    pub fn new(
+        config: Arc<dyn ConfigInterface>,
+        game_time: Arc<GameTime>,
+        encounter_service: Arc<dyn EncounterServiceInterface>,
+        weather_service: Arc<dyn WeatherServiceInterface>,
+    ) -> Self {
+        Self {
+            config,
+            game_time,
+            encounter_service,
+            weather_service,
+        }
+    }
+
+    fn scan_location(
+        &self,
+        location_data: Arc<LocationData>,
+        days: u32,
+        equipment: EncounterEquipment,
+        species_id: Option<i32>,
+    ) -> GameResult<Vec<ForecastWindow>> {
+        let start = self.game_time.now_in(&location_data.timezone)?;
+        let (times, end) = self.sample_times(&location_data, start, days, species_id)?;
+
+        let mut open_windows: HashMap<i32, OpenWindow> = HashMap::new();
+        let mut windows = Vec::new();
+
+        for time in times {
+            let weather = self
+                .weather_service
+                .get_game_time_weather(location_data.clone(), time)?;
+            let probabilities = self.encounter_service.get_encounter_probabilities(
+                time,
+                &weather,
+                location_data.id,
+                equipment,
+            );
+
+            let mut available = HashMap::new();
+            for encounter in probabilities {
+                if species_id.is_none_or(|species_id| species_id == encounter.species_id) {
+                    available.insert(encounter.species_id, encounter.probability);
+                }
+            }
+
+            let closed_species_ids: Vec<i32> = open_windows
+                .keys()
+                .filter(|species_id| !available.contains_key(species_id))
+                .copied()
+                .collect();
+            for closed_species_id in closed_species_ids {
+                if let Some(window) = open_windows.remove(&closed_species_id) {
+                    windows.push(self.close_window(
+                        &location_data,
+                        closed_species_id,
+                        window,
+                        time,
+                    )?);
+                }
+            }
+
+            for (available_species_id, probability) in available {
+                open_windows
+                    .entry(available_species_id)
+                    .or_insert_with(|| OpenWindow::new(time))
+                    .add_sample(probability);
+            }
+        }
+
+        for (species_id, window) in open_windows {
+            windows.push(self.close_window(&location_data, species_id, window, end)?);
+        }
+
+        windows.sort_by(|a, b| a.start.cmp(&b.start).then(a.species_id.cmp(&b.species_id)));
+        Ok(windows)
+    }
+
+    /// The times to sample between the start and the end of the forecast, in order, and the end.
+    /// Besides the regular steps, every minute an encounter window of the location opens or closes
+    /// at is sampled, so windows shorter than a step or between two steps aren't missed.
+    fn sample_times(
+        &self,
+        location_data: &LocationData,
+        start: DateTime<Tz>,
+        days: u32,
+        species_id: Option<i32>,
+    ) -> GameResult<(BTreeSet<DateTime<Tz>>, DateTime<Tz>)> {
+        let sample_count = i64::from(days) * 24 * 60 / FORECAST_STEP_MINUTES;
+        let end = sample_time(start, sample_count, days)?;
+        let mut times = BTreeSet::new();
+        for sample in 0..sample_count {
+            times.insert(sample_time(start, sample, days)?);
+        }
+
+        let mut boundary_minutes = BTreeSet::new();
+        for species_data in self.config.species().values() {
+            if species_id.is_some_and(|species_id| species_id != species_data.id) {
+                continue;
+            }
+            for encounter in &species_data.encounters {
+                if encounter.location_id == location_data.id {
+                    boundary_minutes.insert(encounter.get_start_minute_of_day());
+                    // The end is inclusive, the window closes a minute later
+                    boundary_minutes
+                        .insert((encounter.get_end_minute_of_day() + 1) % MINUTES_PER_DAY);
+                }
+            }
+        }
+
+        for date in start.date_naive().iter_days() {
+            if date > end.date_naive() {
+                break;
+            }
+            for minute in &boundary_minutes {
+                let Some(local_time) =
+                    date.and_hms_opt(u32::from(minute / 60), u32::from(minute % 60), 0)
+                else {
+                    continue;
+                };
+                // Minutes skipped by a daylight saving change don't exist
+                let Some(time) = location_data
+                    .timezone
+                    .from_local_datetime(&local_time)
+                    .earliest()
+                else {
+                    continue;
+                };
+                if time > start && time < end {
+                    times.insert(time);
+                }
+            }
+        }
+
+        Ok((times, end))
+    }
+
+    fn close_window(
+        &self,
+        location_data: &LocationData,
+        species_id: i32,
+        window: OpenWindow,
+        end: DateTime<Tz>,
+    ) -> GameResult<ForecastWindow> {
+        Ok(ForecastWindow {
+            location_id: location_data.id,
+            species_id,
+            start: window.start,
+            end,
+            real_start: self.to_real_time(window.start)?,
+            real_end: self.to_real_time(end)?,
+            max_probability: window.max_probability,
+            average_probability: window.probability_sum / window.sample_count as f64,
+        })
+    }
+
+    fn to_real_time(&self, game_time: DateTime<Tz>) -> GameResult<DateTime<Utc>> {
+        self.game_time.to_real_time(game_time.with_timezone(&Utc))
+    }
+}
+
+impl ForecastServiceInterface for ForecastService {
+    fn forecast_location(
+        &self,
+        location_data: Arc<LocationData>,
+        days: u32,
+        equipment: EncounterEquipment,
+    ) -> GameResult<Vec<ForecastWindow>> {
+        check_days(days)?;
+        self.scan_location(location_data, days, equipment, None)
+    }
+
+    fn forecast_species(
+        &self,
+        species_data: Arc<SpeciesData>,
+        days: u32,
+        equipment: EncounterEquipment,
+    ) -> GameResult<Vec<ForecastWindow>> {
+        check_days(days)?;
+        let location_ids: BTreeSet<i32> = species_data
+            .encounters
+            .iter()
+            .map(|encounter| encounter.location_id)
+            .collect();
+
+        let mut windows = Vec::new();
+        for location_id in location_ids {
+            let Some(location_data) = self.config.get_location_data(location_id) else {
+                continue;
+            };
+            windows.extend(self.scan_location(
+                location_data,
+                days,
+                equipment,
+                Some(species_data.id),
+            )?);
+        }
+
+        windows.sort_by(|a, b| {
+            a.real_start
+                .cmp(&b.real_start)
+                .then(a.location_id.cmp(&b.location_id))
+        });
+        Ok(windows)
+    }
+}
+
+fn check_days(days: u32) -> GameResult<()> {
+    if days > MAX_FORECAST_DAYS {
+        return Err(GameInputError::forecast_too_long(days, MAX_FORECAST_DAYS).into());
+    }
+    Ok(())
+}
+
+/// A forecast too close to the end of time to be represented is as invalid as a too long one
+fn sample_time(start: DateTime<Tz>, sample: i64, days: u32) -> GameResult<DateTime<Tz>> {
+    start
+        .checked_add_signed(TimeDelta::minutes(FORECAST_STEP_MINUTES * sample))
+        .ok_or_else(|| GameInputError::forecast_too_long(days, MAX_FORECAST_DAYS).into())
+}
+
+struct OpenWindow {
+    start: DateTime<Tz>,
+    max_probability: f64,
+    probability_sum: f64,
+    sample_count: u64,
+}
+
+impl OpenWindow {
+    fn new(start: DateTime<Tz>) -> Self {
+        Self {
+            start,
+            max_probability: 0.0,
+            probability_sum: 0.0,
+            sample_count: 0,
+        }
+    }
+
+    fn add_sample(&mut self, probability: f64) {
+        self.max_probability = self.max_probability.max(probability);
+        self.probability_sum += probability;
+        self.sample_count += 1;
+    }
+}
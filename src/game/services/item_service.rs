@@ -3,6 +3,8 @@ use crate::data::item_data::ItemData;
 use crate::dto::inventory::Inventory;
 use crate::game::errors::resource::GameResourceError;
 use crate::game::errors::GameResult;
+use crate::game::event_bus::game_event::GameEvent;
+use crate::game::event_bus::EventBusInterface;
 use crate::game::repositories::item_repository::ItemRepositoryInterface;
 use crate::models::item::properties_container::ItemPropertiesContainerInterface;
 use crate::models::item::{Item, ItemEventResult, ItemEventSuccess, NewItem};
@@ -24,24 +26,54 @@ pub trait ItemServiceInterface: Send + Sync {
         item: Item,
         function: Box<dyn Fn(&mut Item) -> ItemEventResult>,
     ) -> GameResult<ItemEventSuccess>;
+    fn use_as_rod(&self, rod: Item) -> GameResult<Option<Item>>;
+    fn use_as_bait(&self, bait: Item) -> GameResult<Option<Item>>;
     fn get_inventory(&self, user: &User) -> GameResult<Inventory>;
 }
 
 pub struct ItemService {
     config: Arc<dyn ConfigInterface>,
+    event_bus: Arc<dyn EventBusInterface>,
     item_repository: Arc<dyn ItemRepositoryInterface>,
 }
 
 impl ItemService {
     pub fn new(
         config: Arc<dyn ConfigInterface>,
+        event_bus: Arc<dyn EventBusInterface>,
         item_repository: Arc<dyn ItemRepositoryInterface>,
     ) -> ItemService {
         ItemService {
             config,
+            event_bus,
             item_repository,
         }
     }
+
+    /// Applies an item event and persists its result.
+    /// Returns the updated item, or None if the item was consumed.
+    fn process_event(
+        &self,
+        mut item: Item,
+        event: impl FnOnce(&mut Item) -> ItemEventResult,
+    ) -> GameResult<Option<Item>> {
+        let success = event(&mut item)?;
+        self.persist_event_result(item, success.consume)
+    }
+
+    /// Deletes the item if it was consumed by an item event and saves it otherwise
+    fn persist_event_result(&self, item: Item, consume: bool) -> GameResult<Option<Item>> {
+        if consume {
+            self.item_repository.delete(item.clone())?;
+            self.event_bus.emit(GameEvent::ItemConsumed { item });
+            Ok(None)
+        } else {
+            let item = self.item_repository.save_checked(item)?;
+            self.event_bus
+                .emit(GameEvent::ItemChanged { item: item.clone() });
+            Ok(Some(item))
+        }
+    }
 }
 
 impl ItemServiceInterface for ItemService {
@@ -92,9 +124,11 @@ impl ItemServiceInterface for ItemService {
                     ))?;
 
             item_to_edit.add(amount)?;
-            self.item_repository.save(item_to_edit)?
+            self.item_repository.save_checked(item_to_edit)?
         };
 
+        self.event_bus
+            .emit(GameEvent::ItemReceived { item: item.clone() });
         Ok(item)
     }
 
@@ -128,14 +162,16 @@ impl ItemServiceInterface for ItemService {
         function: Box<dyn Fn(&mut Item) -> ItemEventResult>,
     ) -> GameResult<ItemEventSuccess> {
         let success = function(&mut item)?;
+        self.persist_event_result(item, success.consume)?;
+        Ok(success)
+    }
 
-        if success.consume {
-            self.item_repository.delete(item)?;
-        } else {
-            self.item_repository.save(item)?;
-        }
+    fn use_as_rod(&self, rod: Item) -> GameResult<Option<Item>> {
+        self.process_event(rod, |item| item.use_as_rod(self.config.clone()))
+    }
 
-        Ok(success)
+    fn use_as_bait(&self, bait: Item) -> GameResult<Option<Item>> {
+        self.process_event(bait, |item| item.use_as_bait(self.config.clone()))
     }
 
     fn get_inventory(&self, user: &User) -> GameResult<Inventory> {
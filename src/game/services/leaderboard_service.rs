@@ -0,0 +1,152 @@
+use crate::config::ConfigInterface;
+use crate::dto::leaderboard::{Leaderboard, LeaderboardPage, RecordLeaderboardEntry};
+use crate::enums::leaderboard_kind::LeaderboardKind;
+use crate::enums::leaderboard_window::LeaderboardWindow;
+use crate::game::errors::input::GameInputError;
+use crate::game::errors::resource::GameResourceError;
+use crate::game::errors::GameResult;
+use crate::game::game_time::GameTime;
+use crate::game::repositories::catch_event_repository::CatchEventRepositoryInterface;
+use crate::game::repositories::fishing_history_entry_repository::FishingHistoryEntryRepositoryInterface;
+use crate::game::repositories::sale_event_repository::SaleEventRepositoryInterface;
+use crate::game::repositories::user_repository::UserRepositoryInterface;
+use std::sync::Arc;
+
+pub trait LeaderboardServiceInterface: Send + Sync {
+    fn get_leaderboard(
+        &self,
+        kind: LeaderboardKind,
+        window: LeaderboardWindow,
+        page: LeaderboardPage,
+    ) -> GameResult<Leaderboard>;
+    fn get_species_leaderboard(
+        &self,
+        species_id: i32,
+        window: LeaderboardWindow,
+        page: LeaderboardPage,
+    ) -> GameResult<Leaderboard<RecordLeaderboardEntry>>;
+}
+
+pub struct LeaderboardService {
+    config: Arc<dyn ConfigInterface>,
+    game_time: Arc<GameTime>,
+    catch_event_repository: Arc<dyn CatchEventRepositoryInterface>,
+    fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
+    sale_event_repository: Arc<dyn SaleEventRepositoryInterface>,
+    user_repository: Arc<dyn UserRepositoryInterface>,
+}
+
+impl LeaderboardService {
+    pub fn new(
+        config: Arc<dyn ConfigInterface>,
+        game_time: Arc<GameTime>,
+        catch_event_repository: Arc<dyn CatchEventRepositoryInterface>,
+        fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
+        sale_event_repository: Arc<dyn SaleEventRepositoryInterface>,
+        user_repository: Arc<dyn UserRepositoryInterface>,
+    ) -> Self {
+        Self {
+            config,
+            game_time,
+            catch_event_repository,
+            fishing_history_entry_repository,
+            sale_event_repository,
+            user_repository,
+        }
+    }
+}
+
+impl LeaderboardServiceInterface for LeaderboardService {
+    fn get_leaderboard(
+        &self,
+        kind: LeaderboardKind,
+        window: LeaderboardWindow,
+        page: LeaderboardPage,
+    ) -> GameResult<Leaderboard> {
+        let since = window.get_start(&self.game_time)?;
+        let leaderboard = match (kind, since) {
+            (LeaderboardKind::MostCatches, None) => self
+                .fishing_history_entry_repository
+                .rank_users_by_caught_count(page)?,
+            (LeaderboardKind::MostCatches, Some(since)) => self
+                .catch_event_repository
+                .rank_users_by_catches_since(since, page)?,
+            (LeaderboardKind::MostSpecies, None) => self
+                .fishing_history_entry_repository
+                .rank_users_by_caught_species(page)?,
+            (LeaderboardKind::MostSpecies, Some(since)) => self
+                .catch_event_repository
+                .rank_users_by_species_caught_since(since, page)?,
+            (LeaderboardKind::MostCredits, None) => self.user_repository.rank_by_credits(page)?,
+            // Credits are a balance, there is nothing to count within a window
+            (LeaderboardKind::MostCredits, Some(_)) => {
+                return Err(GameInputError::unsupported_leaderboard_window(kind, window).into());
+            }
+            (LeaderboardKind::MostSold, None) => self
+                .fishing_history_entry_repository
+                .rank_users_by_sold_count(page)?,
+            (LeaderboardKind::MostSold, Some(since)) => self
+                .sale_event_repository
+                .rank_users_by_sales_since(since, page)?,
+        };
+        Ok(leaderboard)
+    }
+
+    fn get_species_leaderboard(
+        &self,
+        species_id: i32,
+        window: LeaderboardWindow,
+        page: LeaderboardPage,
+    ) -> GameResult<Leaderboard<RecordLeaderboardEntry>> {
+        if self.config.get_species_data(species_id).is_none() {
+            return Err(GameResourceError::species_not_found(species_id).into());
+        }
+
+        let (total_entries, records) = match window.get_start(&self.game_time)? {
+            None => {
+                let ranked_entries = self
+                    .fishing_history_entry_repository
+                    .rank_by_largest_catch(species_id, page)?;
+                let records = ranked_entries
+                    .entries
+                    .into_iter()
+                    .map(|(entry, external_id)| {
+                        let record = entry.get_largest_record(self.config.clone())?;
+                        Ok((entry.user_id, external_id, record))
+                    })
+                    .collect::<GameResult<Vec<_>>>()?;
+                (ranked_entries.total_entries, records)
+            }
+            Some(since) => {
+                let ranked_catches = self
+                    .catch_event_repository
+                    .rank_by_largest_catch_since(species_id, since, page)?;
+                let records = ranked_catches
+                    .entries
+                    .into_iter()
+                    .map(|(catch, external_id)| (catch.user_id, external_id, catch.get_record()))
+                    .collect();
+                (ranked_catches.total_entries, records)
+            }
+        };
+
+        let entries = records
+            .into_iter()
+            .enumerate()
+            .map(
+                |(index, (user_id, external_id, record))| RecordLeaderboardEntry {
+                    rank: page.rank_at(index),
+                    user_id,
+                    external_id,
+                    species_id,
+                    record,
+                },
+            )
+            .collect();
+
+        Ok(Leaderboard {
+            total_entries,
+            entries,
+        })
+    }
+}
@@ -1,29 +1,120 @@
-use crate::game::errors::GameResult;
+use crate::database::{transaction, DatabaseInterface};
+use crate::game::errors::input::GameInputError;
+use crate::game::errors::resource::GameResourceError;
+use crate::game::errors::{GameError, GameResult};
 use crate::game::repositories::pond_repository::PondRepositoryInterface;
+use crate::game::repositories::specimen_repository::SpecimenRepositoryInterface;
 use crate::models::pond::{NewPond, Pond};
+use crate::models::specimen::Specimen;
 use crate::models::user::User;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 pub trait PondServiceInterface: Send + Sync {
     fn create_and_save_pond(&self, owner_user: &User, capacity: i32) -> GameResult<Pond>;
+    fn get_ponds(&self, owner_user: &User) -> GameResult<Vec<Pond>>;
+    fn get_pond_specimens(&self, pond: &Pond) -> GameResult<Vec<Specimen>>;
+    fn add_specimen(&self, user: &User, pond: &Pond, specimen: Specimen) -> GameResult<Specimen>;
+    fn remove_specimen(&self, user: &User, specimen: Specimen) -> GameResult<Specimen>;
 }
 
 pub struct PondService {
+    database: Arc<RwLock<dyn DatabaseInterface>>,
     pond_repository: Arc<dyn PondRepositoryInterface>,
+    specimen_repository: Arc<dyn SpecimenRepositoryInterface>,
 }
 
 impl PondService {
-    pub fn new(pond_repository: Arc<dyn PondRepositoryInterface>) -> PondService {
-        PondService { pond_repository }
+    pub fn new(
+        database: Arc<RwLock<dyn DatabaseInterface>>,
+        pond_repository: Arc<dyn PondRepositoryInterface>,
+        specimen_repository: Arc<dyn SpecimenRepositoryInterface>,
+    ) -> PondService {
+        PondService {
+            database,
+            pond_repository,
+            specimen_repository,
+        }
+    }
+
+    /// Reloads a specimen of the user, the given one might be outdated or not theirs
+    fn find_owned_specimen(&self, user: &User, specimen: &Specimen) -> GameResult<Specimen> {
+        match self.specimen_repository.find(specimen.id)? {
+            Some(stored_specimen) if stored_specimen.user_id == user.id => Ok(stored_specimen),
+            Some(_) => {
+                Err(GameResourceError::specimen_not_owned(specimen.id, user.external_id).into())
+            }
+            None => Err(GameResourceError::specimen_not_found(specimen.id).into()),
+        }
+    }
+
+    fn save_specimen(&self, specimen: Specimen) -> GameResult<Specimen> {
+        let specimen_id = specimen.id;
+        self.specimen_repository
+            .save_checked(specimen)
+            .map_err(|e| -> GameError {
+                match e.get_database_error() {
+                    Some(db_error) if db_error.is_not_found() => {
+                        GameResourceError::specimen_not_found(specimen_id).into()
+                    }
+                    _ => e.into(),
+                }
+            })
     }
 }
 
 impl PondServiceInterface for PondService {
     fn create_and_save_pond(&self, owner_user: &User, capacity: i32) -> GameResult<Pond> {
+        if capacity <= 0 {
+            return Err(GameInputError::invalid_pond_capacity(capacity).into());
+        }
+
         let pond = NewPond {
             user_id: owner_user.id,
             capacity,
         };
         Ok(self.pond_repository.create(pond)?)
     }
+
+    fn get_ponds(&self, owner_user: &User) -> GameResult<Vec<Pond>> {
+        Ok(self.pond_repository.find_by_user(owner_user)?)
+    }
+
+    fn get_pond_specimens(&self, pond: &Pond) -> GameResult<Vec<Specimen>> {
+        Ok(self.specimen_repository.find_by_pond(pond.id)?)
+    }
+
+    fn add_specimen(&self, user: &User, pond: &Pond, specimen: Specimen) -> GameResult<Specimen> {
+        transaction(&self.database, || {
+            let mut specimen = self.find_owned_specimen(user, &specimen)?;
+
+            // Use the stored pond, the given one might have an outdated capacity.
+            // Its lock makes concurrent additions to the same pond wait for this one.
+            let pond = self
+                .pond_repository
+                .find_for_update(pond.id)?
+                .ok_or_else(|| GameResourceError::pond_not_found(pond.id))?;
+            if pond.user_id != user.id {
+                return Err(GameResourceError::pond_not_owned(pond.id, user.external_id).into());
+            }
+
+            if specimen.pond_id == Some(pond.id) {
+                return Ok(specimen);
+            }
+
+            let specimen_count = self.specimen_repository.count_by_pond(pond.id)?;
+            if specimen_count >= pond.capacity as i64 {
+                return Err(GameResourceError::pond_full(pond.id, pond.capacity).into());
+            }
+
+            specimen.pond_id = Some(pond.id);
+            self.save_specimen(specimen)
+        })
+    }
+
+    fn remove_specimen(&self, user: &User, specimen: Specimen) -> GameResult<Specimen> {
+        let mut specimen = self.find_owned_specimen(user, &specimen)?;
+
+        specimen.pond_id = None;
+        self.save_specimen(specimen)
+    }
 }
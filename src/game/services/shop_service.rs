@@ -0,0 +1,220 @@
+use crate::config::ConfigInterface;
+use crate::data::item_data::ItemData;
+use crate::database::{transaction, DatabaseInterface};
+use crate::dto::purchase_result::PurchaseResult;
+use crate::dto::sell_result::SellResult;
+use crate::enums::item_category::ItemCategory;
+use crate::game::errors::repository::GameRepositoryError;
+use crate::game::errors::resource::GameResourceError;
+use crate::game::errors::{GameError, GameResult};
+use crate::game::game_time::GameTime;
+use crate::game::repositories::specimen_repository::SpecimenRepositoryInterface;
+use crate::game::repositories::user_repository::UserRepositoryInterface;
+use crate::game::services::fishing_history_service::FishingHistoryServiceInterface;
+use crate::game::services::item_service::ItemServiceInterface;
+use crate::models::item::attributes_container::ItemAttributesContainerInterface;
+use crate::models::item::properties_container::ItemPropertiesContainerInterface;
+use crate::models::specimen::Specimen;
+use crate::models::user::User;
+use std::sync::{Arc, RwLock};
+
+pub trait ShopServiceInterface: Send + Sync {
+    fn get_purchasable_items(&self) -> Vec<Arc<ItemData>>;
+    fn purchase_item(
+        &self,
+        user: &User,
+        item_data: Arc<ItemData>,
+        count: u64,
+    ) -> GameResult<PurchaseResult>;
+    fn get_sell_price(&self, specimen: &Specimen) -> GameResult<i64>;
+    fn sell_specimens(&self, user: &User, specimens: Vec<Specimen>) -> GameResult<SellResult>;
+    fn sell_species(&self, user: &User, species_id: i32) -> GameResult<SellResult>;
+    fn sell_below_size_ratio(&self, user: &User, max_size_ratio: f32) -> GameResult<SellResult>;
+}
+
+pub struct ShopService {
+    config: Arc<dyn ConfigInterface>,
+    game_time: Arc<GameTime>,
+    database: Arc<RwLock<dyn DatabaseInterface>>,
+    specimen_repository: Arc<dyn SpecimenRepositoryInterface>,
+    user_repository: Arc<dyn UserRepositoryInterface>,
+    fishing_history_service: Arc<dyn FishingHistoryServiceInterface>,
+    item_service: Arc<dyn ItemServiceInterface>,
+}
+
+impl ShopService {
+    pub fn new(
+        config: Arc<dyn ConfigInterface>,
+        game_time: Arc<GameTime>,
+        database: Arc<RwLock<dyn DatabaseInterface>>,
+        specimen_repository: Arc<dyn SpecimenRepositoryInterface>,
+        user_repository: Arc<dyn UserRepositoryInterface>,
+        fishing_history_service: Arc<dyn FishingHistoryServiceInterface>,
+        item_service: Arc<dyn ItemServiceInterface>,
+    ) -> Self {
+        Self {
+            config,
+            game_time,
+            database,
+            specimen_repository,
+            user_repository,
+            fishing_history_service,
+            item_service,
+        }
+    }
+
+    /// Sells the user's specimens with the given ids, `skip_missing` ignores specimens which were
+    /// sold or released since their ids were selected instead of failing the whole sale
+    fn sell_specimen_ids(
+        &self,
+        user: &User,
+        mut ids: Vec<i64>,
+        skip_missing: bool,
+    ) -> GameResult<SellResult> {
+        ids.sort_unstable();
+        ids.dedup();
+
+        transaction(&self.database, || {
+            // Deleting first makes concurrent sales of the same specimens wait for each other,
+            // only the stored rows are trusted for the ownership and the price
+            let sold_specimens = self.specimen_repository.delete_many(user, &ids)?;
+            let missing_id = ids
+                .iter()
+                .find(|id| !sold_specimens.iter().any(|specimen| specimen.id == **id));
+            if let (Some(&missing_id), false) = (missing_id, skip_missing) {
+                let error = match self.specimen_repository.find(missing_id)? {
+                    Some(_) => GameResourceError::specimen_not_owned(missing_id, user.external_id),
+                    None => GameResourceError::specimen_not_found(missing_id),
+                };
+                return Err(error.into());
+            }
+
+            let credits = sold_specimens
+                .iter()
+                .map(|specimen| self.get_sell_price(specimen))
+                .sum::<GameResult<i64>>()?;
+
+            let updated_user = self
+                .user_repository
+                .add_credits(user.id, credits)
+                .map_err(|e| Self::map_user_not_found(user, e))?;
+
+            let sell_time = self.game_time.real_now();
+            for specimen in &sold_specimens {
+                self.fishing_history_service
+                    .register_sell(specimen, sell_time)?;
+            }
+
+            Ok(SellResult {
+                user: updated_user,
+                specimens: sold_specimens,
+                credits,
+                achievements: Vec::new(),
+            })
+        })
+    }
+
+    fn map_user_not_found(user: &User, error: GameRepositoryError) -> GameError {
+        match error.get_database_error() {
+            Some(db_error) if db_error.is_not_found() => {
+                GameResourceError::user_not_found(user.external_id).into()
+            }
+            _ => error.into(),
+        }
+    }
+}
+
+impl ShopServiceInterface for ShopService {
+    fn get_purchasable_items(&self) -> Vec<Arc<ItemData>> {
+        let mut items = self
+            .config
+            .get_items_by_category(ItemCategory::Shop)
+            .map(|items| items.to_vec())
+            .unwrap_or_default();
+        items.sort_by_key(|item| item.id);
+        items
+    }
+
+    fn purchase_item(
+        &self,
+        user: &User,
+        item_data: Arc<ItemData>,
+        count: u64,
+    ) -> GameResult<PurchaseResult> {
+        let cost_per_unit = item_data
+            .get_cost()
+            .ok_or_else(|| GameResourceError::item_not_purchasable(item_data.id))?;
+        let count = if item_data.is_stackable() {
+            count.max(1)
+        } else {
+            1
+        };
+        let cost = (cost_per_unit as i64).saturating_mul(count.try_into().unwrap_or(i64::MAX));
+
+        transaction(&self.database, || {
+            let Some(paid_user) = self.user_repository.remove_credits(user.id, cost)? else {
+                return match self.user_repository.find(user.id)? {
+                    Some(found_user) => Err(GameResourceError::insufficient_credits(
+                        user.external_id,
+                        cost,
+                        found_user.credits,
+                    )
+                    .into()),
+                    None => Err(GameResourceError::user_not_found(user.external_id).into()),
+                };
+            };
+
+            let item = if item_data.is_stackable() {
+                self.item_service
+                    .create_and_save_item_with_count(item_data, &paid_user, count)?
+            } else {
+                self.item_service
+                    .create_and_save_item(item_data, &paid_user)?
+            };
+
+            Ok(PurchaseResult {
+                user: paid_user,
+                item,
+                cost,
+                achievements: Vec::new(),
+            })
+        })
+    }
+
+    fn get_sell_price(&self, specimen: &Specimen) -> GameResult<i64> {
+        specimen.get_sell_price(self.config.clone(), &self.game_time)
+    }
+
+    fn sell_specimens(&self, user: &User, specimens: Vec<Specimen>) -> GameResult<SellResult> {
+        let ids: Vec<i64> = specimens.iter().map(|specimen| specimen.id).collect();
+        self.sell_specimen_ids(user, ids, false)
+    }
+
+    fn sell_species(&self, user: &User, species_id: i32) -> GameResult<SellResult> {
+        // Selecting in the same transaction as the sale, specimens sold concurrently are skipped
+        transaction(&self.database, || {
+            let ids = self
+                .specimen_repository
+                .find_by_user_and_species(user, species_id)?
+                .iter()
+                .map(|specimen| specimen.id)
+                .collect();
+            self.sell_specimen_ids(user, ids, true)
+        })
+    }
+
+    fn sell_below_size_ratio(&self, user: &User, max_size_ratio: f32) -> GameResult<SellResult> {
+        // Selecting in the same transaction as the sale, specimens sold concurrently are skipped
+        transaction(&self.database, || {
+            let mut ids = Vec::new();
+            for specimen in self.specimen_repository.find_by_user(user)? {
+                let size_ratio =
+                    specimen.get_total_size_ratio(self.config.clone(), &self.game_time)?;
+                if size_ratio < max_size_ratio {
+                    ids.push(specimen.id);
+                }
+            }
+            self.sell_specimen_ids(user, ids, true)
+        })
+    }
+}
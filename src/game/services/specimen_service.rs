@@ -1,28 +1,42 @@
 use crate::data::species_data::SpeciesData;
 use crate::game::errors::resource::GameResourceError;
 use crate::game::errors::GameResult;
+use crate::game::event_bus::game_event::GameEvent;
+use crate::game::event_bus::EventBusInterface;
 use crate::game::repositories::specimen_repository::SpecimenRepositoryInterface;
 use crate::models::specimen::{NewSpecimen, Specimen};
 use crate::models::user::User;
 use std::sync::Arc;
 
 pub trait SpecimenServiceInterface: Send + Sync {
+    /// Generates the same specimen for the same seed
     fn generate_and_save_specimen(
         &self,
         owner_user: &User,
         species_data: Arc<SpeciesData>,
+        seed: u64,
     ) -> GameResult<Specimen>;
 
-    fn process_catch(&self, user: &User, species_data: Arc<SpeciesData>) -> GameResult<Specimen>;
+    fn process_catch(
+        &self,
+        user: &User,
+        species_data: Arc<SpeciesData>,
+        seed: u64,
+    ) -> GameResult<Specimen>;
 }
 
 pub struct SpecimenService {
+    event_bus: Arc<dyn EventBusInterface>,
     specimen_repository: Arc<dyn SpecimenRepositoryInterface>,
 }
 
 impl SpecimenService {
-    pub fn new(specimen_repository: Arc<dyn SpecimenRepositoryInterface>) -> Self {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        specimen_repository: Arc<dyn SpecimenRepositoryInterface>,
+    ) -> Self {
         Self {
+            event_bus,
             specimen_repository,
         }
     }
@@ -33,8 +47,9 @@ impl SpecimenServiceInterface for SpecimenService {
         &self,
         owner_user: &User,
         species_data: Arc<SpeciesData>,
+        seed: u64,
     ) -> GameResult<Specimen> {
-        let new_fish = NewSpecimen::generate(owner_user.id, species_data.id);
+        let new_fish = NewSpecimen::generate(owner_user.id, species_data.id, seed);
         self.specimen_repository
             .create(new_fish)
             .map_err(|e| match e.get_database_error() {
@@ -45,8 +60,16 @@ impl SpecimenServiceInterface for SpecimenService {
             })
     }
 
-    fn process_catch(&self, user: &User, species_data: Arc<SpeciesData>) -> GameResult<Specimen> {
-        let fish = self.generate_and_save_specimen(user, species_data)?;
+    fn process_catch(
+        &self,
+        user: &User,
+        species_data: Arc<SpeciesData>,
+        seed: u64,
+    ) -> GameResult<Specimen> {
+        let fish = self.generate_and_save_specimen(user, species_data, seed)?;
+        self.event_bus.emit(GameEvent::SpecimenCaught {
+            specimen: fish.clone(),
+        });
         Ok(fish)
     }
 }
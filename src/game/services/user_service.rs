@@ -1,7 +1,9 @@
 use crate::data::location_data::LocationData;
 use crate::dto::location_unlock_requirements::LocationUnlockRequirements;
 use crate::game::errors::resource::GameResourceError;
-use crate::game::errors::GameResult;
+use crate::game::errors::{GameError, GameResult};
+use crate::game::event_bus::game_event::GameEvent;
+use crate::game::event_bus::EventBusInterface;
 use crate::game::repositories::fishing_history_entry_repository::FishingHistoryEntryRepositoryInterface;
 use crate::game::repositories::user_repository::UserRepositoryInterface;
 use crate::models::user::{NewUser, User};
@@ -25,16 +27,19 @@ pub trait UserServiceInterface: Send + Sync {
 }
 
 pub struct UserService {
+    event_bus: Arc<dyn EventBusInterface>,
     fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
     user_repository: Arc<dyn UserRepositoryInterface>,
 }
 
 impl UserService {
     pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
         fishing_history_entry_repository: Arc<dyn FishingHistoryEntryRepositoryInterface>,
         user_repository: Arc<dyn UserRepositoryInterface>,
     ) -> UserService {
         UserService {
+            event_bus,
             fishing_history_entry_repository,
             user_repository,
         }
@@ -43,8 +48,10 @@ impl UserService {
 
 impl UserServiceInterface for UserService {
     fn create_and_save_user(&self, external_id: i64) -> GameResult<User> {
-        let user = NewUser { external_id };
-        Ok(self.user_repository.create(user)?)
+        let user = self.user_repository.create(NewUser { external_id })?;
+        self.event_bus
+            .emit(GameEvent::UserRegistered { user: user.clone() });
+        Ok(user)
     }
 
     fn get_unmet_location_unlock_requirements(
@@ -89,15 +96,26 @@ impl UserServiceInterface for UserService {
             );
         }
 
-        self.user_repository
+        let user_location = self
+            .user_repository
             .unlock_location(user.id, location_data.id)
-            .map_err(|e| match e.get_database_error() {
-                Some(db_error) if db_error.is_unique_constraint_violation() => {
-                    GameResourceError::location_already_unlocked(user.external_id, location_data.id)
+            .map_err(|e| -> GameError {
+                match e.get_database_error() {
+                    Some(db_error) if db_error.is_unique_constraint_violation() => {
+                        GameResourceError::location_already_unlocked(
+                            user.external_id,
+                            location_data.id,
+                        )
                         .into()
+                    }
+                    _ => e.into(),
                 }
-                _ => e.into(),
-            })
+            })?;
+
+        self.event_bus.emit(GameEvent::LocationUnlocked {
+            location: user_location.clone(),
+        });
+        Ok(user_location)
     }
 
     fn get_unlocked_locations(&self, user: &User) -> GameResult<Vec<UserLocation>> {
@@ -1,32 +1,65 @@
 use crate::config::ConfigInterface;
 use crate::data::location_data::LocationData;
+use crate::dto::daily_weather_summary::DailyWeatherSummary;
+use crate::game::errors::input::GameInputError;
 use crate::game::errors::resource::GameResourceError;
 use crate::game::errors::GameResult;
+use crate::game::game_time::GameTime;
 use crate::game::systems::weather_system::config::WeatherSystemConfig;
 use crate::game::systems::weather_system::weather::Weather;
 use crate::game::systems::weather_system::WeatherSystem;
-use chrono::DateTime;
+use chrono::{DateTime, NaiveDate, TimeDelta, TimeZone};
 use chrono_tz::Tz;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// The most samples a weather timeline can have
+pub const MAX_TIMELINE_SAMPLES: i64 = 10_000;
+/// The most days the weather can be summarized for at once
+pub const MAX_SUMMARY_DAYS: u32 = 366;
+
 pub trait WeatherServiceInterface: Send + Sync {
+    /// The weather at the in-game time corresponding to the given real time
     fn get_weather(
         &self,
         location_data: Arc<LocationData>,
         time: DateTime<Tz>,
     ) -> GameResult<Weather>;
     fn get_current_weather(&self, location_data: Arc<LocationData>) -> GameResult<Weather>;
+    /// The weather at the given in-game time
+    fn get_game_time_weather(
+        &self,
+        location_data: Arc<LocationData>,
+        game_time: DateTime<Tz>,
+    ) -> GameResult<Weather>;
+    /// The weather from the start (inclusive) to the end (exclusive) in-game time,
+    /// sampled at the given positive in-game step, raised to at least one minute.
+    /// Timelines have at most [MAX_TIMELINE_SAMPLES] samples.
+    fn get_weather_timeline(
+        &self,
+        location_data: Arc<LocationData>,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        step: TimeDelta,
+    ) -> GameResult<Vec<Weather>>;
+    /// A summary of the weather for each in-game day starting at the given date,
+    /// at most [MAX_SUMMARY_DAYS] days
+    fn get_daily_weather_summaries(
+        &self,
+        location_data: Arc<LocationData>,
+        start_date: NaiveDate,
+        days: u32,
+    ) -> GameResult<Vec<DailyWeatherSummary>>;
 }
 
 pub struct WeatherService {
-    time_multiplier: f32,
+    game_time: Arc<GameTime>,
     // Weather systems by location id
     weather_systems: HashMap<i32, WeatherSystem>,
 }
 
 impl WeatherService {
-    pub fn new(config: Arc<dyn ConfigInterface>) -> Self {
+    pub fn new(config: Arc<dyn ConfigInterface>, game_time: Arc<GameTime>) -> Self {
         let mut systems = HashMap::new();
         config
             .locations()
@@ -40,27 +73,128 @@ impl WeatherService {
             });
 
         Self {
-            time_multiplier: config.settings().time_speed_multiplier,
+            game_time,
             weather_systems: systems,
         }
     }
 }
 
+impl WeatherService {
+    fn summarize_day(
+        &self,
+        location_data: Arc<LocationData>,
+        date: NaiveDate,
+    ) -> GameResult<DailyWeatherSummary> {
+        // Hours skipped by daylight saving time are left out
+        let hourly_weather = (0..24)
+            .filter_map(|hour| {
+                let local_time = date.and_hms_opt(hour, 0, 0)?;
+                location_data
+                    .timezone
+                    .from_local_datetime(&local_time)
+                    .earliest()
+            })
+            .map(|time| self.get_game_time_weather(location_data.clone(), time))
+            .collect::<GameResult<Vec<Weather>>>()?;
+
+        let sample_count = hourly_weather.len() as f32;
+        let temperatures = hourly_weather.iter().map(|weather| weather.temperature_c);
+
+        Ok(DailyWeatherSummary {
+            location_id: location_data.id,
+            date,
+            season: hourly_weather
+                .first()
+                .map(|weather| weather.season)
+                .unwrap_or_default(),
+            min_temperature_c: temperatures.clone().fold(f32::INFINITY, f32::min),
+            max_temperature_c: temperatures.clone().fold(f32::NEG_INFINITY, f32::max),
+            average_temperature_c: temperatures.sum::<f32>() / sample_count,
+            rain_hours: hourly_weather
+                .iter()
+                .filter(|weather| weather.is_raining)
+                .count() as u32,
+            average_cloudiness: hourly_weather
+                .iter()
+                .map(|weather| weather.cloudiness)
+                .sum::<f32>()
+                / sample_count,
+        })
+    }
+}
+
 impl WeatherServiceInterface for WeatherService {
     fn get_weather(
         &self,
         location_data: Arc<LocationData>,
         time: DateTime<Tz>,
+    ) -> GameResult<Weather> {
+        let game_time = self.game_time.to_game_time(time)?;
+        self.get_game_time_weather(location_data, game_time)
+    }
+
+    fn get_current_weather(&self, location_data: Arc<LocationData>) -> GameResult<Weather> {
+        let time_now = self
+            .game_time
+            .real_now()
+            .with_timezone(&location_data.timezone);
+        self.get_weather(location_data, time_now)
+    }
+
+    fn get_game_time_weather(
+        &self,
+        location_data: Arc<LocationData>,
+        game_time: DateTime<Tz>,
     ) -> GameResult<Weather> {
         let location_id = location_data.id;
         self.weather_systems
             .get(&location_id)
-            .map(|system| system.get_weather(time, self.time_multiplier))
+            .map(|system| system.get_weather(game_time))
             .ok_or_else(|| GameResourceError::location_not_found(location_id).into())
     }
 
-    fn get_current_weather(&self, location_data: Arc<LocationData>) -> GameResult<Weather> {
-        let time_now = location_data.get_local_time();
-        self.get_weather(location_data, time_now)
+    fn get_weather_timeline(
+        &self,
+        location_data: Arc<LocationData>,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        step: TimeDelta,
+    ) -> GameResult<Vec<Weather>> {
+        if step <= TimeDelta::zero() {
+            return Err(GameInputError::invalid_timeline_step(step).into());
+        }
+        let step = step.max(TimeDelta::minutes(1));
+
+        let span_ms = (end - start).num_milliseconds().max(0);
+        let step_ms = step.num_milliseconds();
+        let samples = span_ms / step_ms + i64::from(span_ms % step_ms != 0);
+        if samples > MAX_TIMELINE_SAMPLES {
+            return Err(GameInputError::timeline_too_long(samples, MAX_TIMELINE_SAMPLES).into());
+        }
+
+        let mut timeline = Vec::new();
+        let mut time = Some(start);
+        while let Some(sample_time) = time.filter(|time| *time < end) {
+            timeline.push(self.get_game_time_weather(location_data.clone(), sample_time)?);
+            time = sample_time.checked_add_signed(step);
+        }
+        Ok(timeline)
+    }
+
+    fn get_daily_weather_summaries(
+        &self,
+        location_data: Arc<LocationData>,
+        start_date: NaiveDate,
+        days: u32,
+    ) -> GameResult<Vec<DailyWeatherSummary>> {
+        if days > MAX_SUMMARY_DAYS {
+            return Err(GameInputError::summary_too_long(days, MAX_SUMMARY_DAYS).into());
+        }
+
+        start_date
+            .iter_days()
+            .take(days as usize)
+            .map(|date| self.summarize_day(location_data.clone(), date))
+            .collect()
     }
 }
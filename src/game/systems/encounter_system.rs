@@ -1,4 +1,10 @@
+use crate::data::encounter_data::EncounterData;
 use crate::data::species_data::SpeciesData;
+use crate::enums::moon_phase::MoonPhase;
+use crate::enums::season::Season;
+use crate::enums::tide_state::TideState;
+use crate::enums::weather_condition::WeatherCondition;
+use crate::game::systems::weather_system::weather::Weather;
 use chrono::{DateTime, Timelike};
 use chrono_tz::Tz;
 use rand::seq::IndexedRandom;
@@ -10,46 +16,105 @@ pub type SpeciesId = i32;
 pub type LocationId = i32;
 pub type RarityLevel = u8;
 
-pub type RarityEncounters = HashMap<RarityLevel, Vec<SpeciesId>>;
+pub type RarityEncounters = HashMap<RarityLevel, Vec<PossibleEncounter>>;
 pub type LocationEncounters = HashMap<LocationId, RarityEncounters>;
-pub type WeatherEncounters = HashMap<EncounterWeather, LocationEncounters>;
-pub type HourlyEncounters = HashMap<u8, WeatherEncounters>;
+pub type HourlyEncounters = HashMap<u8, LocationEncounters>;
 
-#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-pub enum EncounterWeather {
-    Any,
-    Rain,
+/// The environmental conditions at the time and location of an encounter
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct EncounterConditions {
+    pub season: Season,
+    pub temperature_c: f32,
+    pub water_temperature_c: f32,
+    pub light_level: f32,
+    pub is_raining: bool,
+    pub weather_condition: WeatherCondition,
+    pub moon_phase: MoonPhase,
+    /// None if the location has no tides
+    pub tide_state: Option<TideState>,
+}
+
+impl From<&Weather> for EncounterConditions {
+    fn from(weather: &Weather) -> Self {
+        Self {
+            season: weather.season,
+            temperature_c: weather.temperature_c,
+            water_temperature_c: weather.water_temperature_c,
+            light_level: weather.light_level,
+            is_raining: weather.is_raining,
+            weather_condition: weather.condition,
+            moon_phase: weather.moon_phase,
+            tide_state: weather.tide_state,
+        }
+    }
+}
+
+/// The equipment levels a user is fishing with
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct EncounterEquipment {
+    pub rod_level: u64,
+    pub bait_level: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PossibleEncounter {
+    pub species_id: SpeciesId,
+    pub data: EncounterData,
+}
+
+impl PossibleEncounter {
+    pub fn is_available(
+        &self,
+        time: DateTime<Tz>,
+        conditions: &EncounterConditions,
+        equipment: &EncounterEquipment,
+    ) -> bool {
+        equipment.rod_level >= self.data.min_rod_level
+            && self.data.is_active_at(time.time())
+            && self.data.are_conditions_met(conditions)
+    }
+}
+
+/// The chance of encountering a species with a single cast
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncounterProbability {
+    pub species_id: SpeciesId,
+    pub rarity_level: RarityLevel,
+    /// Between 0.0 and 1.0, the probabilities of all available encounters add up to 1.0
+    pub probability: f64,
 }
 
 pub struct EncounterSystem {
-    /// Hour -> Weather -> Location ID -> (Species ID, Rarity Level)
+    /// Hour -> Location ID -> Rarity Level -> Possible encounters
     encounters: HourlyEncounters,
     cached_weights: HashMap<RarityLevel, u64>,
+    rarity_exponent: f64,
+    bait_level_rarity_exponent_reduction: f64,
 }
 
 impl EncounterSystem {
-    pub fn new(species: Arc<HashMap<i32, Arc<SpeciesData>>>, rarity_exponent: f64) -> Self {
+    pub fn new(
+        species: Arc<HashMap<i32, Arc<SpeciesData>>>,
+        rarity_exponent: f64,
+        bait_level_rarity_exponent_reduction: f64,
+    ) -> Self {
         let mut encounters: HourlyEncounters = HashMap::new();
 
         for (species_id, species_data) in species.iter() {
             for encounter in &species_data.encounters {
-                let weather = if encounter.needs_rain {
-                    EncounterWeather::Rain
-                } else {
-                    EncounterWeather::Any
-                };
-
+                // Encounters are bucketed by hour, minute precision and conditions are checked when rolling
                 for hour in encounter.get_hours() {
                     encounters
                         .entry(hour)
                         .or_default()
-                        .entry(weather)
-                        .or_default()
                         .entry(encounter.location_id)
                         .or_default()
                         .entry(encounter.rarity_level)
                         .or_default()
-                        .push(*species_id);
+                        .push(PossibleEncounter {
+                            species_id: *species_id,
+                            data: encounter.clone(),
+                        });
                 }
             }
         }
@@ -61,6 +126,8 @@ impl EncounterSystem {
         Self {
             encounters,
             cached_weights,
+            rarity_exponent,
+            bait_level_rarity_exponent_reduction,
         }
     }
 
@@ -68,7 +135,23 @@ impl EncounterSystem {
         ((255 - rarity_level) as f64).powf(rarity_exponent) as u64 + 1
     }
 
-    fn roll_rarity_level(&self, available_rarities: &[RarityLevel]) -> Option<RarityLevel> {
+    /// Bait lowers the rarity exponent, which flattens the weight curve in favor of rarer fish
+    fn get_rarity_weight(&self, rarity_level: RarityLevel, equipment: &EncounterEquipment) -> u64 {
+        if equipment.bait_level == 0 {
+            return self.cached_weights[&rarity_level];
+        }
+
+        let reduction = equipment.bait_level as f64 * self.bait_level_rarity_exponent_reduction;
+        let rarity_exponent = (self.rarity_exponent - reduction).max(0.0);
+        Self::rarity_level_weight(rarity_level, rarity_exponent)
+    }
+
+    fn roll_rarity_level<R: Rng + ?Sized>(
+        &self,
+        available_rarities: &[RarityLevel],
+        equipment: &EncounterEquipment,
+        rng: &mut R,
+    ) -> Option<RarityLevel> {
         if available_rarities.is_empty() {
             return None;
         }
@@ -76,14 +159,13 @@ impl EncounterSystem {
         let cumulative_weights: Vec<u64> = available_rarities
             .iter()
             .scan(0u64, |sum, &rarity| {
-                *sum += self.cached_weights[&rarity];
+                *sum += self.get_rarity_weight(rarity, equipment);
                 Some(*sum)
             })
             .collect();
 
         let total = cumulative_weights.last()?;
 
-        let mut rng = rand::rng();
         let roll = rng.random_range(0..*total);
 
         let index = cumulative_weights.partition_point(|&weight| weight <= roll);
@@ -93,30 +175,101 @@ impl EncounterSystem {
     fn get_possible_rarity_encounters(
         &self,
         time: DateTime<Tz>,
-        weather: EncounterWeather,
         location_id: i32,
     ) -> Option<&RarityEncounters> {
-        self.encounters
-            .get(&(time.hour() as u8))?
-            .get(&weather)?
-            .get(&location_id)
+        self.encounters.get(&(time.hour() as u8))?.get(&location_id)
+    }
+
+    fn get_available_rarity_encounters(
+        &self,
+        time: DateTime<Tz>,
+        conditions: &EncounterConditions,
+        location_id: i32,
+        equipment: &EncounterEquipment,
+    ) -> HashMap<RarityLevel, Vec<SpeciesId>> {
+        let Some(possible_rarity_encounters) =
+            self.get_possible_rarity_encounters(time, location_id)
+        else {
+            return HashMap::new();
+        };
+
+        // Preferred conditions can change the rarity level of an encounter
+        let mut available_rarity_encounters: HashMap<RarityLevel, Vec<SpeciesId>> = HashMap::new();
+        for encounter in possible_rarity_encounters.values().flatten() {
+            if encounter.is_available(time, conditions, equipment) {
+                available_rarity_encounters
+                    .entry(encounter.data.get_rarity_level(conditions))
+                    .or_default()
+                    .push(encounter.species_id);
+            }
+        }
+
+        // A stable order makes the roll reproducible with the same RNG
+        for species_ids in available_rarity_encounters.values_mut() {
+            species_ids.sort_unstable();
+        }
+        available_rarity_encounters
     }
 
-    pub fn roll_encounter(
+    /// Rolls the same encounter for the same state of the RNG
+    pub fn roll_encounter<R: Rng + ?Sized>(
         &self,
         time: DateTime<Tz>,
-        weather: EncounterWeather,
+        conditions: &EncounterConditions,
         location_id: LocationId,
+        equipment: &EncounterEquipment,
+        rng: &mut R,
     ) -> Option<SpeciesId> {
-        let possible_rarity_encounters =
-            self.get_possible_rarity_encounters(time, weather, location_id)?;
+        let available_rarity_encounters =
+            self.get_available_rarity_encounters(time, conditions, location_id, equipment);
 
-        let valid_rarity_levels: Vec<RarityLevel> =
-            possible_rarity_encounters.keys().copied().collect();
-        let rarity = self.roll_rarity_level(&valid_rarity_levels)?;
+        let mut valid_rarity_levels: Vec<RarityLevel> =
+            available_rarity_encounters.keys().copied().collect();
+        valid_rarity_levels.sort_unstable();
+        let rarity = self.roll_rarity_level(&valid_rarity_levels, equipment, rng)?;
+
+        let possible_species = available_rarity_encounters.get(&rarity)?;
+        possible_species.choose(rng).copied()
+    }
+
+    /// The exact probabilities [Self::roll_encounter] picks each available species with,
+    /// ordered from most to least likely
+    pub fn get_encounter_probabilities(
+        &self,
+        time: DateTime<Tz>,
+        conditions: &EncounterConditions,
+        location_id: LocationId,
+        equipment: &EncounterEquipment,
+    ) -> Vec<EncounterProbability> {
+        let available_rarity_encounters =
+            self.get_available_rarity_encounters(time, conditions, location_id, equipment);
+
+        let total_weight: u64 = available_rarity_encounters
+            .keys()
+            .map(|rarity| self.get_rarity_weight(*rarity, equipment))
+            .sum();
+
+        let mut probabilities: Vec<EncounterProbability> = available_rarity_encounters
+            .iter()
+            .flat_map(|(rarity, species_ids)| {
+                let rarity_probability =
+                    self.get_rarity_weight(*rarity, equipment) as f64 / total_weight as f64;
+                let probability = rarity_probability / species_ids.len() as f64;
+                species_ids
+                    .iter()
+                    .map(move |species_id| EncounterProbability {
+                        species_id: *species_id,
+                        rarity_level: *rarity,
+                        probability,
+                    })
+            })
+            .collect();
 
-        let mut rng = rand::rng();
-        let possible_species = possible_rarity_encounters.get(&rarity)?;
-        possible_species.choose(&mut rng).copied()
+        probabilities.sort_by(|a, b| {
+            b.probability
+                .total_cmp(&a.probability)
+                .then(a.species_id.cmp(&b.species_id))
+        });
+        probabilities
     }
 }
@@ -1,6 +1,9 @@
+use crate::enums::moon_phase::MoonPhase;
+use crate::enums::weather_condition::WeatherCondition;
 use crate::game::systems::weather_system::attributes::WeatherAttributes;
 use crate::game::systems::weather_system::config::WeatherSystemConfig;
 use crate::game::systems::weather_system::weather::Weather;
+use crate::utils::lunar::{lunar_progress, moon_illumination};
 use crate::utils::math::float_interpolate;
 use chrono::{DateTime, Timelike};
 use chrono_tz::Tz;
@@ -10,6 +13,10 @@ pub mod attributes;
 pub mod config;
 pub mod weather;
 
+/// Water barely warms up during the day, so its temperature follows the air temperature
+/// at the average light level of a day instead of the current one
+const WATER_TEMPERATURE_LIGHT_LEVEL: f32 = 0.55;
+
 pub struct WeatherSystem {
     cloudiness: Perlin,
     cloud_brightness: Perlin,
@@ -18,6 +25,7 @@ pub struct WeatherSystem {
     wind_strength: Perlin,
     temperature: Perlin,
     rain_intensity: Perlin,
+    wind_direction: Perlin,
     config: WeatherSystemConfig,
 }
 
@@ -26,33 +34,46 @@ impl WeatherSystem {
         let seed = config.location_data.weather_seed;
         Self {
             cloudiness: Perlin::new(seed),
-            cloud_brightness: Perlin::new(seed * 2),
-            moisture: Perlin::new(seed * 3),
-            wind_presence: Perlin::new(seed * 4),
-            wind_strength: Perlin::new(seed * 5),
-            temperature: Perlin::new(seed * 6),
-            rain_intensity: Perlin::new(seed * 7),
+            cloud_brightness: Perlin::new(seed.wrapping_mul(2)),
+            moisture: Perlin::new(seed.wrapping_mul(3)),
+            wind_presence: Perlin::new(seed.wrapping_mul(4)),
+            wind_strength: Perlin::new(seed.wrapping_mul(5)),
+            temperature: Perlin::new(seed.wrapping_mul(6)),
+            rain_intensity: Perlin::new(seed.wrapping_mul(7)),
+            wind_direction: Perlin::new(seed.wrapping_mul(8)),
             config,
         }
     }
 
-    fn time_to_noise_input(time: DateTime<Tz>) -> f64 {
-        time.timestamp() as f64 / 1_000_000.0
+    fn time_to_noise_input(game_time: DateTime<Tz>) -> f64 {
+        game_time.timestamp() as f64 / 1_000_000.0
     }
 
     fn normalize_noise(noise: f64) -> f32 {
         (noise as f32 + 1.0) / 2.0
     }
 
-    /// Ensures its hottest at the middle of the day
-    pub fn light_level(time: DateTime<Tz>) -> f32 {
-        let hour = time.hour() as f32 + (time.minute() as f32 / 60.0);
+    /// The light level from the position of the sun at the location, if it has coordinates.
+    /// Falls back to [Self::light_level] otherwise.
+    pub fn location_light_level(&self, game_time: DateTime<Tz>) -> f32 {
+        let Some(elevation) = self.config.location_data.get_solar_elevation_deg(game_time) else {
+            return Self::light_level(game_time);
+        };
+
+        // Fully dark at the end of civil twilight, brightest with the sun straight above
+        let twilight_elevation = (elevation + 6.0).clamp(0.0, 90.0) as f32;
+        (0.1 + 0.9 * twilight_elevation.to_radians().sin()).clamp(0.1, 1.0)
+    }
+
+    /// Ensures its hottest at the middle of the in-game day
+    pub fn light_level(game_time: DateTime<Tz>) -> f32 {
+        let hour = game_time.hour() as f32 + (game_time.minute() as f32 / 60.0);
         let multiplier = (((hour - 6.0) * std::f32::consts::PI / 12.0).sin() * 0.45) + 0.55;
         multiplier.clamp(0.1, 1.0)
     }
 
-    pub fn get_weather_attributes(&self, time: DateTime<Tz>) -> WeatherAttributes {
-        let t = Self::time_to_noise_input(time);
+    pub fn get_weather_attributes(&self, game_time: DateTime<Tz>) -> WeatherAttributes {
+        let t = Self::time_to_noise_input(game_time);
 
         let cloudiness_noise = self.cloudiness.get([t * 5.5, 0.0]);
         let cloud_brightness_noise = self.cloud_brightness.get([t * 2.5, 1.0]);
@@ -61,12 +82,14 @@ impl WeatherSystem {
         let wind_strength_noise = self.wind_strength.get([t * 4.5, 5_000_000.0]);
         let temperature_noise = self.temperature.get([t, 2_000_000.0]);
         let rain_intensity_noise = self.rain_intensity.get([t, 0.0]);
+        let wind_direction_noise = self.wind_direction.get([t * 2.0, 3_000_000.0]);
 
         let moisture = Self::normalize_noise(moisture_noise);
         let rain_intensity = Self::normalize_noise(rain_intensity_noise);
 
         let wind_strength = Self::normalize_noise(wind_strength_noise);
         let wind_presence = Self::normalize_noise(wind_presence_noise);
+        let wind_direction = Self::normalize_noise(wind_direction_noise);
 
         let cloudiness = Self::normalize_noise(cloudiness_noise);
         let cloud_brightness_raw = Self::normalize_noise(cloud_brightness_noise);
@@ -75,12 +98,14 @@ impl WeatherSystem {
         let cloud_brightness =
             (cloud_brightness_raw * (1.0 - cloudiness) + 1.0 * (1.0 - cloudiness)).clamp(0.0, 1.0);
 
-        let raw_light = Self::light_level(time);
+        let raw_light = self.location_light_level(game_time);
 
         let cloud_light_blocking =
             cloudiness * (1.0 - cloud_brightness * self.config.cloud_brightness_light_block_factor);
         let light = raw_light * (1.0 - cloud_light_blocking);
         let temperature = Self::normalize_noise(temperature_noise) * raw_light;
+        let water_temperature =
+            Self::normalize_noise(temperature_noise) * WATER_TEMPERATURE_LIGHT_LEVEL;
 
         WeatherAttributes {
             cloudiness,
@@ -88,18 +113,19 @@ impl WeatherSystem {
             moisture,
             wind_presence,
             wind_strength,
+            wind_direction,
             temperature,
+            water_temperature,
             light,
             rain_intensity,
         }
     }
 
-    pub fn get_weather(&self, time: DateTime<Tz>, time_multiplier: f32) -> Weather {
-        let attributes = self.get_weather_attributes(time);
-        let (season_data, season, season_progress) = self
-            .config
-            .location_data
-            .full_season_information(time, time_multiplier);
+    /// The weather at the given in-game time
+    pub fn get_weather(&self, game_time: DateTime<Tz>) -> Weather {
+        let attributes = self.get_weather_attributes(game_time);
+        let (season_data, season, season_progress) =
+            self.config.location_data.season_information_at(game_time);
 
         let raining_rain_intensity_met =
             attributes.rain_intensity > season_data.rain_intensity_raining_threshold;
@@ -115,16 +141,31 @@ impl WeatherSystem {
             0.0
         };
 
+        let temperature_c = float_interpolate(
+            season_data.min_temp_c,
+            season_data.max_temp_c,
+            attributes.temperature,
+        );
+        let water_temperature_c = float_interpolate(
+            season_data.min_temp_c,
+            season_data.max_temp_c,
+            attributes.water_temperature,
+        );
+        let (sunrise, sunset) = self
+            .config
+            .location_data
+            .get_sunrise_sunset(game_time)
+            .unzip();
+        let (tide_level, tide_state) = self.config.location_data.get_tide(game_time).unzip();
+        let condition = self.get_condition(&attributes, temperature_c, is_raining, rain_strength);
+
         Weather {
             location_name: self.config.location_data.name.clone(),
-            time,
+            time: game_time,
             season,
             season_progress,
-            temperature_c: float_interpolate(
-                season_data.min_temp_c,
-                season_data.max_temp_c,
-                attributes.temperature,
-            ),
+            temperature_c,
+            water_temperature_c,
             min_possible_temp_c: season_data.min_temp_c,
             max_possible_temp_c: season_data.max_temp_c,
             humidity: attributes.moisture,
@@ -133,6 +174,44 @@ impl WeatherSystem {
             cloud_brightness: attributes.cloud_brightness,
             is_raining,
             rain_strength,
+            wind_speed_kmh: attributes.wind_presence
+                * attributes.wind_strength
+                * self.config.max_wind_speed_kmh,
+            // The noise rarely reaches its extremes, so it's stretched to cover all directions
+            wind_direction_deg: (attributes.wind_direction * 720.0).rem_euclid(360.0),
+            condition,
+            sunrise,
+            sunset,
+            moon_phase: MoonPhase::from_progress(lunar_progress(&game_time)),
+            moon_illumination: moon_illumination(&game_time) as f32,
+            tide_level,
+            tide_state,
+        }
+    }
+
+    fn get_condition(
+        &self,
+        attributes: &WeatherAttributes,
+        temperature_c: f32,
+        is_raining: bool,
+        rain_strength: f32,
+    ) -> WeatherCondition {
+        if is_raining {
+            if temperature_c <= self.config.snow_max_temp_c {
+                WeatherCondition::Snow
+            } else if rain_strength >= self.config.thunderstorm_rain_strength_threshold {
+                WeatherCondition::Thunderstorm
+            } else {
+                WeatherCondition::Rain
+            }
+        } else if attributes.moisture >= self.config.fog_humidity_threshold
+            && attributes.light <= self.config.fog_max_light_level
+        {
+            WeatherCondition::Fog
+        } else if attributes.cloudiness >= self.config.cloudy_cloudiness_threshold {
+            WeatherCondition::Cloudy
+        } else {
+            WeatherCondition::Clear
         }
     }
 }
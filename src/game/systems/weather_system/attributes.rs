@@ -5,7 +5,9 @@ pub struct WeatherAttributes {
     pub moisture: f32,
     pub wind_presence: f32,
     pub wind_strength: f32,
+    pub wind_direction: f32,
     pub temperature: f32,
+    pub water_temperature: f32,
     pub light: f32,
     pub rain_intensity: f32,
 }
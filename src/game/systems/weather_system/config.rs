@@ -4,6 +4,18 @@ use std::sync::Arc;
 #[derive(Debug)]
 pub struct WeatherSystemConfig {
     pub cloud_brightness_light_block_factor: f32,
+    /// The wind speed at full wind presence and strength
+    pub max_wind_speed_kmh: f32,
+    /// Rain turns into snow at or below this temperature
+    pub snow_max_temp_c: f32,
+    /// How strong the rain has to be for a thunderstorm
+    pub thunderstorm_rain_strength_threshold: f32,
+    /// How big the humidity has to be for fog
+    pub fog_humidity_threshold: f32,
+    /// How low the light level has to be for fog
+    pub fog_max_light_level: f32,
+    /// How big the cloudiness has to be for it to be cloudy
+    pub cloudy_cloudiness_threshold: f32,
     pub location_data: Arc<LocationData>,
 }
 
@@ -11,6 +23,12 @@ impl Default for WeatherSystemConfig {
     fn default() -> Self {
         Self {
             cloud_brightness_light_block_factor: 0.7,
+            max_wind_speed_kmh: 90.0,
+            snow_max_temp_c: 0.0,
+            thunderstorm_rain_strength_threshold: 0.6,
+            fog_humidity_threshold: 0.7,
+            fog_max_light_level: 0.35,
+            cloudy_cloudiness_threshold: 0.6,
             location_data: Arc::new(LocationData::default()),
         }
     }
@@ -33,6 +51,36 @@ impl WeatherSystemConfigBuilder {
         self
     }
 
+    pub fn with_max_wind_speed_kmh(mut self, value: f32) -> Self {
+        self.config.max_wind_speed_kmh = value;
+        self
+    }
+
+    pub fn with_snow_max_temp_c(mut self, value: f32) -> Self {
+        self.config.snow_max_temp_c = value;
+        self
+    }
+
+    pub fn with_thunderstorm_rain_strength_threshold(mut self, value: f32) -> Self {
+        self.config.thunderstorm_rain_strength_threshold = value;
+        self
+    }
+
+    pub fn with_fog_humidity_threshold(mut self, value: f32) -> Self {
+        self.config.fog_humidity_threshold = value;
+        self
+    }
+
+    pub fn with_fog_max_light_level(mut self, value: f32) -> Self {
+        self.config.fog_max_light_level = value;
+        self
+    }
+
+    pub fn with_cloudy_cloudiness_threshold(mut self, value: f32) -> Self {
+        self.config.cloudy_cloudiness_threshold = value;
+        self
+    }
+
     pub fn with_location_data(mut self, value: Arc<LocationData>) -> Self {
         self.config.location_data = value;
         self
@@ -1,20 +1,42 @@
+use crate::enums::moon_phase::MoonPhase;
 use crate::enums::season::Season;
+use crate::enums::tide_state::TideState;
+use crate::enums::weather_condition::WeatherCondition;
 use chrono::DateTime;
 use chrono_tz::Tz;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Weather {
     pub location_name: String,
+    /// The in-game time this weather occurs at, in the timezone of the location
     pub time: DateTime<Tz>,
     pub season: Season,
     pub season_progress: f64,
     pub temperature_c: f32,
+    /// Follows the air temperature without its swing between day and night
+    pub water_temperature_c: f32,
     pub min_possible_temp_c: f32,
     pub max_possible_temp_c: f32,
     pub humidity: f32,
     pub light_level: f32,
     pub cloudiness: f32,
     pub cloud_brightness: f32,
+    /// If there is any precipitation, this includes snow
     pub is_raining: bool,
     pub rain_strength: f32,
+    pub wind_speed_kmh: f32,
+    /// The direction the wind is blowing from in degrees, 0 is north and 90 is east
+    pub wind_direction_deg: f32,
+    pub condition: WeatherCondition,
+    /// The in-game sunrise on this day, None during polar day or polar night
+    pub sunrise: Option<DateTime<Tz>>,
+    /// The in-game sunset on this day, None during polar day or polar night
+    pub sunset: Option<DateTime<Tz>>,
+    pub moon_phase: MoonPhase,
+    /// The illuminated fraction (0-1) of the moon
+    pub moon_illumination: f32,
+    /// The water level (-1 to 1) relative to mean sea level, None if the location is not coastal
+    pub tide_level: Option<f32>,
+    /// None if the location is not coastal
+    pub tide_state: Option<TideState>,
 }
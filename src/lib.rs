@@ -28,6 +28,47 @@
 //! assert_eq!(user, found_user);
 //! ```
 //!
+//! ## Storage backends
+//!
+//! With the `in-memory` feature enabled, [`crate::game::Game::new_in_memory`] creates a game which
+//! keeps all data in memory instead of Postgres, while enforcing the same constraints. This is
+//! useful for running tests without a database server.
+//!
+//! With the `sqlite` feature enabled, passing a `sqlite://` URL to [`crate::game::Game::new`]
+//! stores the data in a SQLite file instead, e.g. `sqlite://fish.db` or `sqlite::memory:`.
+//!
+//! ## Game events
+//!
+//! The services emit a [`crate::game::event_bus::game_event::GameEvent`] for everything that
+//! happens in the game, e.g. a new species being caught, a record being broken or a location
+//! being unlocked. Subscribers are called synchronously once the change was committed, events of
+//! rolled back transactions are never delivered.
+//!
+//! ```rust
+//! use std::env;
+//! use std::sync::{Arc, Mutex};
+//! use fish_lib::config::{Config, ConfigBuilderInterface};
+//! use fish_lib::game::event_bus::game_event::GameEvent;
+//! use fish_lib::game::prelude::*;
+//! use fish_lib::game::service_provider::ServiceProviderInterface;
+//!
+//! let config = Config::builder().build().unwrap();
+//! let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+//! let game = Game::new(&database_url, Some(config)).unwrap();
+//! game.database().write().unwrap().clear().unwrap();
+//!
+//! let registered_ids = Arc::new(Mutex::new(Vec::new()));
+//! let subscriber_ids = registered_ids.clone();
+//! game.event_bus().subscribe(Arc::new(move |event: &GameEvent| {
+//!     if let GameEvent::UserRegistered { user } = event {
+//!         subscriber_ids.lock().unwrap().push(user.external_id);
+//!     }
+//! }));
+//!
+//! game.user_register(1337).unwrap();
+//! assert_eq!(*registered_ids.lock().unwrap(), vec![1337]);
+//! ```
+//!
 //! ## Core Modules
 //!
 //! - [`game`] - The primary module containing all game functionality
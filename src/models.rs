@@ -1,6 +1,9 @@
+pub mod catch_event;
 pub mod fishing_history_entry;
 pub mod item;
 pub mod pond;
+pub mod sale_event;
 pub mod specimen;
 pub mod user;
+pub mod user_achievement;
 pub mod user_location;
@@ -0,0 +1,82 @@
+use crate::dto::personal_best::PersonalRecord;
+use crate::models::fishing_history_entry::CatchRecord;
+use crate::traits::model::Model;
+use chrono::{DateTime, Utc};
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+/// A single catch, kept so leaderboards can count the catches within a time window
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Queryable, Selectable, AsChangeset)]
+#[diesel(table_name = crate::schema::fish_catch_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct CatchEvent {
+    /// Primary key of this catch event in the database
+    pub id: i64,
+    /// The primary key of the user who caught the specimen
+    pub user_id: i64,
+    /// The species ID of the caught specimen (species are defined in the config)
+    pub species_id: i32,
+    /// The ID of the caught specimen, it might have been sold since
+    pub specimen_id: i64,
+    /// Size ratio (0-1) of the specimen relative to its species' min/max size range
+    pub total_size_ratio: f32,
+    /// Size of the specimen when it was caught
+    pub size_mm: f32,
+    /// Weight of the specimen when it was caught
+    pub weight_g: f32,
+    /// When the specimen was caught
+    pub caught_at: DateTime<Utc>,
+}
+
+impl CatchEvent {
+    pub fn get_record(&self) -> PersonalRecord {
+        PersonalRecord {
+            specimen_id: Some(self.specimen_id),
+            total_size_ratio: self.total_size_ratio,
+            size_mm: self.size_mm,
+            weight_g: self.weight_g,
+            set_at: Some(self.caught_at),
+        }
+    }
+}
+
+impl Model for CatchEvent {
+    type Table = crate::schema::fish_catch_events::table;
+    type PrimaryKeyType = i64;
+    type InsertType = NewCatchEvent;
+
+    fn table() -> Self::Table {
+        crate::schema::fish_catch_events::table
+    }
+
+    fn id(&self) -> Self::PrimaryKeyType {
+        self.id
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::fish_catch_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct NewCatchEvent {
+    pub user_id: i64,
+    pub species_id: i32,
+    pub specimen_id: i64,
+    pub total_size_ratio: f32,
+    pub size_mm: f32,
+    pub weight_g: f32,
+    pub caught_at: DateTime<Utc>,
+}
+
+impl NewCatchEvent {
+    pub fn new(user_id: i64, species_id: i32, catch: &CatchRecord) -> Self {
+        Self {
+            user_id,
+            species_id,
+            specimen_id: catch.specimen_id,
+            total_size_ratio: catch.total_size_ratio,
+            size_mm: catch.size_mm,
+            weight_g: catch.weight_g,
+            caught_at: catch.catch_time,
+        }
+    }
+}
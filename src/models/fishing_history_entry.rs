@@ -1,4 +1,5 @@
 use crate::config::ConfigInterface;
+use crate::dto::personal_best::PersonalRecord;
 use crate::game::errors::resource::GameResourceError;
 use crate::game::errors::GameResult;
 use crate::traits::model::Model;
@@ -38,6 +39,42 @@ pub struct FishingHistoryEntry {
     pub first_sell: Option<DateTime<Utc>>,
     /// When a specimen of this species was last sold
     pub last_sell: Option<DateTime<Utc>>,
+    /// The ID of the specimen holding the smallest catch record
+    /// None for entries whose record was set before specimens were tracked
+    pub smallest_catch_specimen_id: Option<i64>,
+    /// Size of the smallest catch at the time it was caught
+    pub smallest_catch_size_mm: Option<f32>,
+    /// Weight of the smallest catch at the time it was caught
+    pub smallest_catch_weight_g: Option<f32>,
+    /// When the smallest catch record was set
+    pub smallest_catch_time: Option<DateTime<Utc>>,
+    /// The ID of the specimen holding the largest catch record
+    /// None for entries whose record was set before specimens were tracked
+    pub largest_catch_specimen_id: Option<i64>,
+    /// Size of the largest catch at the time it was caught
+    pub largest_catch_size_mm: Option<f32>,
+    /// Weight of the largest catch at the time it was caught
+    pub largest_catch_weight_g: Option<f32>,
+    /// When the largest catch record was set
+    pub largest_catch_time: Option<DateTime<Utc>>,
+    /// Incremented on every save, a copy with an older version is stale
+    #[diesel(skip_update)]
+    pub version: i64,
+}
+
+/// Measurements of a single catch, as they are recorded in the fishing history
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CatchRecord {
+    /// The ID of the caught specimen
+    pub specimen_id: i64,
+    /// Size ratio (0-1) of the specimen relative to its species' min/max size range
+    pub total_size_ratio: f32,
+    /// Size of the specimen when it was caught
+    pub size_mm: f32,
+    /// Weight of the specimen when it was caught
+    pub weight_g: f32,
+    /// When the specimen was caught
+    pub catch_time: DateTime<Utc>,
 }
 
 impl FishingHistoryEntry {
@@ -45,13 +82,24 @@ impl FishingHistoryEntry {
         self.created_at
     }
 
-    pub fn register_catch(&mut self, total_size_ratio: f32, catch_time: DateTime<Utc>) {
-        if total_size_ratio < self.smallest_catch_size_ratio {
-            self.smallest_catch_size_ratio = total_size_ratio;
-        } else if total_size_ratio > self.largest_catch_size_ratio {
-            self.largest_catch_size_ratio = total_size_ratio;
+    /// Count the catch and update the size records it beats.
+    /// A single catch can set both records, e.g. when the entry has no records yet.
+    pub fn register_catch(&mut self, catch: &CatchRecord) {
+        if self.caught_count == 0 || catch.total_size_ratio < self.smallest_catch_size_ratio {
+            self.smallest_catch_size_ratio = catch.total_size_ratio;
+            self.smallest_catch_specimen_id = Some(catch.specimen_id);
+            self.smallest_catch_size_mm = Some(catch.size_mm);
+            self.smallest_catch_weight_g = Some(catch.weight_g);
+            self.smallest_catch_time = Some(catch.catch_time);
+        }
+        if self.caught_count == 0 || catch.total_size_ratio > self.largest_catch_size_ratio {
+            self.largest_catch_size_ratio = catch.total_size_ratio;
+            self.largest_catch_specimen_id = Some(catch.specimen_id);
+            self.largest_catch_size_mm = Some(catch.size_mm);
+            self.largest_catch_weight_g = Some(catch.weight_g);
+            self.largest_catch_time = Some(catch.catch_time);
         }
-        self.last_catch = catch_time;
+        self.last_catch = catch.catch_time;
         self.caught_count = self.caught_count.saturating_add(1);
     }
 
@@ -106,6 +154,52 @@ impl FishingHistoryEntry {
             self.largest_catch_size_ratio,
         ))
     }
+
+    /// The smallest catch record, entries from before specimens were tracked fall back to the
+    /// size and weight interpolated from the recorded size ratio
+    pub fn get_smallest_record(
+        &self,
+        config: Arc<dyn ConfigInterface>,
+    ) -> GameResult<PersonalRecord> {
+        let size_mm = match self.smallest_catch_size_mm {
+            Some(size_mm) => size_mm,
+            None => self.get_smallest_size_mm(config.clone())?,
+        };
+        let weight_g = match self.smallest_catch_weight_g {
+            Some(weight_g) => weight_g,
+            None => self.get_smallest_weight_g(config)?,
+        };
+        Ok(PersonalRecord {
+            specimen_id: self.smallest_catch_specimen_id,
+            total_size_ratio: self.smallest_catch_size_ratio,
+            size_mm,
+            weight_g,
+            set_at: self.smallest_catch_time,
+        })
+    }
+
+    /// The largest catch record, entries from before specimens were tracked fall back to the
+    /// size and weight interpolated from the recorded size ratio
+    pub fn get_largest_record(
+        &self,
+        config: Arc<dyn ConfigInterface>,
+    ) -> GameResult<PersonalRecord> {
+        let size_mm = match self.largest_catch_size_mm {
+            Some(size_mm) => size_mm,
+            None => self.get_largest_size_mm(config.clone())?,
+        };
+        let weight_g = match self.largest_catch_weight_g {
+            Some(weight_g) => weight_g,
+            None => self.get_largest_weight_g(config)?,
+        };
+        Ok(PersonalRecord {
+            specimen_id: self.largest_catch_specimen_id,
+            total_size_ratio: self.largest_catch_size_ratio,
+            size_mm,
+            weight_g,
+            set_at: self.largest_catch_time,
+        })
+    }
 }
 
 impl Model for FishingHistoryEntry {
@@ -132,4 +226,34 @@ pub struct NewFishingHistoryEntry {
     pub sold_count: i32,
     pub smallest_catch_size_ratio: f32,
     pub largest_catch_size_ratio: f32,
+    pub smallest_catch_specimen_id: Option<i64>,
+    pub smallest_catch_size_mm: Option<f32>,
+    pub smallest_catch_weight_g: Option<f32>,
+    pub smallest_catch_time: Option<DateTime<Utc>>,
+    pub largest_catch_specimen_id: Option<i64>,
+    pub largest_catch_size_mm: Option<f32>,
+    pub largest_catch_weight_g: Option<f32>,
+    pub largest_catch_time: Option<DateTime<Utc>>,
+}
+
+impl NewFishingHistoryEntry {
+    /// A new entry for a user's first catch of a species, the catch holds both size records
+    pub fn first_catch(user_id: i64, species_id: i32, catch: &CatchRecord) -> Self {
+        Self {
+            user_id,
+            species_id,
+            caught_count: 1,
+            sold_count: 0,
+            smallest_catch_size_ratio: catch.total_size_ratio,
+            largest_catch_size_ratio: catch.total_size_ratio,
+            smallest_catch_specimen_id: Some(catch.specimen_id),
+            smallest_catch_size_mm: Some(catch.size_mm),
+            smallest_catch_weight_g: Some(catch.weight_g),
+            smallest_catch_time: Some(catch.catch_time),
+            largest_catch_specimen_id: Some(catch.specimen_id),
+            largest_catch_size_mm: Some(catch.size_mm),
+            largest_catch_weight_g: Some(catch.weight_g),
+            largest_catch_time: Some(catch.catch_time),
+        }
+    }
 }
@@ -29,6 +29,8 @@ pub struct Item {
     pub properties: ItemPropertiesContainer,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[diesel(skip_update)]
+    pub version: i64,
 }
 
 impl Model for Item {
@@ -124,6 +126,19 @@ impl Item {
         }
     }
 
+    pub fn use_as_bait(&mut self, config: Arc<dyn ConfigInterface>) -> ItemEventResult {
+        let attributes = self
+            .attributes(config)
+            .ok_or(GameItemEventError::invalid_item_type(self.type_id))?;
+
+        if !attributes.is_bait() {
+            Err(GameItemEventError::not_a_bait(self.type_id))
+        } else {
+            self.properties.on_use(1);
+            Ok(ItemEventSuccess::new(self.should_consume()))
+        }
+    }
+
     pub fn add(&mut self, amount: u64) -> ItemEventResult {
         self.on_add(amount);
         Ok(ItemEventSuccess::new(self.should_consume()))
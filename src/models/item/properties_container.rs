@@ -11,6 +11,7 @@ use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, FromSqlRow, AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Jsonb)]
+#[cfg_attr(feature = "sqlite", diesel(sql_type = diesel::sql_types::Text))]
 pub struct ItemPropertiesContainer {
     components: HashMap<ItemPropertiesType, ItemProperties>,
 }
@@ -103,9 +104,9 @@ pub trait ItemPropertiesContainerInterface {
 
     // Direct manipulation
     fn set_count(&mut self, count: u64) {
-        self.get_stackable_properties_mut().map(|stackable| {
+        if let Some(stackable) = self.get_stackable_properties_mut() {
             stackable.set_count(count);
-        });
+        }
     }
 
     // Properties-existence functions
@@ -170,6 +171,26 @@ impl FromSql<Jsonb, Pg> for ItemPropertiesContainer {
     }
 }
 
+// SQLite has no JSONB, the properties are stored as JSON text instead
+#[cfg(feature = "sqlite")]
+impl ToSql<diesel::sql_types::Text, diesel::sqlite::Sqlite> for ItemPropertiesContainer {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::sqlite::Sqlite>) -> serialize::Result {
+        out.set_value(serde_json::to_string(self)?);
+        Ok(serialize::IsNull::No)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl FromSql<diesel::sql_types::Text, diesel::sqlite::Sqlite> for ItemPropertiesContainer {
+    fn from_sql(
+        bytes: <diesel::sqlite::Sqlite as diesel::backend::Backend>::RawValue<'_>,
+    ) -> deserialize::Result<Self> {
+        let value =
+            <String as FromSql<diesel::sql_types::Text, diesel::sqlite::Sqlite>>::from_sql(bytes)?;
+        Ok(serde_json::from_str(&value)?)
+    }
+}
+
 // Serde
 impl<'de> Deserialize<'de> for ItemPropertiesContainer {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
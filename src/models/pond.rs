@@ -19,6 +19,9 @@ pub struct Pond {
     pub updated_at: DateTime<Utc>,
     /// How many specimen fit in this pond
     pub capacity: i32,
+    /// Incremented on every save, a copy with an older version is stale
+    #[diesel(skip_update)]
+    pub version: i64,
 }
 
 impl Model for Pond {
@@ -0,0 +1,57 @@
+use crate::models::specimen::Specimen;
+use crate::traits::model::Model;
+use chrono::{DateTime, Utc};
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+/// A single sale of a specimen, kept so leaderboards can count the sales within a time window
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Queryable, Selectable, AsChangeset)]
+#[diesel(table_name = crate::schema::fish_sale_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SaleEvent {
+    /// Primary key of this sale event in the database
+    pub id: i64,
+    /// The primary key of the user who sold the specimen
+    pub user_id: i64,
+    /// The species ID of the sold specimen (species are defined in the config)
+    pub species_id: i32,
+    /// The ID of the sold specimen, which doesn't exist anymore
+    pub specimen_id: i64,
+    /// When the specimen was sold
+    pub sold_at: DateTime<Utc>,
+}
+
+impl Model for SaleEvent {
+    type Table = crate::schema::fish_sale_events::table;
+    type PrimaryKeyType = i64;
+    type InsertType = NewSaleEvent;
+
+    fn table() -> Self::Table {
+        crate::schema::fish_sale_events::table
+    }
+
+    fn id(&self) -> Self::PrimaryKeyType {
+        self.id
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::fish_sale_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct NewSaleEvent {
+    pub user_id: i64,
+    pub species_id: i32,
+    pub specimen_id: i64,
+    pub sold_at: DateTime<Utc>,
+}
+
+impl NewSaleEvent {
+    pub fn new(specimen: &Specimen, sold_at: DateTime<Utc>) -> Self {
+        Self {
+            user_id: specimen.user_id,
+            species_id: specimen.species_id,
+            specimen_id: specimen.id,
+            sold_at,
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use crate::config::ConfigInterface;
 use crate::game::errors::resource::GameResourceError;
 use crate::game::errors::GameResult;
+use crate::game::game_time::GameTime;
 use crate::traits::model::Model;
-use crate::utils::random::random_normal_01;
+use crate::utils::random::{random_normal_01, seeded_rng, SPECIMEN_STREAM};
 use chrono::{DateTime, Utc};
 use diesel::{AsChangeset, Insertable, Queryable, Selectable};
-use rand::random;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -14,6 +15,7 @@ use std::sync::Arc;
 )]
 #[diesel(table_name = crate::schema::fish_specimens)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(treat_none_as_null = true)]
 pub struct Specimen {
     /// Primary key of this specimen in the database
     pub id: i64,
@@ -33,13 +35,26 @@ pub struct Specimen {
     pub lifespan_days_ratio: f32,
     /// The age this fish was caught at (from 0 to 1)
     pub catch_age: f32,
+    /// The primary key of the pond this specimen is kept in, if any
+    pub pond_id: Option<i64>,
+    /// The seed this specimen was generated from, the bits of the u64 seed are stored as i64
+    /// None for specimens which were caught before seeds were recorded
+    pub catch_seed: Option<i64>,
+    /// Incremented on every save, a copy with an older version is stale
+    #[diesel(skip_update)]
+    pub version: i64,
 }
 
 impl Specimen {
+    /// The seed this specimen was generated from, see [`crate::game::random_source::RandomSource`]
+    pub fn get_catch_seed(&self) -> Option<u64> {
+        self.catch_seed.map(|seed| seed as u64)
+    }
+
     pub fn get_age(
         &self,
         config: Arc<dyn ConfigInterface>,
-        time_multiplier: f32,
+        game_time: &GameTime,
     ) -> GameResult<f32> {
         let data = config
             .get_species_data(self.species_id)
@@ -50,13 +65,15 @@ impl Specimen {
             return Ok(1.0);
         }
 
-        let now = Utc::now();
-        let seconds_since_catch = (now - self.created_at).num_seconds();
+        // A clock set before the catch doesn't make the specimen younger than it was caught
+        let seconds_since_catch = game_time
+            .elapsed_since(self.created_at)
+            .num_seconds()
+            .max(0);
         let days_since_catch = seconds_since_catch as f32 / (86400f32);
 
         let remaining_lifespan_days_after_catch = lifespan_days * (1.0 - self.catch_age);
-        let age_progress_since_catch =
-            (days_since_catch * time_multiplier) / remaining_lifespan_days_after_catch;
+        let age_progress_since_catch = days_since_catch / remaining_lifespan_days_after_catch;
 
         let age = (self.catch_age + age_progress_since_catch).clamp(0.0, 1.0);
         Ok(age)
@@ -65,7 +82,7 @@ impl Specimen {
     pub fn get_size_mm(
         &self,
         config: Arc<dyn ConfigInterface>,
-        time_multiplier: f32,
+        game_time: &GameTime,
     ) -> GameResult<f32> {
         let data = config
             .get_species_data(self.species_id)
@@ -73,7 +90,7 @@ impl Specimen {
         let size_baby_mm = data.get_baby_size_by_ratio(self.size_baby_ratio);
         let size_adult_mm = data.get_adult_size_by_ratio(self.size_adult_ratio);
 
-        let current_age = self.get_age(config, time_multiplier)?;
+        let current_age = self.get_age(config, game_time)?;
         let size_mm = size_baby_mm + (size_adult_mm - size_baby_mm) * current_age;
         Ok(size_mm)
     }
@@ -81,7 +98,7 @@ impl Specimen {
     pub fn get_weight_g(
         &self,
         config: Arc<dyn ConfigInterface>,
-        time_multiplier: f32,
+        game_time: &GameTime,
     ) -> GameResult<f32> {
         let data = config
             .get_species_data(self.species_id)
@@ -89,7 +106,7 @@ impl Specimen {
         let weight_baby_g = data.get_baby_weight_by_ratio(self.size_baby_ratio);
         let weight_adult_g = data.get_adult_weight_by_ratio(self.size_adult_ratio);
 
-        let current_age = self.get_age(config, time_multiplier)?;
+        let current_age = self.get_age(config, game_time)?;
         let weight_g = weight_baby_g + (weight_adult_g - weight_baby_g) * current_age;
         Ok(weight_g)
     }
@@ -97,7 +114,7 @@ impl Specimen {
     pub fn get_total_size_ratio(
         &self,
         config: Arc<dyn ConfigInterface>,
-        time_multiplier: f32,
+        game_time: &GameTime,
     ) -> GameResult<f32> {
         let data = config
             .get_species_data(self.species_id)
@@ -105,12 +122,34 @@ impl Specimen {
         let min_possible_size = data.min_size_baby_mm as f32;
         let max_possible_size = data.max_size_adult_mm as f32;
 
-        let current_size = self.get_size_mm(config, time_multiplier)?;
+        let current_size = self.get_size_mm(config, game_time)?;
 
         let ratio = (current_size - min_possible_size) / (max_possible_size - min_possible_size);
         let total_size_ratio = ratio.clamp(0.0, 1.0);
         Ok(total_size_ratio)
     }
+
+    pub fn get_sell_price(
+        &self,
+        config: Arc<dyn ConfigInterface>,
+        game_time: &GameTime,
+    ) -> GameResult<i64> {
+        let data = config
+            .get_species_data(self.species_id)
+            .ok_or_else(|| GameResourceError::species_not_found(self.species_id))?;
+        let settings = config.settings();
+
+        let size_ratio = self.get_total_size_ratio(config.clone(), game_time)?;
+        let rarity_ratio = data.get_rarity_level() as f32 / u8::MAX as f32;
+        let age = self.get_age(config, game_time)?;
+
+        let size_factor = 1.0 + size_ratio * settings.sell_price_size_multiplier;
+        let rarity_factor = 1.0 + rarity_ratio * settings.sell_price_rarity_multiplier;
+        let age_factor = 1.0 + age * settings.sell_price_age_multiplier;
+
+        let price = data.base_value as f32 * size_factor * rarity_factor * age_factor;
+        Ok(price.round().max(0.0) as i64)
+    }
 }
 
 impl Model for Specimen {
@@ -137,17 +176,21 @@ pub struct NewSpecimen {
     pub size_adult_ratio: f32,
     pub lifespan_days_ratio: f32,
     pub catch_age: f32,
+    pub catch_seed: Option<i64>,
 }
 
 impl NewSpecimen {
-    pub fn generate(user_id: i64, species_id: i32) -> NewSpecimen {
+    /// Generates the same specimen for the same seed
+    pub fn generate(user_id: i64, species_id: i32, seed: u64) -> NewSpecimen {
+        let mut rng = seeded_rng(seed, SPECIMEN_STREAM);
         NewSpecimen {
             user_id,
-            catch_age: random(),
+            catch_age: rng.random(),
             species_id,
-            size_baby_ratio: random_normal_01(),
-            size_adult_ratio: random_normal_01(),
-            lifespan_days_ratio: random_normal_01(),
+            size_baby_ratio: random_normal_01(&mut rng),
+            size_adult_ratio: random_normal_01(&mut rng),
+            lifespan_days_ratio: random_normal_01(&mut rng),
+            catch_seed: Some(seed as i64),
         }
     }
 }
@@ -1,3 +1,5 @@
+use crate::game::errors::GameResult;
+use crate::game::game_time::GameTime;
 use crate::traits::model::Model;
 use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
@@ -23,6 +25,9 @@ pub struct User {
     pub updated_at: DateTime<Utc>,
     /// The Timezone of this user, defaults to UTC
     pub timezone: String,
+    /// Incremented on every save, a copy with an older version is stale
+    #[diesel(skip_update)]
+    pub version: i64,
 }
 
 impl User {
@@ -34,6 +39,13 @@ impl User {
         self.timezone = timezone.to_string();
     }
 
+    /// The current in-game time in the timezone of this user
+    pub fn get_local_game_time(&self, game_time: &GameTime) -> GameResult<DateTime<Tz>> {
+        game_time.now_in(&self.get_timezone())
+    }
+
+    /// The current real time in the timezone of this user
+    #[deprecated(note = "use `get_local_game_time` for the in-game time")]
     pub fn get_local_time(&self) -> DateTime<Tz> {
         Utc::now().with_timezone(&self.get_timezone())
     }
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Queryable)]
+#[diesel(table_name = crate::schema::fish_user_achievements)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct UserAchievement {
+    pub user_id: i64,
+    pub achievement_id: i32,
+    pub unlocked_at: DateTime<Utc>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::fish_user_achievements)]
+pub struct NewUserAchievement {
+    pub user_id: i64,
+    pub achievement_id: i32,
+}
@@ -5,7 +5,8 @@ diesel::table! {
         credits -> BigInt,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
-        timezone -> VarChar
+        timezone -> VarChar,
+        version -> BigInt,
     }
 }
 
@@ -17,6 +18,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    fish_user_achievements (user_id, achievement_id) {
+        user_id -> BigInt,
+        achievement_id -> Integer,
+        unlocked_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     fish_specimens (id) {
         id -> BigInt,
@@ -27,7 +36,10 @@ diesel::table! {
         size_baby_ratio -> Float,
         size_adult_ratio -> Float,
         lifespan_days_ratio -> Float,
-        catch_age -> Float
+        catch_age -> Float,
+        pond_id -> Nullable<BigInt>,
+        catch_seed -> Nullable<BigInt>,
+        version -> BigInt,
     }
 }
 
@@ -37,7 +49,8 @@ diesel::table! {
         user_id -> BigInt,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
-        capacity -> Integer
+        capacity -> Integer,
+        version -> BigInt,
     }
 }
 
@@ -55,6 +68,15 @@ diesel::table! {
         last_catch -> Timestamptz,
         first_sell -> Nullable<Timestamptz>,
         last_sell -> Nullable<Timestamptz>,
+        smallest_catch_specimen_id -> Nullable<BigInt>,
+        smallest_catch_size_mm -> Nullable<Float>,
+        smallest_catch_weight_g -> Nullable<Float>,
+        smallest_catch_time -> Nullable<Timestamptz>,
+        largest_catch_specimen_id -> Nullable<BigInt>,
+        largest_catch_size_mm -> Nullable<Float>,
+        largest_catch_weight_g -> Nullable<Float>,
+        largest_catch_time -> Nullable<Timestamptz>,
+        version -> BigInt,
     }
 }
 
@@ -66,15 +88,45 @@ diesel::table! {
         properties -> Jsonb,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        version -> BigInt,
+    }
+}
+
+diesel::table! {
+    fish_catch_events (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+        species_id -> Integer,
+        specimen_id -> BigInt,
+        total_size_ratio -> Float,
+        size_mm -> Float,
+        weight_g -> Float,
+        caught_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    fish_sale_events (id) {
+        id -> BigInt,
+        user_id -> BigInt,
+        species_id -> Integer,
+        specimen_id -> BigInt,
+        sold_at -> Timestamptz,
     }
 }
 
 diesel::joinable!(fish_user_locations -> fish_users (user_id));
 diesel::allow_tables_to_appear_in_same_query!(fish_user_locations, fish_users);
 
+diesel::joinable!(fish_user_achievements -> fish_users (user_id));
+diesel::allow_tables_to_appear_in_same_query!(fish_user_achievements, fish_users);
+
 diesel::joinable!(fish_specimens -> fish_users (user_id));
 diesel::allow_tables_to_appear_in_same_query!(fish_specimens, fish_users);
 
+diesel::joinable!(fish_specimens -> fish_ponds (pond_id));
+diesel::allow_tables_to_appear_in_same_query!(fish_specimens, fish_ponds);
+
 diesel::joinable!(fish_ponds -> fish_users (user_id));
 diesel::allow_tables_to_appear_in_same_query!(fish_ponds, fish_users);
 
@@ -83,3 +135,9 @@ diesel::allow_tables_to_appear_in_same_query!(fish_fishing_history_entries, fish
 
 diesel::joinable!(fish_items -> fish_users (user_id));
 diesel::allow_tables_to_appear_in_same_query!(fish_items, fish_users);
+
+diesel::joinable!(fish_catch_events -> fish_users (user_id));
+diesel::allow_tables_to_appear_in_same_query!(fish_catch_events, fish_users);
+
+diesel::joinable!(fish_sale_events -> fish_users (user_id));
+diesel::allow_tables_to_appear_in_same_query!(fish_sale_events, fish_users);
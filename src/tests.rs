@@ -3,4 +3,17 @@ mod models;
 mod repositories;
 mod services;
 mod test_asset_server;
+mod test_clock;
 mod test_config;
+mod test_database;
+mod test_encounter_system;
+mod test_event_bus;
+mod test_game_time;
+mod test_lunar;
+#[cfg(feature = "in-memory")]
+mod test_memory;
+mod test_random_source;
+mod test_solar;
+#[cfg(feature = "sqlite")]
+mod test_sqlite;
+mod test_weather_system;
@@ -1,6 +1,8 @@
 use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
 use crate::database::{Database, DatabaseInterface};
-use crate::game::service_provider::{ServiceProvider, ServiceProviderInterface};
+use crate::game::clock::Clock;
+use crate::game::service_provider::{Repositories, ServiceProvider, ServiceProviderInterface};
+use crate::game::Game;
 use std::env;
 use std::path::Path;
 use std::sync::{Arc, RwLock};
@@ -31,6 +33,30 @@ pub fn mock_service_provider(
     ServiceProvider::create(config, db)
 }
 
+pub fn mock_service_provider_with_clock(
+    config: Arc<dyn ConfigInterface>,
+    clock: Arc<dyn Clock>,
+) -> Arc<dyn ServiceProviderInterface> {
+    let db = mock_default_db();
+    let sp = ServiceProvider::new(config, db);
+    Arc::new(ServiceProvider::with_repositories(
+        sp.config(),
+        clock.clone(),
+        sp.random_source(),
+        sp.database(),
+        sp.event_bus(),
+        Repositories::of(&sp).with_clock(clock),
+    ))
+}
+
 pub fn mock_default_service_provider() -> Arc<dyn ServiceProviderInterface> {
     mock_service_provider(mock_default_config())
 }
+
+pub fn mock_game(config: Arc<dyn ConfigInterface>) -> Game {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+
+    let game = Game::new(&database_url, Some(config)).unwrap();
+    game.database().read().unwrap().clear().unwrap();
+    game
+}
@@ -104,6 +104,33 @@ fn test_use_as_rod() {
     assert_eq!(existence_error.get_type_id(), Some(-1));
 }
 
+#[test]
+fn test_use_as_bait() {
+    let config = mock_config();
+
+    let mut bait = create_item(&config, BAIT_ITEM);
+    bait.add(2).unwrap();
+    assert_eq!(bait.get_count(), Some(2));
+
+    let result = bait.use_as_bait(config.clone()).unwrap();
+    assert!(!result.consume);
+    assert_eq!(bait.get_count(), Some(1));
+
+    let result = bait.use_as_bait(config.clone()).unwrap();
+    assert!(result.consume);
+    assert_eq!(bait.get_count(), Some(0));
+
+    let mut rod = create_item(&config, ROD_ITEM);
+    let use_error = rod.use_as_bait(config.clone()).unwrap_err();
+    assert!(use_error.is_not_a_bait());
+    assert_eq!(use_error.get_type_id(), Some(ROD_ITEM));
+
+    let mut none_existent = create_non_existent_item();
+    let existence_error = none_existent.use_as_bait(config.clone()).unwrap_err();
+    assert!(existence_error.is_invalid_item_type());
+    assert_eq!(existence_error.get_type_id(), Some(-1));
+}
+
 #[test]
 fn test_add_remove() {
     let config = mock_config();
@@ -1,7 +1,10 @@
 use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::settings::Settings;
 use crate::data::species_data::SpeciesData;
+use crate::game::clock::FixedClock;
+use crate::game::game_time::GameTime;
 use crate::models::specimen::Specimen;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -27,6 +30,10 @@ fn mock_config() -> Arc<dyn ConfigInterface> {
     Config::builder().species(species_data_map).build().unwrap()
 }
 
+fn mock_game_time(now: DateTime<Utc>, speed: f64) -> GameTime {
+    GameTime::new(Arc::new(FixedClock::new(now)), DateTime::UNIX_EPOCH, speed)
+}
+
 #[test]
 fn test_age_calculation() {
     let config = mock_config();
@@ -44,6 +51,9 @@ fn test_age_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 0.0,
         catch_age: 0.5,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
     let specimen2 = Specimen {
@@ -56,6 +66,9 @@ fn test_age_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 0.0,
         catch_age: 0.5,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
     let specimen3 = Specimen {
@@ -68,30 +81,41 @@ fn test_age_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 1.0,
         catch_age: 0.0,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
-    let age = specimen.get_age(config.clone(), 1.0).unwrap();
+    let age = specimen
+        .get_age(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (age - 0.5).abs() < 0.001,
         "Expected age to be approximately 0.5, got {}",
         age
     );
 
-    let age2 = specimen2.get_age(config.clone(), 1.0).unwrap();
+    let age2 = specimen2
+        .get_age(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (age2 - 1.0).abs() < 0.001,
         "Expected age2 to be approximately 1.0, got {}",
         age2
     );
 
-    let age3 = specimen3.get_age(config.clone(), 1.0).unwrap();
+    let age3 = specimen3
+        .get_age(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (age3 - 0.25).abs() < 0.001,
         "Expected age3 to be approximately 0.25, got {}",
         age3
     );
 
-    let age3_accelerated = specimen3.get_age(config.clone(), 2.0).unwrap();
+    let age3_accelerated = specimen3
+        .get_age(config.clone(), &mock_game_time(now, 2.0))
+        .unwrap();
     assert!(
         (age3_accelerated - 0.5).abs() < 0.001,
         "Expected age3_accelerated to be approximately 0.5, got {}",
@@ -116,6 +140,9 @@ fn test_size_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 0.0,
         catch_age: 0.5,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
     let specimen2 = Specimen {
@@ -128,6 +155,9 @@ fn test_size_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 0.0,
         catch_age: 0.5,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
     let specimen3 = Specimen {
@@ -140,30 +170,41 @@ fn test_size_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 1.0,
         catch_age: 0.0,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
-    let size = specimen.get_size_mm(config.clone(), 1.0).unwrap();
+    let size = specimen
+        .get_size_mm(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (size - 30.0).abs() < 0.001,
         "Expected size to be approximately 30.0, got {}",
         size
     );
 
-    let size2 = specimen2.get_size_mm(config.clone(), 1.0).unwrap();
+    let size2 = specimen2
+        .get_size_mm(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (size2 - 40.0).abs() < 0.001,
         "Expected size2 to be approximately 40.0, got {}",
         size2
     );
 
-    let size3 = specimen3.get_size_mm(config.clone(), 1.0).unwrap();
+    let size3 = specimen3
+        .get_size_mm(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (size3 - 25.0).abs() < 0.001,
         "Expected size3 to be approximately 25.0, got {}",
         size3
     );
 
-    let size3_accelerated = specimen3.get_size_mm(config.clone(), 3.0).unwrap();
+    let size3_accelerated = specimen3
+        .get_size_mm(config.clone(), &mock_game_time(now, 3.0))
+        .unwrap();
     assert!(
         (size3_accelerated - 35.0).abs() < 0.001,
         "Expected size3_accelerated to be approximately 35.0, got {}",
@@ -188,6 +229,9 @@ fn test_weight_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 0.0,
         catch_age: 0.5,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
     let specimen2 = Specimen {
@@ -200,6 +244,9 @@ fn test_weight_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 0.0,
         catch_age: 0.5,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
     let specimen3 = Specimen {
@@ -212,33 +259,102 @@ fn test_weight_calculation() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 1.0,
         catch_age: 0.0,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
     };
 
-    let weight = specimen.get_weight_g(config.clone(), 1.0).unwrap();
+    let weight = specimen
+        .get_weight_g(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (weight - 60.0).abs() < 0.001,
         "Expected weight to be approximately 60.0, got {}",
         weight
     );
 
-    let weight2 = specimen2.get_weight_g(config.clone(), 1.0).unwrap();
+    let weight2 = specimen2
+        .get_weight_g(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (weight2 - 80.0).abs() < 0.001,
         "Expected weight2 to be approximately 80.0, got {}",
         weight2
     );
 
-    let weight3 = specimen3.get_weight_g(config.clone(), 1.0).unwrap();
+    let weight3 = specimen3
+        .get_weight_g(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
     assert!(
         (weight3 - 50.0).abs() < 0.001,
         "Expected weight3 to be approximately 50.0, got {}",
         weight3
     );
 
-    let weight3_accelerated = specimen3.get_weight_g(config.clone(), 3.0).unwrap();
+    let weight3_accelerated = specimen3
+        .get_weight_g(config.clone(), &mock_game_time(now, 3.0))
+        .unwrap();
     assert!(
         (weight3_accelerated - 70.0).abs() < 0.001,
         "Expected weight3_accelerated to be approximately 70.0, got {}",
         weight3_accelerated
     );
 }
+
+#[test]
+fn test_sell_price_calculation() {
+    let species_data = SpeciesData {
+        name: "salmon".to_string(),
+        base_value: 100,
+        min_size_baby_mm: 10,
+        max_size_baby_mm: 30,
+        min_size_adult_mm: 20,
+        max_size_adult_mm: 60,
+        min_lifespan_days: 1,
+        max_lifespan_days: 4,
+        ..Default::default()
+    };
+    let settings = Settings {
+        sell_price_size_multiplier: 1.0,
+        sell_price_rarity_multiplier: 4.0,
+        sell_price_age_multiplier: 0.5,
+        ..Default::default()
+    };
+    let config = Config::builder()
+        .species(HashMap::from([(0, species_data)]))
+        .settings(settings)
+        .build()
+        .unwrap();
+
+    let now = Utc::now();
+    let yesterday = now - Duration::days(1);
+    let specimen = Specimen {
+        id: 0,
+        user_id: 0,
+        species_id: 0,
+        created_at: yesterday,
+        updated_at: yesterday,
+        size_baby_ratio: 0.5,
+        size_adult_ratio: 0.5,
+        lifespan_days_ratio: 0.0,
+        catch_age: 0.5,
+        pond_id: None,
+        catch_seed: None,
+        version: 0,
+    };
+
+    // Fully grown with a size of 40mm => total size ratio 0.6, age 1.0, no rarity
+    let price = specimen
+        .get_sell_price(config.clone(), &mock_game_time(now, 1.0))
+        .unwrap();
+    assert_eq!(price, 240);
+
+    let unknown_specimen = Specimen {
+        species_id: -1,
+        ..specimen
+    };
+    let error = unknown_specimen
+        .get_sell_price(config, &mock_game_time(now, 1.0))
+        .unwrap_err();
+    assert!(error.is_not_found());
+}
@@ -1,5 +1,7 @@
+mod test_catch_event_repository;
 mod test_fishing_history_entry_repository;
 mod test_item_repository;
 mod test_pond_repository;
+mod test_sale_event_repository;
 mod test_specimen_repository;
 mod test_user_repository;
@@ -0,0 +1,77 @@
+use crate::models::catch_event::NewCatchEvent;
+use crate::tests::mock::mock_default_service_provider;
+use chrono::{TimeZone, Utc};
+
+fn new_catch_event(user_id: i64) -> NewCatchEvent {
+    NewCatchEvent {
+        user_id,
+        species_id: 1,
+        specimen_id: 2,
+        total_size_ratio: 0.5,
+        size_mm: 120.0,
+        weight_g: 80.0,
+        caught_at: Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap(),
+    }
+}
+
+#[test]
+fn test_create_and_find() {
+    let sp = mock_default_service_provider();
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let catch_event = sp
+        .catch_event_repository()
+        .create(new_catch_event(user.id))
+        .unwrap();
+    assert_eq!(catch_event.user_id, user.id);
+    assert_eq!(catch_event.specimen_id, 2);
+    assert_eq!(catch_event.size_mm, 120.0);
+    assert_eq!(
+        catch_event.caught_at,
+        Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap()
+    );
+
+    let found_catch_event = sp
+        .catch_event_repository()
+        .find(catch_event.id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(catch_event, found_catch_event);
+
+    let error = sp
+        .catch_event_repository()
+        .create(new_catch_event(-1))
+        .unwrap_err();
+    assert!(error
+        .get_database_error()
+        .unwrap()
+        .is_foreign_key_violation());
+}
+
+#[test]
+fn test_save_and_delete() {
+    let sp = mock_default_service_provider();
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let mut catch_event = sp
+        .catch_event_repository()
+        .create(new_catch_event(user.id))
+        .unwrap();
+    catch_event.weight_g = 90.0;
+    let saved_catch_event = sp
+        .catch_event_repository()
+        .save(catch_event.clone())
+        .unwrap();
+    assert_eq!(saved_catch_event, catch_event);
+
+    assert!(sp
+        .catch_event_repository()
+        .delete(catch_event.clone())
+        .unwrap());
+    assert_eq!(
+        sp.catch_event_repository().find(catch_event.id).unwrap(),
+        None
+    );
+    let error = sp.catch_event_repository().save(catch_event).unwrap_err();
+    assert!(error.get_database_error().unwrap().is_not_found());
+}
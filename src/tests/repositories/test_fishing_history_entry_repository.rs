@@ -13,6 +13,14 @@ fn new_user_and_entry(sp: &Arc<dyn ServiceProviderInterface>) -> (User, FishingH
         sold_count: 0,
         smallest_catch_size_ratio: 0.5,
         largest_catch_size_ratio: 0.75,
+        smallest_catch_specimen_id: None,
+        smallest_catch_size_mm: None,
+        smallest_catch_weight_g: None,
+        smallest_catch_time: None,
+        largest_catch_specimen_id: None,
+        largest_catch_size_mm: None,
+        largest_catch_weight_g: None,
+        largest_catch_time: None,
     };
     let entry = sp
         .fishing_history_entry_repository()
@@ -47,6 +55,25 @@ fn test_find_caught_species_ids_by_user() {
     assert_eq!(caught_species_ids[0], entry.species_id);
 }
 
+#[test]
+fn test_find_by_user() {
+    let sp = mock_default_service_provider();
+
+    let (user, entry) = new_user_and_entry(&sp);
+    let entries = sp
+        .fishing_history_entry_repository()
+        .find_by_user(user.id)
+        .unwrap();
+    assert_eq!(entries, vec![entry]);
+
+    let other_user = sp.user_service().create_and_save_user(1338).unwrap();
+    let other_entries = sp
+        .fishing_history_entry_repository()
+        .find_by_user(other_user.id)
+        .unwrap();
+    assert!(other_entries.is_empty());
+}
+
 #[test]
 fn test_find() {
     let sp = mock_default_service_provider();
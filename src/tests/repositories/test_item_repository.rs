@@ -96,3 +96,36 @@ fn test_find_by_user() {
     assert_eq!(found_items.len(), 1);
     assert_eq!(found_items[0], item);
 }
+
+#[test]
+fn test_save_stale() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let item = create_and_save_item(&sp, user.id, 1);
+
+    // Two copies of the same item are modified concurrently
+    let mut first_copy = item.clone();
+    let mut second_copy = item;
+    first_copy.use_as_rod(config.clone()).unwrap();
+    second_copy.use_as_rod(config.clone()).unwrap();
+
+    let saved_item = sp.item_repository().save_checked(first_copy).unwrap();
+    let error = sp
+        .item_repository()
+        .save_checked(second_copy.clone())
+        .unwrap_err();
+    assert!(error.is_stale_entity());
+    assert_eq!(error.get_entity_id(), Some(saved_item.id));
+
+    // Retrying with the reloaded item succeeds
+    let mut reloaded_item = sp.item_repository().find(saved_item.id).unwrap().unwrap();
+    reloaded_item.use_as_rod(config).unwrap();
+    let saved_item = sp.item_repository().save_checked(reloaded_item).unwrap();
+    assert_eq!(saved_item.get_times_used(), Some(2));
+
+    // Without the check, the last save wins
+    let saved_item = sp.item_repository().save(second_copy).unwrap();
+    assert_eq!(saved_item.get_times_used(), Some(1));
+}
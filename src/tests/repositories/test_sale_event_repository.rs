@@ -0,0 +1,70 @@
+use crate::models::sale_event::NewSaleEvent;
+use crate::tests::mock::mock_default_service_provider;
+use chrono::{TimeZone, Utc};
+
+fn new_sale_event(user_id: i64) -> NewSaleEvent {
+    NewSaleEvent {
+        user_id,
+        species_id: 1,
+        specimen_id: 2,
+        sold_at: Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap(),
+    }
+}
+
+#[test]
+fn test_create_and_find() {
+    let sp = mock_default_service_provider();
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let sale_event = sp
+        .sale_event_repository()
+        .create(new_sale_event(user.id))
+        .unwrap();
+    assert_eq!(sale_event.user_id, user.id);
+    assert_eq!(sale_event.specimen_id, 2);
+    assert_eq!(
+        sale_event.sold_at,
+        Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap()
+    );
+
+    let found_sale_event = sp
+        .sale_event_repository()
+        .find(sale_event.id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(sale_event, found_sale_event);
+
+    let error = sp
+        .sale_event_repository()
+        .create(new_sale_event(-1))
+        .unwrap_err();
+    assert!(error
+        .get_database_error()
+        .unwrap()
+        .is_foreign_key_violation());
+}
+
+#[test]
+fn test_save_and_delete() {
+    let sp = mock_default_service_provider();
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let mut sale_event = sp
+        .sale_event_repository()
+        .create(new_sale_event(user.id))
+        .unwrap();
+    sale_event.species_id = 3;
+    let saved_sale_event = sp.sale_event_repository().save(sale_event.clone()).unwrap();
+    assert_eq!(saved_sale_event, sale_event);
+
+    assert!(sp
+        .sale_event_repository()
+        .delete(sale_event.clone())
+        .unwrap());
+    assert_eq!(
+        sp.sale_event_repository().find(sale_event.id).unwrap(),
+        None
+    );
+    let error = sp.sale_event_repository().save(sale_event).unwrap_err();
+    assert!(error.get_database_error().unwrap().is_not_found());
+}
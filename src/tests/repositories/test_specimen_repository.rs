@@ -23,19 +23,19 @@ fn test_find_by_user() {
     let user = sp.user_service().create_and_save_user(1337).unwrap();
     let _ = sp
         .specimen_service()
-        .generate_and_save_specimen(&user, species.clone())
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
         .unwrap();
     let _ = sp
         .specimen_service()
-        .generate_and_save_specimen(&user, species.clone())
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
         .unwrap();
     let _ = sp
         .specimen_service()
-        .generate_and_save_specimen(&user, species.clone())
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
         .unwrap();
     let _ = sp
         .specimen_service()
-        .generate_and_save_specimen(&user, species.clone())
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
         .unwrap();
 
     let all_user_specimen = sp.specimen_repository().find_by_user(&user).unwrap();
@@ -55,7 +55,7 @@ fn test_find() {
 
     let specimen = sp
         .specimen_service()
-        .generate_and_save_specimen(&user, species)
+        .generate_and_save_specimen(&user, species, sp.random_source().next_seed())
         .unwrap();
     let found_specimen = sp.specimen_repository().find(specimen.id).unwrap().unwrap();
     assert_eq!(specimen, found_specimen);
@@ -69,7 +69,7 @@ fn test_save() {
     let user = sp.user_service().create_and_save_user(1337).unwrap();
     let mut specimen = sp
         .specimen_service()
-        .generate_and_save_specimen(&user, species)
+        .generate_and_save_specimen(&user, species, sp.random_source().next_seed())
         .unwrap();
     specimen.species_id = 2;
 
@@ -87,7 +87,7 @@ fn test_delete() {
     let user = sp.user_service().create_and_save_user(1337).unwrap();
     let specimen = sp
         .specimen_service()
-        .generate_and_save_specimen(&user, species)
+        .generate_and_save_specimen(&user, species, sp.random_source().next_seed())
         .unwrap();
 
     let found_specimen = sp.specimen_repository().find(specimen.id).unwrap().unwrap();
@@ -100,3 +100,64 @@ fn test_delete() {
         None
     );
 }
+
+#[test]
+fn test_find_by_user_and_species() {
+    let sp = mock_service_provider(mock_config());
+    let species = sp.species_service().get_species_data(2).unwrap();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let specimen = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species, sp.random_source().next_seed())
+        .unwrap();
+
+    let found_specimens = sp
+        .specimen_repository()
+        .find_by_user_and_species(&user, 2)
+        .unwrap();
+    assert_eq!(found_specimens, vec![specimen]);
+
+    let no_specimens = sp
+        .specimen_repository()
+        .find_by_user_and_species(&user, 3)
+        .unwrap();
+    assert!(no_specimens.is_empty());
+}
+
+#[test]
+fn test_delete_many() {
+    let sp = mock_service_provider(mock_config());
+    let species = sp.species_service().get_species_data(2).unwrap();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let specimen1 = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
+        .unwrap();
+    let specimen2 = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
+        .unwrap();
+    let specimen3 = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species, sp.random_source().next_seed())
+        .unwrap();
+
+    // Specimens of other users are not deleted
+    let other_user = sp.user_service().create_and_save_user(1338).unwrap();
+    let no_specimens = sp
+        .specimen_repository()
+        .delete_many(&other_user, &[specimen1.id, specimen2.id])
+        .unwrap();
+    assert!(no_specimens.is_empty());
+
+    let deleted_specimens = sp
+        .specimen_repository()
+        .delete_many(&user, &[specimen1.id, specimen2.id, -1])
+        .unwrap();
+    assert_eq!(deleted_specimens.len(), 2);
+
+    let remaining_specimens = sp.specimen_repository().find_by_user(&user).unwrap();
+    assert_eq!(remaining_specimens, vec![specimen3]);
+}
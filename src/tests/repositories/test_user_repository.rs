@@ -1,4 +1,10 @@
-use crate::tests::mock::mock_default_service_provider;
+use crate::game::clock::FixedClock;
+use crate::models::user::User;
+use crate::tests::mock::{
+    mock_default_config, mock_default_service_provider, mock_service_provider_with_clock,
+};
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
 
 #[test]
 fn test_find_by_external_id() {
@@ -45,6 +51,41 @@ fn test_unlocked_location_ids() {
     assert_eq!(unlocked_location_ids[0], 68);
 }
 
+#[test]
+fn test_unlock_achievement() {
+    let sp = mock_default_service_provider();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let user_achievement = sp
+        .user_repository()
+        .unlock_achievement(user.id, 3)
+        .unwrap()
+        .unwrap();
+    sp.user_repository()
+        .unlock_achievement(user.id, 1)
+        .unwrap()
+        .unwrap();
+
+    let achievements = sp.user_repository().find_achievements(user.id).unwrap();
+    assert_eq!(achievements.len(), 2);
+    assert_eq!(achievements[0].achievement_id, 1);
+    assert_eq!(achievements[1], user_achievement);
+
+    let mut achievement_ids = sp
+        .user_repository()
+        .find_unlocked_achievement_ids(user.id)
+        .unwrap();
+    achievement_ids.sort();
+    assert_eq!(achievement_ids, vec![1, 3]);
+
+    // Unlocking an achievement twice keeps the first unlock
+    let second_unlock = sp.user_repository().unlock_achievement(user.id, 3).unwrap();
+    assert_eq!(second_unlock, None);
+    let achievements = sp.user_repository().find_achievements(user.id).unwrap();
+    assert_eq!(achievements.len(), 2);
+    assert_eq!(achievements[1], user_achievement);
+}
+
 #[test]
 fn test_find() {
     let sp = mock_default_service_provider();
@@ -89,3 +130,105 @@ fn test_delete() {
     sp.user_repository().delete(found_user).unwrap();
     assert_eq!(sp.user_repository().find(found_user_id).unwrap(), None);
 }
+
+#[test]
+fn test_add_credits() {
+    let sp = mock_default_service_provider();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    assert_eq!(user.credits, 0);
+
+    let updated_user = sp.user_repository().add_credits(user.id, 50).unwrap();
+    assert_eq!(updated_user.credits, 50);
+
+    let updated_user = sp.user_repository().add_credits(user.id, -20).unwrap();
+    assert_eq!(updated_user.credits, 30);
+
+    let error = sp.user_repository().add_credits(-1, 10).unwrap_err();
+    assert!(error.get_database_error().unwrap().is_not_found());
+}
+
+#[test]
+fn test_remove_credits() {
+    let sp = mock_default_service_provider();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    sp.user_repository().add_credits(user.id, 50).unwrap();
+
+    let updated_user = sp
+        .user_repository()
+        .remove_credits(user.id, 30)
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated_user.credits, 20);
+
+    let insufficient = sp.user_repository().remove_credits(user.id, 30).unwrap();
+    assert!(insufficient.is_none());
+
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, 20);
+}
+
+#[test]
+fn test_save_stale() {
+    let sp = mock_default_service_provider();
+
+    let mut user = sp.user_service().create_and_save_user(1337).unwrap();
+    sp.user_repository().add_credits(user.id, 50).unwrap();
+
+    // The credits were changed after the user was loaded
+    user.credits = 10;
+    let error = sp.user_repository().save_checked(user.clone()).unwrap_err();
+    assert!(error.is_stale_entity());
+    assert_eq!(error.get_entity_id(), Some(user.id));
+
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, 50);
+
+    let error = sp
+        .user_repository()
+        .save_checked(User::default())
+        .unwrap_err();
+    assert!(error.get_database_error().unwrap().is_not_found());
+
+    // Without the check, the stale user overwrites the credits
+    let saved_user = sp.user_repository().save(user).unwrap();
+    assert_eq!(saved_user.credits, 10);
+}
+
+#[test]
+fn test_save_stale_at_same_time() {
+    // Every save happens at the same time, so only the version tells the copies apart
+    let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let clock = Arc::new(FixedClock::new(start));
+    let sp = mock_service_provider_with_clock(mock_default_config(), clock);
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    assert_eq!(user.version, 0);
+    let mut first_copy = user.clone();
+    first_copy.credits = 10;
+    let mut second_copy = user;
+    second_copy.credits = 20;
+
+    let saved_user = sp.user_repository().save_checked(first_copy).unwrap();
+    assert_eq!(saved_user.version, 1);
+    assert_eq!(saved_user.updated_at, start);
+    let error = sp
+        .user_repository()
+        .save_checked(second_copy.clone())
+        .unwrap_err();
+    assert!(error.is_stale_entity());
+
+    // Unchecked saves and credit changes increment the version as well
+    let saved_user = sp.user_repository().save(second_copy.clone()).unwrap();
+    assert_eq!(saved_user.version, 2);
+    let error = sp.user_repository().save_checked(second_copy).unwrap_err();
+    assert!(error.is_stale_entity());
+    let updated_user = sp.user_repository().add_credits(saved_user.id, 5).unwrap();
+    assert_eq!(updated_user.version, 3);
+    let error = sp.user_repository().save_checked(saved_user).unwrap_err();
+    assert!(error.is_stale_entity());
+
+    let found_user = sp.user_repository().find(updated_user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, 25);
+}
@@ -1,7 +1,13 @@
+mod test_achievement_service;
+mod test_fishing;
 mod test_fishing_history_service;
+mod test_forecast_service;
 mod test_item_service;
+mod test_leaderboard_service;
 mod test_location_service;
 mod test_pond_service;
+mod test_shop_service;
 mod test_species_service;
 mod test_specimen_service;
 mod test_user_service;
+mod test_weather_service;
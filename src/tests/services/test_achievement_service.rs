@@ -0,0 +1,261 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::achievement_data::{AchievementData, AchievementRequirement};
+use crate::data::encounter_data::EncounterData;
+use crate::data::item_data::ItemData;
+use crate::data::location_data::LocationData;
+use crate::data::species_data::SpeciesData;
+use crate::game::services::achievement_service::AchievementTrigger;
+use crate::models::item::attributes_container::ItemAttributesContainer;
+use crate::tests::mock::mock_service_provider;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const SPECIES_ID: i32 = 1;
+const LOCATION_ID: i32 = 1;
+const OTHER_LOCATION_ID: i32 = 2;
+const ITEM_ID: i32 = 1;
+
+const TWO_CATCHES_ID: i32 = 1;
+const RARE_IN_RAIN_ID: i32 = 2;
+const FIRST_SALE_ID: i32 = 3;
+const RICH_ID: i32 = 4;
+const SHOPPER_ID: i32 = 5;
+const EXPLORER_ID: i32 = 6;
+
+fn mock_config() -> Arc<dyn ConfigInterface> {
+    let species = SpeciesData {
+        name: "Salmon".to_string(),
+        base_value: 100,
+        min_size_baby_mm: 10,
+        max_size_baby_mm: 20,
+        min_size_adult_mm: 50,
+        max_size_adult_mm: 80,
+        min_lifespan_days: 300,
+        max_lifespan_days: 400,
+        encounters: vec![EncounterData {
+            location_id: LOCATION_ID,
+            rarity_level: 200,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let item = ItemData {
+        name: "Rod".to_string(),
+        attributes: ItemAttributesContainer::new()
+            .with_rod(1)
+            .with_purchasable(10),
+        ..Default::default()
+    };
+
+    let achievement = |name: &str, requirement| AchievementData {
+        name: name.to_string(),
+        requirement,
+        ..Default::default()
+    };
+    let achievements = HashMap::from([
+        (
+            TWO_CATCHES_ID,
+            achievement(
+                "Two catches",
+                AchievementRequirement::CatchCount {
+                    count: 2,
+                    species_id: Some(SPECIES_ID),
+                },
+            ),
+        ),
+        (
+            RARE_IN_RAIN_ID,
+            achievement(
+                "Rare in the rain",
+                AchievementRequirement::Catch {
+                    species_id: None,
+                    location_id: Some(LOCATION_ID),
+                    min_rarity_level: Some(200),
+                    min_size_ratio: None,
+                    needs_rain: true,
+                },
+            ),
+        ),
+        (
+            FIRST_SALE_ID,
+            achievement("First sale", AchievementRequirement::SellCount { count: 1 }),
+        ),
+        (
+            RICH_ID,
+            achievement("Rich", AchievementRequirement::Credits { amount: 100 }),
+        ),
+        (
+            SHOPPER_ID,
+            achievement(
+                "Shopper",
+                AchievementRequirement::Purchase {
+                    item_id: Some(ITEM_ID),
+                },
+            ),
+        ),
+        (
+            EXPLORER_ID,
+            achievement(
+                "Explorer",
+                AchievementRequirement::LocationsUnlocked { count: None },
+            ),
+        ),
+    ]);
+
+    Config::builder()
+        .species(HashMap::from([(SPECIES_ID, species)]))
+        .locations(HashMap::from([
+            (LOCATION_ID, LocationData::default()),
+            (OTHER_LOCATION_ID, LocationData::default()),
+        ]))
+        .items(HashMap::from([(ITEM_ID, item)]))
+        .achievements(achievements)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_process_catch_trigger() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+    let mut dry_weather = sp.weather_service().get_current_weather(location).unwrap();
+    dry_weather.is_raining = false;
+    let mut rainy_weather = dry_weather.clone();
+    rainy_weather.is_raining = true;
+
+    let catch = |weather| {
+        let specimen = sp
+            .specimen_service()
+            .process_catch(&user, species.clone(), 1)
+            .unwrap();
+        sp.fishing_history_service()
+            .register_catch(&specimen)
+            .unwrap();
+        sp.achievement_service()
+            .process_trigger(
+                &user,
+                AchievementTrigger::Catch {
+                    specimen: &specimen,
+                    species: &species,
+                    location_id: Some(LOCATION_ID),
+                    weather,
+                },
+            )
+            .unwrap()
+    };
+
+    // Neither enough catches nor rain
+    assert!(catch(Some(&dry_weather)).is_empty());
+
+    let unlocks = catch(Some(&rainy_weather));
+    let unlocked_ids: Vec<i32> = unlocks.iter().map(|unlock| unlock.achievement.id).collect();
+    assert_eq!(unlocked_ids, vec![TWO_CATCHES_ID, RARE_IN_RAIN_ID]);
+
+    // Achievements are only unlocked once
+    assert!(catch(Some(&rainy_weather)).is_empty());
+
+    let achievements = sp.achievement_service().get_achievements(&user).unwrap();
+    assert_eq!(achievements, unlocks);
+}
+
+#[test]
+fn test_process_catch_trigger_without_weather() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+    let specimen = sp
+        .specimen_service()
+        .process_catch(&user, species.clone(), 1)
+        .unwrap();
+
+    // The rain can't be checked without knowing the weather
+    let unlocks = sp
+        .achievement_service()
+        .process_trigger(
+            &user,
+            AchievementTrigger::Catch {
+                specimen: &specimen,
+                species: &species,
+                location_id: None,
+                weather: None,
+            },
+        )
+        .unwrap();
+    assert!(unlocks.is_empty());
+}
+
+#[test]
+fn test_process_sell_and_purchase_triggers() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+    let specimen = sp
+        .specimen_service()
+        .process_catch(&user, species, 1)
+        .unwrap();
+    sp.fishing_history_service()
+        .register_catch(&specimen)
+        .unwrap();
+
+    // The sale pays at least the base value of 100 credits
+    let sell_result = sp
+        .shop_service()
+        .sell_specimens(&user, vec![specimen])
+        .unwrap();
+    let unlocks = sp
+        .achievement_service()
+        .process_trigger(&sell_result.user, AchievementTrigger::Sell)
+        .unwrap();
+    let unlocked_ids: Vec<i32> = unlocks.iter().map(|unlock| unlock.achievement.id).collect();
+    assert_eq!(unlocked_ids, vec![FIRST_SALE_ID, RICH_ID]);
+
+    let item_data = config.get_item_data(ITEM_ID).unwrap();
+    let purchase_result = sp
+        .shop_service()
+        .purchase_item(&sell_result.user, item_data, 1)
+        .unwrap();
+    let unlocks = sp
+        .achievement_service()
+        .process_trigger(
+            &purchase_result.user,
+            AchievementTrigger::Purchase {
+                item_id: purchase_result.item.type_id,
+            },
+        )
+        .unwrap();
+    assert_eq!(unlocks.len(), 1);
+    assert_eq!(unlocks[0].achievement.id, SHOPPER_ID);
+
+    let achievements = sp.achievement_service().get_achievements(&user).unwrap();
+    assert_eq!(achievements.len(), 3);
+}
+
+#[test]
+fn test_process_location_unlock_trigger() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let unlock_location = |location_id| {
+        let location = config.get_location_data(location_id).unwrap();
+        sp.user_service().unlock_location(&user, location).unwrap();
+        sp.achievement_service()
+            .process_trigger(&user, AchievementTrigger::LocationUnlock)
+            .unwrap()
+    };
+
+    assert!(unlock_location(LOCATION_ID).is_empty());
+
+    let unlocks = unlock_location(OTHER_LOCATION_ID);
+    assert_eq!(unlocks.len(), 1);
+    assert_eq!(unlocks[0].achievement.id, EXPLORER_ID);
+}
@@ -0,0 +1,198 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::encounter_data::EncounterData;
+use crate::data::item_data::ItemData;
+use crate::data::location_data::LocationData;
+use crate::data::season_data::SeasonData;
+use crate::data::species_data::SpeciesData;
+use crate::game::interface::GameInterface;
+use crate::game::service_provider::ServiceProviderInterface;
+use crate::models::item::attributes_container::ItemAttributesContainer;
+use crate::models::item::properties_container::{
+    ItemPropertiesContainer, ItemPropertiesContainerInterface,
+};
+use crate::models::item::Item;
+use crate::models::user::User;
+use crate::tests::mock::mock_game;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const LOCATION_ID: i32 = 1;
+const SPECIES_ID: i32 = 1;
+const ROD_ID: i32 = 1;
+const BAIT_ID: i32 = 2;
+
+fn mock_config() -> Arc<dyn ConfigInterface> {
+    // A location where it never rains, so the species can always be encountered
+    let every_season = SeasonData {
+        rain_intensity_raining_threshold: 2.0,
+        ..Default::default()
+    };
+    let location = LocationData {
+        spring: every_season.clone(),
+        summer: every_season.clone(),
+        autumn: every_season.clone(),
+        winter: every_season,
+        ..Default::default()
+    };
+
+    let species = SpeciesData {
+        name: "Salmon".to_string(),
+        min_size_baby_mm: 10,
+        max_size_baby_mm: 20,
+        min_size_adult_mm: 50,
+        max_size_adult_mm: 80,
+        min_lifespan_days: 300,
+        max_lifespan_days: 400,
+        encounters: vec![EncounterData {
+            location_id: LOCATION_ID,
+            min_time_hour: 0,
+            max_time_hour: 23,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let rod = ItemData {
+        name: "Rod".to_string(),
+        attributes: ItemAttributesContainer::new().with_rod(1),
+        default_properties: ItemPropertiesContainer::new().with_usage(0),
+        ..Default::default()
+    };
+    let bait = ItemData {
+        name: "Bait".to_string(),
+        attributes: ItemAttributesContainer::new().with_bait(1),
+        default_properties: ItemPropertiesContainer::new().with_stackable(1),
+        ..Default::default()
+    };
+
+    Config::builder()
+        .locations(HashMap::from([(LOCATION_ID, location)]))
+        .species(HashMap::from([(SPECIES_ID, species)]))
+        .items(HashMap::from([(ROD_ID, rod), (BAIT_ID, bait)]))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_fish_with_items_of_other_users() {
+    let config = mock_config();
+    let game = mock_game(config.clone());
+    let user = game.user_register(1337).unwrap();
+    let other_user = game.user_register(1338).unwrap();
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+    game.user_unlock_location(&user, location.clone()).unwrap();
+
+    let rod_data = config.get_item_data(ROD_ID).unwrap();
+    let other_rod = game.user_item_give(&other_user, rod_data, 1).unwrap();
+
+    // The owner of the given item is not trusted
+    let forged_rod = Item {
+        user_id: user.id,
+        ..other_rod.clone()
+    };
+    let error = game
+        .user_fish(&user, location.clone(), Some(forged_rod), None)
+        .unwrap_err();
+    let resource_error = error.as_resource_error().unwrap();
+    assert!(resource_error.is_item_not_owned());
+    assert_eq!(resource_error.get_item_id(), Some(other_rod.id));
+
+    // Nothing was caught and the rod was not used
+    let found_rod = game.item_repository().find(other_rod.id).unwrap();
+    assert_eq!(found_rod, Some(other_rod));
+    assert!(game
+        .specimen_repository()
+        .find_by_user(&user)
+        .unwrap()
+        .is_empty());
+
+    // Bait which was used up can't be used again
+    let bait_data = config.get_item_data(BAIT_ID).unwrap();
+    let bait = game.user_item_give(&user, bait_data, 1).unwrap();
+    let result = game
+        .user_fish(&user, location.clone(), None, Some(bait.clone()))
+        .unwrap();
+    assert_eq!(result.bait, None);
+    let error = game
+        .user_fish(&user, location, None, Some(bait.clone()))
+        .unwrap_err();
+    let resource_error = error.as_resource_error().unwrap();
+    assert!(resource_error.is_item_not_owned());
+    assert_eq!(resource_error.get_item_id(), Some(bait.id));
+}
+
+#[test]
+fn test_fish() {
+    let config = mock_config();
+    let game = mock_game(config.clone());
+    let user = game.user_register(1337).unwrap();
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+    game.user_unlock_location(&user, location.clone()).unwrap();
+
+    let rod = game
+        .user_item_give(&user, config.get_item_data(ROD_ID).unwrap(), 1)
+        .unwrap();
+    let bait = game
+        .user_item_give(&user, config.get_item_data(BAIT_ID).unwrap(), 2)
+        .unwrap();
+    let result = game
+        .user_fish(&user, location.clone(), Some(rod), Some(bait))
+        .unwrap();
+    assert_eq!(result.location.id, LOCATION_ID);
+    assert_eq!(result.species.id, SPECIES_ID);
+    assert_eq!(result.specimen.user_id, user.id);
+    assert_eq!(result.specimen.species_id, SPECIES_ID);
+    assert_eq!(result.history_entry.caught_count, 1);
+    assert_eq!(result.rod.unwrap().get_times_used(), Some(1));
+    assert_eq!(result.bait.unwrap().get_count(), Some(1));
+
+    // The catch is persisted
+    let specimens = game.specimen_repository().find_by_user(&user).unwrap();
+    assert_eq!(specimens, vec![result.specimen]);
+}
+
+#[test]
+fn test_fish_errors() {
+    let config = mock_config();
+    let game = mock_game(config.clone());
+    let user = game.user_register(1337).unwrap();
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+
+    let error = game
+        .user_fish(&user, location.clone(), None, None)
+        .unwrap_err();
+    let resource_error = error.as_resource_error().unwrap();
+    assert!(resource_error.is_location_locked());
+    assert_eq!(resource_error.get_external_id(), Some(user.external_id));
+    assert_eq!(resource_error.get_location_id(), Some(LOCATION_ID));
+
+    // A bait can't be used as a rod
+    game.user_unlock_location(&user, location.clone()).unwrap();
+    let bait = game
+        .user_item_give(&user, config.get_item_data(BAIT_ID).unwrap(), 1)
+        .unwrap();
+    let error = game
+        .user_fish(&user, location.clone(), Some(bait.clone()), None)
+        .unwrap_err();
+    assert!(error.as_item_event_error().unwrap().is_not_a_rod());
+    let found_bait = game.item_repository().find(bait.id).unwrap();
+    assert_eq!(found_bait, Some(bait));
+
+    let dummy_user = User {
+        id: -1,
+        external_id: 1338,
+        ..Default::default()
+    };
+    let error = game
+        .user_fish(&dummy_user, location, None, None)
+        .unwrap_err();
+    let resource_error = error.as_resource_error().unwrap();
+    assert!(resource_error.is_user_not_found());
+    assert_eq!(resource_error.get_external_id(), Some(1338));
+
+    assert!(game
+        .specimen_repository()
+        .find_by_user(&user)
+        .unwrap()
+        .is_empty());
+}
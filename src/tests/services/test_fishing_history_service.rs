@@ -1,5 +1,11 @@
 use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::encounter_data::EncounterData;
+use crate::data::location_data::LocationData;
 use crate::data::species_data::SpeciesData;
+use crate::dto::fishdex::FishdexCompletion;
+use crate::models::fishing_history_entry::{
+    CatchRecord, FishingHistoryEntry, NewFishingHistoryEntry,
+};
 use crate::models::specimen::NewSpecimen;
 use crate::tests::mock::mock_service_provider;
 use chrono::Utc;
@@ -40,6 +46,7 @@ fn test_register_catch() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 0.5,
         catch_age: 1.0,
+        catch_seed: None,
     };
     let fish = sp.specimen_repository().create(new_fish).unwrap();
 
@@ -57,6 +64,11 @@ fn test_register_catch() {
     assert_eq!(entry.smallest_catch_size_ratio, 0.8913044f32);
     assert_eq!(entry.largest_catch_size_ratio, 0.8913044f32);
     assert_eq!(entry.last_catch, entry.created_at);
+    assert_eq!(entry.smallest_catch_specimen_id, Some(fish.id));
+    assert_eq!(entry.largest_catch_specimen_id, Some(fish.id));
+    assert_eq!(entry.smallest_catch_size_mm, Some(450.0));
+    assert_eq!(entry.largest_catch_weight_g, Some(285.0));
+    assert_eq!(entry.largest_catch_time, Some(fish.created_at));
 
     let found_entry = sp
         .fishing_history_entry_repository()
@@ -72,6 +84,7 @@ fn test_register_catch() {
         size_adult_ratio: 0.75,
         lifespan_days_ratio: 0.5,
         catch_age: 1.0,
+        catch_seed: None,
     };
     let fish2 = sp.specimen_repository().create(new_fish2).unwrap();
     let entry2 = sp.fishing_history_service().register_catch(&fish2).unwrap();
@@ -82,6 +95,10 @@ fn test_register_catch() {
     assert_eq!(entry2.smallest_catch_size_ratio, 0.8913044f32);
     assert_eq!(entry2.largest_catch_size_ratio, 0.9456522f32);
     assert_eq!(entry2.last_catch, fish2.created_at);
+    assert_eq!(entry2.smallest_catch_specimen_id, Some(fish.id));
+    assert_eq!(entry2.largest_catch_specimen_id, Some(fish2.id));
+    assert_eq!(entry2.largest_catch_size_mm, Some(475.0));
+    assert_eq!(entry2.largest_catch_time, Some(fish2.created_at));
 
     let found_entry2 = sp
         .fishing_history_entry_repository()
@@ -97,6 +114,7 @@ fn test_register_catch() {
         size_adult_ratio: 0.25,
         lifespan_days_ratio: 0.5,
         catch_age: 1.0,
+        catch_seed: None,
     };
     let fish3 = sp.specimen_repository().create(new_fish3).unwrap();
     let entry3 = sp.fishing_history_service().register_catch(&fish3).unwrap();
@@ -107,6 +125,10 @@ fn test_register_catch() {
     assert_eq!(entry3.smallest_catch_size_ratio, 0.8369565f32);
     assert_eq!(entry3.largest_catch_size_ratio, 0.9456522f32);
     assert_eq!(entry3.last_catch, fish3.created_at);
+    assert_eq!(entry3.smallest_catch_specimen_id, Some(fish3.id));
+    assert_eq!(entry3.smallest_catch_size_mm, Some(425.0));
+    assert_eq!(entry3.smallest_catch_time, Some(fish3.created_at));
+    assert_eq!(entry3.largest_catch_specimen_id, Some(fish2.id));
 
     let found_entry3 = sp
         .fishing_history_entry_repository()
@@ -116,6 +138,108 @@ fn test_register_catch() {
     assert_eq!(found_entry3, entry3);
 }
 
+#[test]
+fn test_register_catch_sets_both_records() {
+    let mut entry = FishingHistoryEntry::default();
+    let catch = CatchRecord {
+        specimen_id: 42,
+        total_size_ratio: 0.5,
+        size_mm: 250.0,
+        weight_g: 150.0,
+        catch_time: Utc::now(),
+    };
+
+    entry.register_catch(&catch);
+    assert_eq!(entry.caught_count, 1);
+    assert_eq!(entry.smallest_catch_specimen_id, Some(42));
+    assert_eq!(entry.largest_catch_specimen_id, Some(42));
+    assert_eq!(entry.smallest_catch_size_ratio, 0.5);
+    assert_eq!(entry.largest_catch_size_ratio, 0.5);
+    assert_eq!(entry.smallest_catch_time, Some(catch.catch_time));
+    assert_eq!(entry.largest_catch_time, Some(catch.catch_time));
+}
+
+#[test]
+fn test_get_personal_bests() {
+    let sp = mock_service_provider(mock_config());
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    assert!(sp
+        .fishing_history_service()
+        .get_personal_bests(user.id)
+        .unwrap()
+        .is_empty());
+
+    let mut fish_ids = Vec::new();
+    for ratio in [0.5, 0.25, 0.75] {
+        let new_fish = NewSpecimen {
+            user_id: user.id,
+            species_id: 1,
+            size_baby_ratio: ratio,
+            size_adult_ratio: ratio,
+            lifespan_days_ratio: 0.5,
+            catch_age: 1.0,
+            catch_seed: None,
+        };
+        let fish = sp.specimen_repository().create(new_fish).unwrap();
+        sp.fishing_history_service().register_catch(&fish).unwrap();
+        fish_ids.push(fish.id);
+    }
+
+    let personal_bests = sp
+        .fishing_history_service()
+        .get_personal_bests(user.id)
+        .unwrap();
+    assert_eq!(personal_bests.len(), 1);
+    let personal_best = &personal_bests[0];
+    assert_eq!(personal_best.species_id, 1);
+    assert_eq!(personal_best.smallest.specimen_id, Some(fish_ids[1]));
+    assert_eq!(personal_best.smallest.size_mm, 425.0);
+    assert_eq!(personal_best.smallest.weight_g, 252.5);
+    assert!(personal_best.smallest.set_at.is_some());
+    assert_eq!(personal_best.largest.specimen_id, Some(fish_ids[2]));
+    assert_eq!(personal_best.largest.size_mm, 475.0);
+    assert_eq!(personal_best.largest.weight_g, 317.5);
+}
+
+#[test]
+fn test_get_personal_bests_without_tracked_specimens() {
+    let sp = mock_service_provider(mock_config());
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let new_entry = NewFishingHistoryEntry {
+        user_id: user.id,
+        species_id: 1,
+        caught_count: 2,
+        sold_count: 0,
+        smallest_catch_size_ratio: 0.0,
+        largest_catch_size_ratio: 1.0,
+        smallest_catch_specimen_id: None,
+        smallest_catch_size_mm: None,
+        smallest_catch_weight_g: None,
+        smallest_catch_time: None,
+        largest_catch_specimen_id: None,
+        largest_catch_size_mm: None,
+        largest_catch_weight_g: None,
+        largest_catch_time: None,
+    };
+    sp.fishing_history_entry_repository()
+        .create(new_entry)
+        .unwrap();
+
+    let personal_bests = sp
+        .fishing_history_service()
+        .get_personal_bests(user.id)
+        .unwrap();
+    assert_eq!(personal_bests.len(), 1);
+    assert_eq!(personal_bests[0].smallest.specimen_id, None);
+    assert_eq!(personal_bests[0].smallest.size_mm, 40.0);
+    assert_eq!(personal_bests[0].smallest.weight_g, 20.0);
+    assert_eq!(personal_bests[0].smallest.set_at, None);
+    assert_eq!(personal_bests[0].largest.size_mm, 500.0);
+    assert_eq!(personal_bests[0].largest.weight_g, 350.0);
+}
+
 #[test]
 fn test_register_sell() {
     let sp = mock_service_provider(mock_config());
@@ -128,6 +252,7 @@ fn test_register_sell() {
         size_adult_ratio: 0.5,
         lifespan_days_ratio: 0.5,
         catch_age: 1.0,
+        catch_seed: None,
     };
     let fish = sp.specimen_repository().create(new_fish).unwrap();
 
@@ -179,3 +304,114 @@ fn test_register_sell() {
     assert_ne!(entry2.last_sell, entry.first_sell);
     assert!(entry2.first_sell < entry2.last_sell);
 }
+
+#[test]
+fn test_get_fishdex() {
+    let encounter = |location_id, rarity_level| EncounterData {
+        location_id,
+        rarity_level,
+        ..Default::default()
+    };
+    let species = |name: &str, encounters| SpeciesData {
+        name: name.to_string(),
+        encounters,
+        ..Default::default()
+    };
+    let config = Config::builder()
+        .locations(HashMap::from([
+            (1, LocationData::default()),
+            (2, LocationData::default()),
+        ]))
+        .species(HashMap::from([
+            (1, species("salmon", vec![encounter(1, 1), encounter(2, 1)])),
+            (2, species("trout", vec![encounter(1, 5)])),
+            (
+                3,
+                species("sturgeon", vec![encounter(2, 5), encounter(2, 8)]),
+            ),
+        ]))
+        .build()
+        .unwrap();
+    let sp = mock_service_provider(config);
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let empty_fishdex = sp
+        .fishing_history_service()
+        .get_fishdex(user.id, false)
+        .unwrap();
+    assert_eq!(
+        empty_fishdex.completion,
+        FishdexCompletion {
+            caught: 0,
+            total: 3
+        }
+    );
+    assert_eq!(empty_fishdex.completion.get_percentage(), 0.0);
+    assert!(empty_fishdex
+        .entries
+        .iter()
+        .all(|entry| entry.name.is_some()));
+
+    for species_id in [1, 3, 3] {
+        let new_fish = NewSpecimen {
+            user_id: user.id,
+            species_id,
+            size_baby_ratio: 0.5,
+            size_adult_ratio: 0.5,
+            lifespan_days_ratio: 0.5,
+            catch_age: 1.0,
+            catch_seed: None,
+        };
+        let fish = sp.specimen_repository().create(new_fish).unwrap();
+        sp.fishing_history_service().register_catch(&fish).unwrap();
+    }
+
+    let fishdex = sp
+        .fishing_history_service()
+        .get_fishdex(user.id, true)
+        .unwrap();
+    let species_ids: Vec<i32> = fishdex
+        .entries
+        .iter()
+        .map(|entry| entry.species_id)
+        .collect();
+    assert_eq!(species_ids, vec![1, 2, 3]);
+
+    let sturgeon = fishdex.get_entry(3).unwrap();
+    assert!(sturgeon.is_caught());
+    assert_eq!(sturgeon.name, Some("sturgeon".to_string()));
+    assert_eq!(sturgeon.caught_count, 2);
+    assert_eq!(sturgeon.rarity_level, 5);
+    assert_eq!(sturgeon.location_ids, vec![2]);
+
+    let trout = fishdex.get_entry(2).unwrap();
+    assert!(!trout.is_caught());
+    assert_eq!(trout.name, None);
+    assert_eq!(trout.first_catch, None);
+    assert_eq!(trout.caught_count, 0);
+
+    assert_eq!(
+        fishdex.completion,
+        FishdexCompletion {
+            caught: 2,
+            total: 3
+        }
+    );
+    assert_eq!(
+        fishdex.completion_by_location[&1],
+        FishdexCompletion {
+            caught: 1,
+            total: 2
+        }
+    );
+    assert!(fishdex.completion_by_location[&2].is_complete());
+    assert_eq!(fishdex.completion_by_location[&1].get_percentage(), 50.0);
+    assert!(fishdex.completion_by_rarity[&1].is_complete());
+    assert_eq!(
+        fishdex.completion_by_rarity[&5],
+        FishdexCompletion {
+            caught: 1,
+            total: 2
+        }
+    );
+}
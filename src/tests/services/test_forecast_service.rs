@@ -0,0 +1,203 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::encounter_data::EncounterData;
+use crate::data::location_data::LocationData;
+use crate::data::settings::Settings;
+use crate::data::species_data::SpeciesData;
+use crate::game::clock::FixedClock;
+use crate::game::services::forecast_service::MAX_FORECAST_DAYS;
+use crate::game::systems::encounter_system::EncounterEquipment;
+use crate::tests::mock::mock_service_provider_with_clock;
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const LOCATION_ID: i32 = 1;
+const NOCTURNAL_SPECIES_ID: i32 = 1;
+const DAYTIME_SPECIES_ID: i32 = 2;
+
+fn mock_config(time_speed_multiplier: f32, time_epoch: DateTime<Utc>) -> Arc<dyn ConfigInterface> {
+    let location = LocationData {
+        timezone: Tz::UTC,
+        ..Default::default()
+    };
+
+    let encounter = |min_time_hour, max_time_hour| EncounterData {
+        location_id: LOCATION_ID,
+        min_time_hour,
+        max_time_hour,
+        ..Default::default()
+    };
+    let nocturnal_species = SpeciesData {
+        encounters: vec![encounter(22, 3)],
+        ..Default::default()
+    };
+    let daytime_species = SpeciesData {
+        encounters: vec![encounter(8, 17)],
+        ..Default::default()
+    };
+
+    let settings = Settings {
+        time_speed_multiplier,
+        time_epoch,
+        ..Default::default()
+    };
+
+    Config::builder()
+        .locations(HashMap::from([(LOCATION_ID, location)]))
+        .species(HashMap::from([
+            (NOCTURNAL_SPECIES_ID, nocturnal_species),
+            (DAYTIME_SPECIES_ID, daytime_species),
+        ]))
+        .settings(settings)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_forecast_location() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let config = mock_config(1.0, DateTime::UNIX_EPOCH);
+    let sp = mock_service_provider_with_clock(config.clone(), Arc::new(FixedClock::new(now)));
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+
+    let windows = sp
+        .forecast_service()
+        .forecast_location(location, 2, EncounterEquipment::default())
+        .unwrap();
+
+    let at = |day, hour| Tz::UTC.with_ymd_and_hms(2025, 1, day, hour, 0, 0).unwrap();
+    let spans: Vec<(i32, DateTime<Tz>, DateTime<Tz>)> = windows
+        .iter()
+        .map(|window| (window.species_id, window.start, window.end))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            // The scan starts within the daytime window
+            (DAYTIME_SPECIES_ID, at(1, 12), at(1, 18)),
+            (NOCTURNAL_SPECIES_ID, at(1, 22), at(2, 4)),
+            (DAYTIME_SPECIES_ID, at(2, 8), at(2, 18)),
+            (NOCTURNAL_SPECIES_ID, at(2, 22), at(3, 4)),
+            (DAYTIME_SPECIES_ID, at(3, 8), at(3, 12)),
+        ]
+    );
+
+    // Never overlapping, so each species is the only possible catch in its windows
+    for window in windows {
+        assert_eq!(window.max_probability, 1.0);
+        assert_eq!(window.average_probability, 1.0);
+        assert_eq!(window.real_start, window.start.with_timezone(&Utc));
+    }
+}
+
+#[test]
+fn test_forecast_species() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    // A full in-game day passes every real hour
+    let config = mock_config(24.0, now);
+    let sp = mock_service_provider_with_clock(config.clone(), Arc::new(FixedClock::new(now)));
+    let species = config.get_species_data(NOCTURNAL_SPECIES_ID).unwrap();
+
+    let windows = sp
+        .forecast_service()
+        .forecast_species(species, 1, EncounterEquipment::default())
+        .unwrap();
+    assert_eq!(windows.len(), 2);
+    assert!(windows
+        .iter()
+        .all(|window| window.species_id == NOCTURNAL_SPECIES_ID));
+
+    // From midnight until 4am in-game, which are 10 real minutes
+    assert_eq!(windows[0].start, now.with_timezone(&Tz::UTC));
+    assert_eq!(windows[0].real_end, now + TimeDelta::minutes(10));
+    // From 10pm in-game until the end of the forecast
+    assert_eq!(windows[1].real_start, now + TimeDelta::minutes(55));
+    assert_eq!(windows[1].real_end, now + TimeDelta::hours(1));
+}
+
+#[test]
+fn test_forecast_short_window() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let location = LocationData {
+        timezone: Tz::UTC,
+        ..Default::default()
+    };
+    // Shorter than a forecast step and in between two of them
+    let species = SpeciesData {
+        encounters: vec![EncounterData {
+            location_id: LOCATION_ID,
+            min_time_hour: 22,
+            min_time_minute: Some(5),
+            max_time_hour: 22,
+            max_time_minute: Some(12),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let config = Config::builder()
+        .locations(HashMap::from([(LOCATION_ID, location)]))
+        .species(HashMap::from([(NOCTURNAL_SPECIES_ID, species)]))
+        .settings(Settings {
+            time_epoch: DateTime::UNIX_EPOCH,
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+    let sp = mock_service_provider_with_clock(config.clone(), Arc::new(FixedClock::new(now)));
+    let species = config.get_species_data(NOCTURNAL_SPECIES_ID).unwrap();
+
+    let windows = sp
+        .forecast_service()
+        .forecast_species(species, 2, EncounterEquipment::default())
+        .unwrap();
+
+    let at = |day, minute| {
+        Tz::UTC
+            .with_ymd_and_hms(2025, 1, day, 22, minute, 0)
+            .unwrap()
+    };
+    let spans: Vec<(DateTime<Tz>, DateTime<Tz>)> = windows
+        .iter()
+        .map(|window| (window.start, window.end))
+        .collect();
+    assert_eq!(spans, vec![(at(1, 5), at(1, 13)), (at(2, 5), at(2, 13))]);
+}
+
+#[test]
+fn test_forecast_too_long() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let config = mock_config(1.0, now);
+    let sp = mock_service_provider_with_clock(config.clone(), Arc::new(FixedClock::new(now)));
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+    let species = config.get_species_data(NOCTURNAL_SPECIES_ID).unwrap();
+
+    let windows = sp
+        .forecast_service()
+        .forecast_location(
+            location.clone(),
+            MAX_FORECAST_DAYS,
+            EncounterEquipment::default(),
+        )
+        .unwrap();
+    assert!(!windows.is_empty());
+
+    let error = sp
+        .forecast_service()
+        .forecast_location(location, u32::MAX, EncounterEquipment::default())
+        .unwrap_err();
+    let input_error = error.as_input_error().unwrap();
+    assert!(input_error.is_forecast_too_long());
+    assert_eq!(input_error.get_days(), Some(u32::MAX));
+    assert_eq!(input_error.get_max_days(), Some(MAX_FORECAST_DAYS));
+
+    let error = sp
+        .forecast_service()
+        .forecast_species(
+            species,
+            MAX_FORECAST_DAYS + 1,
+            EncounterEquipment::default(),
+        )
+        .unwrap_err();
+    assert!(error.as_input_error().unwrap().is_forecast_too_long());
+}
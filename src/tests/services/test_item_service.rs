@@ -237,3 +237,44 @@ fn test_get_inventory() {
     let no_items = inventory.get_items_by_category(ItemCategory::Bait);
     assert_eq!(no_items.len(), 0);
 }
+
+#[test]
+fn test_use_as_rod_and_bait() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let rod_data = config.get_item_data(UNIQUE_ROD_ID).unwrap();
+    let rod = sp
+        .item_service()
+        .create_and_save_item(rod_data, &user)
+        .unwrap();
+    let used_rod = sp.item_service().use_as_rod(rod.clone()).unwrap().unwrap();
+    assert_eq!(used_rod.get_times_used(), Some(1));
+    let found_rod = sp.item_repository().find(rod.id).unwrap().unwrap();
+    assert_eq!(found_rod.get_times_used(), Some(1));
+
+    let rod_error = sp.item_service().use_as_bait(used_rod).unwrap_err();
+    if let Some(item_event_error) = rod_error.as_item_event_error() {
+        assert!(item_event_error.is_not_a_bait());
+        assert_eq!(item_event_error.get_type_id(), Some(UNIQUE_ROD_ID));
+    } else {
+        panic!("{:?}", rod_error);
+    }
+
+    let bait_data = config.get_item_data(BAIT_ID).unwrap();
+    let bait = sp
+        .item_service()
+        .create_and_save_item_with_count(bait_data, &user, 2)
+        .unwrap();
+    let used_bait = sp
+        .item_service()
+        .use_as_bait(bait.clone())
+        .unwrap()
+        .unwrap();
+    assert_eq!(used_bait.get_count(), Some(1));
+
+    let consumed = sp.item_service().use_as_bait(used_bait).unwrap();
+    assert!(consumed.is_none());
+    assert!(sp.item_repository().find(bait.id).unwrap().is_none());
+}
@@ -0,0 +1,437 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::location_data::SECONDS_PER_YEAR;
+use crate::data::species_data::SpeciesData;
+use crate::dto::leaderboard::LeaderboardPage;
+use crate::enums::leaderboard_kind::LeaderboardKind;
+use crate::enums::leaderboard_window::LeaderboardWindow;
+use crate::game::clock::FixedClock;
+use crate::game::game_time::GameTime;
+use crate::game::service_provider::ServiceProviderInterface;
+use crate::models::catch_event::{CatchEvent, NewCatchEvent};
+use crate::models::sale_event::NewSaleEvent;
+use crate::models::specimen::{NewSpecimen, Specimen};
+use crate::models::user::User;
+use crate::tests::mock::{mock_service_provider, mock_service_provider_with_clock};
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const SALMON_ID: i32 = 1;
+const TROUT_ID: i32 = 2;
+
+fn mock_config() -> Arc<dyn ConfigInterface> {
+    let species = |name: &str| SpeciesData {
+        name: name.to_string(),
+        min_size_baby_mm: 40,
+        max_size_baby_mm: 50,
+        min_size_adult_mm: 400,
+        max_size_adult_mm: 500,
+        min_weight_baby_g: 20,
+        max_weight_baby_g: 30,
+        min_weight_adult_g: 220,
+        max_weight_adult_g: 350,
+        min_lifespan_days: 480,
+        max_lifespan_days: 720,
+        ..Default::default()
+    };
+    let species_data_map =
+        HashMap::from([(SALMON_ID, species("salmon")), (TROUT_ID, species("trout"))]);
+
+    Config::builder().species(species_data_map).build().unwrap()
+}
+
+fn catch_event(
+    sp: &Arc<dyn ServiceProviderInterface>,
+    user: &User,
+    species_id: i32,
+    size_ratio: f32,
+    caught_at: DateTime<Utc>,
+) -> CatchEvent {
+    let new_catch_event = NewCatchEvent {
+        user_id: user.id,
+        species_id,
+        specimen_id: 1,
+        total_size_ratio: size_ratio,
+        size_mm: size_ratio * 100.0,
+        weight_g: size_ratio * 10.0,
+        caught_at,
+    };
+    sp.catch_event_repository().create(new_catch_event).unwrap()
+}
+
+fn catch(
+    sp: &Arc<dyn ServiceProviderInterface>,
+    user: &User,
+    species_id: i32,
+    size_ratio: f32,
+) -> Specimen {
+    let new_fish = NewSpecimen {
+        user_id: user.id,
+        species_id,
+        size_baby_ratio: size_ratio,
+        size_adult_ratio: size_ratio,
+        lifespan_days_ratio: 0.5,
+        catch_age: 1.0,
+        catch_seed: None,
+    };
+    let fish = sp.specimen_repository().create(new_fish).unwrap();
+    sp.fishing_history_service().register_catch(&fish).unwrap();
+    fish
+}
+
+/// Three users: 1 catches 3 salmon, 2 catches a salmon and a trout and sells both, 3 catches nothing
+fn populate(sp: &Arc<dyn ServiceProviderInterface>) -> (User, User, User) {
+    let user1 = sp.user_service().create_and_save_user(1).unwrap();
+    let user2 = sp.user_service().create_and_save_user(2).unwrap();
+    let user3 = sp.user_service().create_and_save_user(3).unwrap();
+
+    catch(sp, &user1, SALMON_ID, 0.25);
+    catch(sp, &user1, SALMON_ID, 0.5);
+    catch(sp, &user1, SALMON_ID, 0.75);
+
+    let salmon = catch(sp, &user2, SALMON_ID, 1.0);
+    let trout = catch(sp, &user2, TROUT_ID, 0.5);
+    sp.fishing_history_service()
+        .register_sell(&salmon, Utc::now())
+        .unwrap();
+    sp.fishing_history_service()
+        .register_sell(&trout, Utc::now())
+        .unwrap();
+    sp.specimen_repository()
+        .delete_many(&user2, &[salmon.id, trout.id])
+        .unwrap();
+
+    sp.user_repository().add_credits(user3.id, 500).unwrap();
+    sp.user_repository().add_credits(user1.id, 100).unwrap();
+
+    (user1, user2, user3)
+}
+
+fn ranked_external_ids(
+    sp: &Arc<dyn ServiceProviderInterface>,
+    kind: LeaderboardKind,
+    window: LeaderboardWindow,
+) -> Vec<(i64, i64)> {
+    sp.leaderboard_service()
+        .get_leaderboard(kind, window, LeaderboardPage::default())
+        .unwrap()
+        .entries
+        .into_iter()
+        .map(|entry| (entry.external_id, entry.value))
+        .collect()
+}
+
+#[test]
+fn test_get_leaderboard() {
+    let sp = mock_service_provider(mock_config());
+    populate(&sp);
+
+    let all_time = LeaderboardWindow::AllTime;
+    assert_eq!(
+        ranked_external_ids(&sp, LeaderboardKind::MostCatches, all_time),
+        vec![(1, 3), (2, 2)]
+    );
+    assert_eq!(
+        ranked_external_ids(&sp, LeaderboardKind::MostSpecies, all_time),
+        vec![(2, 2), (1, 1)]
+    );
+    assert_eq!(
+        ranked_external_ids(&sp, LeaderboardKind::MostCredits, all_time),
+        vec![(3, 500), (1, 100), (2, 0)]
+    );
+    assert_eq!(
+        ranked_external_ids(&sp, LeaderboardKind::MostSold, all_time),
+        vec![(2, 2)]
+    );
+
+    // Sold specimens still count as catches within a window
+    assert_eq!(
+        ranked_external_ids(
+            &sp,
+            LeaderboardKind::MostCatches,
+            LeaderboardWindow::ThisWeek
+        ),
+        vec![(1, 3), (2, 2)]
+    );
+    assert_eq!(
+        ranked_external_ids(
+            &sp,
+            LeaderboardKind::MostSold,
+            LeaderboardWindow::ThisSeason
+        ),
+        vec![(2, 2)]
+    );
+}
+
+#[test]
+fn test_get_leaderboard_pagination() {
+    let sp = mock_service_provider(mock_config());
+    populate(&sp);
+
+    let page = |page| {
+        sp.leaderboard_service()
+            .get_leaderboard(
+                LeaderboardKind::MostCredits,
+                LeaderboardWindow::AllTime,
+                LeaderboardPage::new(page, 2),
+            )
+            .unwrap()
+    };
+
+    let first_page = page(0);
+    assert_eq!(first_page.total_entries, 3);
+    assert_eq!(first_page.total_pages(2), 2);
+    assert_eq!(first_page.entries.len(), 2);
+    assert_eq!(first_page.entries[0].rank, 1);
+    assert_eq!(first_page.entries[1].rank, 2);
+
+    let second_page = page(1);
+    assert_eq!(second_page.total_entries, 3);
+    assert_eq!(second_page.entries.len(), 1);
+    assert_eq!(second_page.entries[0].rank, 3);
+    assert_eq!(second_page.entries[0].external_id, 2);
+
+    assert!(page(2).entries.is_empty());
+}
+
+#[test]
+fn test_get_leaderboard_window_excludes_older_activity() {
+    let clock = Arc::new(FixedClock::new(Utc::now()));
+    let sp = mock_service_provider_with_clock(mock_config(), clock.clone());
+    populate(&sp);
+    // Far enough ahead that the current week and season started after the activity
+    clock.advance(TimeDelta::days(200));
+
+    for kind in [
+        LeaderboardKind::MostCatches,
+        LeaderboardKind::MostSpecies,
+        LeaderboardKind::MostSold,
+    ] {
+        for window in [LeaderboardWindow::ThisWeek, LeaderboardWindow::ThisSeason] {
+            let leaderboard = sp
+                .leaderboard_service()
+                .get_leaderboard(kind, window, LeaderboardPage::default())
+                .unwrap();
+            assert_eq!(leaderboard.total_entries, 0, "{kind:?} {window:?}");
+            assert!(leaderboard.entries.is_empty());
+        }
+        let all_time = sp
+            .leaderboard_service()
+            .get_leaderboard(kind, LeaderboardWindow::AllTime, LeaderboardPage::default())
+            .unwrap();
+        assert!(all_time.total_entries > 0, "{kind:?}");
+    }
+}
+
+#[test]
+fn test_get_leaderboard_window_counts_activity_within_it() {
+    // Wednesday, 2025-03-19 12:00 UTC
+    let now = Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap();
+    let last_week = now - TimeDelta::days(7);
+    let sp = mock_service_provider_with_clock(mock_config(), Arc::new(FixedClock::new(now)));
+    let user1 = sp.user_service().create_and_save_user(1).unwrap();
+    let user2 = sp.user_service().create_and_save_user(2).unwrap();
+
+    // User 1 caught and sold more in total, but user 2 was more active this week
+    for (user, time, count) in [(&user1, last_week, 3), (&user1, now, 1), (&user2, now, 2)] {
+        for _ in 0..count {
+            catch_event(&sp, user, SALMON_ID, 0.5, time);
+            sp.sale_event_repository()
+                .create(NewSaleEvent {
+                    user_id: user.id,
+                    species_id: SALMON_ID,
+                    specimen_id: 1,
+                    sold_at: time,
+                })
+                .unwrap();
+        }
+    }
+
+    let this_week = LeaderboardWindow::ThisWeek;
+    assert_eq!(
+        ranked_external_ids(&sp, LeaderboardKind::MostCatches, this_week),
+        vec![(2, 2), (1, 1)]
+    );
+    assert_eq!(
+        ranked_external_ids(&sp, LeaderboardKind::MostSold, this_week),
+        vec![(2, 2), (1, 1)]
+    );
+}
+
+#[test]
+fn test_get_leaderboard_window_species() {
+    // Wednesday, 2025-03-19 12:00 UTC
+    let now = Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap();
+    let last_week = now - TimeDelta::days(7);
+    let sp = mock_service_provider_with_clock(mock_config(), Arc::new(FixedClock::new(now)));
+    let user1 = sp.user_service().create_and_save_user(1).unwrap();
+    let user2 = sp.user_service().create_and_save_user(2).unwrap();
+
+    // Species caught before the window count again when they are caught within it
+    for (user, species_id, time) in [
+        (&user1, SALMON_ID, last_week),
+        (&user1, TROUT_ID, last_week),
+        (&user1, SALMON_ID, now),
+        (&user1, TROUT_ID, now),
+        (&user2, SALMON_ID, now),
+        (&user2, SALMON_ID, now),
+    ] {
+        catch_event(&sp, user, species_id, 0.5, time);
+    }
+
+    assert_eq!(
+        ranked_external_ids(
+            &sp,
+            LeaderboardKind::MostSpecies,
+            LeaderboardWindow::ThisWeek
+        ),
+        vec![(1, 2), (2, 1)]
+    );
+}
+
+#[test]
+fn test_get_leaderboard_credits_window() {
+    let sp = mock_service_provider(mock_config());
+    populate(&sp);
+
+    for window in [LeaderboardWindow::ThisWeek, LeaderboardWindow::ThisSeason] {
+        let error = sp
+            .leaderboard_service()
+            .get_leaderboard(
+                LeaderboardKind::MostCredits,
+                window,
+                LeaderboardPage::default(),
+            )
+            .unwrap_err();
+        let input_error = error.as_input_error().unwrap();
+        assert!(input_error.is_unsupported_leaderboard_window());
+        assert_eq!(
+            input_error.get_leaderboard_kind(),
+            Some(LeaderboardKind::MostCredits)
+        );
+        assert_eq!(input_error.get_leaderboard_window(), Some(window));
+    }
+}
+
+#[test]
+fn test_get_species_leaderboard() {
+    let sp = mock_service_provider(mock_config());
+    let (user1, user2, _) = populate(&sp);
+
+    let leaderboard = sp
+        .leaderboard_service()
+        .get_species_leaderboard(
+            SALMON_ID,
+            LeaderboardWindow::AllTime,
+            LeaderboardPage::default(),
+        )
+        .unwrap();
+    assert_eq!(leaderboard.total_entries, 2);
+    assert_eq!(leaderboard.entries[0].rank, 1);
+    assert_eq!(leaderboard.entries[0].user_id, user2.id);
+    assert_eq!(leaderboard.entries[0].species_id, SALMON_ID);
+    assert_eq!(leaderboard.entries[0].record.size_mm, 500.0);
+    assert_eq!(leaderboard.entries[1].rank, 2);
+    assert_eq!(leaderboard.entries[1].user_id, user1.id);
+    assert_eq!(leaderboard.entries[1].record.size_mm, 475.0);
+
+    let trout_leaderboard = sp
+        .leaderboard_service()
+        .get_species_leaderboard(
+            TROUT_ID,
+            LeaderboardWindow::ThisWeek,
+            LeaderboardPage::new(0, 1),
+        )
+        .unwrap();
+    assert_eq!(trout_leaderboard.total_entries, 1);
+    assert_eq!(trout_leaderboard.entries[0].user_id, user2.id);
+
+    let error = sp
+        .leaderboard_service()
+        .get_species_leaderboard(3, LeaderboardWindow::AllTime, LeaderboardPage::default())
+        .unwrap_err();
+    assert!(error.is_not_found());
+}
+
+#[test]
+fn test_get_species_leaderboard_window() {
+    // Wednesday, 2025-03-19 12:00 UTC
+    let now = Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap();
+    let last_week = now - TimeDelta::days(7);
+    let sp = mock_service_provider_with_clock(mock_config(), Arc::new(FixedClock::new(now)));
+    let user1 = sp.user_service().create_and_save_user(1).unwrap();
+    let user2 = sp.user_service().create_and_save_user(2).unwrap();
+    let user3 = sp.user_service().create_and_save_user(3).unwrap();
+
+    // User 1's all-time record is older than the window, their smaller catch this week still counts
+    catch_event(&sp, &user1, SALMON_ID, 0.9, last_week);
+    let user1_catch = catch_event(&sp, &user1, SALMON_ID, 0.3, now);
+    let user2_catch = catch_event(&sp, &user2, SALMON_ID, 0.5, now);
+    catch_event(&sp, &user2, SALMON_ID, 0.4, now);
+    catch_event(&sp, &user3, SALMON_ID, 0.7, last_week);
+    catch_event(&sp, &user3, TROUT_ID, 0.8, now);
+
+    let leaderboard = sp
+        .leaderboard_service()
+        .get_species_leaderboard(
+            SALMON_ID,
+            LeaderboardWindow::ThisWeek,
+            LeaderboardPage::default(),
+        )
+        .unwrap();
+    assert_eq!(leaderboard.total_entries, 2);
+    assert_eq!(leaderboard.entries[0].user_id, user2.id);
+    assert_eq!(leaderboard.entries[0].record, user2_catch.get_record());
+    assert_eq!(leaderboard.entries[1].rank, 2);
+    assert_eq!(leaderboard.entries[1].user_id, user1.id);
+    assert_eq!(leaderboard.entries[1].record, user1_catch.get_record());
+
+    let second_page = sp
+        .leaderboard_service()
+        .get_species_leaderboard(
+            SALMON_ID,
+            LeaderboardWindow::ThisWeek,
+            LeaderboardPage::new(1, 1),
+        )
+        .unwrap();
+    assert_eq!(second_page.total_entries, 2);
+    assert_eq!(second_page.entries.len(), 1);
+    assert_eq!(second_page.entries[0].rank, 2);
+    assert_eq!(second_page.entries[0].external_id, 1);
+}
+
+#[test]
+fn test_window_start() {
+    // Wednesday, 2025-03-19 12:00 UTC
+    let now = Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap();
+    let clock = Arc::new(FixedClock::new(now));
+    let game_time = GameTime::new(clock, Utc.timestamp_opt(0, 0).unwrap(), 1.0);
+
+    assert_eq!(
+        LeaderboardWindow::AllTime.get_start(&game_time).unwrap(),
+        None
+    );
+    assert_eq!(
+        LeaderboardWindow::ThisWeek.get_start(&game_time).unwrap(),
+        Some(Utc.with_ymd_and_hms(2025, 3, 17, 0, 0, 0).unwrap())
+    );
+
+    // Spring starts at the March equinox (2025-03-20 09:01 UTC), so it's still winter
+    let winter_start = LeaderboardWindow::ThisSeason
+        .get_start(&game_time)
+        .unwrap()
+        .unwrap();
+
+    let march_equinox = Utc.with_ymd_and_hms(2025, 3, 20, 9, 1, 0).unwrap();
+    let clock = Arc::new(FixedClock::new(march_equinox + TimeDelta::hours(12)));
+    let game_time = GameTime::new(clock, Utc.timestamp_opt(0, 0).unwrap(), 1.0);
+    let spring_start = LeaderboardWindow::ThisSeason
+        .get_start(&game_time)
+        .unwrap()
+        .unwrap();
+    assert!((spring_start - march_equinox).abs() < TimeDelta::hours(1));
+
+    // Seasons are a quarter of a year long
+    let quarter_year = TimeDelta::seconds((SECONDS_PER_YEAR / 4.0) as i64);
+    assert!((spring_start - winter_start - quarter_year).abs() < TimeDelta::seconds(1));
+}
@@ -1,4 +1,6 @@
+use crate::models::specimen::Specimen;
 use crate::tests::mock::mock_default_service_provider;
+use std::thread;
 
 #[test]
 fn test_create_and_save_pond() {
@@ -9,4 +11,216 @@ fn test_create_and_save_pond() {
 
     let found_pond = sp.pond_repository().find(pond.id).unwrap().unwrap();
     assert_eq!(pond, found_pond);
+
+    let error = sp
+        .pond_service()
+        .create_and_save_pond(&user, 0)
+        .unwrap_err();
+    let input_error = error.as_input_error().unwrap();
+    assert!(input_error.is_invalid_pond_capacity());
+    assert_eq!(input_error.get_capacity(), Some(0));
+}
+
+#[test]
+fn test_add_and_remove_specimen() {
+    let sp = mock_default_service_provider();
+    let species = sp.species_service().get_species_data(1).unwrap();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let pond = sp.pond_service().create_and_save_pond(&user, 2).unwrap();
+    let other_pond = sp.pond_service().create_and_save_pond(&user, 1).unwrap();
+
+    let specimen1 = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
+        .unwrap();
+    let specimen2 = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
+        .unwrap();
+    let specimen3 = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
+        .unwrap();
+
+    let specimen1 = sp
+        .pond_service()
+        .add_specimen(&user, &pond, specimen1)
+        .unwrap();
+    assert_eq!(specimen1.pond_id, Some(pond.id));
+    let specimen2 = sp
+        .pond_service()
+        .add_specimen(&user, &pond, specimen2)
+        .unwrap();
+
+    // Adding a specimen that is already in the pond changes nothing
+    let specimen2 = sp
+        .pond_service()
+        .add_specimen(&user, &pond, specimen2)
+        .unwrap();
+    assert_eq!(sp.specimen_repository().count_by_pond(pond.id).unwrap(), 2);
+
+    let error = sp
+        .pond_service()
+        .add_specimen(&user, &pond, specimen3.clone())
+        .unwrap_err();
+    if let Some(resource_error) = error.as_resource_error() {
+        assert!(resource_error.is_pond_full());
+        assert_eq!(resource_error.get_pond_id(), Some(pond.id));
+        assert_eq!(resource_error.get_capacity(), Some(2));
+    } else {
+        panic!("{:?}", error);
+    }
+
+    // Moving a specimen into another pond
+    let specimen2 = sp
+        .pond_service()
+        .add_specimen(&user, &other_pond, specimen2)
+        .unwrap();
+    assert_eq!(specimen2.pond_id, Some(other_pond.id));
+    let contents = sp.pond_service().get_pond_specimens(&pond).unwrap();
+    assert_eq!(contents, vec![specimen1.clone()]);
+
+    let specimen1 = sp.pond_service().remove_specimen(&user, specimen1).unwrap();
+    assert_eq!(specimen1.pond_id, None);
+    let found_specimen = sp
+        .specimen_repository()
+        .find(specimen1.id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(found_specimen.pond_id, None);
+    assert!(sp
+        .pond_service()
+        .get_pond_specimens(&pond)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_add_specimen_errors() {
+    let sp = mock_default_service_provider();
+    let species = sp.species_service().get_species_data(1).unwrap();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let other_user = sp.user_service().create_and_save_user(1338).unwrap();
+    let pond = sp.pond_service().create_and_save_pond(&user, 2).unwrap();
+    let other_pond = sp
+        .pond_service()
+        .create_and_save_pond(&other_user, 2)
+        .unwrap();
+
+    let specimen = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
+        .unwrap();
+    let other_specimen = sp
+        .specimen_service()
+        .generate_and_save_specimen(&other_user, species, sp.random_source().next_seed())
+        .unwrap();
+
+    let error = sp
+        .pond_service()
+        .add_specimen(&user, &other_pond, specimen.clone())
+        .unwrap_err();
+    assert!(error.as_resource_error().unwrap().is_pond_not_owned());
+
+    let error = sp
+        .pond_service()
+        .add_specimen(&user, &pond, other_specimen.clone())
+        .unwrap_err();
+    assert!(error.as_resource_error().unwrap().is_specimen_not_owned());
+
+    // The owner of the given specimen is not trusted
+    let forged_specimen = Specimen {
+        user_id: user.id,
+        ..other_specimen.clone()
+    };
+    let error = sp
+        .pond_service()
+        .add_specimen(&user, &pond, forged_specimen)
+        .unwrap_err();
+    assert!(error.as_resource_error().unwrap().is_specimen_not_owned());
+    let found_specimen = sp.specimen_repository().find(other_specimen.id).unwrap();
+    assert_eq!(found_specimen, Some(other_specimen));
+
+    let deleted_pond = sp.pond_service().create_and_save_pond(&user, 2).unwrap();
+    sp.pond_repository().delete(deleted_pond.clone()).unwrap();
+    let error = sp
+        .pond_service()
+        .add_specimen(&user, &deleted_pond, specimen.clone())
+        .unwrap_err();
+    assert!(error.as_resource_error().unwrap().is_pond_not_found());
+
+    sp.specimen_repository().delete(specimen.clone()).unwrap();
+    let error = sp
+        .pond_service()
+        .remove_specimen(&user, specimen.clone())
+        .unwrap_err();
+    assert!(error.as_resource_error().unwrap().is_specimen_not_found());
+}
+
+#[test]
+fn test_delete_pond_releases_specimens() {
+    let sp = mock_default_service_provider();
+    let species = sp.species_service().get_species_data(1).unwrap();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let pond = sp.pond_service().create_and_save_pond(&user, 2).unwrap();
+    let specimen = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species, sp.random_source().next_seed())
+        .unwrap();
+    let specimen = sp
+        .pond_service()
+        .add_specimen(&user, &pond, specimen)
+        .unwrap();
+
+    sp.pond_repository().delete(pond).unwrap();
+    let found_specimen = sp.specimen_repository().find(specimen.id).unwrap().unwrap();
+    assert_eq!(found_specimen.pond_id, None);
+}
+
+#[test]
+fn test_concurrent_add_specimen() {
+    let sp = mock_default_service_provider();
+    let species = sp.species_service().get_species_data(1).unwrap();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let pond = sp.pond_service().create_and_save_pond(&user, 1).unwrap();
+    let specimens: Vec<Specimen> = (0..4)
+        .map(|_| {
+            sp.specimen_service()
+                .generate_and_save_specimen(&user, species.clone(), sp.random_source().next_seed())
+                .unwrap()
+        })
+        .collect();
+
+    // Only one of the additions fits, no matter how they interleave
+    let results: Vec<Result<(), bool>> = thread::scope(|scope| {
+        let handles: Vec<_> = specimens
+            .into_iter()
+            .map(|specimen| {
+                let (sp, user, pond) = (&sp, &user, &pond);
+                scope.spawn(move || {
+                    // Errors can't be sent between threads, only whether the pond was full is
+                    sp.pond_service()
+                        .add_specimen(user, pond, specimen)
+                        .map(|_| ())
+                        .map_err(|error| {
+                            error
+                                .as_resource_error()
+                                .is_some_and(|error| error.is_pond_full())
+                        })
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+    assert!(results.iter().all(|result| *result != Err(false)));
+    assert_eq!(sp.specimen_repository().count_by_pond(pond.id).unwrap(), 1);
 }
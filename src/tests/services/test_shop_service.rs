@@ -0,0 +1,412 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::item_data::ItemData;
+use crate::data::species_data::SpeciesData;
+use crate::models::item::attributes_container::ItemAttributesContainer;
+use crate::models::item::properties_container::{
+    ItemPropertiesContainer, ItemPropertiesContainerInterface,
+};
+use crate::models::specimen::Specimen;
+use crate::models::user::User;
+use crate::tests::mock::mock_service_provider;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
+
+const SPECIES_ID: i32 = 1;
+const OTHER_SPECIES_ID: i32 = 2;
+const ROD_ID: i32 = 1;
+const BAIT_ID: i32 = 2;
+const TROPHY_ID: i32 = 3;
+
+fn mock_config() -> Arc<dyn ConfigInterface> {
+    let species = SpeciesData {
+        name: "Salmon".to_string(),
+        base_value: 100,
+        min_size_baby_mm: 10,
+        max_size_baby_mm: 20,
+        min_size_adult_mm: 50,
+        max_size_adult_mm: 80,
+        min_lifespan_days: 300,
+        max_lifespan_days: 400,
+        ..Default::default()
+    };
+
+    let other_species = SpeciesData {
+        name: "Trout".to_string(),
+        base_value: 10,
+        min_size_baby_mm: 10,
+        max_size_baby_mm: 20,
+        min_size_adult_mm: 30,
+        max_size_adult_mm: 40,
+        min_lifespan_days: 300,
+        max_lifespan_days: 400,
+        ..Default::default()
+    };
+
+    let species_data_map =
+        HashMap::from([(SPECIES_ID, species), (OTHER_SPECIES_ID, other_species)]);
+
+    let rod = ItemData {
+        name: "Rod".to_string(),
+        attributes: ItemAttributesContainer::new()
+            .with_rod(1)
+            .with_purchasable(100),
+        default_properties: ItemPropertiesContainer::new().with_usage(0),
+        ..Default::default()
+    };
+
+    let bait = ItemData {
+        name: "Bait".to_string(),
+        attributes: ItemAttributesContainer::new()
+            .with_bait(1)
+            .with_purchasable(5),
+        default_properties: ItemPropertiesContainer::new().with_stackable(1),
+        ..Default::default()
+    };
+
+    let trophy = ItemData {
+        name: "Trophy".to_string(),
+        ..Default::default()
+    };
+
+    let item_data_map = HashMap::from([(ROD_ID, rod), (BAIT_ID, bait), (TROPHY_ID, trophy)]);
+
+    Config::builder()
+        .species(species_data_map)
+        .items(item_data_map)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_sell_specimens() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+
+    let specimen1 = sp
+        .specimen_service()
+        .process_catch(&user, species.clone(), sp.random_source().next_seed())
+        .unwrap();
+    let specimen2 = sp
+        .specimen_service()
+        .process_catch(&user, species.clone(), sp.random_source().next_seed())
+        .unwrap();
+    sp.fishing_history_service()
+        .register_catch(&specimen1)
+        .unwrap();
+    sp.fishing_history_service()
+        .register_catch(&specimen2)
+        .unwrap();
+
+    let price1 = sp.shop_service().get_sell_price(&specimen1).unwrap();
+    let price2 = sp.shop_service().get_sell_price(&specimen2).unwrap();
+    assert!(price1 >= 100);
+    assert!(price2 >= 100);
+
+    let result = sp
+        .shop_service()
+        .sell_specimens(&user, vec![specimen1.clone(), specimen2.clone()])
+        .unwrap();
+    assert_eq!(result.specimens.len(), 2);
+    assert_eq!(result.credits, price1 + price2);
+    assert_eq!(result.user.credits, price1 + price2);
+
+    let history_entry = sp
+        .fishing_history_entry_repository()
+        .find_by_user_and_species_id(user.id, SPECIES_ID)
+        .unwrap()
+        .unwrap();
+    assert_eq!(history_entry.sold_count, 2);
+    assert!(sp
+        .specimen_repository()
+        .find_by_user(&user)
+        .unwrap()
+        .is_empty());
+
+    // Already sold specimens are not paid for twice
+    let error = sp
+        .shop_service()
+        .sell_specimens(&result.user, vec![specimen1.clone()])
+        .unwrap_err();
+    assert!(error.as_resource_error().unwrap().is_specimen_not_found());
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, price1 + price2);
+}
+
+#[test]
+fn test_sell_specimens_not_owned() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let other_user = sp.user_service().create_and_save_user(1338).unwrap();
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+
+    let specimen = sp
+        .specimen_service()
+        .process_catch(&other_user, species, sp.random_source().next_seed())
+        .unwrap();
+    let error = sp
+        .shop_service()
+        .sell_specimens(&user, vec![specimen.clone()])
+        .unwrap_err();
+    if let Some(resource_error) = error.as_resource_error() {
+        assert!(resource_error.is_specimen_not_owned());
+        assert_eq!(resource_error.get_specimen_id(), Some(specimen.id));
+        assert_eq!(resource_error.get_external_id(), Some(user.external_id));
+    } else {
+        panic!("{:?}", error);
+    }
+
+    // Nothing was sold
+    let found_specimen = sp.specimen_repository().find(specimen.id).unwrap();
+    assert_eq!(found_specimen, Some(specimen.clone()));
+
+    // A forged owner is not trusted, and the sale is rolled back as a whole
+    let own_specimen = sp
+        .specimen_service()
+        .process_catch(&user, config.get_species_data(SPECIES_ID).unwrap(), 1)
+        .unwrap();
+    let forged_specimen = Specimen {
+        user_id: user.id,
+        ..specimen.clone()
+    };
+    let error = sp
+        .shop_service()
+        .sell_specimens(&user, vec![own_specimen.clone(), forged_specimen])
+        .unwrap_err();
+    assert!(error.as_resource_error().unwrap().is_specimen_not_owned());
+    let found_specimen = sp.specimen_repository().find(own_specimen.id).unwrap();
+    assert_eq!(found_specimen, Some(own_specimen));
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, 0);
+
+    let dummy_user = User {
+        id: -1,
+        external_id: 1339,
+        ..Default::default()
+    };
+    let error = sp
+        .shop_service()
+        .sell_specimens(&dummy_user, vec![])
+        .unwrap_err();
+    if let Some(resource_error) = error.as_resource_error() {
+        assert!(resource_error.is_user_not_found());
+        assert_eq!(resource_error.get_external_id(), Some(1339));
+    } else {
+        panic!("{:?}", error);
+    }
+}
+
+#[test]
+fn test_sell_species() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+    let other_species = config.get_species_data(OTHER_SPECIES_ID).unwrap();
+
+    for species in [species.clone(), species, other_species] {
+        let specimen = sp
+            .specimen_service()
+            .process_catch(&user, species, sp.random_source().next_seed())
+            .unwrap();
+        sp.fishing_history_service()
+            .register_catch(&specimen)
+            .unwrap();
+    }
+
+    let result = sp.shop_service().sell_species(&user, SPECIES_ID).unwrap();
+    assert_eq!(result.specimens.len(), 2);
+    assert!(result
+        .specimens
+        .iter()
+        .all(|specimen| specimen.species_id == SPECIES_ID));
+
+    let remaining_specimens = sp.specimen_repository().find_by_user(&user).unwrap();
+    assert_eq!(remaining_specimens.len(), 1);
+    assert_eq!(remaining_specimens[0].species_id, OTHER_SPECIES_ID);
+}
+
+#[test]
+fn test_concurrent_sell_species() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+
+    for _ in 0..3 {
+        let specimen = sp
+            .specimen_service()
+            .process_catch(&user, species.clone(), sp.random_source().next_seed())
+            .unwrap();
+        sp.fishing_history_service()
+            .register_catch(&specimen)
+            .unwrap();
+    }
+
+    // Specimens sold by the other sale are skipped instead of failing the whole sale
+    let results: Vec<(usize, i64)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let (sp, user) = (&sp, &user);
+                scope.spawn(move || {
+                    let result = sp.shop_service().sell_species(user, SPECIES_ID).unwrap();
+                    (result.specimens.len(), result.credits)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    let sold_count: usize = results.iter().map(|(count, _)| count).sum();
+    let credits: i64 = results.iter().map(|(_, credits)| credits).sum();
+    assert_eq!(sold_count, 3);
+    assert!(sp
+        .specimen_repository()
+        .find_by_user(&user)
+        .unwrap()
+        .is_empty());
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, credits);
+}
+
+#[test]
+fn test_sell_below_size_ratio() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+
+    let mut size_ratios = Vec::new();
+    for _ in 0..5 {
+        let specimen = sp
+            .specimen_service()
+            .process_catch(&user, species.clone(), sp.random_source().next_seed())
+            .unwrap();
+        sp.fishing_history_service()
+            .register_catch(&specimen)
+            .unwrap();
+        let size_ratio = specimen
+            .get_total_size_ratio(config.clone(), &sp.game_time())
+            .unwrap();
+        size_ratios.push(size_ratio);
+    }
+
+    let median = {
+        let mut sorted = size_ratios.clone();
+        sorted.sort_by(f32::total_cmp);
+        sorted[2]
+    };
+
+    let result = sp
+        .shop_service()
+        .sell_below_size_ratio(&user, median)
+        .unwrap();
+    let expected_count = size_ratios.iter().filter(|ratio| **ratio < median).count();
+    assert_eq!(result.specimens.len(), expected_count);
+
+    let remaining_specimens = sp.specimen_repository().find_by_user(&user).unwrap();
+    assert_eq!(remaining_specimens.len(), 5 - expected_count);
+}
+
+#[test]
+fn test_get_purchasable_items() {
+    let sp = mock_service_provider(mock_config());
+
+    let items = sp.shop_service().get_purchasable_items();
+    let item_ids: Vec<i32> = items.iter().map(|item| item.id).collect();
+    assert_eq!(item_ids, vec![ROD_ID, BAIT_ID]);
+}
+
+#[test]
+fn test_purchase_item() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let user = sp.user_repository().add_credits(user.id, 130).unwrap();
+
+    let rod_data = config.get_item_data(ROD_ID).unwrap();
+    let result = sp
+        .shop_service()
+        .purchase_item(&user, rod_data.clone(), 3)
+        .unwrap();
+    assert_eq!(result.cost, 100);
+    assert_eq!(result.user.credits, 30);
+    assert_eq!(result.item.type_id, ROD_ID);
+    assert_eq!(result.item.user_id, user.id);
+
+    let bait_data = config.get_item_data(BAIT_ID).unwrap();
+    let result = sp
+        .shop_service()
+        .purchase_item(&user, bait_data.clone(), 2)
+        .unwrap();
+    assert_eq!(result.cost, 10);
+    assert_eq!(result.user.credits, 20);
+    assert_eq!(result.item.get_count(), Some(2));
+
+    let error = sp
+        .shop_service()
+        .purchase_item(&user, bait_data, 5)
+        .unwrap_err();
+    if let Some(resource_error) = error.as_resource_error() {
+        assert!(resource_error.is_insufficient_credits());
+        assert_eq!(resource_error.get_cost(), Some(25));
+        assert_eq!(resource_error.get_credits(), Some(20));
+        assert_eq!(resource_error.get_external_id(), Some(user.external_id));
+    } else {
+        panic!("{:?}", error);
+    }
+
+    let trophy_data = config.get_item_data(TROPHY_ID).unwrap();
+    let error = sp
+        .shop_service()
+        .purchase_item(&user, trophy_data, 1)
+        .unwrap_err();
+    if let Some(resource_error) = error.as_resource_error() {
+        assert!(resource_error.is_item_not_purchasable());
+        assert_eq!(resource_error.get_item_type_id(), Some(TROPHY_ID));
+    } else {
+        panic!("{:?}", error);
+    }
+
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, 20);
+}
+
+#[test]
+fn test_purchase_item_max_count_exceeded() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let user = sp.user_repository().add_credits(user.id, 200).unwrap();
+
+    let rod_data = config.get_item_data(ROD_ID).unwrap();
+    sp.shop_service()
+        .purchase_item(&user, rod_data.clone(), 1)
+        .unwrap();
+
+    let error = sp
+        .shop_service()
+        .purchase_item(&user, rod_data, 1)
+        .unwrap_err();
+    if let Some(resource_error) = error.as_resource_error() {
+        assert!(resource_error.is_item_max_count_exceeded());
+        assert_eq!(resource_error.get_item_type_id(), Some(ROD_ID));
+    } else {
+        panic!("{:?}", error);
+    }
+
+    // The user has not paid for the item they did not receive
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, 100);
+    let rods = sp
+        .item_repository()
+        .find_by_type_and_user(ROD_ID, user.id)
+        .unwrap();
+    assert_eq!(rods.len(), 1);
+}
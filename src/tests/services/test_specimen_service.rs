@@ -23,7 +23,7 @@ fn test_generate_and_save_specimen() {
     let user = sp.user_service().create_and_save_user(1337).unwrap();
     let specimen = sp
         .specimen_service()
-        .generate_and_save_specimen(&user, species)
+        .generate_and_save_specimen(&user, species, sp.random_source().next_seed())
         .unwrap();
 
     let found_specimen = sp.specimen_repository().find(specimen.id).unwrap().unwrap();
@@ -36,9 +36,55 @@ fn test_process_catch() {
     let species = sp.species_service().get_species_data(1).unwrap();
 
     let user = sp.user_service().create_and_save_user(1337).unwrap();
-    let specimen = sp.specimen_service().process_catch(&user, species).unwrap();
+    let specimen = sp
+        .specimen_service()
+        .process_catch(&user, species, sp.random_source().next_seed())
+        .unwrap();
 
     let user_specimens = sp.specimen_repository().find_by_user(&user).unwrap();
     let first_specimen = user_specimens.first().unwrap();
     assert_eq!(*first_specimen, specimen);
 }
+
+#[test]
+fn test_generate_with_seed() {
+    let sp = mock_service_provider(mock_config());
+    let species = sp.species_service().get_species_data(1).unwrap();
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let specimen = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), 42)
+        .unwrap();
+    let same_seed_specimen = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), 42)
+        .unwrap();
+    let other_seed_specimen = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), 43)
+        .unwrap();
+
+    assert_eq!(specimen.get_catch_seed(), Some(42));
+    let found_specimen = sp.specimen_repository().find(specimen.id).unwrap().unwrap();
+    assert_eq!(found_specimen.get_catch_seed(), Some(42));
+
+    // Seeds which don't fit into an i64 are recorded as well
+    let large_seed_specimen = sp
+        .specimen_service()
+        .generate_and_save_specimen(&user, species.clone(), u64::MAX)
+        .unwrap();
+    assert_eq!(large_seed_specimen.get_catch_seed(), Some(u64::MAX));
+
+    assert_eq!(specimen.catch_age, same_seed_specimen.catch_age);
+    assert_eq!(specimen.size_baby_ratio, same_seed_specimen.size_baby_ratio);
+    assert_eq!(
+        specimen.size_adult_ratio,
+        same_seed_specimen.size_adult_ratio
+    );
+    assert_eq!(
+        specimen.lifespan_days_ratio,
+        same_seed_specimen.lifespan_days_ratio
+    );
+    assert_ne!(specimen.catch_age, other_seed_specimen.catch_age);
+}
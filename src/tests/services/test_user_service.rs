@@ -64,7 +64,10 @@ fn test_get_unmet_location_unlock_requirements() {
     sp.user_service()
         .unlock_location(&user, location2.clone())
         .unwrap();
-    let specimen = sp.specimen_service().process_catch(&user, species).unwrap();
+    let specimen = sp
+        .specimen_service()
+        .process_catch(&user, species, sp.random_source().next_seed())
+        .unwrap();
     sp.fishing_history_service()
         .register_catch(&specimen)
         .unwrap();
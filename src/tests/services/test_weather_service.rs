@@ -0,0 +1,177 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::location_data::LocationData;
+use crate::game::clock::FixedClock;
+use crate::game::services::weather_service::{MAX_SUMMARY_DAYS, MAX_TIMELINE_SAMPLES};
+use crate::tests::mock::mock_service_provider_with_clock;
+use chrono::{NaiveDate, TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const LOCATION_ID: i32 = 1;
+
+fn mock_config() -> Arc<dyn ConfigInterface> {
+    let location = LocationData {
+        timezone: Tz::Europe__Berlin,
+        weather_seed: 1337,
+        ..Default::default()
+    };
+
+    Config::builder()
+        .locations(HashMap::from([(LOCATION_ID, location)]))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_weather_timeline() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let config = mock_config();
+    let sp = mock_service_provider_with_clock(config.clone(), Arc::new(FixedClock::new(now)));
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+
+    let now = now.with_timezone(&Tz::Europe__Berlin);
+    let start = now - TimeDelta::days(1);
+    let timeline = sp
+        .weather_service()
+        .get_weather_timeline(location.clone(), start, now, TimeDelta::hours(1))
+        .unwrap();
+    assert_eq!(timeline.len(), 24);
+    for (hour, weather) in timeline.iter().enumerate() {
+        assert_eq!(weather.time, start + TimeDelta::hours(hour as i64));
+        let single = sp
+            .weather_service()
+            .get_game_time_weather(location.clone(), weather.time)
+            .unwrap();
+        assert_eq!(single.temperature_c, weather.temperature_c);
+    }
+
+    // Steps below a minute are raised to a minute
+    let timeline = sp
+        .weather_service()
+        .get_weather_timeline(
+            location.clone(),
+            now,
+            now + TimeDelta::minutes(5),
+            TimeDelta::seconds(1),
+        )
+        .unwrap();
+    assert_eq!(timeline.len(), 5);
+
+    for step in [TimeDelta::zero(), TimeDelta::minutes(-1)] {
+        let error = sp
+            .weather_service()
+            .get_weather_timeline(location.clone(), start, now, step)
+            .unwrap_err();
+        let input_error = error.as_input_error().unwrap();
+        assert!(input_error.is_invalid_timeline_step());
+        assert_eq!(input_error.get_step(), Some(step));
+    }
+
+    let error = sp
+        .weather_service()
+        .get_weather_timeline(
+            location.clone(),
+            start,
+            start + TimeDelta::minutes(MAX_TIMELINE_SAMPLES + 1),
+            TimeDelta::minutes(1),
+        )
+        .unwrap_err();
+    let input_error = error.as_input_error().unwrap();
+    assert!(input_error.is_timeline_too_long());
+    assert_eq!(input_error.get_samples(), Some(MAX_TIMELINE_SAMPLES + 1));
+    assert_eq!(input_error.get_max_samples(), Some(MAX_TIMELINE_SAMPLES));
+
+    let timeline = sp
+        .weather_service()
+        .get_weather_timeline(
+            location.clone(),
+            start,
+            start + TimeDelta::minutes(MAX_TIMELINE_SAMPLES),
+            TimeDelta::minutes(1),
+        )
+        .unwrap();
+    assert_eq!(timeline.len() as i64, MAX_TIMELINE_SAMPLES);
+
+    let timeline = sp
+        .weather_service()
+        .get_weather_timeline(location, now, start, TimeDelta::hours(1))
+        .unwrap();
+    assert!(timeline.is_empty());
+}
+
+#[test]
+fn test_daily_weather_summaries() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let config = mock_config();
+    let sp = mock_service_provider_with_clock(config.clone(), Arc::new(FixedClock::new(now)));
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+
+    let start_date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+    let summaries = sp
+        .weather_service()
+        .get_daily_weather_summaries(location.clone(), start_date, 5)
+        .unwrap();
+    assert_eq!(summaries.len(), 5);
+
+    for (day, summary) in summaries.iter().enumerate() {
+        let date = start_date + TimeDelta::days(day as i64);
+        assert_eq!(summary.date, date);
+        assert_eq!(summary.location_id, LOCATION_ID);
+
+        let start = Tz::Europe__Berlin
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+            .unwrap();
+        let hourly = sp
+            .weather_service()
+            .get_weather_timeline(
+                location.clone(),
+                start,
+                start + TimeDelta::days(1),
+                TimeDelta::hours(1),
+            )
+            .unwrap();
+
+        let min = hourly
+            .iter()
+            .map(|weather| weather.temperature_c)
+            .fold(f32::INFINITY, f32::min);
+        let max = hourly
+            .iter()
+            .map(|weather| weather.temperature_c)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let rain_hours = hourly.iter().filter(|weather| weather.is_raining).count();
+
+        assert_eq!(summary.min_temperature_c, min);
+        assert_eq!(summary.max_temperature_c, max);
+        assert!(summary.min_temperature_c <= summary.average_temperature_c);
+        assert!(summary.average_temperature_c <= summary.max_temperature_c);
+        assert_eq!(summary.rain_hours as usize, rain_hours);
+        assert!((0.0..=1.0).contains(&summary.average_cloudiness));
+        assert_eq!(summary.season, hourly[0].season);
+    }
+}
+
+#[test]
+fn test_daily_weather_summaries_too_long() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let config = mock_config();
+    let sp = mock_service_provider_with_clock(config.clone(), Arc::new(FixedClock::new(now)));
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+    let start_date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+    let summaries = sp
+        .weather_service()
+        .get_daily_weather_summaries(location.clone(), start_date, MAX_SUMMARY_DAYS)
+        .unwrap();
+    assert_eq!(summaries.len(), MAX_SUMMARY_DAYS as usize);
+
+    let error = sp
+        .weather_service()
+        .get_daily_weather_summaries(location, start_date, u32::MAX)
+        .unwrap_err();
+    let input_error = error.as_input_error().unwrap();
+    assert!(input_error.is_summary_too_long());
+    assert_eq!(input_error.get_days(), Some(u32::MAX));
+    assert_eq!(input_error.get_max_days(), Some(MAX_SUMMARY_DAYS));
+}
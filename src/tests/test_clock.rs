@@ -0,0 +1,141 @@
+use crate::game::clock::{Clock, FixedClock, OffsetClock, SystemClock};
+use crate::game::service_provider::ServiceProviderInterface;
+use crate::models::item::NewItem;
+use crate::tests::mock::{mock_default_config, mock_service_provider_with_clock};
+use chrono::{TimeDelta, TimeZone, Utc};
+use std::sync::Arc;
+
+#[test]
+fn test_fixed_clock() {
+    let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let clock = FixedClock::new(start);
+    assert_eq!(clock.now(), start);
+    assert_eq!(clock.now(), start);
+
+    clock.advance(TimeDelta::days(2));
+    assert_eq!(clock.now(), start + TimeDelta::days(2));
+
+    clock.set(start);
+    assert_eq!(clock.now(), start);
+}
+
+#[test]
+fn test_offset_clock() {
+    let clock = OffsetClock::new(TimeDelta::days(1));
+    let expected = SystemClock::new().now() + TimeDelta::days(1);
+    assert!((clock.now() - expected).abs() < TimeDelta::seconds(1));
+
+    clock.advance(TimeDelta::days(1));
+    assert_eq!(clock.get_offset(), TimeDelta::days(2));
+
+    clock.set_offset(TimeDelta::zero());
+    assert!((clock.now() - Utc::now()).abs() < TimeDelta::seconds(1));
+}
+
+#[test]
+fn test_service_provider_clock() {
+    let start = Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap();
+    let clock = Arc::new(FixedClock::new(start));
+    let config = mock_default_config();
+    let sp = mock_service_provider_with_clock(config.clone(), clock.clone());
+    assert_eq!(sp.clock().now(), start);
+
+    // The weather is read at the time of the clock, in the timezone of the location
+    let location = config.get_location_data(1).unwrap();
+    let weather = sp
+        .weather_service()
+        .get_current_weather(location.clone())
+        .unwrap();
+    assert_eq!(weather.time, start.with_timezone(&location.timezone));
+
+    clock.advance(TimeDelta::hours(6));
+    let later_weather = sp.weather_service().get_current_weather(location).unwrap();
+    assert_eq!(later_weather.time, weather.time + TimeDelta::hours(6));
+}
+
+#[test]
+fn test_specimen_aging() {
+    let clock = Arc::new(FixedClock::new(Utc::now()));
+    let config = mock_default_config();
+    let sp = mock_service_provider_with_clock(config.clone(), clock.clone());
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let species = config.get_species_data(1).unwrap();
+    let mut specimen = sp
+        .specimen_service()
+        .process_catch(&user, species, sp.random_source().next_seed())
+        .unwrap();
+    specimen.catch_age = 0.0;
+
+    let age = specimen.get_age(config.clone(), &sp.game_time()).unwrap();
+    assert!(age < 0.01, "Expected a fresh catch, got age {}", age);
+
+    // Time only passes when the clock is advanced
+    clock.advance(TimeDelta::days(365 * 1000));
+    let age = specimen.get_age(config.clone(), &sp.game_time()).unwrap();
+    assert_eq!(age, 1.0);
+
+    // A clock set before the catch doesn't make the specimen younger than it was caught
+    clock.set(specimen.created_at - TimeDelta::days(1));
+    let age = specimen.get_age(config, &sp.game_time()).unwrap();
+    assert_eq!(age, 0.0);
+}
+
+/// Everything persisted through the repositories is timestamped with the clock of the service provider
+pub(crate) fn assert_timestamps_follow_clock(
+    sp: &dyn ServiceProviderInterface,
+    clock: &FixedClock,
+) {
+    let start = clock.now();
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    assert_eq!(user.created_at, start);
+    assert_eq!(user.updated_at, start);
+    let location = sp.user_repository().unlock_location(user.id, 1).unwrap();
+    assert_eq!(location.unlocked_at, start);
+    let achievement = sp
+        .user_repository()
+        .unlock_achievement(user.id, 1)
+        .unwrap()
+        .unwrap();
+    assert_eq!(achievement.unlocked_at, start);
+
+    let species = sp.config().get_species_data(1).unwrap();
+    let specimen = sp
+        .specimen_service()
+        .process_catch(&user, species, sp.random_source().next_seed())
+        .unwrap();
+    assert_eq!(specimen.created_at, start);
+    let entry = sp
+        .fishing_history_service()
+        .register_catch(&specimen)
+        .unwrap();
+    assert_eq!(entry.created_at, start);
+    assert_eq!(entry.last_catch, start);
+    let pond = sp.pond_service().create_and_save_pond(&user, 10).unwrap();
+    assert_eq!(pond.created_at, start);
+    let item = sp
+        .item_repository()
+        .create(NewItem {
+            user_id: user.id,
+            type_id: 1,
+            properties: Default::default(),
+        })
+        .unwrap();
+    assert_eq!(item.created_at, start);
+
+    clock.advance(TimeDelta::hours(1));
+    let user = sp.user_repository().add_credits(user.id, 10).unwrap();
+    assert_eq!(user.updated_at, start + TimeDelta::hours(1));
+    let item = sp.item_repository().save(item).unwrap();
+    assert_eq!(item.created_at, start);
+    assert_eq!(item.updated_at, start + TimeDelta::hours(1));
+}
+
+#[test]
+fn test_persisted_timestamps() {
+    let clock = Arc::new(FixedClock::new(
+        Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap(),
+    ));
+    let sp = mock_service_provider_with_clock(mock_default_config(), clock.clone());
+    assert_timestamps_follow_clock(sp.as_ref(), &clock);
+}
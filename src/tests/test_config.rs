@@ -1,9 +1,13 @@
 use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::achievement_data::{AchievementData, AchievementRequirement};
 use crate::data::encounter_data::EncounterData;
 use crate::data::item_data::ItemData;
 use crate::data::location_data::LocationData;
 use crate::data::species_data::SpeciesData;
 use crate::enums::item_category::ItemCategory;
+use crate::enums::moon_phase::MoonPhase;
+use crate::enums::tide_state::TideState;
+use crate::enums::weather_condition::WeatherCondition;
 use crate::models::item::attributes::ItemAttributesType;
 use crate::models::item::attributes_container::ItemAttributesContainerInterface;
 use crate::models::item::properties::ItemPropertiesType;
@@ -19,6 +23,7 @@ fn mock_file_config() -> Arc<dyn ConfigInterface> {
     let species_json_file = Path::new("./example_data/species_data.json");
     let settings_json_file = Path::new("./example_data/settings.json");
     let items_json_file = Path::new("./example_data/items.json");
+    let achievements_json_file = Path::new("./example_data/achievements.json");
 
     Config::builder()
         .locations_json_file(locations_json_file)
@@ -29,6 +34,8 @@ fn mock_file_config() -> Arc<dyn ConfigInterface> {
         .unwrap()
         .items_json_file(items_json_file)
         .unwrap()
+        .achievements_json_file(achievements_json_file)
+        .unwrap()
         .build()
         .unwrap()
 }
@@ -56,6 +63,23 @@ fn test_building() {
     assert!(!config.is_item_in_category(item2.clone(), ItemCategory::Bait));
     assert!(!config.is_item_in_category(item2.clone(), ItemCategory::Shop));
     assert!(config.is_item_in_category(item2.clone(), ItemCategory::Rod));
+
+    let achievement2 = config.get_achievement_data(2).unwrap();
+    assert_eq!(achievement2.name, "Rainy Day");
+    assert_eq!(
+        achievement2.requirement,
+        AchievementRequirement::Catch {
+            species_id: Some(1),
+            location_id: None,
+            min_rarity_level: None,
+            min_size_ratio: None,
+            needs_rain: true,
+        }
+    );
+    assert_eq!(
+        config.get_achievement_data(3).unwrap().requirement,
+        AchievementRequirement::LocationsUnlocked { count: None }
+    );
 }
 
 #[test]
@@ -152,3 +176,345 @@ fn test_mapping_items_by_attributes_and_properties() {
     assert!(rod.has_usage());
     assert_eq!(rod.name, "Bobber");
 }
+
+#[test]
+fn test_validation_encounter_times() {
+    let nocturnal_species = SpeciesData {
+        encounters: vec![EncounterData {
+            location_id: 1,
+            min_time_hour: 22,
+            max_time_hour: 3,
+            min_time_minute: Some(30),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let invalid_species = SpeciesData {
+        encounters: vec![EncounterData {
+            location_id: 1,
+            min_time_hour: 12,
+            max_time_hour: 24,
+            max_time_minute: Some(60),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let config = Config::builder()
+        .locations(HashMap::from([(1, LocationData::default())]))
+        .species(HashMap::from([(1, nocturnal_species)]))
+        .build();
+    assert!(config.is_ok());
+
+    let validation_report = Config::builder()
+        .locations(HashMap::from([(1, LocationData::default())]))
+        .species(HashMap::from([(2, invalid_species)]))
+        .build()
+        .unwrap_err();
+
+    let errors = validation_report.errors();
+    assert_eq!(errors.len(), 2);
+
+    assert!(errors.iter().any(|e| {
+        e.is_species_encounter_invalid_hour()
+            && e.get_source_species_id() == Some(2)
+            && e.get_hour() == Some(24)
+    }));
+
+    assert!(errors.iter().any(|e| {
+        e.is_species_encounter_invalid_minute()
+            && e.get_source_species_id() == Some(2)
+            && e.get_minute() == Some(60)
+    }));
+}
+
+#[test]
+fn test_validation_encounter_conditions() {
+    let invalid_species = SpeciesData {
+        encounters: vec![EncounterData {
+            location_id: 1,
+            needs_rain: true,
+            needs_no_rain: true,
+            min_temperature_c: Some(20.0),
+            max_temperature_c: Some(5.0),
+            min_water_temperature_c: Some(15.0),
+            max_water_temperature_c: Some(10.0),
+            min_light_level: Some(0.5),
+            max_light_level: Some(1.5),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let validation_report = Config::builder()
+        .locations(HashMap::from([(1, LocationData::default())]))
+        .species(HashMap::from([(3, invalid_species)]))
+        .build()
+        .unwrap_err();
+
+    let errors = validation_report.errors();
+    assert_eq!(errors.len(), 4);
+    assert!(errors.iter().all(|e| e.get_source_species_id() == Some(3)));
+    assert!(errors
+        .iter()
+        .any(|e| e.is_species_encounter_conflicting_rain()));
+    assert!(errors
+        .iter()
+        .any(|e| e.is_species_encounter_invalid_temperature_range()));
+    assert!(errors
+        .iter()
+        .any(|e| e.is_species_encounter_invalid_water_temperature_range()));
+    assert!(errors
+        .iter()
+        .any(|e| e.is_species_encounter_invalid_light_level_range()));
+}
+
+#[test]
+fn test_validation_encounter_weather_conditions() {
+    let encounter = |needs_rain, needs_no_rain, weather_conditions| EncounterData {
+        location_id: 1,
+        needs_rain,
+        needs_no_rain,
+        weather_conditions,
+        ..Default::default()
+    };
+
+    let species = HashMap::from([
+        (
+            1,
+            SpeciesData {
+                encounters: vec![
+                    encounter(true, false, vec![WeatherCondition::Snow]),
+                    encounter(false, true, vec![WeatherCondition::Fog]),
+                    encounter(false, false, vec![WeatherCondition::Rain]),
+                ],
+                ..Default::default()
+            },
+        ),
+        (
+            2,
+            SpeciesData {
+                encounters: vec![encounter(true, false, vec![WeatherCondition::Cloudy])],
+                ..Default::default()
+            },
+        ),
+        (
+            3,
+            SpeciesData {
+                encounters: vec![encounter(
+                    false,
+                    true,
+                    vec![WeatherCondition::Rain, WeatherCondition::Thunderstorm],
+                )],
+                ..Default::default()
+            },
+        ),
+    ]);
+
+    let validation_report = Config::builder()
+        .locations(HashMap::from([(1, LocationData::default())]))
+        .species(species)
+        .build()
+        .unwrap_err();
+
+    let errors = validation_report.errors();
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|e| e.is_species_encounter_conflicting_weather_conditions()));
+    assert!(errors.iter().any(|e| e.get_source_species_id() == Some(2)));
+    assert!(errors.iter().any(|e| e.get_source_species_id() == Some(3)));
+}
+
+#[test]
+fn test_validation_location_coordinates() {
+    let location = |latitude, longitude| LocationData {
+        latitude,
+        longitude,
+        ..Default::default()
+    };
+
+    let locations = HashMap::from([
+        (1, location(None, None)),
+        (2, location(Some(-33.87), Some(151.21))),
+        (3, location(Some(52.52), None)),
+        (4, location(Some(91.0), Some(0.0))),
+        (5, location(Some(0.0), Some(-181.0))),
+    ]);
+
+    let validation_report = Config::builder().locations(locations).build().unwrap_err();
+
+    let errors = validation_report.errors();
+    assert_eq!(errors.len(), 3);
+    assert!(errors.iter().all(|e| e.is_location_invalid_coordinates()));
+    for location_id in [3, 4, 5] {
+        assert!(errors
+            .iter()
+            .any(|e| e.get_source_location_id() == Some(location_id)));
+    }
+}
+
+#[test]
+fn test_validation_encounter_moon_and_tides() {
+    let encounter = |location_id| EncounterData {
+        location_id,
+        ..Default::default()
+    };
+
+    let species = HashMap::from([
+        (
+            1,
+            SpeciesData {
+                encounters: vec![
+                    EncounterData {
+                        tide_states: vec![TideState::High],
+                        ..encounter(1)
+                    },
+                    EncounterData {
+                        preferred_tide_states: vec![TideState::Low],
+                        preferred_rarity_level: Some(10),
+                        ..encounter(2)
+                    },
+                    EncounterData {
+                        moon_phases: vec![MoonPhase::FullMoon],
+                        preferred_moon_phases: vec![MoonPhase::FullMoon],
+                        preferred_rarity_level: Some(10),
+                        ..encounter(2)
+                    },
+                ],
+                ..Default::default()
+            },
+        ),
+        (
+            2,
+            SpeciesData {
+                encounters: vec![EncounterData {
+                    preferred_moon_phases: vec![MoonPhase::NewMoon],
+                    ..encounter(2)
+                }],
+                ..Default::default()
+            },
+        ),
+        (
+            3,
+            SpeciesData {
+                encounters: vec![EncounterData {
+                    preferred_rarity_level: Some(10),
+                    ..encounter(2)
+                }],
+                ..Default::default()
+            },
+        ),
+    ]);
+
+    let coastal_location = LocationData {
+        coastal: true,
+        ..Default::default()
+    };
+    let locations = HashMap::from([(1, LocationData::default()), (2, coastal_location)]);
+
+    let validation_report = Config::builder()
+        .locations(locations)
+        .species(species)
+        .build()
+        .unwrap_err();
+
+    let errors = validation_report.errors();
+    assert_eq!(errors.len(), 3);
+
+    assert!(errors.iter().any(|e| {
+        e.is_species_encounter_tides_not_coastal()
+            && e.get_source_species_id() == Some(1)
+            && e.get_target_location_id() == Some(1)
+    }));
+    for species_id in [2, 3] {
+        assert!(errors.iter().any(|e| {
+            e.is_species_encounter_invalid_preference()
+                && e.get_source_species_id() == Some(species_id)
+        }));
+    }
+}
+
+#[test]
+fn test_validation_achievements() {
+    let location_data_map = HashMap::from([(1, LocationData::default())]);
+    let species_data_map = HashMap::from([(1, SpeciesData::default())]);
+    let achievement = |requirement| AchievementData {
+        requirement,
+        ..Default::default()
+    };
+
+    let achievements_data_map = HashMap::from([
+        (
+            1,
+            achievement(AchievementRequirement::CatchCount {
+                count: 0,
+                species_id: Some(9),
+            }),
+        ),
+        (
+            2,
+            achievement(AchievementRequirement::Catch {
+                species_id: Some(1),
+                location_id: Some(8),
+                min_rarity_level: Some(200),
+                min_size_ratio: Some(1.5),
+                needs_rain: true,
+            }),
+        ),
+        (
+            3,
+            achievement(AchievementRequirement::Purchase { item_id: Some(7) }),
+        ),
+        (
+            4,
+            achievement(AchievementRequirement::LocationsUnlocked { count: Some(2) }),
+        ),
+        (
+            5,
+            achievement(AchievementRequirement::SpeciesCount { count: 1 }),
+        ),
+    ]);
+
+    let validation_report = Config::builder()
+        .locations(location_data_map)
+        .species(species_data_map)
+        .achievements(achievements_data_map)
+        .build()
+        .unwrap_err();
+
+    let errors = validation_report.errors();
+    assert_eq!(errors.len(), 6);
+
+    assert!(errors.iter().any(|e| {
+        e.is_achievement_invalid_count()
+            && e.get_source_achievement_id() == Some(1)
+            && e.get_count() == Some(0)
+    }));
+    assert!(errors.iter().any(|e| {
+        e.is_achievement_species()
+            && e.get_source_achievement_id() == Some(1)
+            && e.get_target_species_id() == Some(9)
+    }));
+    assert!(errors.iter().any(|e| {
+        e.is_achievement_location()
+            && e.get_source_achievement_id() == Some(2)
+            && e.get_target_location_id() == Some(8)
+    }));
+    assert!(errors.iter().any(|e| {
+        e.is_achievement_invalid_size_ratio()
+            && e.get_source_achievement_id() == Some(2)
+            && e.get_min_size_ratio() == Some(1.5)
+    }));
+    assert!(errors.iter().any(|e| {
+        e.is_achievement_item()
+            && e.get_source_achievement_id() == Some(3)
+            && e.get_target_item_id() == Some(7)
+    }));
+    assert!(errors.iter().any(|e| {
+        e.is_achievement_invalid_count()
+            && e.get_source_achievement_id() == Some(4)
+            && e.get_count() == Some(2)
+    }));
+}
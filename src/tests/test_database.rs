@@ -0,0 +1,109 @@
+use crate::database::transaction;
+use crate::game::errors::resource::GameResourceError;
+use crate::game::errors::{GameError, GameResult};
+use crate::tests::mock::mock_default_service_provider;
+use std::panic::{self, AssertUnwindSafe};
+
+#[test]
+fn test_transaction_commit() {
+    let sp = mock_default_service_provider();
+
+    let user = transaction(&sp.database(), || -> GameResult<_> {
+        let user = sp.user_service().create_and_save_user(1337)?;
+        Ok(sp.user_repository().add_credits(user.id, 100)?)
+    })
+    .unwrap();
+
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, 100);
+}
+
+#[test]
+fn test_transaction_rollback() {
+    let sp = mock_default_service_provider();
+
+    let error = transaction(&sp.database(), || -> GameResult<()> {
+        let user = sp.user_service().create_and_save_user(1337)?;
+        sp.user_repository().add_credits(user.id, 100)?;
+        Err(GameResourceError::user_not_found(user.external_id).into())
+    })
+    .unwrap_err();
+    assert!(error.is_not_found());
+
+    let found_user = sp.user_repository().find_by_external_id(1337).unwrap();
+    assert_eq!(found_user, None);
+}
+
+#[test]
+fn test_transaction_rollback_on_panic() {
+    let sp = mock_default_service_provider();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _ = transaction(&sp.database(), || -> GameResult<()> {
+            sp.user_service().create_and_save_user(1337)?;
+            panic!("Failed inside of transaction");
+        });
+    }));
+    assert!(result.is_err());
+
+    let found_user = sp.user_repository().find_by_external_id(1337).unwrap();
+    assert_eq!(found_user, None);
+
+    // The thread is not stuck in the aborted transaction
+    let connection = sp.database().read().unwrap().get_connection().unwrap();
+    assert!(!connection.is_in_transaction());
+}
+
+#[test]
+fn test_nested_transaction_rollback() {
+    let sp = mock_default_service_provider();
+
+    let user = transaction(&sp.database(), || -> GameResult<_> {
+        let user = sp.user_service().create_and_save_user(1337)?;
+
+        let inner_result = transaction(&sp.database(), || -> GameResult<()> {
+            sp.user_repository().add_credits(user.id, 100)?;
+            sp.user_service().create_and_save_user(1338)?;
+            Err(GameResourceError::user_not_found(1338).into())
+        });
+        assert!(inner_result.is_err());
+
+        Ok(user)
+    })
+    .unwrap();
+
+    // Only the changes of the inner transaction are rolled back
+    let found_user = sp.user_repository().find(user.id).unwrap().unwrap();
+    assert_eq!(found_user.credits, 0);
+    let inner_user = sp.user_repository().find_by_external_id(1338).unwrap();
+    assert_eq!(inner_user, None);
+}
+
+#[test]
+fn test_transaction_connection() {
+    let sp = mock_default_service_provider();
+
+    let connection = sp.database().read().unwrap().get_connection().unwrap();
+    assert!(!connection.is_in_transaction());
+    drop(connection);
+
+    transaction(&sp.database(), || -> Result<(), GameError> {
+        let connection = sp.database().read().unwrap().get_connection()?;
+        assert!(connection.is_in_transaction());
+
+        // The connection of the transaction can only be borrowed once at a time
+        let error = sp
+            .database()
+            .read()
+            .unwrap()
+            .get_connection()
+            .err()
+            .unwrap();
+        assert!(error.is_transaction_failed());
+        Ok(())
+    })
+    .unwrap();
+
+    let connection = sp.database().read().unwrap().get_connection().unwrap();
+    assert!(!connection.is_in_transaction());
+}
@@ -0,0 +1,399 @@
+use crate::data::encounter_data::EncounterData;
+use crate::data::species_data::SpeciesData;
+use crate::enums::moon_phase::MoonPhase;
+use crate::enums::season::Season;
+use crate::enums::tide_state::TideState;
+use crate::enums::weather_condition::WeatherCondition;
+use crate::game::systems::encounter_system::{
+    EncounterConditions, EncounterEquipment, EncounterSystem,
+};
+use crate::utils::random::{seeded_rng, ENCOUNTER_STREAM};
+use chrono::{NaiveTime, TimeZone};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const NOCTURNAL_SPECIES_ID: i32 = 1;
+const EVENING_SPECIES_ID: i32 = 2;
+
+fn nocturnal_encounter() -> EncounterData {
+    EncounterData {
+        location_id: 1,
+        min_time_hour: 22,
+        max_time_hour: 3,
+        ..Default::default()
+    }
+}
+
+fn evening_encounter() -> EncounterData {
+    EncounterData {
+        location_id: 1,
+        min_time_hour: 18,
+        max_time_hour: 18,
+        min_time_minute: Some(15),
+        max_time_minute: Some(44),
+        ..Default::default()
+    }
+}
+
+fn mock_encounter_system() -> EncounterSystem {
+    let species = HashMap::from([
+        (
+            NOCTURNAL_SPECIES_ID,
+            Arc::new(SpeciesData {
+                id: NOCTURNAL_SPECIES_ID,
+                encounters: vec![nocturnal_encounter()],
+                ..Default::default()
+            }),
+        ),
+        (
+            EVENING_SPECIES_ID,
+            Arc::new(SpeciesData {
+                id: EVENING_SPECIES_ID,
+                encounters: vec![evening_encounter()],
+                ..Default::default()
+            }),
+        ),
+    ]);
+    EncounterSystem::new(Arc::new(species), 2.0, 0.0)
+}
+
+fn roll_at(system: &EncounterSystem, hour: u32, minute: u32) -> Option<i32> {
+    let time = Tz::UTC
+        .with_ymd_and_hms(2025, 1, 1, hour, minute, 0)
+        .unwrap();
+    system.roll_encounter(
+        time,
+        &EncounterConditions::default(),
+        1,
+        &EncounterEquipment::default(),
+        &mut seeded_rng(0, ENCOUNTER_STREAM),
+    )
+}
+
+#[test]
+fn test_hours_wrap_around_midnight() {
+    let encounter = nocturnal_encounter();
+    assert!(encounter.wraps_midnight());
+    assert_eq!(encounter.get_hours(), vec![22, 23, 0, 1, 2, 3]);
+
+    let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+    assert!(encounter.is_active_at(time(22, 0)));
+    assert!(encounter.is_active_at(time(0, 0)));
+    assert!(encounter.is_active_at(time(3, 59)));
+    assert!(!encounter.is_active_at(time(4, 0)));
+    assert!(!encounter.is_active_at(time(21, 59)));
+
+    let day_encounter = EncounterData {
+        min_time_hour: 16,
+        max_time_hour: 17,
+        ..Default::default()
+    };
+    assert!(!day_encounter.wraps_midnight());
+    assert_eq!(day_encounter.get_hours(), vec![16, 17]);
+
+    // Within the same hour, but starting after it ends, covers the rest of the day
+    let almost_all_day = EncounterData {
+        min_time_hour: 16,
+        max_time_hour: 16,
+        min_time_minute: Some(30),
+        max_time_minute: Some(10),
+        ..Default::default()
+    };
+    assert!(almost_all_day.wraps_midnight());
+    assert_eq!(almost_all_day.get_hours().len(), 24);
+    assert!(almost_all_day.is_active_at(time(16, 5)));
+    assert!(!almost_all_day.is_active_at(time(16, 20)));
+    assert!(almost_all_day.is_active_at(time(16, 30)));
+}
+
+#[test]
+fn test_roll_encounter_time_window() {
+    let system = mock_encounter_system();
+
+    assert_eq!(roll_at(&system, 23, 30), Some(NOCTURNAL_SPECIES_ID));
+    assert_eq!(roll_at(&system, 2, 0), Some(NOCTURNAL_SPECIES_ID));
+    assert_eq!(roll_at(&system, 12, 0), None);
+
+    // Minute precision is respected within the hour
+    assert_eq!(roll_at(&system, 18, 14), None);
+    assert_eq!(roll_at(&system, 18, 15), Some(EVENING_SPECIES_ID));
+    assert_eq!(roll_at(&system, 18, 44), Some(EVENING_SPECIES_ID));
+    assert_eq!(roll_at(&system, 18, 45), None);
+}
+
+#[test]
+fn test_encounter_conditions() {
+    let trout_encounter = EncounterData {
+        seasons: vec![Season::Autumn, Season::Winter],
+        max_temperature_c: Some(10.0),
+        needs_no_rain: true,
+        ..Default::default()
+    };
+    let cold_dry_autumn = EncounterConditions {
+        season: Season::Autumn,
+        temperature_c: 5.0,
+        light_level: 0.5,
+        is_raining: false,
+        weather_condition: WeatherCondition::Fog,
+        ..Default::default()
+    };
+    assert!(trout_encounter.are_conditions_met(&cold_dry_autumn));
+    assert!(!trout_encounter.are_conditions_met(&EncounterConditions {
+        season: Season::Summer,
+        ..cold_dry_autumn
+    }));
+    assert!(!trout_encounter.are_conditions_met(&EncounterConditions {
+        temperature_c: 10.5,
+        ..cold_dry_autumn
+    }));
+    assert!(!trout_encounter.are_conditions_met(&EncounterConditions {
+        is_raining: true,
+        ..cold_dry_autumn
+    }));
+
+    // The water stays warm on a cold night
+    let warm_water_encounter = EncounterData {
+        min_water_temperature_c: Some(15.0),
+        ..Default::default()
+    };
+    let cold_night_warm_water = EncounterConditions {
+        temperature_c: 5.0,
+        water_temperature_c: 16.0,
+        ..Default::default()
+    };
+    assert!(warm_water_encounter.are_conditions_met(&cold_night_warm_water));
+    assert!(
+        !warm_water_encounter.are_conditions_met(&EncounterConditions {
+            water_temperature_c: 14.5,
+            ..cold_night_warm_water
+        })
+    );
+
+    let rain_encounter = EncounterData {
+        needs_rain: true,
+        min_light_level: Some(0.2),
+        max_light_level: Some(0.6),
+        ..Default::default()
+    };
+    let rainy_dusk = EncounterConditions {
+        light_level: 0.2,
+        is_raining: true,
+        ..Default::default()
+    };
+    assert!(rain_encounter.are_conditions_met(&rainy_dusk));
+    assert!(!rain_encounter.are_conditions_met(&EncounterConditions {
+        is_raining: false,
+        ..rainy_dusk
+    }));
+    assert!(!rain_encounter.are_conditions_met(&EncounterConditions {
+        light_level: 0.7,
+        ..rainy_dusk
+    }));
+
+    let storm_encounter = EncounterData {
+        weather_conditions: vec![WeatherCondition::Thunderstorm, WeatherCondition::Snow],
+        ..Default::default()
+    };
+    assert!(storm_encounter.are_conditions_met(&EncounterConditions {
+        weather_condition: WeatherCondition::Thunderstorm,
+        ..rainy_dusk
+    }));
+    assert!(storm_encounter.are_conditions_met(&EncounterConditions {
+        weather_condition: WeatherCondition::Snow,
+        ..rainy_dusk
+    }));
+    assert!(!storm_encounter.are_conditions_met(&EncounterConditions {
+        weather_condition: WeatherCondition::Rain,
+        ..rainy_dusk
+    }));
+
+    // Encounters without conditions are always available, rain or not
+    assert!(EncounterData::default().are_conditions_met(&rainy_dusk));
+    assert!(EncounterData::default().are_conditions_met(&cold_dry_autumn));
+}
+
+#[test]
+fn test_roll_encounter_conditions() {
+    let species = HashMap::from([(
+        1,
+        Arc::new(SpeciesData {
+            id: 1,
+            encounters: vec![EncounterData {
+                location_id: 1,
+                min_time_hour: 0,
+                max_time_hour: 23,
+                seasons: vec![Season::Winter],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+    )]);
+    let system = EncounterSystem::new(Arc::new(species), 2.0, 0.0);
+
+    let time = Tz::UTC.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let roll = |season| {
+        system.roll_encounter(
+            time,
+            &EncounterConditions {
+                season,
+                ..Default::default()
+            },
+            1,
+            &EncounterEquipment::default(),
+            &mut seeded_rng(0, ENCOUNTER_STREAM),
+        )
+    };
+    assert_eq!(roll(Season::Winter), Some(1));
+    assert_eq!(roll(Season::Summer), None);
+}
+
+#[test]
+fn test_encounter_probabilities() {
+    let encounter = |rarity_level, min_rod_level| EncounterData {
+        location_id: 1,
+        min_time_hour: 0,
+        max_time_hour: 23,
+        rarity_level,
+        min_rod_level,
+        ..Default::default()
+    };
+    let species = HashMap::from([
+        (1, vec![encounter(0, 0)]),
+        (2, vec![encounter(0, 0)]),
+        (3, vec![encounter(155, 0)]),
+        (4, vec![encounter(255, 1)]),
+    ])
+    .into_iter()
+    .map(|(id, encounters)| {
+        let species_data = SpeciesData {
+            id,
+            encounters,
+            ..Default::default()
+        };
+        (id, Arc::new(species_data))
+    })
+    .collect();
+    let system = EncounterSystem::new(Arc::new(species), 2.0, 0.0);
+
+    let time = Tz::UTC.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let conditions = EncounterConditions::default();
+    let probabilities =
+        system.get_encounter_probabilities(time, &conditions, 1, &EncounterEquipment::default());
+
+    // Weights are (255 - rarity)^2 + 1, species of the same rarity share its weight
+    let common_weight = 255.0 * 255.0 + 1.0;
+    let rare_weight = 100.0 * 100.0 + 1.0;
+    let total_weight = common_weight + rare_weight;
+
+    let species_ids: Vec<i32> = probabilities.iter().map(|p| p.species_id).collect();
+    assert_eq!(species_ids, vec![1, 2, 3]);
+    assert_eq!(probabilities[0].probability, probabilities[1].probability);
+    assert!((probabilities[0].probability - common_weight / total_weight / 2.0).abs() < 1e-12);
+    assert!((probabilities[2].probability - rare_weight / total_weight).abs() < 1e-12);
+    assert_eq!(probabilities[2].rarity_level, 155);
+
+    // Unlocked by a better rod
+    let equipment = EncounterEquipment {
+        rod_level: 1,
+        bait_level: 0,
+    };
+    let probabilities = system.get_encounter_probabilities(time, &conditions, 1, &equipment);
+    assert_eq!(probabilities.len(), 4);
+    let total: f64 = probabilities.iter().map(|p| p.probability).sum();
+    assert!((total - 1.0).abs() < 1e-12);
+
+    assert!(system
+        .get_encounter_probabilities(time, &conditions, 2, &equipment)
+        .is_empty());
+}
+
+#[test]
+fn test_moon_and_tide_conditions() {
+    let encounter = EncounterData {
+        moon_phases: vec![MoonPhase::FullMoon, MoonPhase::WaxingGibbous],
+        tide_states: vec![TideState::High],
+        ..Default::default()
+    };
+    let full_moon_high_tide = EncounterConditions {
+        moon_phase: MoonPhase::FullMoon,
+        tide_state: Some(TideState::High),
+        ..Default::default()
+    };
+    assert!(encounter.are_conditions_met(&full_moon_high_tide));
+    assert!(!encounter.are_conditions_met(&EncounterConditions {
+        moon_phase: MoonPhase::NewMoon,
+        ..full_moon_high_tide
+    }));
+    assert!(!encounter.are_conditions_met(&EncounterConditions {
+        tide_state: Some(TideState::Falling),
+        ..full_moon_high_tide
+    }));
+    // No tides, no tide dependent encounters
+    assert!(!encounter.are_conditions_met(&EncounterConditions {
+        tide_state: None,
+        ..full_moon_high_tide
+    }));
+}
+
+#[test]
+fn test_preferred_moon_and_tide() {
+    let encounter = |rarity_level, preferred_rarity_level, preferred_moon_phases| EncounterData {
+        location_id: 1,
+        min_time_hour: 0,
+        max_time_hour: 23,
+        rarity_level,
+        preferred_rarity_level,
+        preferred_moon_phases,
+        preferred_tide_states: vec![TideState::Rising],
+        ..Default::default()
+    };
+    let species = HashMap::from([
+        (1, encounter(0, None, vec![])),
+        (2, encounter(200, Some(0), vec![MoonPhase::NewMoon])),
+    ])
+    .into_iter()
+    .map(|(id, encounter)| {
+        let species_data = SpeciesData {
+            id,
+            encounters: vec![encounter],
+            ..Default::default()
+        };
+        (id, Arc::new(species_data))
+    })
+    .collect();
+    let system = EncounterSystem::new(Arc::new(species), 2.0, 0.0);
+
+    let time = Tz::UTC.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    let probability_of_preferred = |conditions: EncounterConditions| {
+        system
+            .get_encounter_probabilities(time, &conditions, 1, &EncounterEquipment::default())
+            .into_iter()
+            .find(|probability| probability.species_id == 2)
+            .unwrap()
+    };
+
+    let not_preferred = probability_of_preferred(EncounterConditions {
+        moon_phase: MoonPhase::FullMoon,
+        ..Default::default()
+    });
+    assert_eq!(not_preferred.rarity_level, 200);
+    assert!(not_preferred.probability < 0.1);
+
+    // Both species share the same rarity level with a preferred moon phase or tide
+    for conditions in [
+        EncounterConditions {
+            moon_phase: MoonPhase::NewMoon,
+            ..Default::default()
+        },
+        EncounterConditions {
+            moon_phase: MoonPhase::FullMoon,
+            tide_state: Some(TideState::Rising),
+            ..Default::default()
+        },
+    ] {
+        let preferred = probability_of_preferred(conditions);
+        assert_eq!(preferred.rarity_level, 0);
+        assert_eq!(preferred.probability, 0.5);
+    }
+}
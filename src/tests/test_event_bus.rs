@@ -0,0 +1,251 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::item_data::ItemData;
+use crate::data::location_data::LocationData;
+use crate::data::species_data::SpeciesData;
+use crate::database::transaction;
+use crate::enums::record_kind::RecordKind;
+use crate::game::errors::resource::GameResourceError;
+use crate::game::errors::GameResult;
+use crate::game::event_bus::game_event::GameEvent;
+use crate::game::service_provider::ServiceProviderInterface;
+use crate::models::item::attributes_container::ItemAttributesContainer;
+use crate::models::item::properties_container::ItemPropertiesContainer;
+use crate::tests::mock::{mock_default_service_provider, mock_service_provider};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+const SPECIES_ID: i32 = 1;
+const LOCATION_ID: i32 = 1;
+const BAIT_ID: i32 = 1;
+
+fn mock_config() -> Arc<dyn ConfigInterface> {
+    let species = SpeciesData {
+        name: "Salmon".to_string(),
+        min_size_baby_mm: 10,
+        max_size_baby_mm: 20,
+        min_size_adult_mm: 50,
+        max_size_adult_mm: 80,
+        min_lifespan_days: 300,
+        max_lifespan_days: 400,
+        ..Default::default()
+    };
+
+    let bait = ItemData {
+        name: "Bait".to_string(),
+        attributes: ItemAttributesContainer::new().with_bait(1),
+        default_properties: ItemPropertiesContainer::new().with_stackable(1),
+        ..Default::default()
+    };
+
+    Config::builder()
+        .species(HashMap::from([(SPECIES_ID, species)]))
+        .locations(HashMap::from([(LOCATION_ID, LocationData::default())]))
+        .items(HashMap::from([(BAIT_ID, bait)]))
+        .build()
+        .unwrap()
+}
+
+/// Subscribes to the event bus and returns all events received from then on
+fn record_events(sp: &Arc<dyn ServiceProviderInterface>) -> Arc<Mutex<Vec<GameEvent>>> {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let subscriber_events = events.clone();
+    sp.event_bus().subscribe(Arc::new(move |event: &GameEvent| {
+        subscriber_events.lock().unwrap().push(event.clone());
+    }));
+    events
+}
+
+fn take_events(events: &Arc<Mutex<Vec<GameEvent>>>) -> Vec<GameEvent> {
+    std::mem::take(&mut *events.lock().unwrap())
+}
+
+#[test]
+fn test_events_without_transaction() {
+    let sp = mock_default_service_provider();
+    let events = record_events(&sp);
+    let other_events = record_events(&sp);
+
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+
+    let expected_events = vec![GameEvent::UserRegistered { user }];
+    assert_eq!(take_events(&events), expected_events);
+    assert_eq!(take_events(&other_events), expected_events);
+}
+
+#[test]
+fn test_catch_events() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let events = record_events(&sp);
+
+    let species = config.get_species_data(SPECIES_ID).unwrap();
+    let catch = |seed| {
+        let specimen = sp
+            .specimen_service()
+            .process_catch(&user, species.clone(), seed)
+            .unwrap();
+        let entry = sp
+            .fishing_history_service()
+            .register_catch(&specimen)
+            .unwrap();
+        (specimen, entry)
+    };
+
+    let (first_specimen, _) = catch(1);
+    assert_eq!(
+        take_events(&events),
+        vec![
+            GameEvent::SpecimenCaught {
+                specimen: first_specimen.clone()
+            },
+            GameEvent::SpeciesFirstCaught {
+                user_id: user.id,
+                species_id: SPECIES_ID
+            },
+        ]
+    );
+
+    // The second specimen holds at least one of the records, as both can't be the first one's
+    let (second_specimen, entry) = catch(2);
+    let mut expected_events = vec![GameEvent::SpecimenCaught {
+        specimen: second_specimen.clone(),
+    }];
+    for (kind, specimen_id) in [
+        (RecordKind::Smallest, entry.smallest_catch_specimen_id),
+        (RecordKind::Largest, entry.largest_catch_specimen_id),
+    ] {
+        if specimen_id == Some(second_specimen.id) {
+            expected_events.push(GameEvent::RecordBroken {
+                user_id: user.id,
+                species_id: SPECIES_ID,
+                specimen_id: second_specimen.id,
+                kind,
+            });
+        }
+    }
+    assert!(expected_events.len() > 1);
+    assert_eq!(take_events(&events), expected_events);
+
+    let sell_time = second_specimen.created_at;
+    sp.fishing_history_service()
+        .register_sell(&second_specimen, sell_time)
+        .unwrap();
+    assert_eq!(
+        take_events(&events),
+        vec![GameEvent::SpecimenSold {
+            specimen: second_specimen,
+            sell_time
+        }]
+    );
+}
+
+#[test]
+fn test_location_unlock_event() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let events = record_events(&sp);
+
+    let location = config.get_location_data(LOCATION_ID).unwrap();
+    let user_location = sp
+        .user_service()
+        .unlock_location(&user, location.clone())
+        .unwrap();
+    assert_eq!(
+        take_events(&events),
+        vec![GameEvent::LocationUnlocked {
+            location: user_location
+        }]
+    );
+
+    // Failed unlocks emit nothing
+    assert!(sp.user_service().unlock_location(&user, location).is_err());
+    assert!(take_events(&events).is_empty());
+}
+
+#[test]
+fn test_item_events() {
+    let config = mock_config();
+    let sp = mock_service_provider(config.clone());
+    let user = sp.user_service().create_and_save_user(1337).unwrap();
+    let events = record_events(&sp);
+
+    let bait_data = config.get_item_data(BAIT_ID).unwrap();
+    let bait = sp
+        .item_service()
+        .create_and_save_item_with_count(bait_data, &user, 2)
+        .unwrap();
+    assert_eq!(
+        take_events(&events),
+        vec![GameEvent::ItemReceived { item: bait.clone() }]
+    );
+
+    let used_bait = sp.item_service().use_as_bait(bait).unwrap().unwrap();
+    assert_eq!(
+        take_events(&events),
+        vec![GameEvent::ItemChanged {
+            item: used_bait.clone()
+        }]
+    );
+
+    let success = sp
+        .item_service()
+        .manipulate(
+            used_bait.clone(),
+            Box::new(move |item| item.use_as_bait(config.clone())),
+        )
+        .unwrap();
+    assert!(success.consume);
+    let events = take_events(&events);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], GameEvent::ItemConsumed { item } if item.id == used_bait.id));
+}
+
+#[test]
+fn test_events_delivered_after_commit() {
+    let sp = mock_default_service_provider();
+    let events = record_events(&sp);
+
+    let user = transaction(&sp.database(), || -> GameResult<_> {
+        let user = sp.user_service().create_and_save_user(1337)?;
+        assert!(events.lock().unwrap().is_empty());
+        Ok(user)
+    })
+    .unwrap();
+
+    assert_eq!(
+        take_events(&events),
+        vec![GameEvent::UserRegistered { user }]
+    );
+}
+
+#[test]
+fn test_events_discarded_on_rollback() {
+    let sp = mock_default_service_provider();
+    let events = record_events(&sp);
+
+    let user = transaction(&sp.database(), || -> GameResult<_> {
+        let user = sp.user_service().create_and_save_user(1337)?;
+
+        // Only the events of the rolled back savepoint are discarded
+        let inner_result = transaction(&sp.database(), || -> GameResult<()> {
+            sp.user_service().create_and_save_user(1338)?;
+            Err(GameResourceError::user_not_found(1338).into())
+        });
+        assert!(inner_result.is_err());
+        Ok(user)
+    })
+    .unwrap();
+    assert_eq!(
+        take_events(&events),
+        vec![GameEvent::UserRegistered { user }]
+    );
+
+    let result = transaction(&sp.database(), || -> GameResult<()> {
+        sp.user_service().create_and_save_user(1339)?;
+        Err(GameResourceError::user_not_found(1339).into())
+    });
+    assert!(result.is_err());
+    assert!(take_events(&events).is_empty());
+}
@@ -0,0 +1,200 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::location_data::LocationData;
+use crate::data::settings::{Settings, MAX_TIME_SPEED_MULTIPLIER};
+use crate::game::clock::FixedClock;
+use crate::game::game_time::GameTime;
+use crate::game::services::weather_service::{WeatherService, WeatherServiceInterface};
+use crate::game::systems::weather_system::WeatherSystem;
+use crate::tests::mock::mock_service_provider_with_clock;
+use chrono::{DateTime, TimeDelta, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn mock_config(time_speed_multiplier: f32, time_epoch: DateTime<Utc>) -> Arc<dyn ConfigInterface> {
+    let location = LocationData {
+        name: "Island".to_string(),
+        timezone: Tz::UTC,
+        ..Default::default()
+    };
+    let settings = Settings {
+        time_speed_multiplier,
+        time_epoch,
+        ..Default::default()
+    };
+
+    Config::builder()
+        .locations(HashMap::from([(1, location)]))
+        .settings(settings)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_conversion() {
+    let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let now = epoch + TimeDelta::hours(2);
+    let game_time = GameTime::new(Arc::new(FixedClock::new(now)), epoch, 12.0);
+
+    // Two real hours after the epoch, a full in-game day has passed
+    assert_eq!(game_time.real_now(), now);
+    assert_eq!(game_time.now().unwrap(), epoch + TimeDelta::days(1));
+    assert_eq!(
+        game_time.to_real_time(game_time.now().unwrap()).unwrap(),
+        now
+    );
+
+    // Times before the epoch are converted as well
+    let before_epoch = epoch - TimeDelta::hours(1);
+    assert_eq!(
+        game_time.to_game_time(before_epoch).unwrap(),
+        epoch - TimeDelta::hours(12)
+    );
+
+    assert_eq!(
+        game_time.to_game_duration(TimeDelta::minutes(5)),
+        TimeDelta::hours(1)
+    );
+    assert_eq!(
+        game_time.to_real_duration(TimeDelta::hours(1)),
+        TimeDelta::minutes(5)
+    );
+    assert_eq!(
+        game_time.elapsed_since(epoch + TimeDelta::hours(1)),
+        TimeDelta::hours(12)
+    );
+
+    // The timezone is kept
+    let berlin_time = now.with_timezone(&Tz::Europe__Berlin);
+    let berlin_game_time = game_time.to_game_time(berlin_time).unwrap();
+    assert_eq!(berlin_game_time.timezone(), Tz::Europe__Berlin);
+    assert_eq!(berlin_game_time, game_time.now().unwrap());
+}
+
+#[test]
+fn test_real_time_by_default() {
+    let now = Utc::now();
+    let clock = Arc::new(FixedClock::new(now));
+    let game_time = GameTime::from_settings(clock, &Settings::default());
+    assert_eq!(game_time.now().unwrap(), now);
+}
+
+#[test]
+fn test_accelerated_day_night_cycle() {
+    let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let clock = Arc::new(FixedClock::new(epoch));
+    let config = mock_config(24.0, epoch);
+    let sp = mock_service_provider_with_clock(config.clone(), clock.clone());
+    let location = config.get_location_data(1).unwrap();
+
+    // An in-game day passes every real hour, so half an hour later it's noon
+    clock.advance(TimeDelta::minutes(30));
+    let weather = sp
+        .weather_service()
+        .get_current_weather(location.clone())
+        .unwrap();
+    assert_eq!(weather.time.hour(), 12);
+    assert_eq!(
+        WeatherSystem::light_level(weather.time),
+        WeatherSystem::light_level(Tz::UTC.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap())
+    );
+
+    clock.advance(TimeDelta::minutes(30));
+    let weather = sp.weather_service().get_current_weather(location).unwrap();
+    assert_eq!(weather.time.hour(), 0);
+    assert_eq!(
+        weather.time,
+        (epoch + TimeDelta::days(1)).with_timezone(&Tz::UTC)
+    );
+}
+
+#[test]
+fn test_accelerated_seasons() {
+    let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let clock = Arc::new(FixedClock::new(epoch));
+    let config = mock_config(365.0, epoch);
+    let sp = mock_service_provider_with_clock(config.clone(), clock.clone());
+    let location = config.get_location_data(1).unwrap();
+
+    let weather = sp
+        .weather_service()
+        .get_current_weather(location.clone())
+        .unwrap();
+    let (season, _) = location.season_at(weather.time);
+    assert_eq!(weather.season, season);
+
+    // Six real hours are a quarter of an in-game year, so it's the next season
+    clock.advance(TimeDelta::hours(6));
+    let later_weather = sp
+        .weather_service()
+        .get_current_weather(location.clone())
+        .unwrap();
+    let (later_season, _) =
+        location.season_at((epoch + TimeDelta::hours(6 * 365)).with_timezone(&Tz::UTC));
+    assert_eq!(later_weather.season, later_season);
+    assert_ne!(later_weather.season, weather.season);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_season_lookups() {
+    let location = LocationData::default();
+    let time = Tz::UTC.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+    // The time used to be sped up since the UNIX epoch
+    let scaled_time = Tz::UTC.timestamp_opt(time.timestamp() * 4, 0).unwrap();
+    assert_eq!(
+        location.current_season(time, 4.0),
+        location.season_at(scaled_time)
+    );
+    assert_eq!(
+        location.full_season_information(time, 4.0).1,
+        location.season_at(scaled_time).0
+    );
+    assert_eq!(location.current_season(time, 1.0), location.season_at(time));
+}
+
+#[test]
+fn test_validation() {
+    for time_speed_multiplier in [
+        0.0,
+        -1.0,
+        f32::NAN,
+        f32::INFINITY,
+        MAX_TIME_SPEED_MULTIPLIER * 10.0,
+    ] {
+        let settings = Settings {
+            time_speed_multiplier,
+            ..Default::default()
+        };
+        let report = Config::builder().settings(settings).build().unwrap_err();
+        assert!(report
+            .errors()
+            .iter()
+            .any(|error| error.is_settings_invalid_time_speed_multiplier()));
+    }
+}
+
+#[test]
+fn test_out_of_range() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let clock = Arc::new(FixedClock::new(now));
+
+    // The fastest valid speed still works with the default epoch
+    let config = mock_config(MAX_TIME_SPEED_MULTIPLIER, DateTime::UNIX_EPOCH);
+    let game_time = Arc::new(GameTime::from_settings(clock.clone(), &config.settings()));
+    let game_now = game_time.now().unwrap();
+    assert_eq!(game_time.to_real_time(game_now).unwrap(), now);
+
+    // Faster speeds are rejected by the config, the conversion fails instead of panicking
+    let game_time = Arc::new(GameTime::new(clock, DateTime::UNIX_EPOCH, 10000.0));
+    let error = game_time.now().unwrap_err();
+    let input_error = error.as_input_error().unwrap();
+    assert!(input_error.is_time_out_of_range());
+    assert_eq!(input_error.get_time(), Some(now));
+
+    let weather_service = WeatherService::new(config.clone(), game_time);
+    let location = config.get_location_data(1).unwrap();
+    let error = weather_service.get_current_weather(location).unwrap_err();
+    assert!(error.as_input_error().unwrap().is_time_out_of_range());
+}
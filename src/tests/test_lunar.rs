@@ -0,0 +1,111 @@
+use crate::data::location_data::LocationData;
+use crate::enums::moon_phase::MoonPhase;
+use crate::enums::tide_state::TideState;
+use crate::game::systems::weather_system::config::WeatherSystemConfig;
+use crate::game::systems::weather_system::WeatherSystem;
+use crate::utils::lunar::{lunar_progress, moon_illumination, tide_level, tide_state};
+use chrono::{TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::sync::Arc;
+
+#[test]
+fn test_moon_phase() {
+    let full_moon = Utc.with_ymd_and_hms(2025, 1, 13, 22, 27, 0).unwrap();
+    let new_moon = Utc.with_ymd_and_hms(2025, 1, 29, 12, 36, 0).unwrap();
+    let first_quarter = Utc.with_ymd_and_hms(2025, 2, 5, 8, 2, 0).unwrap();
+
+    assert_eq!(
+        MoonPhase::from_progress(lunar_progress(&full_moon)),
+        MoonPhase::FullMoon
+    );
+    assert_eq!(
+        MoonPhase::from_progress(lunar_progress(&new_moon)),
+        MoonPhase::NewMoon
+    );
+    assert_eq!(
+        MoonPhase::from_progress(lunar_progress(&first_quarter)),
+        MoonPhase::FirstQuarter
+    );
+
+    assert!(moon_illumination(&full_moon) > 0.99);
+    assert!(moon_illumination(&new_moon) < 0.01);
+    assert!((moon_illumination(&first_quarter) - 0.5).abs() < 0.05);
+
+    assert_eq!(MoonPhase::from_progress(0.97), MoonPhase::NewMoon);
+    assert_eq!(MoonPhase::from_progress(0.4), MoonPhase::WaxingGibbous);
+    assert_eq!(MoonPhase::from_progress(0.85), MoonPhase::WaningCrescent);
+}
+
+#[test]
+fn test_tides() {
+    let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let samples: Vec<_> = (0..24 * 60)
+        .map(|minute| start + TimeDelta::minutes(minute))
+        .collect();
+
+    for time in &samples {
+        assert!((-1.0..=1.0).contains(&tide_level(time, 0.0)));
+    }
+
+    // Two high and two low tides a day, passing through rising and falling in between
+    let states: Vec<TideState> = samples.iter().map(|time| tide_state(time, 0.0)).collect();
+    let mut transitions: Vec<TideState> = states.clone();
+    transitions.dedup();
+    let high_tides = transitions
+        .iter()
+        .filter(|state| **state == TideState::High)
+        .count();
+    assert!((2..=3).contains(&high_tides));
+    for window in transitions.windows(2) {
+        let expected_next = match window[0] {
+            TideState::Low => TideState::Rising,
+            TideState::Rising => TideState::High,
+            TideState::High => TideState::Falling,
+            TideState::Falling => TideState::Low,
+        };
+        assert_eq!(window[1], expected_next);
+    }
+
+    // Spring tides around the new moon are stronger than neap tides around the first quarter
+    let max_level = |start: chrono::DateTime<Utc>| {
+        (0..13 * 60)
+            .map(|minute| tide_level(&(start + TimeDelta::minutes(minute)), 0.0))
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+    let new_moon = Utc.with_ymd_and_hms(2025, 1, 29, 12, 0, 0).unwrap();
+    let first_quarter = Utc.with_ymd_and_hms(2025, 2, 5, 8, 0, 0).unwrap();
+    assert!(max_level(new_moon) > 0.95);
+    assert!(max_level(first_quarter) < 0.6);
+}
+
+#[test]
+fn test_weather_moon_and_tides() {
+    let time = Tz::UTC.with_ymd_and_hms(2025, 1, 13, 22, 0, 0).unwrap();
+
+    let coastal = Arc::new(LocationData {
+        coastal: true,
+        longitude: Some(10.0),
+        ..Default::default()
+    });
+    let system = WeatherSystem::new(
+        WeatherSystemConfig::builder()
+            .with_location_data(coastal)
+            .build(),
+    );
+    let weather = system.get_weather(time);
+    assert_eq!(weather.moon_phase, MoonPhase::FullMoon);
+    assert!(weather.moon_illumination > 0.99);
+    assert_eq!(weather.tide_level, Some(tide_level(&time, 10.0) as f32));
+    assert_eq!(weather.tide_state, Some(tide_state(&time, 10.0)));
+
+    let inland = Arc::new(LocationData::default());
+    let system = WeatherSystem::new(
+        WeatherSystemConfig::builder()
+            .with_location_data(inland)
+            .build(),
+    );
+    let weather = system.get_weather(time);
+    assert_eq!(weather.moon_phase, MoonPhase::FullMoon);
+    assert_eq!(weather.tide_level, None);
+    assert_eq!(weather.tide_state, None);
+}
@@ -0,0 +1,167 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::encounter_data::EncounterData;
+use crate::data::location_data::LocationData;
+use crate::data::season_data::SeasonData;
+use crate::data::species_data::SpeciesData;
+use crate::game::interface::GameInterface;
+use crate::game::random_source::{RandomSource, SeededRandomSource};
+use crate::game::service_provider::ServiceProviderInterface;
+use crate::game::Game;
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+
+fn mock_config() -> Arc<dyn ConfigInterface> {
+    // A location where it never rains, so the species can always be encountered
+    let every_season = SeasonData {
+        rain_intensity_raining_threshold: 2.0,
+        ..Default::default()
+    };
+    let location = LocationData {
+        name: "Island".to_string(),
+        spring: every_season.clone(),
+        summer: every_season.clone(),
+        autumn: every_season.clone(),
+        winter: every_season,
+        ..Default::default()
+    };
+
+    // Several species with different rarities, so the encounter roll matters
+    let species = (1..=5)
+        .map(|species_id| {
+            let species_data = SpeciesData {
+                name: format!("Species {}", species_id),
+                min_size_baby_mm: 10,
+                max_size_baby_mm: 20,
+                min_size_adult_mm: 50,
+                max_size_adult_mm: 100,
+                min_lifespan_days: 10,
+                max_lifespan_days: 20,
+                encounters: vec![EncounterData {
+                    location_id: 1,
+                    min_time_hour: 0,
+                    max_time_hour: 23,
+                    rarity_level: species_id as u8 * 40,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            };
+            (species_id, species_data)
+        })
+        .collect();
+
+    Config::builder()
+        .locations(HashMap::from([(1, location)]))
+        .species(species)
+        .build()
+        .unwrap()
+}
+
+fn mock_game(random_source: Arc<dyn RandomSource>) -> Game {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let game = Game::new(&database_url, Some(mock_config()))
+        .unwrap()
+        .with_random_source(random_source);
+    game.database().read().unwrap().clear().unwrap();
+    game
+}
+
+#[test]
+fn test_seeded_random_source() {
+    let source = SeededRandomSource::new(42);
+    let same_source = SeededRandomSource::new(42);
+    let other_source = SeededRandomSource::new(43);
+
+    let seeds: Vec<u64> = (0..10).map(|_| source.next_seed()).collect();
+    let same_seeds: Vec<u64> = (0..10).map(|_| same_source.next_seed()).collect();
+    let other_seeds: Vec<u64> = (0..10).map(|_| other_source.next_seed()).collect();
+    assert_eq!(seeds, same_seeds);
+    assert_ne!(seeds, other_seeds);
+}
+
+#[test]
+fn test_encounter_with_seed() {
+    let game = mock_game(Arc::new(SeededRandomSource::new(42)));
+    let location = game.location_find(1).unwrap();
+    let weather = game.location_weather_current(location.clone()).unwrap();
+
+    for seed in 0..20 {
+        let species_id = game
+            .encounter_service()
+            .roll_encounter(
+                weather.time,
+                weather.clone(),
+                location.id,
+                Default::default(),
+                seed,
+            )
+            .unwrap();
+        let same_species_id = game
+            .encounter_service()
+            .roll_encounter(
+                weather.time,
+                weather.clone(),
+                location.id,
+                Default::default(),
+                seed,
+            )
+            .unwrap();
+        assert_eq!(species_id, same_species_id);
+    }
+}
+
+#[test]
+fn test_fish_with_seed() {
+    let game = mock_game(Arc::new(SeededRandomSource::new(42)));
+    let user = game.user_register(1337).unwrap();
+    let location = game.location_find(1).unwrap();
+    game.user_unlock_location(&user, location.clone()).unwrap();
+
+    let result = game
+        .user_fish_with_seed(&user, location.clone(), None, None, 1234)
+        .unwrap();
+    assert_eq!(result.seed, 1234);
+    assert_eq!(result.specimen.get_catch_seed(), Some(1234));
+
+    // The recorded seed reproduces the catch
+    let reproduced_result = game
+        .user_fish_with_seed(&user, location, None, None, 1234)
+        .unwrap();
+    assert_eq!(reproduced_result.species.id, result.species.id);
+    assert_eq!(
+        reproduced_result.specimen.catch_age,
+        result.specimen.catch_age
+    );
+    assert_eq!(
+        reproduced_result.specimen.size_adult_ratio,
+        result.specimen.size_adult_ratio
+    );
+}
+
+#[test]
+fn test_fish_with_random_source() {
+    let game = mock_game(Arc::new(SeededRandomSource::new(42)));
+    let user = game.user_register(1337).unwrap();
+    let location = game.location_find(1).unwrap();
+    game.user_unlock_location(&user, location.clone()).unwrap();
+
+    // Each catch uses the next seed of the random source
+    let expected_source = SeededRandomSource::new(42);
+    for _ in 0..3 {
+        let result = game.user_fish(&user, location.clone(), None, None).unwrap();
+        let expected_seed = expected_source.next_seed();
+        assert_eq!(result.seed, expected_seed);
+
+        let found_specimen = game
+            .specimen_repository()
+            .find(result.specimen.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(found_specimen.get_catch_seed(), Some(expected_seed));
+    }
+
+    let (specimen, _) = game
+        .user_catch_specific_specimen(&user, game.species_find(1).unwrap())
+        .unwrap();
+    assert_eq!(specimen.get_catch_seed(), Some(expected_source.next_seed()));
+}
@@ -0,0 +1,155 @@
+use crate::data::location_data::LocationData;
+use crate::enums::season::Season;
+use crate::game::systems::weather_system::config::WeatherSystemConfig;
+use crate::game::systems::weather_system::WeatherSystem;
+use crate::utils::solar::{solar_elevation_deg, sunrise_sunset};
+use chrono::{DateTime, NaiveDate, TimeDelta, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use std::sync::Arc;
+
+const BERLIN: (f64, f64) = (52.52, 13.405);
+const SYDNEY: (f64, f64) = (-33.87, 151.21);
+const TROMSO: (f64, f64) = (69.65, 18.96);
+
+fn assert_close(actual: DateTime<Utc>, expected: DateTime<Utc>) {
+    let difference = (actual - expected).abs();
+    assert!(
+        difference <= TimeDelta::minutes(3),
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+fn mock_location(timezone: Tz, coordinates: Option<(f64, f64)>) -> LocationData {
+    LocationData {
+        timezone,
+        latitude: coordinates.map(|(latitude, _)| latitude),
+        longitude: coordinates.map(|(_, longitude)| longitude),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_sunrise_sunset() {
+    let midsummer = NaiveDate::from_ymd_opt(2025, 6, 21).unwrap();
+    let (sunrise, sunset) = sunrise_sunset(midsummer, BERLIN.0, BERLIN.1).unwrap();
+    assert_close(
+        sunrise,
+        Utc.with_ymd_and_hms(2025, 6, 21, 2, 43, 0).unwrap(),
+    );
+    assert_close(
+        sunset,
+        Utc.with_ymd_and_hms(2025, 6, 21, 19, 33, 0).unwrap(),
+    );
+
+    // Winter on the southern hemisphere, the day starts on the previous UTC day
+    let (sunrise, sunset) = sunrise_sunset(midsummer, SYDNEY.0, SYDNEY.1).unwrap();
+    assert_close(
+        sunrise,
+        Utc.with_ymd_and_hms(2025, 6, 20, 20, 59, 0).unwrap(),
+    );
+    assert_close(sunset, Utc.with_ymd_and_hms(2025, 6, 21, 6, 54, 0).unwrap());
+
+    // Polar day and polar night
+    assert!(sunrise_sunset(midsummer, TROMSO.0, TROMSO.1).is_none());
+    let midwinter = NaiveDate::from_ymd_opt(2025, 12, 21).unwrap();
+    assert!(sunrise_sunset(midwinter, TROMSO.0, TROMSO.1).is_none());
+}
+
+#[test]
+fn test_solar_elevation() {
+    let (sunrise, sunset) = sunrise_sunset(
+        NaiveDate::from_ymd_opt(2025, 6, 21).unwrap(),
+        BERLIN.0,
+        BERLIN.1,
+    )
+    .unwrap();
+    let noon = sunrise + (sunset - sunrise) / 2;
+
+    let noon_elevation = solar_elevation_deg(noon, BERLIN.0, BERLIN.1);
+    assert!((noon_elevation - (90.0 - BERLIN.0 + 23.44)).abs() < 0.5);
+    assert!(solar_elevation_deg(sunrise, BERLIN.0, BERLIN.1).abs() < 1.0);
+    assert!(solar_elevation_deg(noon + TimeDelta::hours(12), BERLIN.0, BERLIN.1) < -10.0);
+}
+
+#[test]
+fn test_hemisphere_seasons() {
+    let north = mock_location(Tz::Europe__Berlin, Some(BERLIN));
+    let south = mock_location(Tz::Australia__Sydney, Some(SYDNEY));
+
+    let season_at = |location: &LocationData, month, day| {
+        let time = Tz::UTC
+            .with_ymd_and_hms(2025, month, day, 12, 0, 0)
+            .unwrap();
+        location.season_at(time).0
+    };
+
+    assert_eq!(season_at(&north, 4, 15), Season::Spring);
+    assert_eq!(season_at(&north, 7, 15), Season::Summer);
+    assert_eq!(season_at(&north, 10, 15), Season::Autumn);
+    assert_eq!(season_at(&north, 1, 15), Season::Winter);
+
+    assert_eq!(season_at(&south, 4, 15), Season::Autumn);
+    assert_eq!(season_at(&south, 7, 15), Season::Winter);
+    assert_eq!(season_at(&south, 10, 15), Season::Spring);
+    assert_eq!(season_at(&south, 1, 15), Season::Summer);
+
+    // Spring starts at the March equinox
+    let before_equinox = Tz::UTC.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap();
+    let after_equinox = Tz::UTC.with_ymd_and_hms(2025, 3, 21, 12, 0, 0).unwrap();
+    assert_eq!(north.season_at(before_equinox).0, Season::Winter);
+    assert_eq!(north.season_at(after_equinox).0, Season::Spring);
+}
+
+#[test]
+fn test_weather_daylight() {
+    let location = Arc::new(mock_location(Tz::Europe__Berlin, Some(BERLIN)));
+    let config = WeatherSystemConfig::builder()
+        .with_location_data(location.clone())
+        .build();
+    let system = WeatherSystem::new(config);
+
+    let noon = Tz::Europe__Berlin
+        .with_ymd_and_hms(2025, 6, 21, 13, 15, 0)
+        .unwrap();
+    let weather = system.get_weather(noon);
+    let (sunrise, sunset) = location.get_sunrise_sunset(noon).unwrap();
+    assert_eq!(weather.sunrise, Some(sunrise));
+    assert_eq!(weather.sunset, Some(sunset));
+    assert_eq!(sunrise.hour(), 4);
+    assert_eq!(sunset.hour(), 21);
+
+    assert!(system.location_light_level(noon) > 0.9);
+    assert_eq!(
+        system.location_light_level(noon + TimeDelta::hours(12)),
+        0.1
+    );
+
+    // Long summer days, short winter days
+    let winter_noon = noon - TimeDelta::days(182);
+    let (winter_sunrise, winter_sunset) = location.get_sunrise_sunset(winter_noon).unwrap();
+    assert!(sunset - sunrise > TimeDelta::hours(16));
+    assert!(winter_sunset - winter_sunrise < TimeDelta::hours(8));
+    assert!(system.location_light_level(winter_noon) < system.location_light_level(noon));
+}
+
+#[test]
+fn test_weather_daylight_without_coordinates() {
+    let location = Arc::new(mock_location(Tz::Europe__Berlin, None));
+    let config = WeatherSystemConfig::builder()
+        .with_location_data(location)
+        .build();
+    let system = WeatherSystem::new(config);
+
+    let noon = Tz::Europe__Berlin
+        .with_ymd_and_hms(2025, 6, 21, 12, 0, 0)
+        .unwrap();
+    let weather = system.get_weather(noon);
+    assert_eq!(weather.sunrise.map(|sunrise| sunrise.hour()), Some(6));
+    assert_eq!(weather.sunset.map(|sunset| sunset.hour()), Some(18));
+    assert_eq!(
+        system.location_light_level(noon),
+        WeatherSystem::light_level(noon)
+    );
+}
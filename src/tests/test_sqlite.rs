@@ -0,0 +1,349 @@
+use crate::config::{Config, ConfigBuilderInterface, ConfigInterface};
+use crate::data::achievement_data::{AchievementData, AchievementRequirement};
+use crate::data::encounter_data::EncounterData;
+use crate::data::item_data::ItemData;
+use crate::data::location_data::LocationData;
+use crate::data::season_data::SeasonData;
+use crate::data::species_data::SpeciesData;
+use crate::enums::leaderboard_kind::LeaderboardKind;
+use crate::enums::leaderboard_window::LeaderboardWindow;
+use crate::game::clock::FixedClock;
+use crate::game::interface::GameInterface;
+use crate::game::service_provider::ServiceProviderInterface;
+use crate::game::Game;
+use crate::models::item::attributes_container::ItemAttributesContainer;
+use crate::models::item::properties_container::ItemPropertiesContainer;
+use crate::models::pond::NewPond;
+use crate::models::specimen::NewSpecimen;
+use crate::tests::test_clock::assert_timestamps_follow_clock;
+use chrono::{TimeZone, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn mock_config() -> Arc<dyn ConfigInterface> {
+    // A location where it never rains, so the species can always be encountered
+    let every_season = SeasonData {
+        rain_intensity_raining_threshold: 2.0,
+        ..Default::default()
+    };
+    let location = LocationData {
+        name: "Island".to_string(),
+        spring: every_season.clone(),
+        summer: every_season.clone(),
+        autumn: every_season.clone(),
+        winter: every_season,
+        ..Default::default()
+    };
+
+    let rod = ItemData {
+        name: "Rod".to_string(),
+        attributes: ItemAttributesContainer::new()
+            .with_rod(1)
+            .with_purchasable(100),
+        default_properties: ItemPropertiesContainer::new().with_usage(0),
+        ..Default::default()
+    };
+
+    let species = SpeciesData {
+        name: "Salmon".to_string(),
+        // SQLite stores NaN as NULL, so the size ratios must be well-defined
+        min_size_baby_mm: 10,
+        max_size_baby_mm: 20,
+        min_size_adult_mm: 500,
+        max_size_adult_mm: 1000,
+        min_lifespan_days: 100,
+        max_lifespan_days: 200,
+        encounters: vec![EncounterData {
+            location_id: 1,
+            min_time_hour: 0,
+            max_time_hour: 23,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let achievement = |requirement| AchievementData {
+        requirement,
+        ..Default::default()
+    };
+    let achievements = HashMap::from([
+        (
+            1,
+            achievement(AchievementRequirement::LocationsUnlocked { count: None }),
+        ),
+        (
+            2,
+            achievement(AchievementRequirement::CatchCount {
+                count: 1,
+                species_id: None,
+            }),
+        ),
+        (
+            3,
+            achievement(AchievementRequirement::SellCount { count: 1 }),
+        ),
+        (
+            4,
+            achievement(AchievementRequirement::Purchase { item_id: None }),
+        ),
+    ]);
+
+    Config::builder()
+        .locations(HashMap::from([(1, location)]))
+        .species(HashMap::from([(1, species)]))
+        .items(HashMap::from([(1, rod)]))
+        .achievements(achievements)
+        .build()
+        .unwrap()
+}
+
+fn mock_sqlite_game() -> Game {
+    Game::new("sqlite::memory:", Some(mock_config())).unwrap()
+}
+
+#[test]
+fn test_unique_constraints() {
+    let game = mock_sqlite_game();
+
+    let user = game.user_register(1337).unwrap();
+    let error = game.user_register(1337).unwrap_err();
+    assert!(error.is_already_exists());
+
+    let location = game.location_find(1).unwrap();
+    game.user_unlock_location(&user, location.clone()).unwrap();
+    let error = game.user_unlock_location(&user, location).unwrap_err();
+    assert!(error.is_already_exists());
+
+    let error = game
+        .user_repository()
+        .unlock_location(user.id, 1)
+        .unwrap_err();
+    assert!(error
+        .get_database_error()
+        .unwrap()
+        .is_unique_constraint_violation());
+}
+
+#[test]
+fn test_foreign_keys() {
+    let game = mock_sqlite_game();
+
+    let error = game
+        .pond_repository()
+        .create(NewPond {
+            user_id: -1,
+            capacity: 1,
+        })
+        .unwrap_err();
+    assert!(error
+        .get_database_error()
+        .unwrap()
+        .is_foreign_key_violation());
+
+    let user = game.user_register(1337).unwrap();
+    let pond = game
+        .pond_repository()
+        .create(NewPond {
+            user_id: user.id,
+            capacity: 1,
+        })
+        .unwrap();
+    let mut specimen = game
+        .specimen_repository()
+        .create(NewSpecimen {
+            user_id: user.id,
+            species_id: 1,
+            size_baby_ratio: 0.5,
+            size_adult_ratio: 0.5,
+            lifespan_days_ratio: 0.5,
+            catch_age: 0.5,
+            catch_seed: None,
+        })
+        .unwrap();
+
+    // Deleting a pond releases its specimens
+    specimen.pond_id = Some(pond.id);
+    let specimen = game.specimen_repository().save(specimen).unwrap();
+    assert_eq!(
+        game.specimen_repository().count_by_pond(pond.id).unwrap(),
+        1
+    );
+    assert!(game.pond_repository().delete(pond).unwrap());
+    let found_specimen = game
+        .specimen_repository()
+        .find(specimen.id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(found_specimen.pond_id, None);
+}
+
+#[test]
+fn test_save() {
+    let game = mock_sqlite_game();
+
+    let mut user = game.user_register(1337).unwrap();
+    user.credits = 10;
+    let saved_user = game.user_save_checked(user.clone()).unwrap();
+    assert_eq!(saved_user.credits, 10);
+    assert_eq!(saved_user.version, 1);
+    assert_eq!(game.user_find(1337).unwrap().credits, 10);
+
+    let error = game.user_save_checked(user.clone()).unwrap_err();
+    assert!(error.is_stale_entity());
+    user.credits = 20;
+    let saved_user = game.user_save(user).unwrap();
+    assert_eq!(saved_user.credits, 20);
+    assert_eq!(saved_user.version, 2);
+}
+
+#[test]
+fn test_timestamps() {
+    let clock = Arc::new(FixedClock::new(
+        Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap(),
+    ));
+    let game = mock_sqlite_game().with_clock(clock.clone());
+    assert_timestamps_follow_clock(&game, &clock);
+}
+
+#[test]
+fn test_transactions() {
+    let game = mock_sqlite_game();
+
+    let user = game.user_register(1337).unwrap();
+    let user = game.user_repository().add_credits(user.id, 250).unwrap();
+    let rod = game.item_find(1).unwrap();
+    game.user_item_purchase(&user, rod.clone(), 1).unwrap();
+
+    // The second rod exceeds the max count, so the payment is rolled back
+    let error = game.user_item_purchase(&user, rod, 1).unwrap_err();
+    assert!(error.as_resource_error().is_some());
+    let found_user = game.user_find(1337).unwrap();
+    assert_eq!(found_user.credits, 150);
+
+    let items = game.item_repository().find_by_user(user.id).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        items[0].properties,
+        ItemPropertiesContainer::new().with_usage(0)
+    );
+}
+
+#[test]
+fn test_fishing() {
+    let game = mock_sqlite_game();
+
+    let user = game.user_register(1337).unwrap();
+    let location = game.location_find(1).unwrap();
+    let unlock_result = game.user_unlock_location(&user, location.clone()).unwrap();
+    assert_eq!(unlock_result.achievements[0].achievement.id, 1);
+
+    let result = game.user_fish(&user, location, None, None).unwrap();
+    assert_eq!(result.history_entry.caught_count, 1);
+    assert_eq!(result.specimen.user_id, user.id);
+    assert_eq!(result.achievements[0].achievement.id, 2);
+
+    let specimen = result.specimen;
+    let sell_result = game.user_sell_specimen(&user, specimen).unwrap();
+    assert_eq!(sell_result.user.credits, sell_result.credits);
+    assert_eq!(sell_result.achievements[0].achievement.id, 3);
+    assert!(game
+        .specimen_repository()
+        .find_by_user(&user)
+        .unwrap()
+        .is_empty());
+
+    let user = game.user_repository().add_credits(user.id, 100).unwrap();
+    let rod = game.item_find(1).unwrap();
+    let purchase_result = game.user_item_purchase(&user, rod, 1).unwrap();
+    assert_eq!(purchase_result.achievements[0].achievement.id, 4);
+
+    let achievements = game.user_get_achievements(&user).unwrap();
+    let achievement_ids: Vec<i32> = achievements
+        .iter()
+        .map(|unlock| unlock.achievement.id)
+        .collect();
+    assert_eq!(achievement_ids, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_leaderboards() {
+    let game = mock_sqlite_game();
+
+    let species = game.species_find(1).unwrap();
+    let user = game.user_register(1337).unwrap();
+    let user2 = game.user_register(1338).unwrap();
+    let (specimen, _) = game
+        .user_catch_specific_specimen(&user, species.clone())
+        .unwrap();
+    game.user_catch_specific_specimen(&user2, species.clone())
+        .unwrap();
+    game.user_catch_specific_specimen(&user2, species.clone())
+        .unwrap();
+    game.user_sell_specimen(&user, specimen).unwrap();
+
+    for window in [LeaderboardWindow::AllTime, LeaderboardWindow::ThisWeek] {
+        let catches = game
+            .leaderboard_get(LeaderboardKind::MostCatches, window, 0, 10)
+            .unwrap();
+        // The sold specimen still counts as a catch
+        assert_eq!(catches.total_entries, 2);
+        assert_eq!(catches.entries[0].external_id, 1338);
+        assert_eq!(catches.entries[0].value, 2);
+
+        let sold = game
+            .leaderboard_get(LeaderboardKind::MostSold, window, 0, 10)
+            .unwrap();
+        assert_eq!(sold.total_entries, 1);
+        assert_eq!(sold.entries[0].external_id, 1337);
+
+        let largest = game
+            .species_leaderboard(species.clone(), window, 0, 10)
+            .unwrap();
+        assert_eq!(largest.total_entries, 2);
+        assert!(
+            largest.entries[0].record.total_size_ratio
+                >= largest.entries[1].record.total_size_ratio
+        );
+    }
+
+    let credits = game
+        .leaderboard_get(
+            LeaderboardKind::MostCredits,
+            LeaderboardWindow::AllTime,
+            0,
+            1,
+        )
+        .unwrap();
+    assert_eq!(credits.total_entries, 2);
+    assert_eq!(credits.entries.len(), 1);
+    assert_eq!(credits.entries[0].external_id, 1337);
+    let error = game
+        .leaderboard_get(
+            LeaderboardKind::MostCredits,
+            LeaderboardWindow::ThisWeek,
+            0,
+            1,
+        )
+        .unwrap_err();
+    assert!(error.is_input_error());
+
+    let species_counts = game
+        .leaderboard_get(
+            LeaderboardKind::MostSpecies,
+            LeaderboardWindow::ThisSeason,
+            0,
+            10,
+        )
+        .unwrap();
+    assert_eq!(species_counts.total_entries, 2);
+    assert_eq!(species_counts.entries[0].value, 1);
+}
+
+#[test]
+fn test_clear() {
+    let game = mock_sqlite_game();
+
+    game.user_register(1337).unwrap();
+    game.database().read().unwrap().clear().unwrap();
+    assert!(game.user_find(1337).unwrap_err().is_not_found());
+}
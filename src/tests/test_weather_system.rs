@@ -0,0 +1,145 @@
+use crate::data::location_data::LocationData;
+use crate::data::season_data::SeasonData;
+use crate::enums::weather_condition::WeatherCondition;
+use crate::game::systems::weather_system::config::WeatherSystemConfig;
+use crate::game::systems::weather_system::WeatherSystem;
+use chrono::{TimeDelta, TimeZone};
+use chrono_tz::Tz;
+use std::sync::Arc;
+
+fn mock_weather_system(min_temp_c: f32, max_temp_c: f32) -> WeatherSystem {
+    let every_season = SeasonData {
+        min_temp_c,
+        max_temp_c,
+        rain_intensity_raining_threshold: 0.4,
+        moisture_raining_threshold: 0.75,
+        cloudiness_raining_threshold: 0.6,
+    };
+    let location_data = LocationData {
+        weather_seed: 42,
+        spring: every_season.clone(),
+        summer: every_season.clone(),
+        autumn: every_season.clone(),
+        winter: every_season,
+        ..Default::default()
+    };
+    let config = WeatherSystemConfig::builder()
+        .with_location_data(Arc::new(location_data))
+        .with_max_wind_speed_kmh(50.0)
+        .build();
+    WeatherSystem::new(config)
+}
+
+fn sample_times() -> impl Iterator<Item = chrono::DateTime<Tz>> {
+    let start = Tz::UTC.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    (0..24 * 365).map(move |hour| start + TimeDelta::hours(hour))
+}
+
+#[test]
+fn test_wind() {
+    let system = mock_weather_system(0.0, 20.0);
+
+    let mut directions = Vec::new();
+    for time in sample_times() {
+        let weather = system.get_weather(time);
+        assert!((0.0..=50.0).contains(&weather.wind_speed_kmh));
+        assert!((0.0..360.0).contains(&weather.wind_direction_deg));
+        directions.push(weather.wind_direction_deg);
+    }
+
+    // The wind comes from every quarter of the compass at some point
+    for quarter in 0..4 {
+        let min = quarter as f32 * 90.0;
+        assert!(directions
+            .iter()
+            .any(|direction| (min..min + 90.0).contains(direction)));
+    }
+}
+
+#[test]
+fn test_weather_conditions() {
+    let system = mock_weather_system(5.0, 20.0);
+
+    let mut conditions = Vec::new();
+    for time in sample_times() {
+        let weather = system.get_weather(time);
+        assert_eq!(weather.is_raining, weather.condition.is_precipitation());
+        assert_ne!(weather.condition, WeatherCondition::Snow);
+        if weather.condition == WeatherCondition::Fog {
+            assert!(weather.light_level <= 0.35);
+        }
+        conditions.push(weather.condition);
+    }
+
+    for condition in [
+        WeatherCondition::Clear,
+        WeatherCondition::Cloudy,
+        WeatherCondition::Fog,
+        WeatherCondition::Rain,
+        WeatherCondition::Thunderstorm,
+    ] {
+        assert!(conditions.contains(&condition), "{:?}", condition);
+    }
+}
+
+#[test]
+fn test_snow_below_freezing() {
+    let system = mock_weather_system(-20.0, -5.0);
+
+    let mut snowed = false;
+    for time in sample_times() {
+        let weather = system.get_weather(time);
+        if weather.is_raining {
+            assert_eq!(weather.condition, WeatherCondition::Snow);
+            snowed = true;
+        }
+    }
+    assert!(snowed);
+}
+
+#[test]
+fn test_water_temperature() {
+    let system = mock_weather_system(0.0, 20.0);
+
+    let weather: Vec<_> = sample_times()
+        .map(|time| system.get_weather(time))
+        .collect();
+    for day in weather.chunks(24) {
+        let swing = |temperatures: Vec<f32>| {
+            let min = temperatures.iter().copied().fold(f32::INFINITY, f32::min);
+            let max = temperatures
+                .iter()
+                .copied()
+                .fold(f32::NEG_INFINITY, f32::max);
+            max - min
+        };
+        let air_swing = swing(day.iter().map(|weather| weather.temperature_c).collect());
+        let water_swing = swing(
+            day.iter()
+                .map(|weather| weather.water_temperature_c)
+                .collect(),
+        );
+
+        // The water doesn't cool down at night like the air does
+        assert!(water_swing < air_swing);
+        for weather in day {
+            assert!((0.0..=20.0).contains(&weather.water_temperature_c));
+        }
+    }
+}
+
+#[test]
+fn test_large_weather_seed() {
+    let location_data = LocationData {
+        weather_seed: u32::MAX,
+        ..Default::default()
+    };
+    let config = WeatherSystemConfig::builder()
+        .with_location_data(Arc::new(location_data))
+        .build();
+
+    // The seeds derived from it wrap around instead of overflowing
+    let system = WeatherSystem::new(config);
+    let weather = system.get_weather(sample_times().next().unwrap());
+    assert!(weather.temperature_c.is_finite());
+}
@@ -1,20 +1,42 @@
-use crate::database::DatabaseInterface;
+use crate::database::{DatabaseConnection, DatabaseInterface};
 use crate::game::errors::database::GameDatabaseError;
 use crate::game::errors::repository::GameRepositoryError;
 use crate::traits::model::Model;
-use diesel::r2d2::{ConnectionManager, PooledConnection};
-use diesel::PgConnection;
 use std::sync::{Arc, RwLock};
 
 pub trait Repository<T: Model>: Send + Sync {
     fn get_db(&self) -> Arc<RwLock<dyn DatabaseInterface>>;
     fn create(&self, new_entity: T::InsertType) -> Result<T, GameRepositoryError>;
     fn find(&self, id: T::PrimaryKeyType) -> Result<Option<T>, GameRepositoryError>;
-    fn save(&self, entity: T) -> Result<T, GameRepositoryError>;
+    /// Overwrites the row of the entity, even if it was saved since the entity was loaded
+    fn save(&self, entity: T) -> Result<T, GameRepositoryError> {
+        self.update(entity, false)
+    }
+    /// Saves the entity only if its row wasn't saved since the entity was loaded,
+    /// a stale entity is rejected so the caller can load it again and retry
+    fn save_checked(&self, entity: T) -> Result<T, GameRepositoryError> {
+        self.update(entity, true)
+    }
+    /// Updates the row of the entity, `check_stale` decides whether stale entities are rejected
+    fn update(&self, entity: T, check_stale: bool) -> Result<T, GameRepositoryError>;
     fn delete(&self, entity: T) -> Result<bool, GameRepositoryError>;
-    fn get_connection(
-        &self,
-    ) -> Result<PooledConnection<ConnectionManager<PgConnection>>, GameDatabaseError> {
+    /// The error for a save which did not update any row, because the entity
+    /// was either modified since it was loaded or does not exist anymore
+    fn save_conflict_error(&self, id: T::PrimaryKeyType) -> GameRepositoryError
+    where
+        T::PrimaryKeyType: Into<i64>,
+    {
+        let entity = std::any::type_name::<T>()
+            .rsplit("::")
+            .next()
+            .unwrap_or_default();
+        match self.find(id) {
+            Ok(Some(_)) => GameRepositoryError::stale_entity(entity, id.into()),
+            Ok(None) => GameDatabaseError::not_found().into(),
+            Err(error) => error,
+        }
+    }
+    fn get_connection(&self) -> Result<DatabaseConnection, GameDatabaseError> {
         self.get_db()
             .read()
             .expect("Failed to get read lock on DB")
@@ -1,2 +1,4 @@
+pub mod lunar;
 pub mod math;
 pub mod random;
+pub mod solar;
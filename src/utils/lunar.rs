@@ -0,0 +1,53 @@
+//! Simplified lunar cycle and a semi-diurnal tide model, accurate to about a day for moon phases.
+use crate::enums::tide_state::TideState;
+use chrono::{DateTime, TimeZone};
+use std::f64::consts::TAU;
+
+const SECONDS_PER_SYNODIC_MONTH: f64 = 29.530_588_853 * 86_400.0;
+/// A new moon on 2000-01-06 18:14 UTC
+const NEW_MOON_2000_TIMESTAMP: i64 = 947_182_440;
+/// The time between two high tides, half a lunar day
+const SECONDS_PER_TIDE_CYCLE: f64 = 44_712.0;
+/// Tide levels above this fraction of the current tide range count as high or low tide
+const SLACK_TIDE_THRESHOLD: f64 = 0.7;
+
+/// Progress (0-1) through the current lunar cycle, 0 is new moon and 0.5 is full moon
+pub fn lunar_progress<T: TimeZone>(time: &DateTime<T>) -> f64 {
+    let seconds_since_new_moon = (time.timestamp() - NEW_MOON_2000_TIMESTAMP) as f64;
+    seconds_since_new_moon.rem_euclid(SECONDS_PER_SYNODIC_MONTH) / SECONDS_PER_SYNODIC_MONTH
+}
+
+/// The illuminated fraction (0-1) of the moon
+pub fn moon_illumination<T: TimeZone>(time: &DateTime<T>) -> f64 {
+    (1.0 - (lunar_progress(time) * TAU).cos()) / 2.0
+}
+
+fn tide_angle<T: TimeZone>(time: &DateTime<T>, longitude: f64) -> f64 {
+    // The tidal bulge follows the moon westwards, so high tide comes later further west
+    let seconds = time.timestamp() as f64 + longitude / 360.0 * 2.0 * SECONDS_PER_TIDE_CYCLE;
+    (seconds / SECONDS_PER_TIDE_CYCLE) * TAU
+}
+
+/// The tide range (0.5-1) at the time, spring tides around new and full moon are the strongest
+fn tide_range<T: TimeZone>(time: &DateTime<T>) -> f64 {
+    0.75 + 0.25 * (lunar_progress(time) * 2.0 * TAU).cos()
+}
+
+/// The water level (-1 to 1) relative to mean sea level
+pub fn tide_level<T: TimeZone>(time: &DateTime<T>, longitude: f64) -> f64 {
+    tide_range(time) * tide_angle(time, longitude).cos()
+}
+
+pub fn tide_state<T: TimeZone>(time: &DateTime<T>, longitude: f64) -> TideState {
+    let relative_level = tide_angle(time, longitude).cos();
+    if relative_level >= SLACK_TIDE_THRESHOLD {
+        TideState::High
+    } else if relative_level <= -SLACK_TIDE_THRESHOLD {
+        TideState::Low
+    } else if tide_angle(time, longitude).sin() > 0.0 {
+        // The level is going down while the angle moves from high (0) to low (PI)
+        TideState::Falling
+    } else {
+        TideState::Rising
+    }
+}
@@ -1,25 +1,35 @@
 use rand::distr::Distribution;
-use rand::rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rand_distr::Normal;
 
-pub fn random_normal(min: f32, max: f32) -> f32 {
-    let mut rng = rng();
+/// Stream of the random values used to roll an encounter
+pub const ENCOUNTER_STREAM: u64 = 1;
+/// Stream of the random values used to generate a specimen
+pub const SPECIMEN_STREAM: u64 = 2;
+
+/// Creates the RNG of one stream of a seeded action,
+/// so e.g. rolling the encounter and generating the specimen of a catch don't draw the same values
+pub fn seeded_rng(seed: u64, stream: u64) -> StdRng {
+    StdRng::seed_from_u64(seed ^ stream.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+pub fn random_normal<R: Rng + ?Sized>(rng: &mut R, min: f32, max: f32) -> f32 {
     let mean = (min + max) / 2.0;
     let std_dev = (min - max) / 6.0;
 
     let normal = Normal::new(mean, std_dev).unwrap();
-    let size = normal.sample(&mut rng);
+    let size = normal.sample(rng);
 
     size.clamp(min, max)
 }
 
-pub fn random_normal_01() -> f32 {
-    let mut rng = rng();
+pub fn random_normal_01<R: Rng + ?Sized>(rng: &mut R) -> f32 {
     let mean = 0.5;
     let std_dev = 1.0 / 6.0;
 
     let normal = Normal::new(mean, std_dev).unwrap();
-    let size: f32 = normal.sample(&mut rng);
+    let size: f32 = normal.sample(rng);
 
     size.clamp(0.0, 1.0)
 }
@@ -0,0 +1,78 @@
+//! Simplified solar geometry, accurate to about a minute for sunrise and sunset.
+//! See https://en.wikipedia.org/wiki/Sunrise_equation
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+
+const JULIAN_DAY_J2000: f64 = 2_451_545.0;
+const JULIAN_DAY_UNIX_EPOCH: f64 = 2_440_587.5;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const EARTH_AXIAL_TILT_DEG: f64 = 23.4397;
+/// The sun is considered risen when its upper edge passes the horizon, including refraction
+const SUNRISE_ELEVATION_DEG: f64 = -0.833;
+
+fn to_julian_day(time: DateTime<Utc>) -> f64 {
+    time.timestamp_millis() as f64 / 1000.0 / SECONDS_PER_DAY + JULIAN_DAY_UNIX_EPOCH
+}
+
+fn from_julian_day(julian_day: f64) -> DateTime<Utc> {
+    let millis = ((julian_day - JULIAN_DAY_UNIX_EPOCH) * SECONDS_PER_DAY * 1000.0).round();
+    DateTime::UNIX_EPOCH + TimeDelta::milliseconds(millis as i64)
+}
+
+/// The julian day of the solar noon and the declination of the sun on the given day since J2000
+fn solar_transit_and_declination(day: f64, longitude: f64) -> (f64, f64) {
+    let mean_solar_time = day - longitude / 360.0;
+    let mean_anomaly = (357.5291 + 0.985_600_28 * mean_solar_time).rem_euclid(360.0);
+    let mean_anomaly_rad = mean_anomaly.to_radians();
+    let center = 1.9148 * mean_anomaly_rad.sin()
+        + 0.02 * (2.0 * mean_anomaly_rad).sin()
+        + 0.0003 * (3.0 * mean_anomaly_rad).sin();
+    let ecliptic_longitude_rad = (mean_anomaly + center + 180.0 + 102.9372)
+        .rem_euclid(360.0)
+        .to_radians();
+
+    let transit = JULIAN_DAY_J2000 + mean_solar_time + 0.0053 * mean_anomaly_rad.sin()
+        - 0.0069 * (2.0 * ecliptic_longitude_rad).sin();
+    let declination =
+        (ecliptic_longitude_rad.sin() * EARTH_AXIAL_TILT_DEG.to_radians().sin()).asin();
+
+    (transit, declination)
+}
+
+/// Sunrise and sunset on the given date, None during polar day or polar night
+pub fn sunrise_sunset(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let noon = date.and_hms_opt(12, 0, 0)?.and_utc();
+    let day = (to_julian_day(noon) - JULIAN_DAY_J2000 + 0.0008).round();
+    let (transit, declination) = solar_transit_and_declination(day, longitude);
+
+    let latitude_rad = latitude.to_radians();
+    let cos_hour_angle = (SUNRISE_ELEVATION_DEG.to_radians().sin()
+        - latitude_rad.sin() * declination.sin())
+        / (latitude_rad.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&cos_hour_angle) {
+        return None;
+    }
+
+    let hour_angle_days = cos_hour_angle.acos().to_degrees() / 360.0;
+    Some((
+        from_julian_day(transit - hour_angle_days),
+        from_julian_day(transit + hour_angle_days),
+    ))
+}
+
+/// The angle of the sun above the horizon in degrees, negative when it's below
+pub fn solar_elevation_deg(time: DateTime<Utc>, latitude: f64, longitude: f64) -> f64 {
+    let julian_day = to_julian_day(time);
+    let day = (julian_day - JULIAN_DAY_J2000 + longitude / 360.0).round();
+    let (transit, declination) = solar_transit_and_declination(day, longitude);
+    let hour_angle = ((julian_day - transit) * 360.0).to_radians();
+
+    let latitude_rad = latitude.to_radians();
+    (latitude_rad.sin() * declination.sin()
+        + latitude_rad.cos() * declination.cos() * hour_angle.cos())
+    .asin()
+    .to_degrees()
+}